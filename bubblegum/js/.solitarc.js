@@ -5,11 +5,19 @@ const idlDir = path.join(__dirname, 'idl');
 const sdkDir = path.join(__dirname, 'src', 'generated');
 const binaryInstallDir = path.join(__dirname, '.crates');
 
+// `BubblegumEvent` only exists to decode the events the program logs (events are not IDL
+// types), so it has no place in the generated client.
+const idlHook = (idl) => {
+  idl.types = idl.types.filter((ty) => ty.name !== 'BubblegumEvent');
+  return idl;
+};
+
 module.exports = {
   idlGenerator: 'anchor',
   programName: 'bubblegum',
   programId: 'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY',
   idlDir,
+  idlHook,
   sdkDir,
   binaryInstallDir,
   programDir,
@@ -8,6 +8,7 @@
 import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
+import { LoggingMode, loggingModeBeet } from '../types/LoggingMode'
 
 /**
  * Arguments used to create {@link TreeConfig}
@@ -19,6 +20,14 @@ export type TreeConfigArgs = {
   treeDelegate: web3.PublicKey
   totalMintCapacity: beet.bignum
   numMinted: beet.bignum
+  isHashOnly: boolean
+  loggingMode: LoggingMode
+  isNonTransferable: boolean
+  isCreatorImmutable: boolean
+  programVersion: number
+  isPublic: boolean
+  companionAccounts: number
+  isPaused: boolean
 }
 
 export const treeConfigDiscriminator = [122, 245, 175, 248, 171, 34, 0, 207]
@@ -34,7 +43,15 @@ export class TreeConfig implements TreeConfigArgs {
     readonly treeCreator: web3.PublicKey,
     readonly treeDelegate: web3.PublicKey,
     readonly totalMintCapacity: beet.bignum,
-    readonly numMinted: beet.bignum
+    readonly numMinted: beet.bignum,
+    readonly isHashOnly: boolean,
+    readonly loggingMode: LoggingMode,
+    readonly isNonTransferable: boolean,
+    readonly isCreatorImmutable: boolean,
+    readonly programVersion: number,
+    readonly isPublic: boolean,
+    readonly companionAccounts: number,
+    readonly isPaused: boolean
   ) {}
 
   /**
@@ -45,7 +62,15 @@ export class TreeConfig implements TreeConfigArgs {
       args.treeCreator,
       args.treeDelegate,
       args.totalMintCapacity,
-      args.numMinted
+      args.numMinted,
+      args.isHashOnly,
+      args.loggingMode,
+      args.isNonTransferable,
+      args.isCreatorImmutable,
+      args.programVersion,
+      args.isPublic,
+      args.companionAccounts,
+      args.isPaused
     )
   }
 
@@ -172,6 +197,14 @@ export class TreeConfig implements TreeConfigArgs {
         }
         return x
       })(),
+      isHashOnly: this.isHashOnly,
+      loggingMode: 'LoggingMode.' + LoggingMode[this.loggingMode],
+      isNonTransferable: this.isNonTransferable,
+      isCreatorImmutable: this.isCreatorImmutable,
+      programVersion: this.programVersion,
+      isPublic: this.isPublic,
+      companionAccounts: this.companionAccounts,
+      isPaused: this.isPaused,
     }
   }
 }
@@ -192,6 +225,14 @@ export const treeConfigBeet = new beet.BeetStruct<
     ['treeDelegate', beetSolana.publicKey],
     ['totalMintCapacity', beet.u64],
     ['numMinted', beet.u64],
+    ['isHashOnly', beet.bool],
+    ['loggingMode', loggingModeBeet],
+    ['isNonTransferable', beet.bool],
+    ['isCreatorImmutable', beet.bool],
+    ['programVersion', beet.u8],
+    ['isPublic', beet.bool],
+    ['companionAccounts', beet.u8],
+    ['isPaused', beet.bool],
   ],
   TreeConfig.fromArgs,
   'TreeConfig'
@@ -1,7 +1,55 @@
+export * from './AssetReceipt'
+export * from './BurnReceipt'
+export * from './CreatorTransfer'
+export * from './DelegateExpiry'
+export * from './DenyList'
+export * from './FeatureFlags'
+export * from './LeafFreeze'
+export * from './MetadataProposal'
+export * from './MintPrice'
+export * from './OnChainMetadata'
+export * from './PendingTransfer'
+export * from './SoulboundOverride'
 export * from './TreeConfig'
+export * from './TreeDelegateRecord'
+export * from './TreeDenyList'
+export * from './TreeMetadata'
 export * from './Voucher'
 
 import { TreeConfig } from './TreeConfig'
+import { TreeMetadata } from './TreeMetadata'
 import { Voucher } from './Voucher'
+import { PendingTransfer } from './PendingTransfer'
+import { SoulboundOverride } from './SoulboundOverride'
+import { DelegateExpiry } from './DelegateExpiry'
+import { LeafFreeze } from './LeafFreeze'
+import { MintPrice } from './MintPrice'
+import { OnChainMetadata } from './OnChainMetadata'
+import { AssetReceipt } from './AssetReceipt'
+import { BurnReceipt } from './BurnReceipt'
+import { CreatorTransfer } from './CreatorTransfer'
+import { MetadataProposal } from './MetadataProposal'
+import { DenyList } from './DenyList'
+import { TreeDenyList } from './TreeDenyList'
+import { TreeDelegateRecord } from './TreeDelegateRecord'
+import { FeatureFlags } from './FeatureFlags'
 
-export const accountProviders = { TreeConfig, Voucher }
+export const accountProviders = {
+  TreeConfig,
+  TreeMetadata,
+  Voucher,
+  PendingTransfer,
+  SoulboundOverride,
+  DelegateExpiry,
+  LeafFreeze,
+  MintPrice,
+  OnChainMetadata,
+  AssetReceipt,
+  BurnReceipt,
+  CreatorTransfer,
+  MetadataProposal,
+  DenyList,
+  TreeDenyList,
+  TreeDelegateRecord,
+  FeatureFlags,
+}
@@ -622,6 +622,1165 @@ createErrorFromNameLookup.set(
   () => new LeafAuthorityMustSignError()
 )
 
+/**
+ * TreeNameTooLong: 'Tree name is too long'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeNameTooLongError extends Error {
+  readonly code: number = 0x178a
+  readonly name: string = 'TreeNameTooLong'
+  constructor() {
+    super('Tree name is too long')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeNameTooLongError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178a, () => new TreeNameTooLongError())
+createErrorFromNameLookup.set(
+  'TreeNameTooLong',
+  () => new TreeNameTooLongError()
+)
+
+/**
+ * TreeUriTooLong: 'Tree uri is too long'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeUriTooLongError extends Error {
+  readonly code: number = 0x178b
+  readonly name: string = 'TreeUriTooLong'
+  constructor() {
+    super('Tree uri is too long')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeUriTooLongError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178b, () => new TreeUriTooLongError())
+createErrorFromNameLookup.set('TreeUriTooLong', () => new TreeUriTooLongError())
+
+/**
+ * TreeNotHashOnly: 'Tree does not accept hashed mints'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeNotHashOnlyError extends Error {
+  readonly code: number = 0x178c
+  readonly name: string = 'TreeNotHashOnly'
+  constructor() {
+    super('Tree does not accept hashed mints')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeNotHashOnlyError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178c, () => new TreeNotHashOnlyError())
+createErrorFromNameLookup.set(
+  'TreeNotHashOnly',
+  () => new TreeNotHashOnlyError()
+)
+
+/**
+ * InsufficientTreeAuthorityFunds: 'Withdrawal would leave the tree authority below rent exemption'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InsufficientTreeAuthorityFundsError extends Error {
+  readonly code: number = 0x178d
+  readonly name: string = 'InsufficientTreeAuthorityFunds'
+  constructor() {
+    super('Withdrawal would leave the tree authority below rent exemption')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InsufficientTreeAuthorityFundsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x178d,
+  () => new InsufficientTreeAuthorityFundsError()
+)
+createErrorFromNameLookup.set(
+  'InsufficientTreeAuthorityFunds',
+  () => new InsufficientTreeAuthorityFundsError()
+)
+
+/**
+ * InvalidProofLength: 'Proof must have a node for every level of the tree below its canopy'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidProofLengthError extends Error {
+  readonly code: number = 0x178e
+  readonly name: string = 'InvalidProofLength'
+  constructor() {
+    super(
+      'Proof must have a node for every level of the tree below its canopy'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidProofLengthError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178e, () => new InvalidProofLengthError())
+createErrorFromNameLookup.set(
+  'InvalidProofLength',
+  () => new InvalidProofLengthError()
+)
+
+/**
+ * LeafIndexNonceMismatch: 'Leaf index does not match the nonce'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class LeafIndexNonceMismatchError extends Error {
+  readonly code: number = 0x178f
+  readonly name: string = 'LeafIndexNonceMismatch'
+  constructor() {
+    super('Leaf index does not match the nonce')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, LeafIndexNonceMismatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178f, () => new LeafIndexNonceMismatchError())
+createErrorFromNameLookup.set(
+  'LeafIndexNonceMismatch',
+  () => new LeafIndexNonceMismatchError()
+)
+
+/**
+ * LeafBeingRedeemed: 'Leaf is being redeemed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class LeafBeingRedeemedError extends Error {
+  readonly code: number = 0x1790
+  readonly name: string = 'LeafBeingRedeemed'
+  constructor() {
+    super('Leaf is being redeemed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, LeafBeingRedeemedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1790, () => new LeafBeingRedeemedError())
+createErrorFromNameLookup.set(
+  'LeafBeingRedeemed',
+  () => new LeafBeingRedeemedError()
+)
+
+/**
+ * TransferStillLocked: 'Transfer cannot be claimed before its unlock slot'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransferStillLockedError extends Error {
+  readonly code: number = 0x1791
+  readonly name: string = 'TransferStillLocked'
+  constructor() {
+    super('Transfer cannot be claimed before its unlock slot')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransferStillLockedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1791, () => new TransferStillLockedError())
+createErrorFromNameLookup.set(
+  'TransferStillLocked',
+  () => new TransferStillLockedError()
+)
+
+/**
+ * TransferUnlocked: 'Transfer cannot be cancelled after its unlock slot'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransferUnlockedError extends Error {
+  readonly code: number = 0x1792
+  readonly name: string = 'TransferUnlocked'
+  constructor() {
+    super('Transfer cannot be cancelled after its unlock slot')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransferUnlockedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1792, () => new TransferUnlockedError())
+createErrorFromNameLookup.set(
+  'TransferUnlocked',
+  () => new TransferUnlockedError()
+)
+
+/**
+ * InvalidAirdropBatchSize: 'Airdrop must have between one and MAX_AIRDROP_BATCH_SIZE owners'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidAirdropBatchSizeError extends Error {
+  readonly code: number = 0x1793
+  readonly name: string = 'InvalidAirdropBatchSize'
+  constructor() {
+    super('Airdrop must have between one and MAX_AIRDROP_BATCH_SIZE owners')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidAirdropBatchSizeError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1793, () => new InvalidAirdropBatchSizeError())
+createErrorFromNameLookup.set(
+  'InvalidAirdropBatchSize',
+  () => new InvalidAirdropBatchSizeError()
+)
+
+/**
+ * AssetIsSoulbound: 'Asset is soulbound and cannot be transferred'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AssetIsSoulboundError extends Error {
+  readonly code: number = 0x1794
+  readonly name: string = 'AssetIsSoulbound'
+  constructor() {
+    super('Asset is soulbound and cannot be transferred')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AssetIsSoulboundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1794, () => new AssetIsSoulboundError())
+createErrorFromNameLookup.set(
+  'AssetIsSoulbound',
+  () => new AssetIsSoulboundError()
+)
+
+/**
+ * DelegateExpired: 'Leaf delegate has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DelegateExpiredError extends Error {
+  readonly code: number = 0x1795
+  readonly name: string = 'DelegateExpired'
+  constructor() {
+    super('Leaf delegate has expired')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DelegateExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1795, () => new DelegateExpiredError())
+createErrorFromNameLookup.set(
+  'DelegateExpired',
+  () => new DelegateExpiredError()
+)
+
+/**
+ * OnChainMetadataUriMismatch: 'Asset uri does not commit to the on-chain metadata'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OnChainMetadataUriMismatchError extends Error {
+  readonly code: number = 0x1796
+  readonly name: string = 'OnChainMetadataUriMismatch'
+  constructor() {
+    super('Asset uri does not commit to the on-chain metadata')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OnChainMetadataUriMismatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x1796,
+  () => new OnChainMetadataUriMismatchError()
+)
+createErrorFromNameLookup.set(
+  'OnChainMetadataUriMismatch',
+  () => new OnChainMetadataUriMismatchError()
+)
+
+/**
+ * OnChainMetadataTooLarge: 'On-chain metadata is too large'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OnChainMetadataTooLargeError extends Error {
+  readonly code: number = 0x1797
+  readonly name: string = 'OnChainMetadataTooLarge'
+  constructor() {
+    super('On-chain metadata is too large')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OnChainMetadataTooLargeError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1797, () => new OnChainMetadataTooLargeError())
+createErrorFromNameLookup.set(
+  'OnChainMetadataTooLarge',
+  () => new OnChainMetadataTooLargeError()
+)
+
+/**
+ * InvalidMetadataChunkOffset: 'Metadata chunk starts past the bytes written so far'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMetadataChunkOffsetError extends Error {
+  readonly code: number = 0x1798
+  readonly name: string = 'InvalidMetadataChunkOffset'
+  constructor() {
+    super('Metadata chunk starts past the bytes written so far')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMetadataChunkOffsetError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x1798,
+  () => new InvalidMetadataChunkOffsetError()
+)
+createErrorFromNameLookup.set(
+  'InvalidMetadataChunkOffset',
+  () => new InvalidMetadataChunkOffsetError()
+)
+
+/**
+ * OnChainMetadataHashMismatch: 'On-chain metadata does not match its hash'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OnChainMetadataHashMismatchError extends Error {
+  readonly code: number = 0x1799
+  readonly name: string = 'OnChainMetadataHashMismatch'
+  constructor() {
+    super('On-chain metadata does not match its hash')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OnChainMetadataHashMismatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x1799,
+  () => new OnChainMetadataHashMismatchError()
+)
+createErrorFromNameLookup.set(
+  'OnChainMetadataHashMismatch',
+  () => new OnChainMetadataHashMismatchError()
+)
+
+/**
+ * CreatorsNotSorted: 'Creators must be sorted by address'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CreatorsNotSortedError extends Error {
+  readonly code: number = 0x179a
+  readonly name: string = 'CreatorsNotSorted'
+  constructor() {
+    super('Creators must be sorted by address')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CreatorsNotSortedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179a, () => new CreatorsNotSortedError())
+createErrorFromNameLookup.set(
+  'CreatorsNotSorted',
+  () => new CreatorsNotSortedError()
+)
+
+/**
+ * RootNotInChangeLog: 'Root is not in the change log of the tree'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RootNotInChangeLogError extends Error {
+  readonly code: number = 0x179b
+  readonly name: string = 'RootNotInChangeLog'
+  constructor() {
+    super('Root is not in the change log of the tree')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RootNotInChangeLogError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179b, () => new RootNotInChangeLogError())
+createErrorFromNameLookup.set(
+  'RootNotInChangeLog',
+  () => new RootNotInChangeLogError()
+)
+
+/**
+ * TreeFull: 'Tree is full'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeFullError extends Error {
+  readonly code: number = 0x179c
+  readonly name: string = 'TreeFull'
+  constructor() {
+    super('Tree is full')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeFullError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179c, () => new TreeFullError())
+createErrorFromNameLookup.set('TreeFull', () => new TreeFullError())
+
+/**
+ * TreeCreatorImmutable: 'Tree was created with an immutable creator'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeCreatorImmutableError extends Error {
+  readonly code: number = 0x179d
+  readonly name: string = 'TreeCreatorImmutable'
+  constructor() {
+    super('Tree was created with an immutable creator')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeCreatorImmutableError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new TreeCreatorImmutableError())
+createErrorFromNameLookup.set(
+  'TreeCreatorImmutable',
+  () => new TreeCreatorImmutableError()
+)
+
+/**
+ * MetadataPadded: 'Metadata name, symbol, or uri ends with null bytes'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MetadataPaddedError extends Error {
+  readonly code: number = 0x179e
+  readonly name: string = 'MetadataPadded'
+  constructor() {
+    super('Metadata name, symbol, or uri ends with null bytes')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MetadataPaddedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new MetadataPaddedError())
+createErrorFromNameLookup.set('MetadataPadded', () => new MetadataPaddedError())
+
+/**
+ * InvalidLeafDelegate: 'Leaf delegate is not a valid address'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidLeafDelegateError extends Error {
+  readonly code: number = 0x179f
+  readonly name: string = 'InvalidLeafDelegate'
+  constructor() {
+    super('Leaf delegate is not a valid address')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidLeafDelegateError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new InvalidLeafDelegateError())
+createErrorFromNameLookup.set(
+  'InvalidLeafDelegate',
+  () => new InvalidLeafDelegateError()
+)
+
+/**
+ * LeafOwnerMustSignForDelegate: 'Leaf owner must sign to set a different leaf delegate'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class LeafOwnerMustSignForDelegateError extends Error {
+  readonly code: number = 0x17a0
+  readonly name: string = 'LeafOwnerMustSignForDelegate'
+  constructor() {
+    super('Leaf owner must sign to set a different leaf delegate')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, LeafOwnerMustSignForDelegateError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a0,
+  () => new LeafOwnerMustSignForDelegateError()
+)
+createErrorFromNameLookup.set(
+  'LeafOwnerMustSignForDelegate',
+  () => new LeafOwnerMustSignForDelegateError()
+)
+
+/**
+ * InstructionDisabled: 'Instruction is disabled for this deployment of the program'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InstructionDisabledError extends Error {
+  readonly code: number = 0x17a1
+  readonly name: string = 'InstructionDisabled'
+  constructor() {
+    super('Instruction is disabled for this deployment of the program')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InstructionDisabledError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new InstructionDisabledError())
+createErrorFromNameLookup.set(
+  'InstructionDisabled',
+  () => new InstructionDisabledError()
+)
+
+/**
+ * MetadataImmutable: 'Metadata is not mutable'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MetadataImmutableError extends Error {
+  readonly code: number = 0x17a2
+  readonly name: string = 'MetadataImmutable'
+  constructor() {
+    super('Metadata is not mutable')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MetadataImmutableError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new MetadataImmutableError())
+createErrorFromNameLookup.set(
+  'MetadataImmutable',
+  () => new MetadataImmutableError()
+)
+
+/**
+ * PrimarySaleCanOnlyBeFlippedToTrue: 'Primary sale can only be flipped to true'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PrimarySaleCanOnlyBeFlippedToTrueError extends Error {
+  readonly code: number = 0x17a3
+  readonly name: string = 'PrimarySaleCanOnlyBeFlippedToTrue'
+  constructor() {
+    super('Primary sale can only be flipped to true')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PrimarySaleCanOnlyBeFlippedToTrueError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a3,
+  () => new PrimarySaleCanOnlyBeFlippedToTrueError()
+)
+createErrorFromNameLookup.set(
+  'PrimarySaleCanOnlyBeFlippedToTrue',
+  () => new PrimarySaleCanOnlyBeFlippedToTrueError()
+)
+
+/**
+ * CollectionCannotBeChanged: 'Collection can only be changed by the collection verification instructions'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CollectionCannotBeChangedError extends Error {
+  readonly code: number = 0x17a4
+  readonly name: string = 'CollectionCannotBeChanged'
+  constructor() {
+    super(
+      'Collection can only be changed by the collection verification instructions'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CollectionCannotBeChangedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a4,
+  () => new CollectionCannotBeChangedError()
+)
+createErrorFromNameLookup.set(
+  'CollectionCannotBeChanged',
+  () => new CollectionCannotBeChangedError()
+)
+
+/**
+ * CannotUnverifyAnotherCreator: 'Cannot unverify another creator'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CannotUnverifyAnotherCreatorError extends Error {
+  readonly code: number = 0x17a5
+  readonly name: string = 'CannotUnverifyAnotherCreator'
+  constructor() {
+    super('Cannot unverify another creator')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CannotUnverifyAnotherCreatorError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a5,
+  () => new CannotUnverifyAnotherCreatorError()
+)
+createErrorFromNameLookup.set(
+  'CannotUnverifyAnotherCreator',
+  () => new CannotUnverifyAnotherCreatorError()
+)
+
+/**
+ * InvalidMintBatchSize: 'Batch must have between one and MAX_MINT_BATCH_SIZE messages'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMintBatchSizeError extends Error {
+  readonly code: number = 0x17a6
+  readonly name: string = 'InvalidMintBatchSize'
+  constructor() {
+    super('Batch must have between one and MAX_MINT_BATCH_SIZE messages')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMintBatchSizeError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new InvalidMintBatchSizeError())
+createErrorFromNameLookup.set(
+  'InvalidMintBatchSize',
+  () => new InvalidMintBatchSizeError()
+)
+
+/**
+ * InvalidTransferBatchSize: 'Batch must have between one and MAX_TRANSFER_BATCH_SIZE transfers'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTransferBatchSizeError extends Error {
+  readonly code: number = 0x17a7
+  readonly name: string = 'InvalidTransferBatchSize'
+  constructor() {
+    super('Batch must have between one and MAX_TRANSFER_BATCH_SIZE transfers')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTransferBatchSizeError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new InvalidTransferBatchSizeError())
+createErrorFromNameLookup.set(
+  'InvalidTransferBatchSize',
+  () => new InvalidTransferBatchSizeError()
+)
+
+/**
+ * InvalidBatchAccounts: 'Remaining accounts do not match the transfers of the batch'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBatchAccountsError extends Error {
+  readonly code: number = 0x17a8
+  readonly name: string = 'InvalidBatchAccounts'
+  constructor() {
+    super('Remaining accounts do not match the transfers of the batch')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBatchAccountsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new InvalidBatchAccountsError())
+createErrorFromNameLookup.set(
+  'InvalidBatchAccounts',
+  () => new InvalidBatchAccountsError()
+)
+
+/**
+ * CanopyTooDeep: 'Canopy must be shallower than the tree'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CanopyTooDeepError extends Error {
+  readonly code: number = 0x17a9
+  readonly name: string = 'CanopyTooDeep'
+  constructor() {
+    super('Canopy must be shallower than the tree')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CanopyTooDeepError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new CanopyTooDeepError())
+createErrorFromNameLookup.set('CanopyTooDeep', () => new CanopyTooDeepError())
+
+/**
+ * AccountTooLarge: 'Merkle tree account exceeds the maximum account size'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AccountTooLargeError extends Error {
+  readonly code: number = 0x17aa
+  readonly name: string = 'AccountTooLarge'
+  constructor() {
+    super('Merkle tree account exceeds the maximum account size')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AccountTooLargeError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new AccountTooLargeError())
+createErrorFromNameLookup.set(
+  'AccountTooLarge',
+  () => new AccountTooLargeError()
+)
+
+/**
+ * RedundantOperation: 'Operation would leave the leaf unchanged'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RedundantOperationError extends Error {
+  readonly code: number = 0x17ab
+  readonly name: string = 'RedundantOperation'
+  constructor() {
+    super('Operation would leave the leaf unchanged')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RedundantOperationError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new RedundantOperationError())
+createErrorFromNameLookup.set(
+  'RedundantOperation',
+  () => new RedundantOperationError()
+)
+
+/**
+ * InvalidZeroAddress: 'Address must not be the default (all zero) address'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidZeroAddressError extends Error {
+  readonly code: number = 0x17ac
+  readonly name: string = 'InvalidZeroAddress'
+  constructor() {
+    super('Address must not be the default (all zero) address')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidZeroAddressError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ac, () => new InvalidZeroAddressError())
+createErrorFromNameLookup.set(
+  'InvalidZeroAddress',
+  () => new InvalidZeroAddressError()
+)
+
+/**
+ * AssetIsFrozen: 'Asset is frozen by its delegate'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AssetIsFrozenError extends Error {
+  readonly code: number = 0x17ad
+  readonly name: string = 'AssetIsFrozen'
+  constructor() {
+    super('Asset is frozen by its delegate')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AssetIsFrozenError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new AssetIsFrozenError())
+createErrorFromNameLookup.set('AssetIsFrozen', () => new AssetIsFrozenError())
+
+/**
+ * MintPriceNotPaid: 'Public mints into a tree with a mint price must pay it'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintPriceNotPaidError extends Error {
+  readonly code: number = 0x17ae
+  readonly name: string = 'MintPriceNotPaid'
+  constructor() {
+    super('Public mints into a tree with a mint price must pay it')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MintPriceNotPaidError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ae, () => new MintPriceNotPaidError())
+createErrorFromNameLookup.set(
+  'MintPriceNotPaid',
+  () => new MintPriceNotPaidError()
+)
+
+/**
+ * InvalidTreasury: 'Treasury does not match the mint price of the tree'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTreasuryError extends Error {
+  readonly code: number = 0x17af
+  readonly name: string = 'InvalidTreasury'
+  constructor() {
+    super('Treasury does not match the mint price of the tree')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTreasuryError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17af, () => new InvalidTreasuryError())
+createErrorFromNameLookup.set(
+  'InvalidTreasury',
+  () => new InvalidTreasuryError()
+)
+
+/**
+ * MetadataProposalMismatch: 'Metadata does not match the proposed update'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MetadataProposalMismatchError extends Error {
+  readonly code: number = 0x17b0
+  readonly name: string = 'MetadataProposalMismatch'
+  constructor() {
+    super('Metadata does not match the proposed update')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MetadataProposalMismatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b0, () => new MetadataProposalMismatchError())
+createErrorFromNameLookup.set(
+  'MetadataProposalMismatch',
+  () => new MetadataProposalMismatchError()
+)
+
+/**
+ * AssetFlagged: 'Asset is on the deny list of the tree'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AssetFlaggedError extends Error {
+  readonly code: number = 0x17b1
+  readonly name: string = 'AssetFlagged'
+  constructor() {
+    super('Asset is on the deny list of the tree')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AssetFlaggedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b1, () => new AssetFlaggedError())
+createErrorFromNameLookup.set('AssetFlagged', () => new AssetFlaggedError())
+
+/**
+ * InvalidDenyList: 'Deny list does not match the one set for the tree'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidDenyListError extends Error {
+  readonly code: number = 0x17b2
+  readonly name: string = 'InvalidDenyList'
+  constructor() {
+    super('Deny list does not match the one set for the tree')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidDenyListError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b2, () => new InvalidDenyListError())
+createErrorFromNameLookup.set(
+  'InvalidDenyList',
+  () => new InvalidDenyListError()
+)
+
+/**
+ * DenyListFull: 'Deny list has no room for more assets'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DenyListFullError extends Error {
+  readonly code: number = 0x17b3
+  readonly name: string = 'DenyListFull'
+  constructor() {
+    super('Deny list has no room for more assets')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DenyListFullError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b3, () => new DenyListFullError())
+createErrorFromNameLookup.set('DenyListFull', () => new DenyListFullError())
+
+/**
+ * TreePaused: 'Tree is paused by its creator'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreePausedError extends Error {
+  readonly code: number = 0x17b4
+  readonly name: string = 'TreePaused'
+  constructor() {
+    super('Tree is paused by its creator')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreePausedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b4, () => new TreePausedError())
+createErrorFromNameLookup.set('TreePaused', () => new TreePausedError())
+
+/**
+ * SoulboundOverrideNotAtMint: 'Soulbound override can only be set for the last minted leaf'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SoulboundOverrideNotAtMintError extends Error {
+  readonly code: number = 0x17b5
+  readonly name: string = 'SoulboundOverrideNotAtMint'
+  constructor() {
+    super('Soulbound override can only be set for the last minted leaf')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SoulboundOverrideNotAtMintError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17b5,
+  () => new SoulboundOverrideNotAtMintError()
+)
+createErrorFromNameLookup.set(
+  'SoulboundOverrideNotAtMint',
+  () => new SoulboundOverrideNotAtMintError()
+)
+
+/**
+ * OnChainMetadataComplete: 'On-chain metadata is already complete'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OnChainMetadataCompleteError extends Error {
+  readonly code: number = 0x17b6
+  readonly name: string = 'OnChainMetadataComplete'
+  constructor() {
+    super('On-chain metadata is already complete')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OnChainMetadataCompleteError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b6, () => new OnChainMetadataCompleteError())
+createErrorFromNameLookup.set(
+  'OnChainMetadataComplete',
+  () => new OnChainMetadataCompleteError()
+)
+
+/**
+ * InvalidFeatureFlags: 'Feature flags can only disable instructions that check them'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidFeatureFlagsError extends Error {
+  readonly code: number = 0x17b7
+  readonly name: string = 'InvalidFeatureFlags'
+  constructor() {
+    super('Feature flags can only disable instructions that check them')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidFeatureFlagsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b7, () => new InvalidFeatureFlagsError())
+createErrorFromNameLookup.set(
+  'InvalidFeatureFlags',
+  () => new InvalidFeatureFlagsError()
+)
+
+/**
+ * NonTransferableAfterMint: 'Whether leaves are soulbound can only be set for trees with no leaves yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NonTransferableAfterMintError extends Error {
+  readonly code: number = 0x17b8
+  readonly name: string = 'NonTransferableAfterMint'
+  constructor() {
+    super(
+      'Whether leaves are soulbound can only be set for trees with no leaves yet'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NonTransferableAfterMintError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b8, () => new NonTransferableAfterMintError())
+createErrorFromNameLookup.set(
+  'NonTransferableAfterMint',
+  () => new NonTransferableAfterMintError()
+)
+
+/**
+ * MetadataProposalPending: 'A metadata update proposed by another authority is still pending'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MetadataProposalPendingError extends Error {
+  readonly code: number = 0x17b9
+  readonly name: string = 'MetadataProposalPending'
+  constructor() {
+    super('A metadata update proposed by another authority is still pending')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MetadataProposalPendingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b9, () => new MetadataProposalPendingError())
+createErrorFromNameLookup.set(
+  'MetadataProposalPending',
+  () => new MetadataProposalPendingError()
+)
+
+/**
+ * FeatureFlagsMissing: 'Feature flags of the deployment must be the first companion account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FeatureFlagsMissingError extends Error {
+  readonly code: number = 0x17ba
+  readonly name: string = 'FeatureFlagsMissing'
+  constructor() {
+    super('Feature flags of the deployment must be the first companion account')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FeatureFlagsMissingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ba, () => new FeatureFlagsMissingError())
+createErrorFromNameLookup.set(
+  'FeatureFlagsMissing',
+  () => new FeatureFlagsMissingError()
+)
+
+/**
+ * BurnReceiptMissing: 'Asset was not burned with a receipt'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BurnReceiptMissingError extends Error {
+  readonly code: number = 0x17bb
+  readonly name: string = 'BurnReceiptMissing'
+  constructor() {
+    super('Asset was not burned with a receipt')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BurnReceiptMissingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bb, () => new BurnReceiptMissingError())
+createErrorFromNameLookup.set(
+  'BurnReceiptMissing',
+  () => new BurnReceiptMissingError()
+)
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -49,6 +49,9 @@ export const burnStruct = new beet.BeetArgsStruct<
  * @property [_writable_] merkleTree
  * @property [] logWrapper
  * @property [] compressionProgram
+ * @property [] voucher
+ * @property [] delegateExpiry
+ * @property [] leafFreeze
  * @category Instructions
  * @category Burn
  * @category generated
@@ -60,6 +63,9 @@ export type BurnInstructionAccounts = {
   merkleTree: web3.PublicKey
   logWrapper: web3.PublicKey
   compressionProgram: web3.PublicKey
+  voucher: web3.PublicKey
+  delegateExpiry: web3.PublicKey
+  leafFreeze: web3.PublicKey
 }
 
 export const burnInstructionDiscriminator = [116, 110, 29, 56, 107, 219, 42, 93]
@@ -114,6 +120,21 @@ export function createBurnInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.voucher,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.delegateExpiry,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.leafFreeze,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
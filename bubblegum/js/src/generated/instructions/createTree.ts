@@ -7,6 +7,7 @@
 
 import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
+import { LoggingMode, loggingModeBeet } from '../types/LoggingMode'
 
 /**
  * @category Instructions
@@ -16,6 +17,9 @@ import * as web3 from '@solana/web3.js'
 export type CreateTreeInstructionArgs = {
   maxDepth: number
   maxBufferSize: number
+  loggingMode: LoggingMode
+  creatorImmutable: boolean
+  isPublic: boolean
 }
 /**
  * @category Instructions
@@ -31,6 +35,9 @@ export const createTreeStruct = new beet.BeetArgsStruct<
     ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
     ['maxDepth', beet.u32],
     ['maxBufferSize', beet.u32],
+    ['loggingMode', loggingModeBeet],
+    ['creatorImmutable', beet.bool],
+    ['isPublic', beet.bool],
   ],
   'CreateTreeInstructionArgs'
 )
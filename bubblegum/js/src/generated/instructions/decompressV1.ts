@@ -47,6 +47,7 @@ export const decompressV1Struct = new beet.FixableBeetArgsStruct<
  * @property [] sysvarRent
  * @property [] tokenMetadataProgram
  * @property [] associatedTokenProgram
+ * @property [] treeAuthority
  * @category Instructions
  * @category DecompressV1
  * @category generated
@@ -64,6 +65,7 @@ export type DecompressV1InstructionAccounts = {
   tokenMetadataProgram: web3.PublicKey
   tokenProgram?: web3.PublicKey
   associatedTokenProgram: web3.PublicKey
+  treeAuthority: web3.PublicKey
 }
 
 export const decompressV1InstructionDiscriminator = [
@@ -150,6 +152,11 @@ export function createDecompressV1Instruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.treeAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
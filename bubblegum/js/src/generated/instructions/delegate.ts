@@ -50,6 +50,8 @@ export const delegateStruct = new beet.BeetArgsStruct<
  * @property [_writable_] merkleTree
  * @property [] logWrapper
  * @property [] compressionProgram
+ * @property [] voucher
+ * @property [] leafFreeze
  * @category Instructions
  * @category Delegate
  * @category generated
@@ -62,6 +64,8 @@ export type DelegateInstructionAccounts = {
   merkleTree: web3.PublicKey
   logWrapper: web3.PublicKey
   compressionProgram: web3.PublicKey
+  voucher: web3.PublicKey
+  leafFreeze: web3.PublicKey
 }
 
 export const delegateInstructionDiscriminator = [
@@ -123,6 +127,16 @@ export function createDelegateInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.voucher,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.leafFreeze,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
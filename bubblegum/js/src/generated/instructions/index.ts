@@ -1,15 +1,56 @@
+export * from './acceptTreeCreator'
+export * from './addTreeDelegate'
+export * from './approveMetadataUpdate'
 export * from './burn'
+export * from './burnWithReceipt'
+export * from './cancelMetadataProposal'
 export * from './cancelRedeem'
+export * from './cancelTimelockedTransfer'
+export * from './cancelTreeCreatorTransfer'
+export * from './claimTimelockedTransfer'
+export * from './closeAssetReceipt'
+export * from './closeBurnReceipt'
+export * from './closeDelegateExpiry'
 export * from './compress'
+export * from './createDenyList'
 export * from './createTree'
 export * from './decompressV1'
 export * from './delegate'
+export * from './delegateWithExpiry'
+export * from './freeze'
+export * from './initOnChainMetadata'
+export * from './mintAirdrop'
+export * from './mintBatchV1'
+export * from './mintToCollectionV1'
 export * from './mintV1'
+export * from './mintV1Hashed'
+export * from './mintV1WithReceipt'
+export * from './proposeMetadataUpdate'
+export * from './proposeTreeCreator'
 export * from './redeem'
+export * from './removeTreeDelegate'
 export * from './setAndVerifyCollection'
+export * from './setFeatureFlags'
+export * from './setLoggingMode'
+export * from './setMintPrice'
+export * from './setSoulboundOverride'
 export * from './setTreeDelegate'
+export * from './setTreeDenyList'
+export * from './setTreeHashOnly'
+export * from './setTreeMetadata'
+export * from './setTreeNonTransferable'
+export * from './setTreePaused'
+export * from './setTreePublic'
+export * from './thaw'
 export * from './transfer'
+export * from './transferBatch'
+export * from './transferTimelocked'
 export * from './unverifyCollection'
 export * from './unverifyCreator'
+export * from './updateDenyList'
+export * from './updateMetadata'
 export * from './verifyCollection'
 export * from './verifyCreator'
+export * from './verifyMetadataHash'
+export * from './withdrawFromTreeAuthority'
+export * from './writeMetadataChunk'
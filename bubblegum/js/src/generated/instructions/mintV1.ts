@@ -40,10 +40,11 @@ export const mintV1Struct = new beet.FixableBeetArgsStruct<
  * @property [] leafOwner
  * @property [] leafDelegate
  * @property [_writable_] merkleTree
- * @property [**signer**] payer
+ * @property [_writable_, **signer**] payer
  * @property [**signer**] treeDelegate
  * @property [] logWrapper
  * @property [] compressionProgram
+ * @property [] treeDelegateRecord
  * @category Instructions
  * @category MintV1
  * @category generated
@@ -57,6 +58,7 @@ export type MintV1InstructionAccounts = {
   treeDelegate: web3.PublicKey
   logWrapper: web3.PublicKey
   compressionProgram: web3.PublicKey
+  treeDelegateRecord: web3.PublicKey
 }
 
 export const mintV1InstructionDiscriminator = [
@@ -105,7 +107,7 @@ export function createMintV1Instruction(
     },
     {
       pubkey: accounts.payer,
-      isWritable: false,
+      isWritable: true,
       isSigner: true,
     },
     {
@@ -123,6 +125,11 @@ export function createMintV1Instruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.treeDelegateRecord,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
@@ -50,6 +50,8 @@ export const redeemStruct = new beet.BeetArgsStruct<
  * @property [_writable_] voucher
  * @property [] logWrapper
  * @property [] compressionProgram
+ * @property [] soulboundOverride
+ * @property [] leafFreeze
  * @category Instructions
  * @category Redeem
  * @category generated
@@ -62,7 +64,9 @@ export type RedeemInstructionAccounts = {
   voucher: web3.PublicKey
   logWrapper: web3.PublicKey
   compressionProgram: web3.PublicKey
+  soulboundOverride: web3.PublicKey
   systemProgram?: web3.PublicKey
+  leafFreeze: web3.PublicKey
 }
 
 export const redeemInstructionDiscriminator = [
@@ -124,11 +128,21 @@ export function createRedeemInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.soulboundOverride,
+      isWritable: false,
+      isSigner: false,
+    },
     {
       pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.leafFreeze,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
@@ -50,6 +50,10 @@ export const transferStruct = new beet.BeetArgsStruct<
  * @property [_writable_] merkleTree
  * @property [] logWrapper
  * @property [] compressionProgram
+ * @property [] voucher
+ * @property [] soulboundOverride
+ * @property [] delegateExpiry
+ * @property [] leafFreeze
  * @category Instructions
  * @category Transfer
  * @category generated
@@ -62,6 +66,10 @@ export type TransferInstructionAccounts = {
   merkleTree: web3.PublicKey
   logWrapper: web3.PublicKey
   compressionProgram: web3.PublicKey
+  voucher: web3.PublicKey
+  soulboundOverride: web3.PublicKey
+  delegateExpiry: web3.PublicKey
+  leafFreeze: web3.PublicKey
 }
 
 export const transferInstructionDiscriminator = [
@@ -123,6 +131,26 @@ export function createTransferInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.voucher,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.soulboundOverride,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.delegateExpiry,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.leafFreeze,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
@@ -26,6 +26,48 @@ export enum InstructionName {
   VerifyCollection,
   UnverifyCollection,
   SetAndVerifyCollection,
+  SetTreeMetadata,
+  VerifyMetadataHash,
+  MintV1Hashed,
+  SetTreeHashOnly,
+  WithdrawFromTreeAuthority,
+  SetLoggingMode,
+  TransferTimelocked,
+  ClaimTimelockedTransfer,
+  CancelTimelockedTransfer,
+  MintAirdrop,
+  SetTreeNonTransferable,
+  SetSoulboundOverride,
+  DelegateWithExpiry,
+  InitOnChainMetadata,
+  WriteMetadataChunk,
+  MintV1WithReceipt,
+  CloseAssetReceipt,
+  BurnWithReceipt,
+  CloseBurnReceipt,
+  ProposeTreeCreator,
+  AcceptTreeCreator,
+  CancelTreeCreatorTransfer,
+  SetFeatureFlags,
+  UpdateMetadata,
+  MintToCollectionV1,
+  MintBatchV1,
+  TransferBatch,
+  Freeze,
+  Thaw,
+  SetTreePublic,
+  SetMintPrice,
+  ProposeMetadataUpdate,
+  ApproveMetadataUpdate,
+  CancelMetadataProposal,
+  CreateDenyList,
+  UpdateDenyList,
+  SetTreeDenyList,
+  AddTreeDelegate,
+  RemoveTreeDelegate,
+  SetTreeDelegate,
+  SetTreePaused,
+  CloseDelegateExpiry,
 }
 
 /**
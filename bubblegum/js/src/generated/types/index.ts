@@ -1,7 +1,13 @@
+export * from './AssetState'
+export * from './BatchTransfer'
 export * from './Collection'
+export * from './CompressionOperation'
 export * from './Creator'
+export * from './CreatorTransferStage'
+export * from './HashedMetadataArgs'
 export * from './InstructionName'
 export * from './LeafSchema'
+export * from './LoggingMode'
 export * from './MetadataArgs'
 export * from './TokenProgramVersion'
 export * from './TokenStandard'
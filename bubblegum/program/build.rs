@@ -1,8 +1,8 @@
 use std::{path::Path, process::Command};
 
-// This build script runs `./download-compression-programs.sh` if either of the paths
-// below does not exist. Please note that running the bash script with replace both
-// files anyway. If both are already present, pretty much nothing happens. They are only
+// This build script runs `./download-compression-programs.sh` if any of the paths
+// below does not exist. Please note that running the bash script with replace all of
+// them anyway. If all are already present, pretty much nothing happens. They are only
 // required for running the tests, but there's no way currently to have a `build.rs`
 // action for tests alone.
 fn main() {
@@ -11,8 +11,13 @@ fn main() {
         Path::new("../../target/deploy/GRoLLzvxpxxu2PGNJMMeZPyMxjAUH9pKqxGXV9DGiceU.so");
     let spl_wrapper_so_path =
         Path::new("../../target/deploy/WRAPYChf58WFCnyjXKJHtrPgzKXgHp6MD9aVDqJBbGh.so");
+    let token_metadata_so_path =
+        Path::new("../../target/deploy/metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s.so");
 
-    if !spl_compression_so_path.exists() || !spl_wrapper_so_path.exists() {
+    if !spl_compression_so_path.exists()
+        || !spl_wrapper_so_path.exists()
+        || !token_metadata_so_path.exists()
+    {
         Command::new("./download-compression-programs.sh")
             .output()
             .expect("failed to execute download compression programs script");
@@ -27,4 +32,8 @@ fn main() {
         spl_compression_so_path.display()
     );
     println!("cargo:rerun-if-changed={}", spl_wrapper_so_path.display());
+    println!(
+        "cargo:rerun-if-changed={}",
+        token_metadata_so_path.display()
+    );
 }
@@ -0,0 +1,82 @@
+// This crate is never deployed. It depends on `mpl-bubblegum` with the `cpi` feature (which
+// implies `no-entrypoint`) and defines an entrypoint of its own, so building it fails with
+// duplicate symbols if Bubblegum ever exports an entrypoint when used as a dependency. It also
+// exercises the generated CPI module and the shared state types, like a program composing with
+// Bubblegum would.
+use anchor_lang::prelude::*;
+use mpl_bubblegum::{program::Bubblegum, state::TreeConfig};
+
+declare_id!("6yLFcA2m4EyKS9y75WGZBLP2Xmc8yAWjNJBJHFVDZ9gU");
+
+#[derive(Accounts)]
+pub struct ForwardTransfer<'info> {
+    pub tree_authority: Account<'info, TreeConfig>,
+    pub leaf_owner: Signer<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub new_leaf_owner: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is checked by Bubblegum
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub voucher: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub soulbound_override: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub delegate_expiry: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub leaf_freeze: UncheckedAccount<'info>,
+    pub bubblegum_program: Program<'info, Bubblegum>,
+}
+
+#[program]
+pub mod bubblegum_cpi_check {
+    use super::*;
+
+    pub fn forward_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, ForwardTransfer<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        nonce: u64,
+        index: u32,
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let cpi_ctx = CpiContext::new(
+            accounts.bubblegum_program.to_account_info(),
+            mpl_bubblegum::cpi::accounts::Transfer {
+                tree_authority: accounts.tree_authority.to_account_info(),
+                leaf_owner: accounts.leaf_owner.to_account_info(),
+                leaf_delegate: accounts.leaf_delegate.to_account_info(),
+                new_leaf_owner: accounts.new_leaf_owner.to_account_info(),
+                merkle_tree: accounts.merkle_tree.to_account_info(),
+                log_wrapper: accounts.log_wrapper.to_account_info(),
+                compression_program: accounts.compression_program.to_account_info(),
+                voucher: accounts.voucher.to_account_info(),
+                soulbound_override: accounts.soulbound_override.to_account_info(),
+                delegate_expiry: accounts.delegate_expiry.to_account_info(),
+                leaf_freeze: accounts.leaf_freeze.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+
+        // `leaf_owner` is not declared as a `Signer` by Bubblegum, since the delegate can sign
+        // instead, so the generated `cpi::transfer` would not forward its signature.
+        mpl_bubblegum::utils::invoke_as_signer(
+            cpi_ctx,
+            mpl_bubblegum::instruction::Transfer {
+                root,
+                data_hash,
+                creator_hash,
+                nonce,
+                index,
+            },
+            &accounts.leaf_owner.key(),
+        )
+    }
+}
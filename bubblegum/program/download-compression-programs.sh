@@ -20,3 +20,7 @@ pushd solana_program_library/account-compression/programs/noop
 popd
 
 rm -rf solana_program_library
+
+# The Token Metadata program in this repository does not have Bubblegum activated, so the tests
+# use the one deployed on mainnet instead.
+solana program dump -u m metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s $MPL_ROOT/target/deploy/metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s.so
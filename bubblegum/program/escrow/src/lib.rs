@@ -0,0 +1,53 @@
+//! A minimal escrow program for Bubblegum assets, which serves as an example of a program owning
+//! assets. Depositing an asset transfers it to an escrow account (a PDA of this program), and
+//! only the depositor can later have the escrow release the asset to someone, or burn it.
+//!
+//! Bubblegum does not require the owner of an asset to hold any lamports or data, so the escrow
+//! signs for the asset via `invoke_signed` like any other PDA would. Since Bubblegum accepts a
+//! signature from either the owner or the delegate of an asset, the accounts of instructions
+//! like `transfer` are not declared as `Signer`, so the calls go through
+//! `mpl_bubblegum::utils::invoke_as_signer` rather than the generated CPI helpers.
+use anchor_lang::prelude::*;
+
+pub mod processor;
+pub mod state;
+
+pub use processor::*;
+
+declare_id!("9kW19jUQusPxjGSGr3FRqM2BNGNbaLVfnTqC6c8vgfsw");
+
+#[program]
+pub mod bubblegum_escrow {
+    use super::*;
+
+    pub fn deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, Deposit<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        nonce: u64,
+        index: u32,
+    ) -> Result<()> {
+        handle_deposit(ctx, root, data_hash, creator_hash, nonce, index)
+    }
+
+    pub fn release<'info>(
+        ctx: Context<'_, '_, '_, 'info, Release<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        index: u32,
+    ) -> Result<()> {
+        handle_release(ctx, root, data_hash, creator_hash, index)
+    }
+
+    pub fn burn<'info>(
+        ctx: Context<'_, '_, '_, 'info, Burn<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        index: u32,
+    ) -> Result<()> {
+        handle_burn(ctx, root, data_hash, creator_hash, index)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::state::{Escrow, ESCROW_PREFIX};
+use anchor_lang::prelude::*;
+use mpl_bubblegum::program::Bubblegum;
+
+#[derive(Accounts)]
+pub struct Burn<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            &escrow.nonce.to_le_bytes(),
+        ],
+        bump = escrow.bump,
+        has_one = depositor,
+        has_one = merkle_tree,
+        close = depositor,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    /// CHECK: This account is checked by Bubblegum
+    pub tree_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified by Bubblegum
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub voucher: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub delegate_expiry: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub leaf_freeze: UncheckedAccount<'info>,
+    pub bubblegum_program: Program<'info, Bubblegum>,
+}
+
+// Has the escrow sign the burn of the asset.
+pub fn handle_burn<'info>(
+    ctx: Context<'_, '_, '_, 'info, Burn<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    index: u32,
+) -> Result<()> {
+    let escrow = *ctx.accounts.escrow;
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let nonce = escrow.nonce.to_le_bytes();
+    let seeds = &[
+        ESCROW_PREFIX.as_ref(),
+        merkle_tree.as_ref(),
+        nonce.as_ref(),
+        &[escrow.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let accounts = &ctx.accounts;
+    let cpi_ctx = CpiContext::new_with_signer(
+        accounts.bubblegum_program.to_account_info(),
+        mpl_bubblegum::cpi::accounts::Burn {
+            tree_authority: accounts.tree_authority.to_account_info(),
+            leaf_owner: accounts.escrow.to_account_info(),
+            leaf_delegate: accounts.escrow.to_account_info(),
+            merkle_tree: accounts.merkle_tree.to_account_info(),
+            log_wrapper: accounts.log_wrapper.to_account_info(),
+            compression_program: accounts.compression_program.to_account_info(),
+            voucher: accounts.voucher.to_account_info(),
+            delegate_expiry: accounts.delegate_expiry.to_account_info(),
+            leaf_freeze: accounts.leaf_freeze.to_account_info(),
+        },
+        signer,
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    mpl_bubblegum::utils::invoke_as_signer(
+        cpi_ctx,
+        mpl_bubblegum::instruction::Burn {
+            root,
+            data_hash,
+            creator_hash,
+            nonce: escrow.nonce,
+            index,
+        },
+        &accounts.escrow.key(),
+    )
+}
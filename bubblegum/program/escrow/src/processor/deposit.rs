@@ -0,0 +1,93 @@
+use crate::state::{Escrow, ESCROW_PREFIX, ESCROW_SIZE};
+use anchor_lang::prelude::*;
+use mpl_bubblegum::program::Bubblegum;
+
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    nonce: u64,
+)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        init,
+        seeds = [ESCROW_PREFIX.as_ref(), merkle_tree.key().as_ref(), &nonce.to_le_bytes()],
+        payer = depositor,
+        space = ESCROW_SIZE,
+        bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    /// CHECK: This account is checked by Bubblegum
+    pub tree_authority: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub leaf_delegate: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified by Bubblegum
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub voucher: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub soulbound_override: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub delegate_expiry: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub leaf_freeze: UncheckedAccount<'info>,
+    pub bubblegum_program: Program<'info, Bubblegum>,
+    pub system_program: Program<'info, System>,
+}
+
+// Transfers the asset from the depositor to the escrow, which also becomes its delegate.
+pub fn handle_deposit<'info>(
+    ctx: Context<'_, '_, '_, 'info, Deposit<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let cpi_ctx = CpiContext::new(
+        accounts.bubblegum_program.to_account_info(),
+        mpl_bubblegum::cpi::accounts::Transfer {
+            tree_authority: accounts.tree_authority.to_account_info(),
+            leaf_owner: accounts.depositor.to_account_info(),
+            leaf_delegate: accounts.leaf_delegate.to_account_info(),
+            new_leaf_owner: accounts.escrow.to_account_info(),
+            merkle_tree: accounts.merkle_tree.to_account_info(),
+            log_wrapper: accounts.log_wrapper.to_account_info(),
+            compression_program: accounts.compression_program.to_account_info(),
+            voucher: accounts.voucher.to_account_info(),
+            soulbound_override: accounts.soulbound_override.to_account_info(),
+            delegate_expiry: accounts.delegate_expiry.to_account_info(),
+            leaf_freeze: accounts.leaf_freeze.to_account_info(),
+        },
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    mpl_bubblegum::utils::invoke_as_signer(
+        cpi_ctx,
+        mpl_bubblegum::instruction::Transfer {
+            root,
+            data_hash,
+            creator_hash,
+            nonce,
+            index,
+        },
+        &accounts.depositor.key(),
+    )?;
+
+    ctx.accounts.escrow.set_inner(Escrow {
+        depositor: ctx.accounts.depositor.key(),
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        nonce,
+        bump: *ctx.bumps.get("escrow").unwrap(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod burn;
+pub mod deposit;
+pub mod release;
+
+pub use burn::*;
+pub use deposit::*;
+pub use release::*;
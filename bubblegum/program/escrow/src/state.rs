@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+pub const ESCROW_SIZE: usize = 8 + 32 + 32 + 8 + 1;
+pub const ESCROW_PREFIX: &str = "escrow";
+
+/// An escrowed asset. The escrow account owns the asset while it exists.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct Escrow {
+    pub depositor: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub nonce: u64,
+    pub bump: u8,
+}
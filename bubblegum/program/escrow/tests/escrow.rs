@@ -0,0 +1,253 @@
+// Reuses the Bubblegum test harness, so that the operations performed by the escrow via CPI are
+// tracked like any other.
+#[path = "../../tests/utils/mod.rs"]
+pub mod utils;
+
+use mpl_bubblegum_escrow::state::{Escrow, ESCROW_PREFIX};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+use utils::{
+    context::{BubblegumTestContext, DEFAULT_LAMPORTS_FUND_AMOUNT},
+    LeafArgs, Result, Tree, TxBuilder,
+};
+
+const MAX_DEPTH: usize = 14;
+const MAX_BUF_SIZE: usize = 64;
+
+type DepositBuilder =
+    TxBuilder<mpl_bubblegum_escrow::accounts::Deposit, mpl_bubblegum_escrow::instruction::Deposit>;
+
+type ReleaseBuilder =
+    TxBuilder<mpl_bubblegum_escrow::accounts::Release, mpl_bubblegum_escrow::instruction::Release>;
+
+type BurnBuilder =
+    TxBuilder<mpl_bubblegum_escrow::accounts::Burn, mpl_bubblegum_escrow::instruction::Burn>;
+
+async fn context_tree_and_leaf() -> Result<(
+    BubblegumTestContext,
+    Tree<MAX_DEPTH, MAX_BUF_SIZE>,
+    LeafArgs,
+)> {
+    let context = BubblegumTestContext::with_programs(&[(
+        "mpl_bubblegum_escrow",
+        mpl_bubblegum_escrow::id(),
+    )])
+    .await?;
+
+    let (tree, leaf) = context
+        .default_create_and_mint::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await?;
+
+    Ok((context, tree, leaf))
+}
+
+fn escrow(tree: &Tree<MAX_DEPTH, MAX_BUF_SIZE>, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            ESCROW_PREFIX.as_ref(),
+            tree.tree_pubkey().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &mpl_bubblegum_escrow::id(),
+    )
+    .0
+}
+
+async fn deposit_tx(
+    tree: &Tree<MAX_DEPTH, MAX_BUF_SIZE>,
+    args: &LeafArgs,
+) -> Result<DepositBuilder> {
+    let accounts = mpl_bubblegum_escrow::accounts::Deposit {
+        depositor: args.owner.pubkey(),
+        escrow: escrow(tree, args.nonce),
+        tree_authority: tree.authority(),
+        leaf_delegate: args.delegate.pubkey(),
+        merkle_tree: tree.tree_pubkey(),
+        log_wrapper: spl_noop::id(),
+        compression_program: spl_account_compression::id(),
+        voucher: tree.voucher(args.nonce),
+        soulbound_override: tree.soulbound_override(args.nonce),
+        delegate_expiry: tree.delegate_expiry(args.nonce),
+        leaf_freeze: tree.leaf_freeze(args.nonce),
+        bubblegum_program: mpl_bubblegum::id(),
+        system_program: system_program::id(),
+    };
+
+    let data = mpl_bubblegum_escrow::instruction::Deposit {
+        root: tree.decode_root().await?,
+        data_hash: args.data_hash,
+        creator_hash: args.creator_hash,
+        nonce: args.nonce,
+        index: args.index(),
+    };
+
+    Ok(tree.external_transfer_tx(
+        mpl_bubblegum_escrow::id(),
+        accounts,
+        data,
+        args.index(),
+        args.owner.pubkey(),
+        &[&args.owner],
+    ))
+}
+
+// `args` describes the asset as it was before the deposit, i.e. `args.owner` is the depositor.
+async fn release_tx(
+    tree: &Tree<MAX_DEPTH, MAX_BUF_SIZE>,
+    args: &LeafArgs,
+    recipient: Pubkey,
+) -> Result<ReleaseBuilder> {
+    let accounts = mpl_bubblegum_escrow::accounts::Release {
+        depositor: args.owner.pubkey(),
+        recipient,
+        escrow: escrow(tree, args.nonce),
+        tree_authority: tree.authority(),
+        merkle_tree: tree.tree_pubkey(),
+        log_wrapper: spl_noop::id(),
+        compression_program: spl_account_compression::id(),
+        voucher: tree.voucher(args.nonce),
+        soulbound_override: tree.soulbound_override(args.nonce),
+        delegate_expiry: tree.delegate_expiry(args.nonce),
+        leaf_freeze: tree.leaf_freeze(args.nonce),
+        bubblegum_program: mpl_bubblegum::id(),
+    };
+
+    let data = mpl_bubblegum_escrow::instruction::Release {
+        root: tree.decode_root().await?,
+        data_hash: args.data_hash,
+        creator_hash: args.creator_hash,
+        index: args.index(),
+    };
+
+    Ok(tree.external_transfer_tx(
+        mpl_bubblegum_escrow::id(),
+        accounts,
+        data,
+        args.index(),
+        args.owner.pubkey(),
+        &[&args.owner],
+    ))
+}
+
+async fn burn_tx(tree: &Tree<MAX_DEPTH, MAX_BUF_SIZE>, args: &LeafArgs) -> Result<BurnBuilder> {
+    let accounts = mpl_bubblegum_escrow::accounts::Burn {
+        depositor: args.owner.pubkey(),
+        escrow: escrow(tree, args.nonce),
+        tree_authority: tree.authority(),
+        merkle_tree: tree.tree_pubkey(),
+        log_wrapper: spl_noop::id(),
+        compression_program: spl_account_compression::id(),
+        voucher: tree.voucher(args.nonce),
+        delegate_expiry: tree.delegate_expiry(args.nonce),
+        leaf_freeze: tree.leaf_freeze(args.nonce),
+        bubblegum_program: mpl_bubblegum::id(),
+    };
+
+    let data = mpl_bubblegum_escrow::instruction::Burn {
+        root: tree.decode_root().await?,
+        data_hash: args.data_hash,
+        creator_hash: args.creator_hash,
+        index: args.index(),
+    };
+
+    Ok(tree.external_leaf_tx(
+        mpl_bubblegum_escrow::id(),
+        accounts,
+        data,
+        args.index(),
+        args.owner.pubkey(),
+        &[&args.owner],
+    ))
+}
+
+#[tokio::test]
+async fn test_escrow_holds_and_releases_asset() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    deposit_tx(&tree, &leaf)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+    let held: Escrow = tree
+        .read_account_data(escrow(&tree, leaf.nonce))
+        .await
+        .unwrap();
+    assert_eq!(held.depositor, leaf.owner.pubkey());
+
+    // The escrow is now both the owner and the delegate of the asset.
+    assert!(tree.transfer(&leaf, Keypair::new().pubkey()).await.is_err());
+
+    // Only the depositor can have the asset released.
+    let impostor = Keypair::new();
+    context
+        .fund_account(impostor.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    let mut tx = release_tx(&tree, &leaf, impostor.pubkey()).await.unwrap();
+    tx.accounts.depositor = impostor.pubkey();
+    assert!(tx
+        .set_payer(impostor.pubkey())
+        .set_signers(&[&impostor])
+        .execute()
+        .await
+        .is_err());
+
+    let recipient = Keypair::new();
+    release_tx(&tree, &leaf, recipient.pubkey())
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+    assert!(tree
+        .client()
+        .get_account(escrow(&tree, leaf.nonce))
+        .await
+        .unwrap()
+        .is_none());
+
+    // The asset belongs to the recipient, who can move it like any other.
+    context
+        .fund_account(recipient.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    tree.transfer(&leaf.with_new_owner(&recipient), Keypair::new().pubkey())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_escrow_burns_asset() {
+    let (_context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    deposit_tx(&tree, &leaf)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+    burn_tx(&tree, &leaf)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+
+    assert!(tree
+        .client()
+        .get_account(escrow(&tree, leaf.nonce))
+        .await
+        .unwrap()
+        .is_none());
+    // There is nothing left to release.
+    assert!(release_tx(&tree, &leaf, leaf.owner.pubkey())
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .is_err());
+}
@@ -0,0 +1,347 @@
+//! Creates a tree, mints a number of compressed NFTs, then transfers and burns a couple of them
+//! against a local `solana-test-validator`, and finally lists the resulting assets using the
+//! `client` module. It doubles as a smoke test for the program and as a reference for the
+//! client API surface.
+//!
+//! The validator must have the Bubblegum, account compression, and noop programs loaded:
+//!
+//! ```text
+//! solana-test-validator --reset \
+//!     --bpf-program BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY ../../target/deploy/mpl_bubblegum.so \
+//!     --bpf-program GRoLLzvxpxxu2PGNJMMeZPyMxjAUH9pKqxGXV9DGiceU ../../target/deploy/GRoLLzvxpxxu2PGNJMMeZPyMxjAUH9pKqxGXV9DGiceU.so \
+//!     --bpf-program WRAPYChf58WFCnyjXKJHtrPgzKXgHp6MD9aVDqJBbGh ../../target/deploy/WRAPYChf58WFCnyjXKJHtrPgzKXgHp6MD9aVDqJBbGh.so
+//!
+//! cargo run --example mint_and_transfer -- [RPC_URL] [NUM_MINTS]
+//! ```
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mpl_bubblegum::{
+    client::{list_assets, mirror::TreeMirror, parse_logs, BubblegumOp},
+    state::{
+        leaf_schema::LeafSchema,
+        metaplex_adapter::{MetadataArgs, TokenProgramVersion},
+        LoggingMode, DELEGATE_EXPIRY_PREFIX, SOULBOUND_OVERRIDE_PREFIX, VOUCHER_PREFIX,
+    },
+};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    system_instruction, system_program,
+};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use solana_transaction_status::UiTransactionEncoding;
+use spl_account_compression::{state::ConcurrentMerkleTreeHeader, Node};
+use spl_concurrent_merkle_tree::concurrent_merkle_tree::ConcurrentMerkleTree;
+use std::{env, error::Error, mem::size_of, thread::sleep, time::Duration};
+
+const DEFAULT_RPC_URL: &str = "http://127.0.0.1:8899";
+const DEFAULT_NUM_MINTS: u64 = 8;
+
+const MAX_DEPTH: usize = 14;
+const MAX_BUFFER_SIZE: usize = 64;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+// The companion accounts (see `utils::take_companion_accounts`) go ahead of the proof. The tree
+// created here has none, since it has no mint price or deny list and `set_feature_flags` was
+// not applied to them.
+fn instruction<T, U>(accounts: T, data: U, companions: &[Pubkey], proof: &[Node]) -> Instruction
+where
+    T: ToAccountMetas,
+    U: InstructionData,
+{
+    let mut accounts = accounts.to_account_metas(None);
+    accounts.extend(
+        companions
+            .iter()
+            .map(|key| AccountMeta::new_readonly(*key, false)),
+    );
+    accounts.extend(
+        proof
+            .iter()
+            .map(|node| AccountMeta::new_readonly(Pubkey::new_from_array(*node), false)),
+    );
+
+    Instruction {
+        program_id: mpl_bubblegum::id(),
+        accounts,
+        data: data.data(),
+    }
+}
+
+// Sends and confirms a transaction, and then records the Bubblegum operations that show up
+// in its logs.
+fn execute(
+    rpc: &RpcClient,
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+    ops: &mut Vec<BubblegumOp>,
+) -> Result<Signature> {
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        signers,
+        rpc.get_latest_blockhash()?,
+    );
+
+    let signature = rpc.send_and_confirm_transaction(&tx)?;
+
+    let confirmed = rpc.get_transaction(&signature, UiTransactionEncoding::Json)?;
+    if let Some(logs) = confirmed
+        .transaction
+        .meta
+        .and_then(|meta| meta.log_messages)
+    {
+        ops.extend(parse_logs(&logs));
+    }
+
+    Ok(signature)
+}
+
+fn airdrop(rpc: &RpcClient, to: &Pubkey, lamports: u64) -> Result<()> {
+    let signature = rpc.request_airdrop(to, lamports)?;
+    while !rpc.confirm_transaction(&signature)? {
+        sleep(Duration::from_millis(500));
+    }
+    Ok(())
+}
+
+fn metadata_args(name: String) -> MetadataArgs {
+    MetadataArgs {
+        name,
+        symbol: "BGUM".to_owned(),
+        uri: "https://example.com/nft.json".to_owned(),
+        seller_fee_basis_points: 0,
+        primary_sale_happened: false,
+        is_mutable: false,
+        edition_nonce: None,
+        token_standard: None,
+        collection: None,
+        uses: None,
+        token_program_version: TokenProgramVersion::Original,
+        creators: Vec::new(),
+    }
+}
+
+// Computes the current root of the tree and the proof for the leaf at `index`, based on the
+// operations observed so far.
+fn root_and_proof(tree_id: Pubkey, ops: &[BubblegumOp], index: u32) -> (Node, Vec<Node>) {
+    let mut mirror = TreeMirror::new(tree_id, MAX_DEPTH);
+    for op in ops {
+        mirror.apply(op);
+    }
+    (mirror.root(), mirror.proof(index))
+}
+
+// Transfers and burns check that the asset is not being redeemed via its voucher account.
+fn voucher(tree_id: Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            VOUCHER_PREFIX.as_ref(),
+            tree_id.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &mpl_bubblegum::id(),
+    )
+    .0
+}
+
+// Transfers and burns by delegates check whether the delegate has expired.
+fn delegate_expiry(tree_id: Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            DELEGATE_EXPIRY_PREFIX.as_ref(),
+            tree_id.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &mpl_bubblegum::id(),
+    )
+    .0
+}
+
+// Transfers also check whether the asset was made soulbound via its override account.
+fn soulbound_override(tree_id: Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            SOULBOUND_OVERRIDE_PREFIX.as_ref(),
+            tree_id.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &mpl_bubblegum::id(),
+    )
+    .0
+}
+
+fn leaf_hashes(leaf: &LeafSchema) -> ([u8; 32], [u8; 32]) {
+    match leaf {
+        LeafSchema::V1 {
+            data_hash,
+            creator_hash,
+            ..
+        } => (*data_hash, *creator_hash),
+    }
+}
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+    let url = args.next().unwrap_or_else(|| DEFAULT_RPC_URL.to_owned());
+    let num_mints = match args.next() {
+        Some(n) => n.parse::<u64>()?,
+        None => DEFAULT_NUM_MINTS,
+    };
+
+    let rpc = RpcClient::new_with_commitment(url, CommitmentConfig::confirmed());
+    let payer = Keypair::new();
+    airdrop(&rpc, &payer.pubkey(), 10 * LAMPORTS_PER_SOL)?;
+
+    let merkle_tree = Keypair::new();
+    let tree_id = merkle_tree.pubkey();
+    let tree_authority = Pubkey::find_program_address(&[tree_id.as_ref()], &mpl_bubblegum::id()).0;
+    let mut ops = Vec::new();
+
+    // Allocate the tree account and create the tree in a single transaction.
+    let account_size = size_of::<ConcurrentMerkleTreeHeader>()
+        + size_of::<ConcurrentMerkleTree<MAX_DEPTH, MAX_BUFFER_SIZE>>();
+    let alloc_ix = system_instruction::create_account(
+        &payer.pubkey(),
+        &tree_id,
+        rpc.get_minimum_balance_for_rent_exemption(account_size)?,
+        account_size as u64,
+        &spl_account_compression::id(),
+    );
+    let create_ix = instruction(
+        mpl_bubblegum::accounts::CreateTree {
+            tree_authority,
+            merkle_tree: tree_id,
+            payer: payer.pubkey(),
+            tree_creator: payer.pubkey(),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            system_program: system_program::id(),
+        },
+        mpl_bubblegum::instruction::CreateTree {
+            max_depth: MAX_DEPTH as u32,
+            max_buffer_size: MAX_BUFFER_SIZE as u32,
+            logging_mode: LoggingMode::Noop,
+            creator_immutable: false,
+            is_public: false,
+        },
+        &[],
+        &[],
+    );
+    execute(
+        &rpc,
+        &[alloc_ix, create_ix],
+        &payer,
+        &[&payer, &merkle_tree],
+        &mut ops,
+    )?;
+    println!("Created tree {}", tree_id);
+
+    for nonce in 0..num_mints {
+        let mint_ix = instruction(
+            mpl_bubblegum::accounts::MintV1 {
+                tree_authority,
+                leaf_owner: payer.pubkey(),
+                leaf_delegate: payer.pubkey(),
+                merkle_tree: tree_id,
+                payer: payer.pubkey(),
+                tree_delegate: payer.pubkey(),
+                log_wrapper: spl_noop::id(),
+                compression_program: spl_account_compression::id(),
+                tree_delegate_record: mpl_bubblegum::pda::find_tree_delegate_record(
+                    &tree_id,
+                    &payer.pubkey(),
+                ),
+            },
+            mpl_bubblegum::instruction::MintV1 {
+                message: metadata_args(format!("Asset #{}", nonce)),
+            },
+            &[],
+            &[],
+        );
+        execute(&rpc, &[mint_ix], &payer, &[&payer], &mut ops)?;
+    }
+    println!("Minted {} assets", num_mints);
+
+    // Transfer the first asset to a new owner.
+    let new_owner = Keypair::new();
+    let assets = list_assets(&tree_id, &ops, 0..num_mints);
+    let first = &assets[0];
+    let (root, proof) = root_and_proof(tree_id, &ops, 0);
+    let (data_hash, creator_hash) = leaf_hashes(&first.leaf);
+    let transfer_ix = instruction(
+        mpl_bubblegum::accounts::Transfer {
+            tree_authority,
+            leaf_owner: payer.pubkey(),
+            leaf_delegate: payer.pubkey(),
+            new_leaf_owner: new_owner.pubkey(),
+            merkle_tree: tree_id,
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            voucher: voucher(tree_id, first.nonce),
+            soulbound_override: soulbound_override(tree_id, first.nonce),
+            delegate_expiry: delegate_expiry(tree_id, first.nonce),
+            leaf_freeze: mpl_bubblegum::pda::find_leaf_freeze(&tree_id, first.nonce),
+        },
+        mpl_bubblegum::instruction::Transfer {
+            root,
+            data_hash,
+            creator_hash,
+            nonce: first.nonce,
+            index: first.nonce as u32,
+        },
+        &[],
+        &proof,
+    );
+    execute(&rpc, &[transfer_ix], &payer, &[&payer], &mut ops)?;
+    println!("Transferred {} to {}", first.asset_id, new_owner.pubkey());
+
+    // Burn the last asset.
+    let assets = list_assets(&tree_id, &ops, 0..num_mints);
+    let last = &assets[assets.len() - 1];
+    let (root, proof) = root_and_proof(tree_id, &ops, last.nonce as u32);
+    let (data_hash, creator_hash) = leaf_hashes(&last.leaf);
+    let burn_ix = instruction(
+        mpl_bubblegum::accounts::Burn {
+            tree_authority,
+            leaf_owner: payer.pubkey(),
+            leaf_delegate: payer.pubkey(),
+            merkle_tree: tree_id,
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            voucher: voucher(tree_id, last.nonce),
+            delegate_expiry: delegate_expiry(tree_id, last.nonce),
+        },
+        mpl_bubblegum::instruction::Burn {
+            root,
+            data_hash,
+            creator_hash,
+            nonce: last.nonce,
+            index: last.nonce as u32,
+        },
+        &[],
+        &proof,
+    );
+    execute(&rpc, &[burn_ix], &payer, &[&payer], &mut ops)?;
+    println!("Burned {}", last.asset_id);
+
+    for asset in list_assets(&tree_id, &ops, 0..num_mints) {
+        let owner = match asset.leaf {
+            LeafSchema::V1 { owner, .. } => owner,
+        };
+        println!(
+            "#{} {} owner={} state={:?}",
+            asset.nonce, asset.asset_id, owner, asset.state
+        );
+    }
+
+    Ok(())
+}
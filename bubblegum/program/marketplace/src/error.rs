@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum MarketplaceError {
+    #[msg("Listing price must be greater than zero")]
+    InvalidPrice,
+    #[msg("Listing price is higher than the maximum price of the buyer")]
+    PriceTooHigh,
+}
@@ -0,0 +1,51 @@
+//! A minimal fixed price marketplace for Bubblegum assets, which mostly serves as an example of
+//! composing with Bubblegum via CPI. Listing an asset makes the listing account its delegate,
+//! so the asset stays with the seller until it is bought, at which point the listing signs the
+//! transfer to the buyer.
+use anchor_lang::prelude::*;
+
+pub mod error;
+pub mod processor;
+pub mod state;
+
+pub use processor::*;
+
+declare_id!("ESwoi4vbcjYUL2HnRrPPpM8JzszVikUGutKF5ATKUoEc");
+
+#[program]
+pub mod bubblegum_marketplace {
+    use super::*;
+
+    pub fn list<'info>(
+        ctx: Context<'_, '_, '_, 'info, List<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        nonce: u64,
+        index: u32,
+        price: u64,
+    ) -> Result<()> {
+        handle_list(ctx, root, data_hash, creator_hash, nonce, index, price)
+    }
+
+    pub fn buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, Buy<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        index: u32,
+        max_price: u64,
+    ) -> Result<()> {
+        handle_buy(ctx, root, data_hash, creator_hash, index, max_price)
+    }
+
+    pub fn cancel<'info>(
+        ctx: Context<'_, '_, '_, 'info, Cancel<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        index: u32,
+    ) -> Result<()> {
+        handle_cancel(ctx, root, data_hash, creator_hash, index)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    error::MarketplaceError,
+    state::{Listing, LISTING_PREFIX},
+};
+use anchor_lang::{prelude::*, solana_program::system_instruction};
+use mpl_bubblegum::program::Bubblegum;
+
+#[derive(Accounts)]
+pub struct Buy<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: This account is checked against the listing
+    pub seller: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            LISTING_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            &listing.nonce.to_le_bytes(),
+        ],
+        bump = listing.bump,
+        has_one = seller,
+        has_one = merkle_tree,
+        close = seller,
+    )]
+    pub listing: Account<'info, Listing>,
+    /// CHECK: This account is checked by Bubblegum
+    pub tree_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified by Bubblegum
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub voucher: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub soulbound_override: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub delegate_expiry: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub leaf_freeze: UncheckedAccount<'info>,
+    pub bubblegum_program: Program<'info, Bubblegum>,
+    pub system_program: Program<'info, System>,
+}
+
+// Pays the seller and has the listing sign the transfer of the asset to the buyer. The
+// transfer fails (and so does the payment) if the asset changed since it was listed.
+pub fn handle_buy<'info>(
+    ctx: Context<'_, '_, '_, 'info, Buy<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    index: u32,
+    max_price: u64,
+) -> Result<()> {
+    let listing = *ctx.accounts.listing;
+    require!(listing.price <= max_price, MarketplaceError::PriceTooHigh);
+
+    anchor_lang::solana_program::program::invoke(
+        &system_instruction::transfer(
+            &ctx.accounts.buyer.key(),
+            &ctx.accounts.seller.key(),
+            listing.price,
+        ),
+        &[
+            ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.seller.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let nonce = listing.nonce.to_le_bytes();
+    let seeds = &[
+        LISTING_PREFIX.as_ref(),
+        merkle_tree.as_ref(),
+        nonce.as_ref(),
+        &[listing.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let accounts = &ctx.accounts;
+    let cpi_ctx = CpiContext::new_with_signer(
+        accounts.bubblegum_program.to_account_info(),
+        mpl_bubblegum::cpi::accounts::Transfer {
+            tree_authority: accounts.tree_authority.to_account_info(),
+            leaf_owner: accounts.seller.to_account_info(),
+            leaf_delegate: accounts.listing.to_account_info(),
+            new_leaf_owner: accounts.buyer.to_account_info(),
+            merkle_tree: accounts.merkle_tree.to_account_info(),
+            log_wrapper: accounts.log_wrapper.to_account_info(),
+            compression_program: accounts.compression_program.to_account_info(),
+            voucher: accounts.voucher.to_account_info(),
+            soulbound_override: accounts.soulbound_override.to_account_info(),
+            delegate_expiry: accounts.delegate_expiry.to_account_info(),
+            leaf_freeze: accounts.leaf_freeze.to_account_info(),
+        },
+        signer,
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    mpl_bubblegum::utils::invoke_as_signer(
+        cpi_ctx,
+        mpl_bubblegum::instruction::Transfer {
+            root,
+            data_hash,
+            creator_hash,
+            nonce: listing.nonce,
+            index,
+        },
+        &accounts.listing.key(),
+    )
+}
@@ -0,0 +1,70 @@
+use crate::state::{Listing, LISTING_PREFIX};
+use anchor_lang::prelude::*;
+use mpl_bubblegum::program::Bubblegum;
+
+#[derive(Accounts)]
+pub struct Cancel<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            LISTING_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            &listing.nonce.to_le_bytes(),
+        ],
+        bump = listing.bump,
+        has_one = seller,
+        has_one = merkle_tree,
+        close = seller,
+    )]
+    pub listing: Account<'info, Listing>,
+    /// CHECK: This account is checked by Bubblegum
+    pub tree_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified by Bubblegum
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub voucher: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub leaf_freeze: UncheckedAccount<'info>,
+    pub bubblegum_program: Program<'info, Bubblegum>,
+}
+
+// Makes the seller the delegate of the asset again, and closes the listing.
+pub fn handle_cancel<'info>(
+    ctx: Context<'_, '_, '_, 'info, Cancel<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    index: u32,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let cpi_ctx = CpiContext::new(
+        accounts.bubblegum_program.to_account_info(),
+        mpl_bubblegum::cpi::accounts::Delegate {
+            tree_authority: accounts.tree_authority.to_account_info(),
+            leaf_owner: accounts.seller.to_account_info(),
+            previous_leaf_delegate: accounts.listing.to_account_info(),
+            new_leaf_delegate: accounts.seller.to_account_info(),
+            merkle_tree: accounts.merkle_tree.to_account_info(),
+            log_wrapper: accounts.log_wrapper.to_account_info(),
+            compression_program: accounts.compression_program.to_account_info(),
+            voucher: accounts.voucher.to_account_info(),
+            leaf_freeze: accounts.leaf_freeze.to_account_info(),
+        },
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    mpl_bubblegum::cpi::delegate(
+        cpi_ctx,
+        root,
+        data_hash,
+        creator_hash,
+        accounts.listing.nonce,
+        index,
+    )
+}
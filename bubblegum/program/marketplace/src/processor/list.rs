@@ -0,0 +1,85 @@
+use crate::{
+    error::MarketplaceError,
+    state::{Listing, LISTING_PREFIX, LISTING_SIZE},
+};
+use anchor_lang::prelude::*;
+use mpl_bubblegum::program::Bubblegum;
+
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    nonce: u64,
+)]
+pub struct List<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    #[account(
+        init,
+        seeds = [LISTING_PREFIX.as_ref(), merkle_tree.key().as_ref(), &nonce.to_le_bytes()],
+        payer = seller,
+        space = LISTING_SIZE,
+        bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    /// CHECK: This account is checked by Bubblegum
+    pub tree_authority: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub previous_leaf_delegate: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified by Bubblegum
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub voucher: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub leaf_freeze: UncheckedAccount<'info>,
+    pub bubblegum_program: Program<'info, Bubblegum>,
+    pub system_program: Program<'info, System>,
+}
+
+// Escrows the asset by making the listing its delegate. The seller keeps ownership, and can
+// still act on the asset (which invalidates the listing).
+pub fn handle_list<'info>(
+    ctx: Context<'_, '_, '_, 'info, List<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    price: u64,
+) -> Result<()> {
+    require!(price > 0, MarketplaceError::InvalidPrice);
+
+    let accounts = &ctx.accounts;
+    let cpi_ctx = CpiContext::new(
+        accounts.bubblegum_program.to_account_info(),
+        mpl_bubblegum::cpi::accounts::Delegate {
+            tree_authority: accounts.tree_authority.to_account_info(),
+            leaf_owner: accounts.seller.to_account_info(),
+            previous_leaf_delegate: accounts.previous_leaf_delegate.to_account_info(),
+            new_leaf_delegate: accounts.listing.to_account_info(),
+            merkle_tree: accounts.merkle_tree.to_account_info(),
+            log_wrapper: accounts.log_wrapper.to_account_info(),
+            compression_program: accounts.compression_program.to_account_info(),
+            voucher: accounts.voucher.to_account_info(),
+            leaf_freeze: accounts.leaf_freeze.to_account_info(),
+        },
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    mpl_bubblegum::cpi::delegate(cpi_ctx, root, data_hash, creator_hash, nonce, index)?;
+
+    ctx.accounts.listing.set_inner(Listing {
+        seller: ctx.accounts.seller.key(),
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        nonce,
+        price,
+        bump: *ctx.bumps.get("listing").unwrap(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod buy;
+pub mod cancel;
+pub mod list;
+
+pub use buy::*;
+pub use cancel::*;
+pub use list::*;
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+pub const LISTING_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+pub const LISTING_PREFIX: &str = "listing";
+
+/// An asset that is for sale. The listing is the delegate of the asset while it exists.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct Listing {
+    pub seller: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub nonce: u64,
+    // In lamports.
+    pub price: u64,
+    pub bump: u8,
+}
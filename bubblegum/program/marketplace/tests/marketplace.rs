@@ -0,0 +1,301 @@
+// Reuses the Bubblegum test harness, so that the operations performed by the marketplace via
+// CPI are tracked like any other.
+#[path = "../../tests/utils/mod.rs"]
+pub mod utils;
+
+use mpl_bubblegum_marketplace::{
+    error::MarketplaceError,
+    state::{Listing, LISTING_PREFIX},
+};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+use utils::{
+    context::{BubblegumTestContext, DEFAULT_LAMPORTS_FUND_AMOUNT},
+    Error, LeafArgs, Result, Tree, TxBuilder,
+};
+
+const MAX_DEPTH: usize = 14;
+const MAX_BUF_SIZE: usize = 64;
+
+const PRICE: u64 = 100_000_000;
+
+type ListBuilder = TxBuilder<
+    mpl_bubblegum_marketplace::accounts::List,
+    mpl_bubblegum_marketplace::instruction::List,
+>;
+
+type BuyBuilder = TxBuilder<
+    mpl_bubblegum_marketplace::accounts::Buy,
+    mpl_bubblegum_marketplace::instruction::Buy,
+>;
+
+type CancelBuilder = TxBuilder<
+    mpl_bubblegum_marketplace::accounts::Cancel,
+    mpl_bubblegum_marketplace::instruction::Cancel,
+>;
+
+async fn context_tree_and_leaf() -> Result<(
+    BubblegumTestContext,
+    Tree<MAX_DEPTH, MAX_BUF_SIZE>,
+    LeafArgs,
+)> {
+    let context = BubblegumTestContext::with_programs(&[(
+        "mpl_bubblegum_marketplace",
+        mpl_bubblegum_marketplace::id(),
+    )])
+    .await?;
+
+    let (tree, leaf) = context
+        .default_create_and_mint::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await?;
+
+    Ok((context, tree, leaf))
+}
+
+fn listing(tree: &Tree<MAX_DEPTH, MAX_BUF_SIZE>, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            LISTING_PREFIX.as_ref(),
+            tree.tree_pubkey().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &mpl_bubblegum_marketplace::id(),
+    )
+    .0
+}
+
+async fn list_tx(
+    tree: &Tree<MAX_DEPTH, MAX_BUF_SIZE>,
+    args: &LeafArgs,
+    price: u64,
+) -> Result<ListBuilder> {
+    let accounts = mpl_bubblegum_marketplace::accounts::List {
+        seller: args.owner.pubkey(),
+        listing: listing(tree, args.nonce),
+        tree_authority: tree.authority(),
+        previous_leaf_delegate: args.delegate.pubkey(),
+        merkle_tree: tree.tree_pubkey(),
+        log_wrapper: spl_noop::id(),
+        compression_program: spl_account_compression::id(),
+        voucher: tree.voucher(args.nonce),
+        leaf_freeze: tree.leaf_freeze(args.nonce),
+        bubblegum_program: mpl_bubblegum::id(),
+        system_program: system_program::id(),
+    };
+
+    let data = mpl_bubblegum_marketplace::instruction::List {
+        root: tree.decode_root().await?,
+        data_hash: args.data_hash,
+        creator_hash: args.creator_hash,
+        nonce: args.nonce,
+        index: args.index(),
+        price,
+    };
+
+    Ok(tree.external_leaf_tx(
+        mpl_bubblegum_marketplace::id(),
+        accounts,
+        data,
+        args.index(),
+        args.owner.pubkey(),
+        &[&args.owner],
+    ))
+}
+
+async fn buy_tx(
+    tree: &Tree<MAX_DEPTH, MAX_BUF_SIZE>,
+    args: &LeafArgs,
+    buyer: &Keypair,
+    max_price: u64,
+) -> Result<BuyBuilder> {
+    let accounts = mpl_bubblegum_marketplace::accounts::Buy {
+        buyer: buyer.pubkey(),
+        seller: args.owner.pubkey(),
+        listing: listing(tree, args.nonce),
+        tree_authority: tree.authority(),
+        merkle_tree: tree.tree_pubkey(),
+        log_wrapper: spl_noop::id(),
+        compression_program: spl_account_compression::id(),
+        voucher: tree.voucher(args.nonce),
+        leaf_freeze: tree.leaf_freeze(args.nonce),
+        soulbound_override: tree.soulbound_override(args.nonce),
+        delegate_expiry: tree.delegate_expiry(args.nonce),
+        bubblegum_program: mpl_bubblegum::id(),
+        system_program: system_program::id(),
+    };
+
+    let data = mpl_bubblegum_marketplace::instruction::Buy {
+        root: tree.decode_root().await?,
+        data_hash: args.data_hash,
+        creator_hash: args.creator_hash,
+        index: args.index(),
+        max_price,
+    };
+
+    Ok(tree.external_transfer_tx(
+        mpl_bubblegum_marketplace::id(),
+        accounts,
+        data,
+        args.index(),
+        buyer.pubkey(),
+        &[buyer],
+    ))
+}
+
+async fn cancel_tx(tree: &Tree<MAX_DEPTH, MAX_BUF_SIZE>, args: &LeafArgs) -> Result<CancelBuilder> {
+    let accounts = mpl_bubblegum_marketplace::accounts::Cancel {
+        seller: args.owner.pubkey(),
+        listing: listing(tree, args.nonce),
+        tree_authority: tree.authority(),
+        merkle_tree: tree.tree_pubkey(),
+        log_wrapper: spl_noop::id(),
+        compression_program: spl_account_compression::id(),
+        voucher: tree.voucher(args.nonce),
+        leaf_freeze: tree.leaf_freeze(args.nonce),
+        bubblegum_program: mpl_bubblegum::id(),
+    };
+
+    let data = mpl_bubblegum_marketplace::instruction::Cancel {
+        root: tree.decode_root().await?,
+        data_hash: args.data_hash,
+        creator_hash: args.creator_hash,
+        index: args.index(),
+    };
+
+    Ok(tree.external_leaf_tx(
+        mpl_bubblegum_marketplace::id(),
+        accounts,
+        data,
+        args.index(),
+        args.owner.pubkey(),
+        &[&args.owner],
+    ))
+}
+
+fn is_marketplace_error(err: &Error, expected: MarketplaceError) -> bool {
+    err.is_custom_error(u32::from(expected))
+}
+
+#[tokio::test]
+async fn test_list_and_buy() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let buyer = Keypair::new();
+    context
+        .fund_account(buyer.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    let err = list_tx(&tree, &leaf, 0)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(is_marketplace_error(&err, MarketplaceError::InvalidPrice));
+
+    list_tx(&tree, &leaf, PRICE)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+    let listed: Listing = tree
+        .read_account_data(listing(&tree, leaf.nonce))
+        .await
+        .unwrap();
+    assert_eq!(listed.seller, leaf.owner.pubkey());
+    assert_eq!(listed.price, PRICE);
+
+    let err = buy_tx(&tree, &leaf, &buyer, PRICE - 1)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(is_marketplace_error(&err, MarketplaceError::PriceTooHigh));
+
+    let seller_balance = tree
+        .client()
+        .get_balance(leaf.owner.pubkey())
+        .await
+        .unwrap();
+    buy_tx(&tree, &leaf, &buyer, PRICE)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+
+    // The seller got paid (and the rent of the listing back).
+    let balance = tree
+        .client()
+        .get_balance(leaf.owner.pubkey())
+        .await
+        .unwrap();
+    assert!(balance > seller_balance + PRICE);
+    assert!(tree
+        .client()
+        .get_account(listing(&tree, leaf.nonce))
+        .await
+        .unwrap()
+        .is_none());
+
+    // The buyer owns the asset.
+    tree.burn(&leaf.with_new_owner(&buyer)).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_cancel_listing() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    list_tx(&tree, &leaf, PRICE)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+
+    // Only the seller can cancel.
+    let impostor = Keypair::new();
+    context
+        .fund_account(impostor.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    let mut tx = cancel_tx(&tree, &leaf).await.unwrap();
+    tx.accounts.seller = impostor.pubkey();
+    assert!(tx
+        .set_payer(impostor.pubkey())
+        .set_signers(&[&impostor])
+        .execute()
+        .await
+        .is_err());
+
+    cancel_tx(&tree, &leaf)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+    assert!(tree
+        .client()
+        .get_account(listing(&tree, leaf.nonce))
+        .await
+        .unwrap()
+        .is_none());
+
+    // The asset can no longer be bought, and the seller is its delegate again.
+    let buyer = Keypair::new();
+    context
+        .fund_account(buyer.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    assert!(buy_tx(&tree, &leaf, &buyer, PRICE)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .is_err());
+    tree.transfer(&leaf, buyer.pubkey()).await.unwrap();
+}
@@ -0,0 +1,196 @@
+//! Estimates of what transferring a compressed asset costs, so that wallets can warn users
+//! before operations on deep trees, where proofs can get close to the transaction size limit.
+//!
+//! Sizes and account counts are exact, since they are derived from the layout of the
+//! `transfer` instruction. Compute units are a conservative static estimate, which can be
+//! replaced with the result of a simulation via `TransferCost::with_simulation_logs`.
+
+use super::rent::checked_merkle_tree_account_size;
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, message::Message},
+    InstructionData,
+};
+
+/// Maximum size of a serialized transaction, which has to fit in a single packet.
+pub const MAX_TRANSACTION_SIZE: usize = 1232;
+
+/// Fee paid for every signature of a transaction.
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5000;
+
+/// Compute units used by a transfer regardless of the depth of the tree.
+pub const TRANSFER_BASE_COMPUTE_UNITS: u64 = 30_000;
+
+/// Compute units used for every proof node that is passed to a transfer.
+pub const COMPUTE_UNITS_PER_PROOF_NODE: u64 = 1_500;
+
+/// Dimensions of the tree the estimate is for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TreeParams {
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferCost {
+    /// Size of the serialized transaction, signatures included.
+    pub tx_size: usize,
+    /// Number of distinct accounts referenced by the transaction.
+    pub num_accounts: usize,
+    /// Number of proof nodes passed as remaining accounts.
+    pub proof_accounts: usize,
+    pub compute_units: u64,
+    /// Price of a compute unit in micro-lamports, as set via the compute budget program.
+    pub priority_fee: u64,
+    /// Signature fees plus the priority fee for `compute_units`.
+    pub fee_lamports: u64,
+}
+
+impl TransferCost {
+    /// Whether the transaction fits in a single packet, which it has to in order to be sent.
+    pub fn fits_in_transaction(&self) -> bool {
+        self.tx_size <= MAX_TRANSACTION_SIZE
+    }
+
+    /// Replaces the estimated compute units with the ones consumed by the program in the logs
+    /// of a simulated transfer, and updates the fees accordingly.
+    pub fn with_simulation_logs(mut self, logs: &[String]) -> Self {
+        let prefix = format!("Program {} consumed ", crate::id());
+        self.compute_units = logs
+            .iter()
+            .filter_map(|log| log.strip_prefix(&prefix))
+            .filter_map(|rest| rest.split(' ').next())
+            .filter_map(|units| units.parse::<u64>().ok())
+            .sum();
+        self.fee_lamports = fee_lamports(self.compute_units, self.priority_fee);
+        self
+    }
+}
+
+/// Estimates the cost of a transfer signed by the leaf owner, who also pays for it, with every
+/// proof node below the canopy passed along (as is the case once the tree fills up). Fails if
+/// the canopy is not shallower than the tree, or the tree would not fit in an account.
+pub fn transfer_cost(
+    tree: TreeParams,
+    canopy_depth: u32,
+    priority_fee: u64,
+) -> Result<TransferCost> {
+    checked_merkle_tree_account_size(tree.max_depth, tree.max_buffer_size, canopy_depth)?;
+    let proof_accounts = (tree.max_depth - canopy_depth) as usize;
+
+    let message = Message::new(
+        &[transfer_instruction(proof_accounts)],
+        Some(&Pubkey::default()),
+    );
+    let num_signatures = usize::from(message.header.num_required_signatures);
+    // One byte for the number of signatures, followed by the signatures and the message.
+    let tx_size = 1 + num_signatures * 64 + message.serialize().len();
+
+    let compute_units =
+        TRANSFER_BASE_COMPUTE_UNITS + COMPUTE_UNITS_PER_PROOF_NODE * proof_accounts as u64;
+
+    Ok(TransferCost {
+        tx_size,
+        num_accounts: message.account_keys.len(),
+        proof_accounts,
+        compute_units,
+        priority_fee,
+        fee_lamports: fee_lamports(compute_units, priority_fee),
+    })
+}
+
+fn fee_lamports(compute_units: u64, priority_fee: u64) -> u64 {
+    // Priority fees are rounded up to the next lamport.
+    let micro_lamports = u128::from(compute_units) * u128::from(priority_fee);
+    let priority_lamports = ((micro_lamports + 999_999) / 1_000_000) as u64;
+    LAMPORTS_PER_SIGNATURE + priority_lamports
+}
+
+// A transfer with placeholder keys, where the leaf owner is the payer and has no delegate. The
+// tree has no companion accounts.
+fn transfer_instruction(proof_accounts: usize) -> Instruction {
+    let owner = Pubkey::default();
+    let accounts = crate::accounts::Transfer {
+        tree_authority: Pubkey::new_unique(),
+        leaf_owner: owner,
+        leaf_delegate: owner,
+        new_leaf_owner: Pubkey::new_unique(),
+        merkle_tree: Pubkey::new_unique(),
+        log_wrapper: spl_noop::id(),
+        compression_program: spl_account_compression::id(),
+        voucher: Pubkey::new_unique(),
+        soulbound_override: Pubkey::new_unique(),
+        delegate_expiry: Pubkey::new_unique(),
+        leaf_freeze: Pubkey::new_unique(),
+    };
+    let mut metas = accounts.to_account_metas(None);
+    metas.extend(
+        (0..proof_accounts).map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false)),
+    );
+
+    let data = crate::instruction::Transfer {
+        root: [0; 32],
+        data_hash: [0; 32],
+        creator_hash: [0; 32],
+        nonce: 0,
+        index: 0,
+    };
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: metas,
+        data: data.data(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TREE: TreeParams = TreeParams {
+        max_depth: 20,
+        max_buffer_size: 64,
+    };
+
+    #[test]
+    fn canopy_reduces_cost() {
+        let shallow = transfer_cost(TREE, 10, 0).unwrap();
+        let deep = transfer_cost(TREE, 0, 0).unwrap();
+
+        assert_eq!(shallow.proof_accounts, 10);
+        assert_eq!(deep.proof_accounts, 20);
+        // Each proof node is an extra account key, plus its index in the instruction.
+        assert_eq!(deep.num_accounts, shallow.num_accounts + 10);
+        assert_eq!(deep.tx_size, shallow.tx_size + 10 * 33);
+        assert!(deep.compute_units > shallow.compute_units);
+        assert!(shallow.fits_in_transaction());
+        assert!(!transfer_cost(
+            TreeParams {
+                max_depth: 30,
+                max_buffer_size: 64
+            },
+            0,
+            0
+        )
+        .unwrap()
+        .fits_in_transaction());
+
+        assert!(transfer_cost(TREE, 20, 0).is_err());
+    }
+
+    #[test]
+    fn fees_follow_compute_units() {
+        let cost = transfer_cost(TREE, 10, 0).unwrap();
+        assert_eq!(cost.fee_lamports, LAMPORTS_PER_SIGNATURE);
+
+        let logs = vec![format!(
+            "Program {} consumed 100000 of 200000 compute units",
+            crate::id()
+        )];
+        let cost = transfer_cost(TREE, 10, 10)
+            .unwrap()
+            .with_simulation_logs(&logs);
+        assert_eq!(cost.compute_units, 100_000);
+        assert_eq!(cost.fee_lamports, LAMPORTS_PER_SIGNATURE + 1);
+    }
+}
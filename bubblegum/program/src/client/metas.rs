@@ -0,0 +1,78 @@
+//! Normalization of the account metas of Bubblegum instructions assembled by clients, i.e.
+//! from named accounts, per-leaf PDAs and proofs that may come from different sources.
+//!
+//! Remaining accounts are read by position (a proof is just the nodes from the leaf up to the
+//! canopy), so duplicate metas cannot be dropped from an instruction. The same key can show up
+//! more than once, e.g. as a shared node of the proofs in a `transfer_batch`, or as a node that
+//! also happens to be one of the named accounts. When the transaction is compiled, all the
+//! occurrences of a key collapse into a single entry of the message, so they are given the same
+//! flags here. That way, the resulting message does not depend on which occurrence comes first.
+
+use anchor_lang::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Gives every occurrence of a key the flags of all of them combined, i.e. a key is a signer
+/// (or writable) everywhere if it is one anywhere. The order and number of the metas is kept.
+pub fn normalize_account_metas(metas: &mut [AccountMeta]) {
+    let mut flags = HashMap::<Pubkey, (bool, bool)>::new();
+    for meta in metas.iter() {
+        let (is_signer, is_writable) = flags.entry(meta.pubkey).or_default();
+        *is_signer |= meta.is_signer;
+        *is_writable |= meta.is_writable;
+    }
+    for meta in metas.iter_mut() {
+        let (is_signer, is_writable) = flags[&meta.pubkey];
+        meta.is_signer = is_signer;
+        meta.is_writable = is_writable;
+    }
+}
+
+/// The distinct keys of `metas` in the order they first appear, e.g. to extend an address
+/// lookup table with the proof nodes of an instruction.
+pub fn unique_keys(metas: &[AccountMeta]) -> Vec<Pubkey> {
+    let mut seen = HashSet::new();
+    metas
+        .iter()
+        .map(|meta| meta.pubkey)
+        .filter(|key| seen.insert(*key))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicates_share_flags_and_keep_their_positions() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut metas = vec![
+            AccountMeta::new_readonly(a, false),
+            AccountMeta::new(b, false),
+            AccountMeta::new_readonly(a, true),
+            AccountMeta::new_readonly(c, false),
+            AccountMeta::new_readonly(b, false),
+        ];
+        normalize_account_metas(&mut metas);
+
+        assert_eq!(
+            metas,
+            vec![
+                AccountMeta::new_readonly(a, true),
+                AccountMeta::new(b, false),
+                AccountMeta::new_readonly(a, true),
+                AccountMeta::new_readonly(c, false),
+                AccountMeta::new(b, false),
+            ]
+        );
+        assert_eq!(unique_keys(&metas), vec![a, b, c]);
+
+        // Normalizing is idempotent.
+        let normalized = metas.clone();
+        normalize_account_metas(&mut metas);
+        assert_eq!(metas, normalized);
+    }
+}
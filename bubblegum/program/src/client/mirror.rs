@@ -0,0 +1,380 @@
+use super::BubblegumOp;
+use crate::{hash::hash_pair, utils::get_asset_id, InstructionName};
+use anchor_lang::prelude::*;
+use memmap2::MmapMut;
+use spl_account_compression::Node;
+use std::{convert::TryInto, fs::OpenOptions, io, mem::size_of, path::Path};
+
+/// Storage for the nodes cached by a `TreeMirror`. Nodes that were never written must read
+/// back as all zeros, which the mirror interprets as the root of an empty subtree.
+pub trait NodeStore {
+    fn node(&self, level: usize, index: usize) -> Node;
+
+    fn set_node(&mut self, level: usize, index: usize, node: Node);
+
+    /// Number of leaf slots up to and including the rightmost one that was ever written.
+    fn num_leaves(&self) -> usize;
+}
+
+/// Keeps all nodes in memory, only allocating space up to the rightmost written node of
+/// each level.
+pub struct MemoryStore {
+    levels: Vec<Vec<Node>>,
+}
+
+impl MemoryStore {
+    pub fn new(max_depth: usize) -> Self {
+        MemoryStore {
+            levels: vec![Vec::new(); max_depth + 1],
+        }
+    }
+}
+
+impl NodeStore for MemoryStore {
+    fn node(&self, level: usize, index: usize) -> Node {
+        self.levels[level].get(index).copied().unwrap_or_default()
+    }
+
+    fn set_node(&mut self, level: usize, index: usize, node: Node) {
+        let nodes = &mut self.levels[level];
+        if nodes.len() <= index {
+            nodes.resize(index + 1, Node::default());
+        }
+        nodes[index] = node;
+    }
+
+    fn num_leaves(&self) -> usize {
+        self.levels[0].len()
+    }
+}
+
+// The file backing an `MmapStore` starts with the number of leaves, followed by the levels
+// of the tree laid out one after the other, starting with the leaves.
+const MMAP_HEADER_SIZE: usize = size_of::<u64>();
+
+/// Keeps the nodes in a memory-mapped file, so very large trees (i.e. for capacity planning
+/// simulations) don't have to fit in memory, and the mirror can be reopened later on. The
+/// file is sized for a full tree upfront, but it is sparse on most file systems, so only the
+/// pages that get written actually take up space.
+pub struct MmapStore {
+    mmap: MmapMut,
+    max_depth: usize,
+    num_leaves: usize,
+}
+
+impl MmapStore {
+    pub fn open<P: AsRef<Path>>(path: P, max_depth: usize) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .open(path)?;
+
+        let size = (MMAP_HEADER_SIZE + ((1 << (max_depth + 1)) - 1) * size_of::<Node>()) as u64;
+        let current_size = file.metadata()?.len();
+        if current_size == 0 {
+            file.set_len(size)?;
+        } else if current_size != size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "file size does not match the tree depth",
+            ));
+        }
+
+        // Safe as long as the file is not concurrently modified by someone else.
+        let mmap = unsafe { MmapMut::map_mut(&file)? };
+        let num_leaves = u64::from_le_bytes(mmap[..MMAP_HEADER_SIZE].try_into().unwrap()) as usize;
+
+        Ok(MmapStore {
+            mmap,
+            max_depth,
+            num_leaves,
+        })
+    }
+
+    pub fn flush(&self) -> io::Result<()> {
+        self.mmap.flush()
+    }
+
+    fn offset(&self, level: usize, index: usize) -> usize {
+        // Level `l` holds `2^(max_depth - l)` nodes, so it's preceded by
+        // `2^(max_depth + 1) - 2^(max_depth + 1 - l)` nodes from the levels below.
+        let preceding = (1 << (self.max_depth + 1)) - (1 << (self.max_depth + 1 - level));
+        MMAP_HEADER_SIZE + (preceding + index) * size_of::<Node>()
+    }
+}
+
+impl NodeStore for MmapStore {
+    fn node(&self, level: usize, index: usize) -> Node {
+        let offset = self.offset(level, index);
+        self.mmap[offset..offset + size_of::<Node>()]
+            .try_into()
+            .unwrap()
+    }
+
+    fn set_node(&mut self, level: usize, index: usize, node: Node) {
+        let offset = self.offset(level, index);
+        self.mmap[offset..offset + size_of::<Node>()].copy_from_slice(&node);
+
+        if level == 0 && index >= self.num_leaves {
+            self.num_leaves = index + 1;
+            self.mmap[..MMAP_HEADER_SIZE].copy_from_slice(&(self.num_leaves as u64).to_le_bytes());
+        }
+    }
+
+    fn num_leaves(&self) -> usize {
+        self.num_leaves
+    }
+}
+
+/// Off-chain copy of a tree that is kept up to date by applying the operations observed for
+/// it. Every level of the tree is cached, so updating a leaf only recomputes the nodes on its
+/// path to the root, and proofs are read directly from the cache.
+pub struct TreeMirror<S = MemoryStore> {
+    tree_id: Pubkey,
+    max_depth: usize,
+    // Level 0 holds the leaves and level `max_depth` the root.
+    store: S,
+    // `empty_nodes[i]` is the root of an empty subtree of height `i`.
+    empty_nodes: Vec<Node>,
+}
+
+impl TreeMirror {
+    pub fn new(tree_id: Pubkey, max_depth: usize) -> Self {
+        Self::with_store(tree_id, max_depth, MemoryStore::new(max_depth))
+    }
+}
+
+impl<S: NodeStore> TreeMirror<S> {
+    pub fn with_store(tree_id: Pubkey, max_depth: usize, store: S) -> Self {
+        let mut empty_nodes = vec![Node::default()];
+        for level in 0..max_depth {
+            let node = empty_nodes[level];
+            empty_nodes.push(hash_pair(&node, &node));
+        }
+
+        TreeMirror {
+            tree_id,
+            max_depth,
+            store,
+            empty_nodes,
+        }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn tree_id(&self) -> Pubkey {
+        self.tree_id
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Number of leaf slots up to and including the rightmost one that was ever set.
+    pub fn num_leaves(&self) -> usize {
+        self.store.num_leaves()
+    }
+
+    fn node(&self, level: usize, index: usize) -> Node {
+        match self.store.node(level, index) {
+            node if node == Node::default() => self.empty_nodes[level],
+            node => node,
+        }
+    }
+
+    pub fn root(&self) -> Node {
+        self.node(self.max_depth, 0)
+    }
+
+    pub fn leaf(&self, index: u32) -> Node {
+        self.node(0, index as usize)
+    }
+
+    pub fn set_leaf(&mut self, index: u32, leaf: Node) {
+        let mut index = index as usize;
+        assert!(
+            index < 1 << self.max_depth,
+            "leaf index {} out of bounds for depth {}",
+            index,
+            self.max_depth
+        );
+
+        let mut node = leaf;
+        for level in 0..=self.max_depth {
+            self.store.set_node(level, index, node);
+
+            if level == self.max_depth {
+                break;
+            }
+
+            let sibling = self.node(level, index ^ 1);
+            node = if index % 2 == 0 {
+                hash_pair(&node, &sibling)
+            } else {
+                hash_pair(&sibling, &node)
+            };
+            index /= 2;
+        }
+    }
+
+    pub fn proof(&self, index: u32) -> Vec<Node> {
+        let mut index = index as usize;
+        (0..self.max_depth)
+            .map(|level| {
+                let sibling = self.node(level, index ^ 1);
+                index /= 2;
+                sibling
+            })
+            .collect()
+    }
+
+    /// Same as `proof`, but without the trailing nodes that are roots of empty subtrees. The
+    /// compression program fills those in on its own, but Bubblegum instructions require a node
+    /// for every level below the canopy (see `utils::assert_proof_length`).
+    pub fn compact_proof(&self, index: u32) -> Vec<Node> {
+        let mut proof = self.proof(index);
+        while let Some(node) = proof.last() {
+            if *node != self.empty_nodes[proof.len() - 1] {
+                break;
+            }
+            proof.pop();
+        }
+        proof
+    }
+
+    /// Applies the leaf changes described by an operation, if the operation concerns this
+    /// tree, and returns how many leaves were changed. Leaves are appended in nonce order, so
+    /// the nonce of an asset is also its index.
+    pub fn apply(&mut self, op: &BubblegumOp) -> usize {
+        let mut changed = 0;
+        for leaf in op.leaves() {
+            let nonce = leaf.nonce();
+            if leaf.id() != get_asset_id(&self.tree_id, nonce) {
+                continue;
+            }
+
+            let node = match op.instruction {
+                // These log the leaf that gets removed from the tree.
+                InstructionName::Burn
+                | InstructionName::BurnWithReceipt
+                | InstructionName::Redeem
+                | InstructionName::TransferTimelocked => Node::default(),
+                _ => leaf.to_node(),
+            };
+
+            self.set_leaf(nonce as u32, node);
+            changed += 1;
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::keccak;
+
+    // Recomputes the root from scratch, for comparison with the incrementally updated one.
+    fn naive_root(leaves: &[Node], max_depth: usize) -> Node {
+        let mut layer = vec![Node::default(); 1 << max_depth];
+        layer[..leaves.len()].copy_from_slice(leaves);
+        for _ in 0..max_depth {
+            layer = layer.chunks(2).map(|p| hash_pair(&p[0], &p[1])).collect();
+        }
+        layer[0]
+    }
+
+    fn verify(leaf: Node, proof: &[Node], index: u32, root: Node) -> bool {
+        let mut node = leaf;
+        for (level, sibling) in proof.iter().enumerate() {
+            node = if (index >> level) % 2 == 0 {
+                hash_pair(&node, sibling)
+            } else {
+                hash_pair(sibling, &node)
+            };
+        }
+        node == root
+    }
+
+    #[test]
+    fn incremental_updates_match_full_recomputation() {
+        const MAX_DEPTH: usize = 8;
+        let mut mirror = TreeMirror::new(Pubkey::new_unique(), MAX_DEPTH);
+        let mut leaves = Vec::new();
+
+        assert_eq!(mirror.root(), naive_root(&leaves, MAX_DEPTH));
+
+        for i in 0..100u8 {
+            let leaf = keccak::hash(&[i]).to_bytes();
+            mirror.set_leaf(i as u32, leaf);
+            leaves.push(leaf);
+        }
+
+        // Overwrite a few leaves, including clearing some of them.
+        for i in (0..100).step_by(7) {
+            let leaf = if i % 2 == 0 {
+                Node::default()
+            } else {
+                keccak::hash(&[i as u8, 1]).to_bytes()
+            };
+            mirror.set_leaf(i, leaf);
+            leaves[i as usize] = leaf;
+        }
+
+        let root = mirror.root();
+        assert_eq!(root, naive_root(&leaves, MAX_DEPTH));
+        assert_eq!(mirror.num_leaves(), leaves.len());
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let index = index as u32;
+            assert!(verify(*leaf, &mirror.proof(index), index, root));
+
+            // Compact proofs verify once the empty subtree roots are added back.
+            let mut proof = mirror.compact_proof(index);
+            proof.extend_from_slice(&mirror.empty_nodes[proof.len()..MAX_DEPTH]);
+            assert!(verify(*leaf, &proof, index, root));
+        }
+    }
+
+    #[test]
+    fn mmap_store_matches_memory_store() {
+        const MAX_DEPTH: usize = 10;
+        let tree_id = Pubkey::new_unique();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mirror");
+
+        let mut memory = TreeMirror::new(tree_id, MAX_DEPTH);
+        let mut mmap = TreeMirror::with_store(
+            tree_id,
+            MAX_DEPTH,
+            MmapStore::open(&path, MAX_DEPTH).unwrap(),
+        );
+
+        for i in (0..1 << MAX_DEPTH).step_by(3) {
+            let leaf = keccak::hash(&i.to_le_bytes()).to_bytes();
+            memory.set_leaf(i, leaf);
+            mmap.set_leaf(i, leaf);
+        }
+
+        assert_eq!(memory.root(), mmap.root());
+        assert_eq!(memory.num_leaves(), mmap.num_leaves());
+        assert_eq!(memory.proof(42), mmap.proof(42));
+
+        // The contents survive reopening the file.
+        mmap.store().flush().unwrap();
+        drop(mmap);
+        let reopened = TreeMirror::with_store(
+            tree_id,
+            MAX_DEPTH,
+            MmapStore::open(&path, MAX_DEPTH).unwrap(),
+        );
+        assert_eq!(memory.root(), reopened.root());
+        assert_eq!(memory.num_leaves(), reopened.num_leaves());
+
+        // Reopening with a different depth is rejected.
+        assert!(MmapStore::open(&path, MAX_DEPTH + 1).is_err());
+    }
+}
@@ -0,0 +1,766 @@
+//! Off-chain helpers that reconstruct the state of compressed assets from the logs emitted by
+//! the program. They are intended for clients, indexers, and tests, and are not compiled for
+//! the on-chain program.
+
+use crate::{
+    hash::hash_metadata_args,
+    state::{
+        leaf_schema::{LeafSchema, LeafSchemaEvent, Version},
+        metaplex_adapter::{Creator, MetadataArgs},
+        MetadataAttestationEvent, MetadataUpdateEvent, NFTDecompressionEvent, NewNFTEvent,
+        TreeCreatorTransferEvent, TreeMetadataEvent,
+    },
+    utils::get_asset_id,
+    InstructionName,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use std::{collections::BTreeMap, ops::Range};
+
+pub mod estimate;
+pub mod metas;
+pub mod mirror;
+pub mod rent;
+
+const PROGRAM_LOG_INSTRUCTION: &str = "Program log: Instruction: ";
+const PROGRAM_DATA: &str = "Program data: ";
+const PROGRAM_RETURN: &str = "Program return: ";
+
+#[derive(Clone)]
+pub enum BubblegumEvent {
+    NewNFT(NewNFTEvent),
+    LeafSchema(LeafSchemaEvent),
+    NFTDecompression(NFTDecompressionEvent),
+    TreeMetadata(TreeMetadataEvent),
+    MetadataAttestation(MetadataAttestationEvent),
+    TreeCreatorTransfer(TreeCreatorTransferEvent),
+    MetadataUpdate(MetadataUpdateEvent),
+    UnknownVersion(UnknownVersionEvent),
+}
+
+/// An event of a known type, but with a version this crate does not know about, i.e. one
+/// emitted by a newer release of the program. Every event starts with its `Version`, so the
+/// type and version can still be told apart, while the rest of `data` is left undecoded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownVersionEvent {
+    pub discriminator: [u8; 8],
+    // The raw Borsh encoding of the `Version`.
+    pub version: u8,
+    // The serialized event, without the discriminator.
+    pub data: Vec<u8>,
+}
+
+impl BubblegumEvent {
+    // Decodes the raw bytes logged by `emit!`, which consist of the event discriminator
+    // followed by the Borsh serialized event. Returns `None` for unknown or malformed events,
+    // and `UnknownVersion` for known events with a version that is newer than this crate.
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        decode_event(data)
+            .map(BubblegumEvent::NewNFT)
+            .or_else(|| decode_event(data).map(BubblegumEvent::LeafSchema))
+            .or_else(|| decode_event(data).map(BubblegumEvent::NFTDecompression))
+            .or_else(|| decode_event(data).map(BubblegumEvent::TreeMetadata))
+            .or_else(|| decode_event(data).map(BubblegumEvent::MetadataAttestation))
+            .or_else(|| decode_event(data).map(BubblegumEvent::TreeCreatorTransfer))
+            .or_else(|| decode_event(data).map(BubblegumEvent::MetadataUpdate))
+            .or_else(|| decode_unknown_version(data).map(BubblegumEvent::UnknownVersion))
+    }
+}
+
+fn decode_unknown_version(data: &[u8]) -> Option<UnknownVersionEvent> {
+    let known = [
+        NewNFTEvent::discriminator(),
+        LeafSchemaEvent::discriminator(),
+        NFTDecompressionEvent::discriminator(),
+        TreeMetadataEvent::discriminator(),
+        MetadataAttestationEvent::discriminator(),
+        TreeCreatorTransferEvent::discriminator(),
+        MetadataUpdateEvent::discriminator(),
+    ];
+
+    if data.len() < 9 || !known.iter().any(|disc| disc[..] == data[..8]) {
+        return None;
+    }
+
+    // Events with a known version that failed to decode are malformed.
+    if Version::try_from_slice(&data[8..9]).is_ok() {
+        return None;
+    }
+
+    let mut discriminator = [0; 8];
+    discriminator.copy_from_slice(&data[..8]);
+    Some(UnknownVersionEvent {
+        discriminator,
+        version: data[8],
+        data: data[8..].to_vec(),
+    })
+}
+
+/// Decodes a single event of type `E` from the bytes logged by `emit!`. The discriminator is
+/// checked before decoding, and the data must be consumed entirely, so arbitrary input never
+/// causes a panic or gets misinterpreted as an event of a different type.
+pub fn decode_event<E>(data: &[u8]) -> Option<E>
+where
+    E: Discriminator + AnchorDeserialize,
+{
+    if data.len() < 8 {
+        return None;
+    }
+
+    let (disc, rest) = data.split_at(8);
+    if disc != E::discriminator() {
+        return None;
+    }
+
+    E::try_from_slice(rest).ok()
+}
+
+/// A single invocation of the program, along with the events it emitted.
+#[derive(Clone)]
+pub struct BubblegumOp {
+    pub instruction: InstructionName,
+    pub events: Vec<BubblegumEvent>,
+    // Raw return data set by the instruction, if any.
+    pub return_data: Option<Vec<u8>>,
+}
+
+impl BubblegumOp {
+    pub fn leaf(&self) -> Option<&LeafSchema> {
+        self.leaves().next()
+    }
+
+    /// All the leaves logged by the operation, in order. The batched instructions (i.e.
+    /// `mint_airdrop`, `mint_batch_v1` and `transfer_batch`) log one leaf per asset.
+    pub fn leaves(&self) -> impl Iterator<Item = &LeafSchema> {
+        self.events.iter().filter_map(|e| match e {
+            BubblegumEvent::LeafSchema(event) => Some(&event.schema),
+            _ => None,
+        })
+    }
+
+    pub fn new_nft(&self) -> Option<&NewNFTEvent> {
+        self.new_nfts().next()
+    }
+
+    /// The `NewNFTEvent`s logged by the operation, which match up with `leaves`.
+    pub fn new_nfts(&self) -> impl Iterator<Item = &NewNFTEvent> {
+        self.events.iter().filter_map(|e| match e {
+            BubblegumEvent::NewNFT(event) => Some(event),
+            _ => None,
+        })
+    }
+
+    pub fn decompression(&self) -> Option<&NFTDecompressionEvent> {
+        self.events.iter().find_map(|e| match e {
+            BubblegumEvent::NFTDecompression(event) => Some(event),
+            _ => None,
+        })
+    }
+
+    pub fn attestation(&self) -> Option<&MetadataAttestationEvent> {
+        self.events.iter().find_map(|e| match e {
+            BubblegumEvent::MetadataAttestation(event) => Some(event),
+            _ => None,
+        })
+    }
+
+    pub fn metadata_update(&self) -> Option<&MetadataUpdateEvent> {
+        self.events.iter().find_map(|e| match e {
+            BubblegumEvent::MetadataUpdate(event) => Some(event),
+            _ => None,
+        })
+    }
+}
+
+/// Checks that the full metadata of an asset minted via `mint_v1_hashed`, as delivered
+/// off-chain, is the one attested to when minting. The collection must also be unverified,
+/// since the program could not check that at mint time.
+pub fn verify_attestation(event: &MetadataAttestationEvent, metadata: &MetadataArgs) -> bool {
+    let attested = &event.metadata;
+    let collection_unverified = metadata
+        .collection
+        .as_ref()
+        .map_or(true, |collection| !collection.verified);
+
+    collection_unverified
+        && attested.name == metadata.name
+        && attested.symbol == metadata.symbol
+        && attested.seller_fee_basis_points == metadata.seller_fee_basis_points
+        && attested.creators == metadata.creators
+        && hash_metadata_args(metadata).ok() == Some(attested.metadata_args_hash)
+}
+
+/// Sorts creators by address, which is the order the program requires when minting (see
+/// `utils::assert_creators_are_sorted`).
+pub fn canonicalize_creators(creators: &mut [Creator]) {
+    creators.sort_by_key(|creator| creator.address);
+}
+
+pub fn instruction_from_log_name(name: &str) -> InstructionName {
+    match name {
+        "MintV1" => InstructionName::MintV1,
+        "Redeem" => InstructionName::Redeem,
+        "CancelRedeem" => InstructionName::CancelRedeem,
+        "Transfer" => InstructionName::Transfer,
+        "Delegate" => InstructionName::Delegate,
+        "DecompressV1" => InstructionName::DecompressV1,
+        "Compress" => InstructionName::Compress,
+        "Burn" => InstructionName::Burn,
+        "CreateTree" => InstructionName::CreateTree,
+        "VerifyCreator" => InstructionName::VerifyCreator,
+        "UnverifyCreator" => InstructionName::UnverifyCreator,
+        "VerifyCollection" => InstructionName::VerifyCollection,
+        "UnverifyCollection" => InstructionName::UnverifyCollection,
+        "SetAndVerifyCollection" => InstructionName::SetAndVerifyCollection,
+        "SetTreeMetadata" => InstructionName::SetTreeMetadata,
+        "VerifyMetadataHash" => InstructionName::VerifyMetadataHash,
+        "MintV1Hashed" => InstructionName::MintV1Hashed,
+        "SetTreeHashOnly" => InstructionName::SetTreeHashOnly,
+        "WithdrawFromTreeAuthority" => InstructionName::WithdrawFromTreeAuthority,
+        "SetLoggingMode" => InstructionName::SetLoggingMode,
+        "TransferTimelocked" => InstructionName::TransferTimelocked,
+        "ClaimTimelockedTransfer" => InstructionName::ClaimTimelockedTransfer,
+        "CancelTimelockedTransfer" => InstructionName::CancelTimelockedTransfer,
+        "MintAirdrop" => InstructionName::MintAirdrop,
+        "SetTreeNonTransferable" => InstructionName::SetTreeNonTransferable,
+        "SetSoulboundOverride" => InstructionName::SetSoulboundOverride,
+        "DelegateWithExpiry" => InstructionName::DelegateWithExpiry,
+        "InitOnChainMetadata" => InstructionName::InitOnChainMetadata,
+        "WriteMetadataChunk" => InstructionName::WriteMetadataChunk,
+        "MintV1WithReceipt" => InstructionName::MintV1WithReceipt,
+        "CloseAssetReceipt" => InstructionName::CloseAssetReceipt,
+        "BurnWithReceipt" => InstructionName::BurnWithReceipt,
+        "CloseBurnReceipt" => InstructionName::CloseBurnReceipt,
+        "ProposeTreeCreator" => InstructionName::ProposeTreeCreator,
+        "AcceptTreeCreator" => InstructionName::AcceptTreeCreator,
+        "CancelTreeCreatorTransfer" => InstructionName::CancelTreeCreatorTransfer,
+        "SetFeatureFlags" => InstructionName::SetFeatureFlags,
+        "UpdateMetadata" => InstructionName::UpdateMetadata,
+        "MintToCollectionV1" => InstructionName::MintToCollectionV1,
+        "MintBatchV1" => InstructionName::MintBatchV1,
+        "TransferBatch" => InstructionName::TransferBatch,
+        "Freeze" => InstructionName::Freeze,
+        "Thaw" => InstructionName::Thaw,
+        "SetTreePublic" => InstructionName::SetTreePublic,
+        "SetMintPrice" => InstructionName::SetMintPrice,
+        "ProposeMetadataUpdate" => InstructionName::ProposeMetadataUpdate,
+        "ApproveMetadataUpdate" => InstructionName::ApproveMetadataUpdate,
+        "CancelMetadataProposal" => InstructionName::CancelMetadataProposal,
+        "CreateDenyList" => InstructionName::CreateDenyList,
+        "UpdateDenyList" => InstructionName::UpdateDenyList,
+        "SetTreeDenyList" => InstructionName::SetTreeDenyList,
+        "AddTreeDelegate" => InstructionName::AddTreeDelegate,
+        "RemoveTreeDelegate" => InstructionName::RemoveTreeDelegate,
+        "SetTreeDelegate" => InstructionName::SetTreeDelegate,
+        "SetTreePaused" => InstructionName::SetTreePaused,
+        "CloseDelegateExpiry" => InstructionName::CloseDelegateExpiry,
+        _ => InstructionName::Unknown,
+    }
+}
+
+/// Extracts the operations performed by the program from the log messages of a transaction.
+/// Invocations of the program via CPI are picked up as well, while logs produced by any other
+/// program (including the ones invoked by Bubblegum) are ignored.
+pub fn parse_logs(logs: &[String]) -> Vec<BubblegumOp> {
+    let program_id = crate::id().to_string();
+    let mut ops = Vec::new();
+    // Tracks which program is currently executing, based on the invoke/success/failed logs.
+    let mut stack: Vec<&str> = Vec::new();
+
+    for log in logs {
+        let mut parts = log.split_whitespace();
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("Program"), Some(id), Some("invoke")) => {
+                stack.push(id);
+                if id == program_id {
+                    ops.push(BubblegumOp {
+                        instruction: InstructionName::Unknown,
+                        events: Vec::new(),
+                        return_data: None,
+                    });
+                }
+                continue;
+            }
+            (Some("Program"), Some(_), Some("success"))
+            | (Some("Program"), Some(_), Some("failed:")) => {
+                stack.pop();
+                continue;
+            }
+            _ => {}
+        }
+
+        if stack.last() != Some(&program_id.as_str()) {
+            continue;
+        }
+
+        // The current op is always the last one that was pushed, since the program does not
+        // call into itself.
+        let op = match ops.last_mut() {
+            Some(op) => op,
+            None => continue,
+        };
+
+        if let Some(name) = log.strip_prefix(PROGRAM_LOG_INSTRUCTION) {
+            op.instruction = instruction_from_log_name(name);
+        } else if let Some(data) = log.strip_prefix(PROGRAM_DATA) {
+            if let Some(event) = base64::decode(data)
+                .ok()
+                .and_then(|bytes| BubblegumEvent::decode(&bytes))
+            {
+                op.events.push(event);
+            }
+        } else if let Some(rest) = log.strip_prefix(PROGRAM_RETURN) {
+            // The runtime logs the program id followed by the base64 encoded data.
+            if let Some((_, data)) = rest.split_once(' ') {
+                op.return_data = base64::decode(data).ok();
+            }
+        }
+    }
+
+    ops
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetState {
+    Live,
+    Redeemed,
+    Decompressed,
+    Burned,
+    PendingTransfer,
+}
+
+/// Summary of a compressed asset, as reconstructed from the program events.
+#[derive(Clone)]
+pub struct AssetSummary {
+    pub asset_id: Pubkey,
+    pub nonce: u64,
+    /// The most recent leaf observed for the asset.
+    pub leaf: LeafSchema,
+    /// The metadata provided when the asset was minted, or by its latest update (i.e. via
+    /// `update_metadata`). Other changes (i.e. creator or collection verification) are only
+    /// reflected in the leaf hashes.
+    pub metadata: MetadataArgs,
+    pub state: AssetState,
+}
+
+/// Replays the operations observed for a tree and returns a summary for every asset whose
+/// nonce falls within `range`, ordered by nonce. Callers can page through all the assets of a
+/// tree by advancing the range up to `TreeConfig::num_minted`. Assets minted via
+/// `mint_v1_hashed` are skipped, since their metadata is not available on-chain.
+pub fn list_assets(tree_id: &Pubkey, ops: &[BubblegumOp], range: Range<u64>) -> Vec<AssetSummary> {
+    replay_assets(tree_id, ops)
+        .range(range)
+        .map(|(_, summary)| summary.clone())
+        .collect()
+}
+
+/// Returns the assets minted into `collection` across all the given trees, each paired with
+/// the operations observed for it, ordered by tree and then by nonce. Collections are not tied
+/// to a single tree, so indexers have to look at every tree that mints into them. Membership is
+/// based on the metadata provided at mint time, so whether an asset is verified (or was added
+/// to the collection later, via `set_and_verify_collection`) has to be checked against its
+/// leaf hashes.
+pub fn list_collection_assets(
+    collection: &Pubkey,
+    trees: &[(Pubkey, &[BubblegumOp])],
+) -> Vec<AssetSummary> {
+    trees
+        .iter()
+        .flat_map(|(tree_id, ops)| replay_assets(tree_id, ops).into_values())
+        .filter(|summary| {
+            summary
+                .metadata
+                .collection
+                .as_ref()
+                .map_or(false, |c| c.key == *collection)
+        })
+        .collect()
+}
+
+/// Number of assets held by each owner of a tree, as reconstructed from the program events.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HolderDistribution {
+    /// Maps owners to the number of assets they hold. Only owners with at least one asset
+    /// show up.
+    pub counts: BTreeMap<Pubkey, u64>,
+}
+
+impl HolderDistribution {
+    pub fn unique_holders(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn total_assets(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    pub fn count(&self, owner: &Pubkey) -> u64 {
+        self.counts.get(owner).copied().unwrap_or_default()
+    }
+}
+
+/// Computes how the live assets of a tree are distributed among their owners. Assets that are
+/// not in the tree (i.e. burned, redeemed, decompressed, or pending a timelocked transfer) are
+/// not counted, and neither are assets minted via `mint_v1_hashed`, which `list_assets` skips
+/// as well.
+pub fn holder_distribution(tree_id: &Pubkey, ops: &[BubblegumOp]) -> HolderDistribution {
+    let mut distribution = HolderDistribution::default();
+    for summary in replay_assets(tree_id, ops).values() {
+        if summary.state != AssetState::Live {
+            continue;
+        }
+
+        let owner = match summary.leaf {
+            LeafSchema::V1 { owner, .. } => owner,
+        };
+        *distribution.counts.entry(owner).or_default() += 1;
+    }
+    distribution
+}
+
+/// The owner and delegate of an asset, as reconstructed from the program events.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResolvedOwner {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    /// Assets that are no longer in the tree keep the owner and delegate of their last leaf.
+    pub state: AssetState,
+}
+
+/// Determines the current owner and delegate of the asset with the given nonce by replaying
+/// the leaves logged for it. Unlike `list_assets`, this doesn't depend on the metadata logged
+/// at mint time, so it works for assets minted via `mint_v1_hashed` as well. Returns `None` if
+/// no leaf of the asset was observed.
+pub fn resolve_owner(tree_id: &Pubkey, ops: &[BubblegumOp], nonce: u64) -> Option<ResolvedOwner> {
+    let asset_id = get_asset_id(tree_id, nonce);
+    let mut resolved = None;
+
+    for op in ops {
+        if let Some(event) = op.decompression() {
+            if event.tree_id == *tree_id && event.nonce == nonce {
+                if let Some(resolved) = resolved.as_mut() {
+                    resolved.state = AssetState::Decompressed;
+                }
+            }
+            continue;
+        }
+
+        // Batched instructions log several leaves, so all of them have to be looked at.
+        for leaf in op.leaves().filter(|leaf| leaf.id() == asset_id) {
+            let (owner, delegate) = match leaf {
+                LeafSchema::V1 {
+                    owner, delegate, ..
+                } => (*owner, *delegate),
+            };
+            resolved = Some(ResolvedOwner {
+                owner,
+                delegate,
+                state: state_after(op.instruction),
+            });
+        }
+    }
+
+    resolved
+}
+
+// Builds the map from nonce to asset summary for the given tree.
+fn replay_assets(tree_id: &Pubkey, ops: &[BubblegumOp]) -> BTreeMap<u64, AssetSummary> {
+    let mut assets: BTreeMap<u64, AssetSummary> = BTreeMap::new();
+    // Asset ids are unique across trees, so they can be used to tell whether an op that does
+    // not carry the tree id (i.e. anything other than a mint) concerns the tree at hand.
+    let mut nonces: BTreeMap<Pubkey, u64> = BTreeMap::new();
+
+    for op in ops {
+        if let Some(event) = op.decompression() {
+            if event.tree_id == *tree_id {
+                if let Some(summary) = assets.get_mut(&event.nonce) {
+                    summary.state = AssetState::Decompressed;
+                }
+            }
+            continue;
+        }
+
+        if op.new_nft().is_some() {
+            for (new_nft, leaf) in op.new_nfts().zip(op.leaves()) {
+                if leaf.id() != get_asset_id(tree_id, new_nft.nonce) {
+                    continue;
+                }
+
+                nonces.insert(leaf.id(), new_nft.nonce);
+                assets.insert(
+                    new_nft.nonce,
+                    AssetSummary {
+                        asset_id: leaf.id(),
+                        nonce: new_nft.nonce,
+                        leaf: *leaf,
+                        metadata: new_nft.metadata.clone(),
+                        state: AssetState::Live,
+                    },
+                );
+            }
+            continue;
+        }
+
+        if let Some(event) = op.metadata_update() {
+            if event.tree_id == *tree_id {
+                if let Some(summary) = assets.get_mut(&event.nonce) {
+                    summary.metadata = event.metadata.clone();
+                }
+            }
+        }
+
+        // Batched instructions log several leaves, so all of them have to be looked at.
+        for leaf in op.leaves() {
+            let summary = match nonces.get(&leaf.id()).and_then(|n| assets.get_mut(n)) {
+                Some(summary) => summary,
+                None => continue,
+            };
+
+            summary.leaf = *leaf;
+            summary.state = state_after(op.instruction);
+        }
+    }
+
+    assets
+}
+
+// The state of an asset after `instruction` logged one of its leaves.
+fn state_after(instruction: InstructionName) -> AssetState {
+    match instruction {
+        // These log the leaf that gets removed from the tree.
+        InstructionName::Burn | InstructionName::BurnWithReceipt => AssetState::Burned,
+        InstructionName::Redeem => AssetState::Redeemed,
+        InstructionName::TransferTimelocked => AssetState::PendingTransfer,
+        _ => AssetState::Live,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{
+        leaf_schema::Version, metaplex_adapter::TokenProgramVersion, CreatorTransferStage, Voucher,
+        VOUCHER_SIZE,
+    };
+
+    // Small deterministic PRNG (xorshift64), which is all the fuzzing below needs.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+
+        fn bytes(&mut self, len: usize) -> Vec<u8> {
+            (0..len).map(|_| self.next() as u8).collect()
+        }
+
+        // Returns a copy of `data` that's randomly truncated, extended, or has some bytes
+        // overwritten, while keeping the discriminator intact most of the time.
+        fn mutate(&mut self, data: &[u8]) -> Vec<u8> {
+            let mut data = data.to_vec();
+            match self.below(3) {
+                0 => data.truncate(self.below(data.len() + 1)),
+                1 => {
+                    let extra = self.below(16) + 1;
+                    data.extend(self.bytes(extra));
+                }
+                _ => {
+                    for _ in 0..self.below(4) + 1 {
+                        let i = self.below(data.len());
+                        data[i] = self.next() as u8;
+                    }
+                }
+            }
+            data
+        }
+    }
+
+    fn encode<E: Discriminator + AnchorSerialize>(event: &E) -> Vec<u8> {
+        [E::discriminator().as_ref(), &event.try_to_vec().unwrap()].concat()
+    }
+
+    fn sample_events() -> Vec<Vec<u8>> {
+        let leaf = LeafSchema::new_v0(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            3,
+            [1; 32],
+            [2; 32],
+        );
+        let metadata = MetadataArgs {
+            name: "name".to_owned(),
+            symbol: "sym".to_owned(),
+            uri: "https://example.com".to_owned(),
+            seller_fee_basis_points: 100,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: Some(1),
+            token_standard: None,
+            collection: None,
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators: vec![Creator {
+                address: Pubkey::new_unique(),
+                verified: false,
+                share: 100,
+            }],
+        };
+
+        vec![
+            encode(&leaf.to_event()),
+            encode(&NewNFTEvent {
+                version: Version::V1,
+                metadata: metadata.clone(),
+                nonce: 3,
+            }),
+            encode(&MetadataUpdateEvent {
+                version: Version::V1,
+                tree_id: Pubkey::new_unique(),
+                nonce: 3,
+                metadata,
+            }),
+            encode(&NFTDecompressionEvent {
+                version: Version::V1,
+                id: leaf.id(),
+                tree_id: Pubkey::new_unique(),
+                nonce: 3,
+            }),
+            encode(&TreeMetadataEvent {
+                version: Version::V1,
+                tree_id: Pubkey::new_unique(),
+                name: "tree".to_owned(),
+                uri: String::new(),
+            }),
+            encode(&TreeCreatorTransferEvent {
+                version: Version::V1,
+                tree_id: Pubkey::new_unique(),
+                tree_creator: Pubkey::new_unique(),
+                new_tree_creator: Pubkey::new_unique(),
+                stage: CreatorTransferStage::Proposed,
+            }),
+        ]
+    }
+
+    #[test]
+    fn decode_rejects_malformed_events() {
+        for data in sample_events() {
+            assert!(BubblegumEvent::decode(&data).is_some());
+
+            // Trailing bytes are not silently ignored.
+            let mut longer = data.clone();
+            longer.push(0);
+            assert!(BubblegumEvent::decode(&longer).is_none());
+
+            // Neither is a truncated event.
+            assert!(BubblegumEvent::decode(&data[..data.len() - 1]).is_none());
+        }
+    }
+
+    #[test]
+    fn decode_surfaces_unknown_versions() {
+        for data in sample_events() {
+            // Every event starts with the version, which is `V1` for all of them for now.
+            assert_eq!(data[8], 0);
+
+            let mut newer = data.clone();
+            newer[8] = 7;
+            newer.extend_from_slice(&[1, 2, 3]);
+            match BubblegumEvent::decode(&newer) {
+                Some(BubblegumEvent::UnknownVersion(event)) => {
+                    assert_eq!(event.discriminator[..], data[..8]);
+                    assert_eq!(event.version, 7);
+                    assert_eq!(event.data, newer[8..]);
+                }
+                _ => panic!("expected an unknown version"),
+            }
+
+            // Unknown event types are still rejected.
+            let mut unknown = newer.clone();
+            unknown[0] ^= 1;
+            assert!(BubblegumEvent::decode(&unknown).is_none());
+        }
+    }
+
+    #[test]
+    fn decode_does_not_panic_on_random_input() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        let samples = sample_events();
+
+        for _ in 0..10_000 {
+            let len = rng.below(512);
+            BubblegumEvent::decode(&rng.bytes(len));
+
+            let sample = &samples[rng.below(samples.len())];
+            BubblegumEvent::decode(&rng.mutate(sample));
+        }
+    }
+
+    #[test]
+    fn voucher_safe_deserialize_does_not_panic_on_random_input() {
+        let voucher = Voucher::new(
+            LeafSchema::new_v0(
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                7,
+                [3; 32],
+                [4; 32],
+            ),
+            7,
+            Pubkey::new_unique(),
+        );
+        let data = encode(&voucher);
+        assert_eq!(data.len(), VOUCHER_SIZE);
+
+        let decoded = Voucher::safe_deserialize(&data).unwrap();
+        assert_eq!(decoded.index, voucher.index);
+        assert_eq!(decoded.merkle_tree, voucher.merkle_tree);
+        assert_eq!(decoded.leaf_schema.to_node(), voucher.leaf_schema.to_node());
+
+        assert!(Voucher::safe_deserialize(&data[..VOUCHER_SIZE - 1]).is_err());
+        assert!(Voucher::safe_deserialize(&[&data[..], &[0]].concat()).is_err());
+        // An event is not a voucher, even if it happens to be long enough.
+        let mut not_a_voucher = encode(&voucher.leaf_schema.to_event());
+        not_a_voucher.resize(VOUCHER_SIZE, 0);
+        assert!(Voucher::safe_deserialize(&not_a_voucher).is_err());
+
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..10_000 {
+            let len = rng.below(2 * VOUCHER_SIZE);
+            assert!(Voucher::safe_deserialize(&rng.bytes(len)).is_err());
+            let _ = Voucher::safe_deserialize(&rng.mutate(&data));
+        }
+    }
+
+    #[test]
+    fn canonical_creators_hash_identically() {
+        let creators: Vec<Creator> = (1..=4u8)
+            .map(|seed| Creator {
+                address: Pubkey::new_from_array([seed; 32]),
+                verified: seed % 2 == 0,
+                share: 25,
+            })
+            .collect();
+        let expected = crate::hash::hash_creators(&creators).unwrap();
+
+        let mut rng = Rng(7);
+        for _ in 0..16 {
+            let mut shuffled = creators.clone();
+            for i in (1..shuffled.len()).rev() {
+                shuffled.swap(i, rng.below(i + 1));
+            }
+
+            canonicalize_creators(&mut shuffled);
+            assert!(crate::utils::assert_creators_are_sorted(&shuffled).is_ok());
+            assert_eq!(crate::hash::hash_creators(&shuffled).unwrap(), expected);
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Rent exemption costs of the accounts created by the program, so that clients can show how
+//! much creating a tree or an asset related account costs before sending the transaction.
+
+pub use crate::state::AccountSize;
+use crate::{error::BubblegumError, state::ON_CHAIN_METADATA_HEADER_SIZE};
+use anchor_lang::{prelude::*, solana_program::system_instruction::MAX_PERMITTED_DATA_LENGTH};
+use spl_account_compression::{state::CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1, Node};
+use std::mem::size_of;
+
+/// Lamports needed for an account of type `T` to be rent exempt, e.g.
+/// `required_lamports_for::<Voucher>()`.
+pub fn required_lamports_for<T: AccountSize>() -> u64 {
+    Rent::default().minimum_balance(T::SIZE)
+}
+
+/// Lamports needed for the `OnChainMetadata` account of a JSON that is `json_size` bytes long.
+pub fn required_lamports_for_on_chain_metadata(json_size: u32) -> u64 {
+    Rent::default().minimum_balance(ON_CHAIN_METADATA_HEADER_SIZE + json_size as usize)
+}
+
+/// Size of the merkle tree account that has to be allocated before calling `create_tree`.
+pub fn merkle_tree_account_size(max_depth: u32, max_buffer_size: u32, canopy_depth: u32) -> usize {
+    let (max_depth, max_buffer_size) = (max_depth as usize, max_buffer_size as usize);
+    // Mirrors the layout described in `utils::get_proof_length`.
+    let path_size = size_of::<Node>() * (max_depth + 1) + 2 * size_of::<u32>();
+    let tree_size = 3 * size_of::<u64>() + (max_buffer_size + 1) * path_size;
+    let canopy_size = ((1 << (canopy_depth + 1)) - 2) * size_of::<Node>();
+    CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1 + tree_size + canopy_size
+}
+
+/// Same as `merkle_tree_account_size`, but fails with the error `create_tree` would return if
+/// the canopy is not shallower than the tree, or the account exceeds the maximum account size.
+pub fn checked_merkle_tree_account_size(
+    max_depth: u32,
+    max_buffer_size: u32,
+    canopy_depth: u32,
+) -> Result<usize> {
+    if canopy_depth > 0 && canopy_depth >= max_depth {
+        return Err(BubblegumError::CanopyTooDeep.into());
+    }
+    let size = merkle_tree_account_size(max_depth, max_buffer_size, canopy_depth);
+    if size > MAX_PERMITTED_DATA_LENGTH as usize {
+        return Err(BubblegumError::AccountTooLarge.into());
+    }
+    Ok(size)
+}
+
+/// Lamports needed for the merkle tree account that has to be allocated before calling
+/// `create_tree`. The `TreeConfig` created by the latter is paid for separately.
+pub fn required_lamports_for_tree(max_depth: u32, max_buffer_size: u32, canopy_depth: u32) -> u64 {
+    Rent::default().minimum_balance(merkle_tree_account_size(
+        max_depth,
+        max_buffer_size,
+        canopy_depth,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{
+        leaf_schema::LeafSchema, AssetReceipt, BurnReceipt, CreatorTransfer, DelegateExpiry,
+        DenyList, FeatureFlags, LeafFreeze, LoggingMode, MetadataProposal, MintPrice,
+        OnChainMetadata, PendingTransfer, SoulboundOverride, TreeConfig, TreeDelegateRecord,
+        TreeDenyList, TreeMetadata, Voucher, MAX_DENY_LIST_LENGTH,
+    };
+
+    fn serialized_size<T: AnchorSerialize>(account: &T) -> usize {
+        8 + account.try_to_vec().unwrap().len()
+    }
+
+    fn leaf_schema() -> LeafSchema {
+        LeafSchema::new_v0(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            [0; 32],
+            [0; 32],
+        )
+    }
+
+    #[test]
+    fn sizes_match_serialized_accounts() {
+        // Takes up all of the room that was reserved for new fields.
+        let tree_config = TreeConfig {
+            tree_creator: Pubkey::new_unique(),
+            tree_delegate: Pubkey::new_unique(),
+            total_mint_capacity: 0,
+            num_minted: 0,
+            is_hash_only: false,
+            logging_mode: LoggingMode::Noop,
+            is_non_transferable: false,
+            is_creator_immutable: false,
+            program_version: 0,
+            is_public: false,
+            companion_accounts: 0,
+            is_paused: false,
+        };
+        assert_eq!(serialized_size(&tree_config), TreeConfig::SIZE);
+
+        // Sized for the longest name and uri.
+        let tree_metadata = TreeMetadata {
+            merkle_tree: Pubkey::new_unique(),
+            name: String::new(),
+            uri: String::new(),
+        };
+        assert_eq!(
+            serialized_size(&tree_metadata) + 32 + 200,
+            TreeMetadata::SIZE
+        );
+
+        let voucher = Voucher::new(leaf_schema(), 0, Pubkey::new_unique());
+        assert_eq!(serialized_size(&voucher), Voucher::SIZE);
+
+        let pending_transfer = PendingTransfer {
+            leaf_schema: leaf_schema(),
+            index: 0,
+            merkle_tree: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+            unlock_slot: 0,
+        };
+        assert_eq!(serialized_size(&pending_transfer), PendingTransfer::SIZE);
+
+        let soulbound_override = SoulboundOverride {
+            merkle_tree: Pubkey::new_unique(),
+            nonce: 0,
+            is_non_transferable: false,
+        };
+        assert_eq!(
+            serialized_size(&soulbound_override),
+            SoulboundOverride::SIZE
+        );
+
+        // Sized for an expiry slot.
+        let delegate_expiry = DelegateExpiry {
+            merkle_tree: Pubkey::new_unique(),
+            nonce: 0,
+            owner: Pubkey::new_unique(),
+            delegate: Pubkey::new_unique(),
+            expiry_slot: Some(0),
+        };
+        assert_eq!(serialized_size(&delegate_expiry), DelegateExpiry::SIZE);
+
+        let leaf_freeze = LeafFreeze {
+            merkle_tree: Pubkey::new_unique(),
+            nonce: 0,
+            delegate: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+        };
+        assert_eq!(serialized_size(&leaf_freeze), LeafFreeze::SIZE);
+
+        let on_chain_metadata = OnChainMetadata {
+            asset_id: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            json_hash: [0; 32],
+            json_size: 0,
+            bytes_written: 0,
+            is_complete: false,
+        };
+        assert_eq!(
+            serialized_size(&on_chain_metadata),
+            ON_CHAIN_METADATA_HEADER_SIZE
+        );
+
+        let asset_receipt = AssetReceipt {
+            merkle_tree: Pubkey::new_unique(),
+            nonce: 0,
+            owner: Pubkey::new_unique(),
+        };
+        assert_eq!(serialized_size(&asset_receipt), AssetReceipt::SIZE);
+
+        let burn_receipt = BurnReceipt {
+            asset_id: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            slot: 0,
+        };
+        assert_eq!(serialized_size(&burn_receipt), BurnReceipt::SIZE);
+
+        let creator_transfer = CreatorTransfer {
+            merkle_tree: Pubkey::new_unique(),
+            new_tree_creator: Pubkey::new_unique(),
+            reset_tree_delegate: false,
+        };
+        assert_eq!(serialized_size(&creator_transfer), CreatorTransfer::SIZE);
+
+        let feature_flags = FeatureFlags::default();
+        assert_eq!(serialized_size(&feature_flags), FeatureFlags::SIZE);
+
+        let mint_price = MintPrice {
+            merkle_tree: Pubkey::new_unique(),
+            lamports: 0,
+            treasury: Pubkey::new_unique(),
+            token_mint: Pubkey::new_unique(),
+            token_amount: 0,
+        };
+        assert_eq!(serialized_size(&mint_price), MintPrice::SIZE);
+
+        let metadata_proposal = MetadataProposal {
+            merkle_tree: Pubkey::new_unique(),
+            nonce: 0,
+            proposer: Pubkey::new_unique(),
+            data_hash: [0; 32],
+            creator_hash: [0; 32],
+        };
+        assert_eq!(serialized_size(&metadata_proposal), MetadataProposal::SIZE);
+
+        let deny_list = DenyList {
+            authority: Pubkey::new_unique(),
+            asset_ids: vec![Pubkey::new_unique(); MAX_DENY_LIST_LENGTH],
+        };
+        assert_eq!(serialized_size(&deny_list), DenyList::SIZE);
+
+        let tree_deny_list = TreeDenyList {
+            merkle_tree: Pubkey::new_unique(),
+            deny_list: Pubkey::new_unique(),
+        };
+        assert_eq!(serialized_size(&tree_deny_list), TreeDenyList::SIZE);
+
+        let tree_delegate_record = TreeDelegateRecord {
+            merkle_tree: Pubkey::new_unique(),
+            delegate: Pubkey::new_unique(),
+        };
+        assert_eq!(
+            serialized_size(&tree_delegate_record),
+            TreeDelegateRecord::SIZE
+        );
+    }
+
+    #[test]
+    fn canopy_and_account_size_limits() {
+        let limit = MAX_PERMITTED_DATA_LENGTH as usize;
+        assert_eq!(
+            checked_merkle_tree_account_size(14, 64, 13).unwrap(),
+            merkle_tree_account_size(14, 64, 13)
+        );
+        assert!(checked_merkle_tree_account_size(14, 64, 14).is_err());
+        assert!(checked_merkle_tree_account_size(14, 64, 20).is_err());
+
+        // A depth 17 canopy fits in an account of a depth 20 tree, but a depth 18 one goes over
+        // the 10MiB limit.
+        let fits = merkle_tree_account_size(20, 64, 17);
+        let too_large = merkle_tree_account_size(20, 64, 18);
+        assert!(fits <= limit && too_large > limit);
+        assert_eq!(checked_merkle_tree_account_size(20, 64, 17).unwrap(), fits);
+        assert!(checked_merkle_tree_account_size(20, 64, 18).is_err());
+
+        // The on-chain check agrees with the client at the boundaries.
+        assert!(crate::utils::assert_valid_tree_account_size(fits, 20, 64).is_ok());
+        assert!(crate::utils::assert_valid_tree_account_size(too_large, 20, 64).is_err());
+        assert!(crate::utils::assert_valid_tree_account_size(
+            merkle_tree_account_size(14, 64, 14),
+            14,
+            64
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn lamports_grow_with_size() {
+        assert!(required_lamports_for::<BurnReceipt>() < required_lamports_for::<Voucher>());
+        assert_eq!(
+            required_lamports_for_on_chain_metadata(0),
+            Rent::default().minimum_balance(ON_CHAIN_METADATA_HEADER_SIZE)
+        );
+        assert!(required_lamports_for_tree(14, 64, 0) < required_lamports_for_tree(14, 64, 5));
+    }
+}
@@ -54,4 +54,104 @@ pub enum BubblegumError {
     UpdateAuthorityIncorrect,
     #[msg("This transaction must be signed by either the leaf owner or leaf delegate")]
     LeafAuthorityMustSign,
+    #[msg("Tree name is too long")]
+    TreeNameTooLong,
+    #[msg("Tree uri is too long")]
+    TreeUriTooLong,
+    #[msg("Tree does not accept hashed mints")]
+    TreeNotHashOnly,
+    #[msg("Withdrawal would leave the tree authority below rent exemption")]
+    InsufficientTreeAuthorityFunds,
+    #[msg("Proof must have a node for every level of the tree below its canopy")]
+    InvalidProofLength,
+    #[msg("Leaf index does not match the nonce")]
+    LeafIndexNonceMismatch,
+    #[msg("Leaf is being redeemed")]
+    LeafBeingRedeemed,
+    #[msg("Transfer cannot be claimed before its unlock slot")]
+    TransferStillLocked,
+    #[msg("Transfer cannot be cancelled after its unlock slot")]
+    TransferUnlocked,
+    #[msg("Airdrop must have between one and MAX_AIRDROP_BATCH_SIZE owners")]
+    InvalidAirdropBatchSize,
+    #[msg("Asset is soulbound and cannot be transferred")]
+    AssetIsSoulbound,
+    #[msg("Leaf delegate has expired")]
+    DelegateExpired,
+    #[msg("Asset uri does not commit to the on-chain metadata")]
+    OnChainMetadataUriMismatch,
+    #[msg("On-chain metadata is too large")]
+    OnChainMetadataTooLarge,
+    #[msg("Metadata chunk starts past the bytes written so far")]
+    InvalidMetadataChunkOffset,
+    #[msg("On-chain metadata does not match its hash")]
+    OnChainMetadataHashMismatch,
+    #[msg("Creators must be sorted by address")]
+    CreatorsNotSorted,
+    #[msg("Root is not in the change log of the tree")]
+    RootNotInChangeLog,
+    #[msg("Tree is full")]
+    TreeFull,
+    #[msg("Tree was created with an immutable creator")]
+    TreeCreatorImmutable,
+    #[msg("Metadata name, symbol, or uri ends with null bytes")]
+    MetadataPadded,
+    #[msg("Leaf delegate is not a valid address")]
+    InvalidLeafDelegate,
+    #[msg("Leaf owner must sign to set a different leaf delegate")]
+    LeafOwnerMustSignForDelegate,
+    #[msg("Instruction is disabled for this deployment of the program")]
+    InstructionDisabled,
+    #[msg("Metadata is not mutable")]
+    MetadataImmutable,
+    #[msg("Primary sale can only be flipped to true")]
+    PrimarySaleCanOnlyBeFlippedToTrue,
+    #[msg("Collection can only be changed by the collection verification instructions")]
+    CollectionCannotBeChanged,
+    #[msg("Cannot unverify another creator")]
+    CannotUnverifyAnotherCreator,
+    #[msg("Batch must have between one and MAX_MINT_BATCH_SIZE messages")]
+    InvalidMintBatchSize,
+    #[msg("Batch must have between one and MAX_TRANSFER_BATCH_SIZE transfers")]
+    InvalidTransferBatchSize,
+    #[msg("Remaining accounts do not match the transfers of the batch")]
+    InvalidBatchAccounts,
+    #[msg("Canopy must be shallower than the tree")]
+    CanopyTooDeep,
+    #[msg("Merkle tree account exceeds the maximum account size")]
+    AccountTooLarge,
+    #[msg("Operation would leave the leaf unchanged")]
+    RedundantOperation,
+    #[msg("Address must not be the default (all zero) address")]
+    InvalidZeroAddress,
+    #[msg("Asset is frozen by its delegate")]
+    AssetIsFrozen,
+    #[msg("Public mints into a tree with a mint price must pay it")]
+    MintPriceNotPaid,
+    #[msg("Treasury does not match the mint price of the tree")]
+    InvalidTreasury,
+    #[msg("Metadata does not match the proposed update")]
+    MetadataProposalMismatch,
+    #[msg("Asset is on the deny list of the tree")]
+    AssetFlagged,
+    #[msg("Deny list does not match the one set for the tree")]
+    InvalidDenyList,
+    #[msg("Deny list has no room for more assets")]
+    DenyListFull,
+    #[msg("Tree is paused by its creator")]
+    TreePaused,
+    #[msg("Soulbound override can only be set for the last minted leaf")]
+    SoulboundOverrideNotAtMint,
+    #[msg("On-chain metadata is already complete")]
+    OnChainMetadataComplete,
+    #[msg("Feature flags can only disable instructions that check them")]
+    InvalidFeatureFlags,
+    #[msg("Whether leaves are soulbound can only be set for trees with no leaves yet")]
+    NonTransferableAfterMint,
+    #[msg("A metadata update proposed by another authority is still pending")]
+    MetadataProposalPending,
+    #[msg("Feature flags of the deployment must be the first companion account")]
+    FeatureFlagsMissing,
+    #[msg("Asset was not burned with a receipt")]
+    BurnReceiptMissing,
 }
@@ -0,0 +1,281 @@
+//! Hashes committed to by the leaves of a tree. Anything that recomputes leaves or roots (the
+//! program itself, the `client` module, or third party indexers) must follow the scheme below
+//! to the letter, so it's the single place where it is implemented.
+//!
+//! Version 1, which is used by `LeafSchema::V1` leaves:
+//!
+//! - `data_hash = keccak(keccak(borsh(MetadataArgs)) || le_bytes(seller_fee_basis_points))`.
+//!   The fee is already part of the serialized metadata, but it's appended once more so it can
+//!   be validated (i.e. by marketplaces enforcing royalties) without the full `MetadataArgs`.
+//!   The name, symbol, and uri are hashed without the null byte padding that Token Metadata
+//!   adds (see `MetadataArgs::unpuffed`).
+//! - `creator_hash = keccak(address || verified as u8 || share)`, with the creators
+//!   concatenated in order. An empty creator list hashes to `keccak("")`.
+//! - `leaf = keccak(1u8 || id || owner || delegate || le_bytes(nonce) || data_hash ||
+//!   creator_hash)`, where `1` is the leaf schema version.
+//! - Inner nodes are `keccak(left || right)`, and the empty leaf consists of zeros.
+//!
+//! Changing any of the above alters the hashes of existing leaves, so it requires a new leaf
+//! schema version.
+
+use crate::state::{
+    leaf_schema::LeafSchema,
+    metaplex_adapter::{Creator, MetadataArgs},
+};
+use anchor_lang::{prelude::*, solana_program::keccak};
+use spl_account_compression::Node;
+
+pub fn hash_creators(creators: &[Creator]) -> Result<[u8; 32]> {
+    // Convert creator Vec to bytes Vec.
+    let creator_data = creators
+        .iter()
+        .map(|c| [c.address.as_ref(), &[c.verified as u8], &[c.share]].concat())
+        .collect::<Vec<_>>();
+    // Calculate new creator hash.
+    Ok(keccak::hashv(
+        creator_data
+            .iter()
+            .map(|c| c.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_ref(),
+    )
+    .to_bytes())
+}
+
+pub fn hash_metadata(metadata: &MetadataArgs) -> Result<[u8; 32]> {
+    Ok(hash_data(
+        &hash_metadata_args(metadata)?,
+        metadata.seller_fee_basis_points,
+    ))
+}
+
+/// The inner hash of `data_hash`, which can be passed along with the fee instead of the whole
+/// metadata.
+pub fn hash_metadata_args(metadata: &MetadataArgs) -> Result<[u8; 32]> {
+    Ok(keccak::hashv(&[metadata.try_to_vec()?.as_slice()]).to_bytes())
+}
+
+pub fn hash_data(metadata_args_hash: &[u8; 32], seller_fee_basis_points: u16) -> [u8; 32] {
+    // Calculate new data hash.
+    keccak::hashv(&[
+        metadata_args_hash.as_ref(),
+        seller_fee_basis_points.to_le_bytes().as_ref(),
+    ])
+    .to_bytes()
+}
+
+pub fn hash_leaf(leaf: &LeafSchema) -> Node {
+    match leaf {
+        LeafSchema::V1 {
+            id,
+            owner,
+            delegate,
+            nonce,
+            data_hash,
+            creator_hash,
+        } => keccak::hashv(&[
+            &[leaf.version().to_bytes()],
+            id.as_ref(),
+            owner.as_ref(),
+            delegate.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+            data_hash.as_ref(),
+            creator_hash.as_ref(),
+        ])
+        .to_bytes(),
+    }
+}
+
+pub fn hash_pair(left: &Node, right: &Node) -> Node {
+    keccak::hashv(&[left.as_ref(), right.as_ref()]).to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::metaplex_adapter::{TokenProgramVersion, TokenStandard};
+    use std::str::FromStr;
+
+    // Reference values computed independently of this crate, which pin down version 1 of the
+    // spec. They must never change.
+    fn expected(value: &str) -> [u8; 32] {
+        Pubkey::from_str(value).unwrap().to_bytes()
+    }
+
+    fn metadata() -> MetadataArgs {
+        MetadataArgs {
+            name: "test".to_owned(),
+            symbol: "tst".to_owned(),
+            uri: String::new(),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: Some(TokenStandard::NonFungible),
+            collection: None,
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators: vec![Creator {
+                address: Pubkey::new_from_array([1; 32]),
+                verified: true,
+                share: 100,
+            }],
+        }
+    }
+
+    #[test]
+    fn hashes_match_reference_values() {
+        let metadata = metadata();
+        let data_hash = hash_metadata(&metadata).unwrap();
+        let creator_hash = hash_creators(&metadata.creators).unwrap();
+
+        assert_eq!(
+            data_hash,
+            expected("2RCaAfPCUrmpDpSggaAMyppqfR8kMTiW9LSc2dKmFK7Y")
+        );
+        assert_eq!(
+            creator_hash,
+            expected("9GKpaMf1m2yvASeMYAZQJAxqam3Z7Uoh8d1bwEybK9JC")
+        );
+        assert_eq!(
+            hash_creators(&[]).unwrap(),
+            expected("EKDHSGbrGztomDfuiV4iqiZ6LschDJPsFiXjZ83f92Md")
+        );
+
+        let leaf = LeafSchema::new_v0(
+            Pubkey::new_from_array([2; 32]),
+            Pubkey::new_from_array([3; 32]),
+            Pubkey::new_from_array([4; 32]),
+            7,
+            data_hash,
+            creator_hash,
+        );
+        assert_eq!(
+            hash_leaf(&leaf),
+            expected("4FErfBSjx1bxqTxT763hyEeMb9HCV5CEdXwG2Eu9JUvS")
+        );
+        assert_eq!(leaf.to_node(), hash_leaf(&leaf));
+        assert_eq!(
+            hash_data(&hash_metadata_args(&metadata).unwrap(), 500),
+            data_hash
+        );
+
+        assert_eq!(
+            hash_pair(&data_hash, &creator_hash),
+            expected("DwFZiPKxuLf2wAuUF1cvy9Lnq4mRpaxCotbcK4QLbQ2M")
+        );
+    }
+
+    #[test]
+    fn unpuffed_metadata_matches_compressed_hash() {
+        let metadata = metadata();
+        let puffed = metadata.puffed();
+        assert_eq!(
+            puffed.name.len(),
+            mpl_token_metadata::state::MAX_NAME_LENGTH
+        );
+        assert_eq!(puffed.uri.len(), mpl_token_metadata::state::MAX_URI_LENGTH);
+
+        // Metadata read back from Token Metadata has to be unpuffed before hashing.
+        assert_ne!(
+            hash_metadata(&puffed).unwrap(),
+            hash_metadata(&metadata).unwrap()
+        );
+        assert!(puffed.unpuffed() == metadata);
+        assert_eq!(
+            hash_metadata(&puffed.unpuffed()).unwrap(),
+            hash_metadata(&metadata).unwrap()
+        );
+
+        // Puffing is idempotent, like it is in Token Metadata.
+        assert!(puffed.puffed() == puffed);
+    }
+
+    #[test]
+    fn fee_is_bound_by_data_hash() {
+        let metadata = metadata();
+        let mut other = metadata.clone();
+        other.seller_fee_basis_points += 1;
+
+        assert_ne!(
+            hash_metadata(&metadata).unwrap(),
+            hash_metadata(&other).unwrap()
+        );
+        // Creator hashes only commit to the creators themselves.
+        assert_eq!(
+            hash_creators(&metadata.creators).unwrap(),
+            hash_creators(&other.creators).unwrap()
+        );
+    }
+
+    #[test]
+    fn every_leaf_field_is_committed() {
+        let leaf = |update: fn(&mut [[u8; 32]; 5], &mut u64)| {
+            let mut keys = [[2; 32], [3; 32], [4; 32], [5; 32], [6; 32]];
+            let mut nonce = 7;
+            update(&mut keys, &mut nonce);
+            LeafSchema::new_v0(
+                Pubkey::new_from_array(keys[0]),
+                Pubkey::new_from_array(keys[1]),
+                Pubkey::new_from_array(keys[2]),
+                nonce,
+                keys[3],
+                keys[4],
+            )
+        };
+
+        let original = hash_leaf(&leaf(|_, _| {}));
+        let cases: [(&str, fn(&mut [[u8; 32]; 5], &mut u64)); 6] = [
+            ("id", |k, _| k[0][0] ^= 1),
+            ("owner", |k, _| k[1][31] ^= 1),
+            ("delegate", |k, _| k[2][0] ^= 1),
+            ("nonce", |_, n| *n += 1),
+            ("data hash", |k, _| k[3][31] ^= 1),
+            ("creator hash", |k, _| k[4][0] ^= 1),
+        ];
+
+        for (field, update) in cases {
+            assert_ne!(hash_leaf(&leaf(update)), original, "{}", field);
+        }
+
+        // Owner and delegate are not interchangeable.
+        let swapped = leaf(|k, _| k.swap(1, 2));
+        assert_ne!(hash_leaf(&swapped), original);
+    }
+
+    #[test]
+    fn every_creator_field_is_committed() {
+        let creators = metadata().creators;
+        let original = hash_creators(&creators).unwrap();
+        let cases: [(&str, fn(&mut Vec<Creator>)); 4] = [
+            ("address", |c| {
+                c[0].address = Pubkey::new_from_array([2; 32])
+            }),
+            ("verified", |c| c[0].verified = !c[0].verified),
+            ("share", |c| c[0].share -= 1),
+            ("count", |c| c.push(c[0])),
+        ];
+
+        for (field, update) in cases {
+            let mut updated = creators.clone();
+            update(&mut updated);
+            assert_ne!(hash_creators(&updated).unwrap(), original, "{}", field);
+        }
+    }
+
+    #[test]
+    fn zero_share_creators_are_committed() {
+        let mut creators = metadata().creators;
+        let without = hash_creators(&creators).unwrap();
+
+        let mut zero_share = creators[0];
+        zero_share.address = Pubkey::new_from_array([2; 32]);
+        zero_share.share = 0;
+        creators.push(zero_share);
+        let unverified = hash_creators(&creators).unwrap();
+        assert_ne!(unverified, without);
+
+        creators[1].verified = true;
+        assert_ne!(hash_creators(&creators).unwrap(), unverified);
+    }
+}
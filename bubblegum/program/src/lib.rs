@@ -1,401 +1,24 @@
-use crate::{
-    error::BubblegumError,
-    state::{
-        leaf_schema::{LeafSchema, Version},
-        metaplex_adapter::{self, Creator, MetadataArgs, TokenProgramVersion},
-        metaplex_anchor::{MasterEdition, MplTokenMetadata, TokenMetadata},
-        NFTDecompressionEvent, NewNFTEvent, TreeConfig, Voucher, ASSET_PREFIX,
-        COLLECTION_CPI_PREFIX, TREE_AUTHORITY_SIZE, VOUCHER_PREFIX, VOUCHER_SIZE,
-    },
-    utils::{
-        append_leaf, assert_metadata_is_mpl_compatible, assert_pubkey_equal, cmp_bytes,
-        cmp_pubkeys, get_asset_id, replace_leaf,
-    },
+use crate::state::{
+    metaplex_adapter::MetadataArgs, BatchTransfer, HashedMetadataArgs, LoggingMode,
 };
-use anchor_lang::{
-    prelude::*,
-    solana_program::{
-        account_info::AccountInfo,
-        keccak,
-        program::{invoke, invoke_signed},
-        program_error::ProgramError,
-        program_pack::Pack,
-        system_instruction,
-    },
-    system_program::System,
-};
-use mpl_token_metadata::{
-    assertions::collection::{assert_collection_verify_is_valid, assert_has_collection_authority},
-    state::CollectionDetails,
-};
-use spl_account_compression::{
-    data_wrapper::{wrap_event, Wrapper},
-    program::SplAccountCompression,
-    Node,
-};
-use spl_token::state::Mint as SplMint;
-use std::collections::HashSet;
+use anchor_lang::prelude::*;
 
+#[cfg(not(target_arch = "bpf"))]
+pub mod client;
 pub mod error;
+pub mod hash;
+pub mod pda;
+pub mod prelude;
+pub mod processor;
 pub mod state;
 pub mod utils;
 
-declare_id!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
-
-#[derive(Accounts)]
-pub struct CreateTree<'info> {
-    #[account(
-        init,
-        seeds = [merkle_tree.key().as_ref()],
-        payer = payer,
-        space = TREE_AUTHORITY_SIZE,
-        bump,
-    )]
-    pub tree_authority: Account<'info, TreeConfig>,
-    #[account(zero)]
-    /// CHECK: This account must be all zeros
-    pub merkle_tree: UncheckedAccount<'info>,
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    pub tree_creator: Signer<'info>,
-    pub log_wrapper: Program<'info, Wrapper>,
-    pub compression_program: Program<'info, SplAccountCompression>,
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct MintV1<'info> {
-    #[account(
-        mut,
-        seeds = [merkle_tree.key().as_ref()],
-        bump,
-    )]
-    pub tree_authority: Account<'info, TreeConfig>,
-    /// CHECK: This account is neither written to nor read from.
-    pub leaf_owner: AccountInfo<'info>,
-    /// CHECK: This account is neither written to nor read from.
-    pub leaf_delegate: AccountInfo<'info>,
-    #[account(mut)]
-    /// CHECK: unsafe
-    pub merkle_tree: UncheckedAccount<'info>,
-    pub payer: Signer<'info>,
-    pub tree_delegate: Signer<'info>,
-    pub log_wrapper: Program<'info, Wrapper>,
-    pub compression_program: Program<'info, SplAccountCompression>,
-}
-
-#[derive(Accounts)]
-pub struct Burn<'info> {
-    #[account(
-        seeds = [merkle_tree.key().as_ref()],
-        bump,
-    )]
-    pub tree_authority: Account<'info, TreeConfig>,
-    /// CHECK: This account is checked in the instruction
-    pub leaf_owner: UncheckedAccount<'info>,
-    /// CHECK: This account is checked in the instruction
-    pub leaf_delegate: UncheckedAccount<'info>,
-    #[account(mut)]
-    /// CHECK: This account is modified in the downstream program
-    pub merkle_tree: UncheckedAccount<'info>,
-    pub log_wrapper: Program<'info, Wrapper>,
-    pub compression_program: Program<'info, SplAccountCompression>,
-}
-
-#[derive(Accounts)]
-pub struct CreatorVerification<'info> {
-    #[account(
-        seeds = [merkle_tree.key().as_ref()],
-        bump,
-    )]
-    pub tree_authority: Account<'info, TreeConfig>,
-    /// CHECK: This account is checked in the instruction
-    pub leaf_owner: UncheckedAccount<'info>,
-    /// CHECK: This account is chekced in the instruction
-    pub leaf_delegate: UncheckedAccount<'info>,
-    #[account(mut)]
-    /// CHECK: This account is modified in the downstream program
-    pub merkle_tree: UncheckedAccount<'info>,
-    pub payer: Signer<'info>,
-    pub creator: Signer<'info>,
-    pub log_wrapper: Program<'info, Wrapper>,
-    pub compression_program: Program<'info, SplAccountCompression>,
-}
-
-#[derive(Accounts)]
-pub struct CollectionVerification<'info> {
-    #[account(
-        seeds = [merkle_tree.key().as_ref()],
-        bump,
-    )]
-    pub tree_authority: Account<'info, TreeConfig>,
-    /// CHECK: This account is checked in the instruction
-    pub leaf_owner: UncheckedAccount<'info>,
-    /// CHECK: This account is checked in the instruction
-    pub leaf_delegate: UncheckedAccount<'info>,
-    #[account(mut)]
-    /// CHECK: This account is modified in the downstream program
-    pub merkle_tree: UncheckedAccount<'info>,
-    pub payer: Signer<'info>,
-    /// CHECK: This account is checked to be a signer in
-    /// the case of `set_and_verify_collection` where
-    /// we are actually changing the NFT metadata.
-    pub tree_delegate: UncheckedAccount<'info>,
-    pub collection_authority: Signer<'info>,
-    /// CHECK: This account is checked in the instruction
-    pub collection_mint: UncheckedAccount<'info>,
-    pub collection_metadata: Box<Account<'info, TokenMetadata>>,
-    /// CHECK: This account is checked in the instruction
-    pub edition_account: UncheckedAccount<'info>,
-    /// CHECK: This is just used as a signing PDA.
-    #[account(
-        seeds = [COLLECTION_CPI_PREFIX.as_ref()],
-        bump,
-    )]
-    pub bubblegum_signer: UncheckedAccount<'info>,
-    pub log_wrapper: Program<'info, Wrapper>,
-    pub compression_program: Program<'info, SplAccountCompression>,
-    pub token_metadata_program: Program<'info, MplTokenMetadata>,
-}
-
-#[derive(Accounts)]
-pub struct Transfer<'info> {
-    #[account(
-        seeds = [merkle_tree.key().as_ref()],
-        bump,
-    )]
-    /// CHECK: This account is neither written to nor read from.
-    pub tree_authority: Account<'info, TreeConfig>,
-    /// CHECK: This account is checked in the instruction
-    pub leaf_owner: UncheckedAccount<'info>,
-    /// CHECK: This account is chekced in the instruction
-    pub leaf_delegate: UncheckedAccount<'info>,
-    /// CHECK: This account is neither written to nor read from.
-    pub new_leaf_owner: UncheckedAccount<'info>,
-    #[account(mut)]
-    /// CHECK: This account is modified in the downstream program
-    pub merkle_tree: UncheckedAccount<'info>,
-    pub log_wrapper: Program<'info, Wrapper>,
-    pub compression_program: Program<'info, SplAccountCompression>,
-}
-
-#[derive(Accounts)]
-pub struct Delegate<'info> {
-    #[account(
-        seeds = [merkle_tree.key().as_ref()],
-        bump,
-    )]
-    /// CHECK: This account is neither written to nor read from.
-    pub tree_authority: Account<'info, TreeConfig>,
-    pub leaf_owner: Signer<'info>,
-    /// CHECK: This account is neither written to nor read from.
-    pub previous_leaf_delegate: UncheckedAccount<'info>,
-    /// CHECK: This account is neither written to nor read from.
-    pub new_leaf_delegate: UncheckedAccount<'info>,
-    #[account(mut)]
-    /// CHECK: This account is modified in the downstream program
-    pub merkle_tree: UncheckedAccount<'info>,
-    pub log_wrapper: Program<'info, Wrapper>,
-    pub compression_program: Program<'info, SplAccountCompression>,
-}
-
-#[derive(Accounts)]
-#[instruction(
-    _root: [u8; 32],
-    _data_hash: [u8; 32],
-    _creator_hash: [u8; 32],
-    nonce: u64,
-    _index: u32,
-)]
-pub struct Redeem<'info> {
-    #[account(
-        seeds = [merkle_tree.key().as_ref()],
-        bump,
-    )]
-    /// CHECK: This account is neither written to nor read from.
-    pub tree_authority: Account<'info, TreeConfig>,
-    #[account(mut)]
-    pub leaf_owner: Signer<'info>,
-    /// CHECK: This account is chekced in the instruction
-    pub leaf_delegate: UncheckedAccount<'info>,
-    #[account(mut)]
-    /// CHECK: checked in cpi
-    pub merkle_tree: UncheckedAccount<'info>,
-    #[account(
-        init,
-        seeds = [
-        VOUCHER_PREFIX.as_ref(),
-        merkle_tree.key().as_ref(),
-        & nonce.to_le_bytes()
-    ],
-    payer = leaf_owner,
-    space = VOUCHER_SIZE,
-    bump
-    )]
-    pub voucher: Account<'info, Voucher>,
-    pub log_wrapper: Program<'info, Wrapper>,
-    pub compression_program: Program<'info, SplAccountCompression>,
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct CancelRedeem<'info> {
-    #[account(
-        seeds = [merkle_tree.key().as_ref()],
-        bump,
-    )]
-    /// CHECK: This account is neither written to nor read from.
-    pub tree_authority: Account<'info, TreeConfig>,
-    #[account(mut)]
-    pub leaf_owner: Signer<'info>,
-    #[account(mut)]
-    /// CHECK: unsafe
-    pub merkle_tree: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        close = leaf_owner,
-        seeds = [
-        VOUCHER_PREFIX.as_ref(),
-        merkle_tree.key().as_ref(),
-        & voucher.leaf_schema.nonce().to_le_bytes()
-    ],
-    bump
-    )]
-    pub voucher: Account<'info, Voucher>,
-    pub log_wrapper: Program<'info, Wrapper>,
-    pub compression_program: Program<'info, SplAccountCompression>,
-}
-
-#[derive(Accounts)]
-pub struct DecompressV1<'info> {
-    #[account(
-        mut,
-        close = leaf_owner,
-        seeds = [
-            VOUCHER_PREFIX.as_ref(),
-            voucher.merkle_tree.as_ref(),
-            voucher.leaf_schema.nonce().to_le_bytes().as_ref()
-        ],
-        bump
-    )]
-    pub voucher: Box<Account<'info, Voucher>>,
-    #[account(mut)]
-    pub leaf_owner: Signer<'info>,
-    /// CHECK: versioning is handled in the instruction
-    #[account(mut)]
-    pub token_account: UncheckedAccount<'info>,
-    /// CHECK: versioning is handled in the instruction
-    #[account(
-        mut,
-        seeds = [
-            ASSET_PREFIX.as_ref(),
-            voucher.merkle_tree.as_ref(),
-            voucher.leaf_schema.nonce().to_le_bytes().as_ref(),
-        ],
-        bump
-    )]
-    pub mint: UncheckedAccount<'info>,
-    /// CHECK:
-    #[account(
-        seeds = [mint.key().as_ref()],
-        bump,
-    )]
-    pub mint_authority: UncheckedAccount<'info>,
-    /// CHECK:
-    #[account(mut)]
-    pub metadata: UncheckedAccount<'info>,
-    /// CHECK: Initialized in Token Metadata Program
-    #[account(mut)]
-    pub master_edition: UncheckedAccount<'info>,
-    pub system_program: Program<'info, System>,
-    pub sysvar_rent: Sysvar<'info, Rent>,
-    /// CHECK:
-    pub token_metadata_program: Program<'info, MplTokenMetadata>,
-    /// CHECK: versioning is handled in the instruction
-    pub token_program: UncheckedAccount<'info>,
-    /// CHECK:
-    pub associated_token_program: UncheckedAccount<'info>,
-}
-
-#[derive(Accounts)]
-pub struct Compress<'info> {
-    #[account(
-        seeds = [merkle_tree.key().as_ref()],
-        bump,
-    )]
-    /// CHECK: This account is neither written to nor read from.
-    pub tree_authority: UncheckedAccount<'info>,
-    /// CHECK: This account is checked in the instruction
-    pub leaf_owner: Signer<'info>,
-    /// CHECK: This account is chekced in the instruction
-    pub leaf_delegate: UncheckedAccount<'info>,
-    /// CHECK: This account is not read
-    pub merkle_tree: UncheckedAccount<'info>,
-
-    /// CHECK: versioning is handled in the instruction
-    #[account(mut)]
-    pub token_account: AccountInfo<'info>,
-    /// CHECK: versioning is handled in the instruction
-    #[account(mut)]
-    pub mint: AccountInfo<'info>,
-    #[account(mut)]
-    pub metadata: Box<Account<'info, TokenMetadata>>,
-    #[account(mut)]
-    pub master_edition: Box<Account<'info, MasterEdition>>,
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    pub log_wrapper: Program<'info, Wrapper>,
-    pub compression_program: Program<'info, SplAccountCompression>,
-    /// CHECK:
-    pub token_program: UncheckedAccount<'info>,
-    /// CHECK:
-    pub token_metadata_program: UncheckedAccount<'info>,
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct SetTreeDelegate<'info> {
-    #[account(
-        mut,
-        seeds = [merkle_tree.key().as_ref()],
-        bump,
-        has_one = tree_creator
-    )]
-    pub tree_authority: Account<'info, TreeConfig>,
-    pub tree_creator: Signer<'info>,
-    /// CHECK: this account is neither read from or written to
-    pub new_tree_delegate: UncheckedAccount<'info>,
-    /// CHECK: this account is neither read from or written to
-    pub merkle_tree: UncheckedAccount<'info>,
-}
-
-pub fn hash_creators(creators: &[Creator]) -> Result<[u8; 32]> {
-    // Convert creator Vec to bytes Vec.
-    let creator_data = creators
-        .iter()
-        .map(|c| [c.address.as_ref(), &[c.verified as u8], &[c.share]].concat())
-        .collect::<Vec<_>>();
-    // Calculate new creator hash.
-    Ok(keccak::hashv(
-        creator_data
-            .iter()
-            .map(|c| c.as_slice())
-            .collect::<Vec<&[u8]>>()
-            .as_ref(),
-    )
-    .to_bytes())
-}
+pub use hash::{hash_creators, hash_metadata};
+pub use processor::*;
 
-pub fn hash_metadata(metadata: &MetadataArgs) -> Result<[u8; 32]> {
-    let metadata_args_hash = keccak::hashv(&[metadata.try_to_vec()?.as_slice()]);
-    // Calculate new data hash.
-    Ok(keccak::hashv(&[
-        &metadata_args_hash.to_bytes(),
-        &metadata.seller_fee_basis_points.to_le_bytes(),
-    ])
-    .to_bytes())
-}
+declare_id!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum InstructionName {
     Unknown,
     MintV1,
@@ -412,6 +35,48 @@ pub enum InstructionName {
     VerifyCollection,
     UnverifyCollection,
     SetAndVerifyCollection,
+    SetTreeMetadata,
+    VerifyMetadataHash,
+    MintV1Hashed,
+    SetTreeHashOnly,
+    WithdrawFromTreeAuthority,
+    SetLoggingMode,
+    TransferTimelocked,
+    ClaimTimelockedTransfer,
+    CancelTimelockedTransfer,
+    MintAirdrop,
+    SetTreeNonTransferable,
+    SetSoulboundOverride,
+    DelegateWithExpiry,
+    InitOnChainMetadata,
+    WriteMetadataChunk,
+    MintV1WithReceipt,
+    CloseAssetReceipt,
+    BurnWithReceipt,
+    CloseBurnReceipt,
+    ProposeTreeCreator,
+    AcceptTreeCreator,
+    CancelTreeCreatorTransfer,
+    SetFeatureFlags,
+    UpdateMetadata,
+    MintToCollectionV1,
+    MintBatchV1,
+    TransferBatch,
+    Freeze,
+    Thaw,
+    SetTreePublic,
+    SetMintPrice,
+    ProposeMetadataUpdate,
+    ApproveMetadataUpdate,
+    CancelMetadataProposal,
+    CreateDenyList,
+    UpdateDenyList,
+    SetTreeDenyList,
+    AddTreeDelegate,
+    RemoveTreeDelegate,
+    SetTreeDelegate,
+    SetTreePaused,
+    CloseDelegateExpiry,
 }
 
 pub fn get_instruction_type(full_bytes: &[u8]) -> InstructionName {
@@ -435,452 +100,264 @@ pub fn get_instruction_type(full_bytes: &[u8]) -> InstructionName {
         [56, 113, 101, 253, 79, 55, 122, 169] => InstructionName::VerifyCollection,
         [250, 251, 42, 106, 41, 137, 186, 168] => InstructionName::UnverifyCollection,
         [235, 242, 121, 216, 158, 234, 180, 234] => InstructionName::SetAndVerifyCollection,
+        [186, 254, 7, 254, 160, 196, 98, 50] => InstructionName::SetTreeMetadata,
+        [177, 200, 135, 231, 116, 80, 141, 11] => InstructionName::VerifyMetadataHash,
+        [72, 26, 197, 156, 122, 222, 77, 188] => InstructionName::MintV1Hashed,
+        [166, 150, 228, 202, 89, 158, 249, 116] => InstructionName::SetTreeHashOnly,
+        [51, 215, 67, 28, 121, 69, 65, 246] => InstructionName::WithdrawFromTreeAuthority,
+        [35, 166, 138, 196, 225, 61, 27, 76] => InstructionName::SetLoggingMode,
+        [134, 70, 104, 39, 36, 236, 115, 240] => InstructionName::TransferTimelocked,
+        [66, 71, 92, 203, 254, 50, 47, 30] => InstructionName::ClaimTimelockedTransfer,
+        [157, 156, 39, 128, 87, 59, 186, 174] => InstructionName::CancelTimelockedTransfer,
+        [92, 124, 235, 176, 68, 55, 176, 100] => InstructionName::MintAirdrop,
+        [69, 242, 201, 43, 18, 4, 14, 248] => InstructionName::SetTreeNonTransferable,
+        [151, 34, 244, 211, 203, 107, 17, 204] => InstructionName::SetSoulboundOverride,
+        [200, 213, 81, 62, 201, 11, 167, 163] => InstructionName::DelegateWithExpiry,
+        [5, 231, 139, 227, 161, 81, 0, 151] => InstructionName::InitOnChainMetadata,
+        [186, 10, 232, 217, 40, 209, 151, 67] => InstructionName::WriteMetadataChunk,
+        [132, 255, 208, 187, 22, 59, 75, 187] => InstructionName::MintV1WithReceipt,
+        [29, 149, 13, 220, 0, 111, 60, 237] => InstructionName::CloseAssetReceipt,
+        [167, 94, 37, 185, 192, 60, 45, 147] => InstructionName::BurnWithReceipt,
+        [78, 54, 10, 152, 154, 92, 82, 87] => InstructionName::CloseBurnReceipt,
+        [101, 6, 141, 180, 47, 246, 28, 48] => InstructionName::ProposeTreeCreator,
+        [106, 65, 242, 169, 157, 90, 154, 67] => InstructionName::AcceptTreeCreator,
+        [7, 136, 246, 144, 26, 38, 118, 94] => InstructionName::CancelTreeCreatorTransfer,
+        [21, 221, 245, 250, 22, 107, 127, 78] => InstructionName::SetFeatureFlags,
+        [170, 182, 43, 239, 97, 78, 225, 186] => InstructionName::UpdateMetadata,
+        [153, 18, 178, 47, 197, 158, 86, 15] => InstructionName::MintToCollectionV1,
+        [248, 4, 56, 142, 14, 49, 169, 71] => InstructionName::MintBatchV1,
+        [114, 216, 40, 75, 132, 196, 172, 29] => InstructionName::TransferBatch,
+        [255, 91, 207, 84, 251, 194, 254, 63] => InstructionName::Freeze,
+        [226, 249, 34, 57, 189, 21, 177, 101] => InstructionName::Thaw,
+        [62, 248, 224, 246, 35, 172, 162, 17] => InstructionName::SetTreePublic,
+        [105, 146, 251, 12, 72, 223, 220, 66] => InstructionName::SetMintPrice,
+        [125, 177, 28, 68, 30, 171, 204, 21] => InstructionName::ProposeMetadataUpdate,
+        [0, 89, 156, 191, 85, 29, 194, 1] => InstructionName::ApproveMetadataUpdate,
+        [45, 214, 54, 56, 221, 204, 180, 209] => InstructionName::CancelMetadataProposal,
+        [12, 149, 114, 8, 93, 249, 109, 125] => InstructionName::CreateDenyList,
+        [21, 228, 19, 23, 199, 122, 131, 108] => InstructionName::UpdateDenyList,
+        [87, 251, 200, 80, 18, 146, 237, 24] => InstructionName::SetTreeDenyList,
+        [149, 205, 7, 165, 197, 89, 212, 142] => InstructionName::AddTreeDelegate,
+        [61, 71, 6, 95, 245, 234, 153, 64] => InstructionName::RemoveTreeDelegate,
+        [253, 118, 66, 37, 190, 49, 154, 102] => InstructionName::SetTreeDelegate,
+        [55, 169, 41, 72, 70, 115, 25, 208] => InstructionName::SetTreePaused,
+        [16, 45, 249, 0, 250, 240, 7, 138] => InstructionName::CloseDelegateExpiry,
         _ => InstructionName::Unknown,
     }
 }
 
-fn process_mint_v1<'info>(
-    message: MetadataArgs,
-    owner: Pubkey,
-    delegate: Pubkey,
-    metadata_auth: HashSet<Pubkey>,
-    authority_bump: u8,
-    authority: &mut Account<'info, TreeConfig>,
-    merkle_tree: &AccountInfo<'info>,
-    wrapper: &Program<'info, Wrapper>,
-    compression_program: &AccountInfo<'info>,
-) -> Result<()> {
-    assert_metadata_is_mpl_compatible(&message)?;
-    // TODO -> Separate V1 / V1 into seperate instructions
+#[program]
+pub mod bubblegum {
+    use super::*;
 
-    // Currently, not allowing a collection to be verified outside of `verify_collection`
-    // instruction to have parity with token-metadata.
-    if let Some(collection) = &message.collection {
-        if collection.verified {
-            return Err(BubblegumError::CollectionCannotBeVerifiedInThisInstruction.into());
-        }
+    pub fn create_tree(
+        ctx: Context<CreateTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+        logging_mode: LoggingMode,
+        creator_immutable: bool,
+        is_public: bool,
+    ) -> Result<()> {
+        handle_create_tree(
+            ctx,
+            max_depth,
+            max_buffer_size,
+            logging_mode,
+            creator_immutable,
+            is_public,
+        )
     }
 
-    // @dev: seller_fee_basis points is encoded twice so that it can be passed to marketplace
-    // instructions, without passing the entire, un-hashed MetadataArgs struct
-    let metadata_args_hash = keccak::hashv(&[message.try_to_vec()?.as_slice()]);
-    let data_hash = keccak::hashv(&[
-        &metadata_args_hash.to_bytes(),
-        &message.seller_fee_basis_points.to_le_bytes(),
-    ]);
-
-    // Use the metadata auth to check whether we can allow `verified` to be set to true in the
-    // creator Vec.
-    let creator_data = message
-        .creators
-        .iter()
-        .map(|c| {
-            if c.verified && !metadata_auth.contains(&c.address) {
-                Err(BubblegumError::CreatorDidNotVerify.into())
-            } else {
-                Ok([c.address.as_ref(), &[c.verified as u8], &[c.share]].concat())
-            }
-        })
-        .collect::<Result<Vec<_>>>()?;
-
-    // Calculate creator hash.
-    let creator_hash = keccak::hashv(
-        creator_data
-            .iter()
-            .map(|c| c.as_slice())
-            .collect::<Vec<&[u8]>>()
-            .as_ref(),
-    );
-
-    let asset_id = get_asset_id(&merkle_tree.key(), authority.num_minted);
-    let leaf = LeafSchema::new_v0(
-        asset_id,
-        owner,
-        delegate,
-        authority.num_minted,
-        data_hash.to_bytes(),
-        creator_hash.to_bytes(),
-    );
-    let new_nft = NewNFTEvent {
-        version: Version::V1,
-        metadata: message,
-        nonce: authority.num_minted,
-    };
-
-    emit!(new_nft);
-    wrap_event(new_nft.try_to_vec()?, wrapper)?;
-
-    emit!(leaf.to_event());
-
-    append_leaf(
-        &merkle_tree.key(),
-        authority_bump,
-        &compression_program.to_account_info(),
-        &authority.to_account_info(),
-        &merkle_tree.to_account_info(),
-        &wrapper.to_account_info(),
-        leaf.to_node(),
-    )
-}
-
-fn process_creator_verification<'info>(
-    ctx: Context<'_, '_, '_, 'info, CreatorVerification<'info>>,
-    root: [u8; 32],
-    data_hash: [u8; 32],
-    creator_hash: [u8; 32],
-    nonce: u64,
-    index: u32,
-    mut message: MetadataArgs,
-    verify: bool,
-) -> Result<()> {
-    let owner = ctx.accounts.leaf_owner.to_account_info();
-    let delegate = ctx.accounts.leaf_delegate.to_account_info();
-    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
-
-    let creator = ctx.accounts.creator.key();
-
-    // Creator Vec must contain creators.
-    if message.creators.is_empty() {
-        return Err(BubblegumError::NoCreatorsPresent.into());
+    pub fn set_tree_delegate(ctx: Context<SetTreeDelegate>) -> Result<()> {
+        handle_set_tree_delegate(ctx)
     }
 
-    // Creator must be in user-provided creator Vec.
-    if !message.creators.iter().any(|c| c.address == creator) {
-        return Err(BubblegumError::CreatorNotFound.into());
+    pub fn propose_tree_creator(
+        ctx: Context<ProposeTreeCreator>,
+        reset_tree_delegate: bool,
+    ) -> Result<()> {
+        handle_propose_tree_creator(ctx, reset_tree_delegate)
     }
 
-    // User-provided creator Vec must result in same user-provided creator hash.
-    let incoming_creator_hash = hash_creators(&message.creators)?;
-    if creator_hash != incoming_creator_hash {
-        return Err(BubblegumError::CreatorHashMismatch.into());
+    pub fn accept_tree_creator(ctx: Context<AcceptTreeCreator>) -> Result<()> {
+        handle_accept_tree_creator(ctx)
     }
 
-    // User-provided metadata must result in same user-provided data hash.
-    let incoming_data_hash = hash_metadata(&message)?;
-    if data_hash != incoming_data_hash {
-        return Err(BubblegumError::DataHashMismatch.into());
+    pub fn cancel_tree_creator_transfer(ctx: Context<CancelTreeCreatorTransfer>) -> Result<()> {
+        handle_cancel_tree_creator_transfer(ctx)
     }
 
-    // Calculate new creator Vec with `verified` set to true for signing creator.
-    let updated_creator_vec = message
-        .creators
-        .iter()
-        .map(|c| {
-            let verified = if c.address == creator.key() {
-                verify
-            } else {
-                c.verified
-            };
-            Creator {
-                address: c.address,
-                verified,
-                share: c.share,
-            }
-        })
-        .collect::<Vec<Creator>>();
-
-    // Calculate new creator hash.
-    let updated_creator_hash = hash_creators(&updated_creator_vec)?;
-
-    // Update creator Vec in metadata args.
-    message.creators = updated_creator_vec;
-
-    // Calculate new data hash.
-    let updated_data_hash = hash_metadata(&message)?;
-
-    // Build previous leaf struct, new leaf struct, and replace the leaf in the tree.
-    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
-    let previous_leaf = LeafSchema::new_v0(
-        asset_id,
-        owner.key(),
-        delegate.key(),
-        nonce,
-        data_hash,
-        creator_hash,
-    );
-    let new_leaf = LeafSchema::new_v0(
-        asset_id,
-        owner.key(),
-        delegate.key(),
-        nonce,
-        updated_data_hash,
-        updated_creator_hash,
-    );
-    emit!(new_leaf.to_event());
-    replace_leaf(
-        &merkle_tree.key(),
-        *ctx.bumps.get("tree_authority").unwrap(),
-        &ctx.accounts.compression_program.to_account_info(),
-        &ctx.accounts.tree_authority.to_account_info(),
-        &ctx.accounts.merkle_tree.to_account_info(),
-        &ctx.accounts.log_wrapper.to_account_info(),
-        ctx.remaining_accounts,
-        root,
-        previous_leaf.to_node(),
-        new_leaf.to_node(),
-        index,
-    )
-}
-
-fn process_collection_verification<'info>(
-    ctx: Context<'_, '_, '_, 'info, CollectionVerification<'info>>,
-    root: [u8; 32],
-    data_hash: [u8; 32],
-    creator_hash: [u8; 32],
-    nonce: u64,
-    index: u32,
-    mut message: MetadataArgs,
-    verify: bool,
-    new_collection: Option<[u8; 32]>,
-) -> Result<()> {
-    let owner = ctx.accounts.leaf_owner.to_account_info();
-    let delegate = ctx.accounts.leaf_delegate.to_account_info();
-    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
-    let collection_metadata = &ctx.accounts.collection_metadata;
-    let collection_mint = ctx.accounts.collection_mint.to_account_info();
-    let edition_account = ctx.accounts.edition_account.to_account_info();
-    let collection_authority = ctx.accounts.collection_authority.to_account_info();
-    let bubblegum_signer = ctx.accounts.bubblegum_signer.to_account_info();
-    let token_metadata_program = ctx.accounts.token_metadata_program.to_account_info();
-
-    // Look for collection authority record PDA as a remaining account.
-    let collection_authority_record = if !ctx.remaining_accounts.is_empty() {
-        Some(&ctx.remaining_accounts[0])
-    } else {
-        None
-    };
-
-    // Verify correct account ownerships.
-    require!(
-        *collection_metadata.to_account_info().owner == ctx.accounts.token_metadata_program.key(),
-        BubblegumError::IncorrectOwner
-    );
-    require!(
-        *collection_mint.owner == spl_token::id(),
-        BubblegumError::IncorrectOwner
-    );
-    require!(
-        *edition_account.owner == ctx.accounts.token_metadata_program.key(),
-        BubblegumError::IncorrectOwner
-    );
+    pub fn set_feature_flags(
+        ctx: Context<SetFeatureFlags>,
+        disabled_instructions: u64,
+    ) -> Result<()> {
+        handle_set_feature_flags(ctx, disabled_instructions)
+    }
 
-    // User-provided metadata must result in same user-provided data hash.
-    let incoming_data_hash = hash_metadata(&message)?;
-    if data_hash != incoming_data_hash {
-        return Err(BubblegumError::DataHashMismatch.into());
+    pub fn withdraw_from_tree_authority(
+        ctx: Context<WithdrawFromTreeAuthority>,
+        amount: u64,
+    ) -> Result<()> {
+        handle_withdraw_from_tree_authority(ctx, amount)
     }
 
-    // If new collection was provided, set it in the NFT metadata.
-    if new_collection.is_some() {
-        message.collection = new_collection.map(|c| metaplex_adapter::Collection {
-            verified: false, // Set to true below.
-            key: Pubkey::new(&c),
-        });
+    pub fn set_tree_hash_only(ctx: Context<SetTreeHashOnly>, is_hash_only: bool) -> Result<()> {
+        handle_set_tree_hash_only(ctx, is_hash_only)
     }
 
-    // If the NFT has collection data, we set it to the correct value after doing some validation.
-    if let Some(collection) = &mut message.collection {
-        // Don't verify already verified items, or unverify unverified items, otherwise for sized
-        // collections we end up with invalid size data.
-        if verify && collection.verified {
-            return Err(BubblegumError::AlreadyVerified.into());
-        } else if !verify && !collection.verified {
-            return Err(BubblegumError::AlreadyUnverified.into());
-        }
+    pub fn set_logging_mode(ctx: Context<SetLoggingMode>, logging_mode: LoggingMode) -> Result<()> {
+        handle_set_logging_mode(ctx, logging_mode)
+    }
 
-        // Collection verify assert from token-metadata program.
-        assert_collection_verify_is_valid(
-            &Some(collection.adapt()),
-            collection_metadata,
-            &collection_mint,
-            &edition_account,
-        )?;
+    pub fn set_tree_non_transferable(
+        ctx: Context<SetTreeNonTransferable>,
+        is_non_transferable: bool,
+    ) -> Result<()> {
+        handle_set_tree_non_transferable(ctx, is_non_transferable)
+    }
 
-        // Collection authority assert from token-metadata.
-        assert_has_collection_authority(
-            &collection_authority,
-            collection_metadata,
-            collection_mint.key,
-            collection_authority_record,
-        )?;
+    pub fn set_tree_public(ctx: Context<SetTreePublic>, is_public: bool) -> Result<()> {
+        handle_set_tree_public(ctx, is_public)
+    }
 
-        // Update collection in metadata args.  Note since this is a mutable reference,
-        // it is still updating `message.collection` after being destructured.
-        collection.verified = verify;
-    } else {
-        return Err(BubblegumError::CollectionNotFound.into());
+    pub fn set_tree_paused(ctx: Context<SetTreePaused>, is_paused: bool) -> Result<()> {
+        handle_set_tree_paused(ctx, is_paused)
     }
 
-    // If this is a sized collection, then increment or decrement collection size.
-    if let Some(details) = &collection_metadata.collection_details {
-        // Increment or decrement existing size.
-        let new_size = match details {
-            CollectionDetails::V1 { size } => {
-                if verify {
-                    size.checked_add(1)
-                        .ok_or(BubblegumError::NumericalOverflowError)?
-                } else {
-                    size.checked_sub(1)
-                        .ok_or(BubblegumError::NumericalOverflowError)?
-                }
-            }
-        };
+    pub fn set_mint_price(
+        ctx: Context<SetMintPrice>,
+        lamports: u64,
+        treasury: Pubkey,
+        token_mint: Pubkey,
+        token_amount: u64,
+    ) -> Result<()> {
+        handle_set_mint_price(ctx, lamports, treasury, token_mint, token_amount)
+    }
 
-        // CPI into to token-metadata program to change the collection size.
-        let mut bubblegum_set_collection_size_infos = vec![
-            collection_metadata.to_account_info(),
-            collection_authority.clone(),
-            collection_mint.clone(),
-            bubblegum_signer.clone(),
-        ];
+    pub fn create_deny_list(ctx: Context<CreateDenyList>) -> Result<()> {
+        handle_create_deny_list(ctx)
+    }
 
-        if let Some(record) = collection_authority_record {
-            bubblegum_set_collection_size_infos.push(record.clone());
-        }
+    pub fn update_deny_list(
+        ctx: Context<UpdateDenyList>,
+        add: Vec<Pubkey>,
+        remove: Vec<Pubkey>,
+    ) -> Result<()> {
+        handle_update_deny_list(ctx, add, remove)
+    }
 
-        invoke_signed(
-            &mpl_token_metadata::instruction::bubblegum_set_collection_size(
-                token_metadata_program.key(),
-                collection_metadata.to_account_info().key(),
-                collection_authority.key(),
-                collection_mint.key(),
-                bubblegum_signer.key(),
-                collection_authority_record.map(|r| r.key()),
-                new_size,
-            ),
-            bubblegum_set_collection_size_infos.as_slice(),
-            &[&[
-                COLLECTION_CPI_PREFIX.as_bytes(),
-                &[ctx.bumps["bubblegum_signer"]],
-            ]],
-        )?;
+    pub fn set_tree_deny_list(ctx: Context<SetTreeDenyList>, deny_list: Pubkey) -> Result<()> {
+        handle_set_tree_deny_list(ctx, deny_list)
     }
 
-    // Calculate new data hash.
-    let updated_data_hash = hash_metadata(&message)?;
+    pub fn add_tree_delegate(ctx: Context<AddTreeDelegate>) -> Result<()> {
+        handle_add_tree_delegate(ctx)
+    }
 
-    // Build previous leaf struct, new leaf struct, and replace the leaf in the tree.
-    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
-    let previous_leaf = LeafSchema::new_v0(
-        asset_id,
-        owner.key(),
-        delegate.key(),
-        nonce,
-        data_hash,
-        creator_hash,
-    );
-    let new_leaf = LeafSchema::new_v0(
-        asset_id,
-        owner.key(),
-        delegate.key(),
-        nonce,
-        updated_data_hash,
-        creator_hash,
-    );
-    emit!(new_leaf.to_event());
-    replace_leaf(
-        &merkle_tree.key(),
-        *ctx.bumps.get("tree_authority").unwrap(),
-        &ctx.accounts.compression_program.to_account_info(),
-        &ctx.accounts.tree_authority.to_account_info(),
-        &ctx.accounts.merkle_tree.to_account_info(),
-        &ctx.accounts.log_wrapper.to_account_info(),
-        ctx.remaining_accounts,
-        root,
-        previous_leaf.to_node(),
-        new_leaf.to_node(),
-        index,
-    )
-}
+    pub fn remove_tree_delegate(ctx: Context<RemoveTreeDelegate>) -> Result<()> {
+        handle_remove_tree_delegate(ctx)
+    }
 
-#[program]
-pub mod bubblegum {
-    use super::*;
+    pub fn set_soulbound_override(
+        ctx: Context<SetSoulboundOverride>,
+        nonce: u64,
+        is_non_transferable: bool,
+    ) -> Result<()> {
+        handle_set_soulbound_override(ctx, nonce, is_non_transferable)
+    }
 
-    pub fn create_tree(
-        ctx: Context<CreateTree>,
-        max_depth: u32,
-        max_buffer_size: u32,
+    pub fn set_tree_metadata(
+        ctx: Context<SetTreeMetadata>,
+        name: String,
+        uri: String,
     ) -> Result<()> {
-        let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
-        let seed = merkle_tree.key();
-        let seeds = &[seed.as_ref(), &[*ctx.bumps.get("tree_authority").unwrap()]];
-        let authority = &mut ctx.accounts.tree_authority;
-        authority.set_inner(TreeConfig {
-            tree_creator: ctx.accounts.tree_creator.key(),
-            tree_delegate: ctx.accounts.tree_creator.key(),
-            total_mint_capacity: 1 << max_depth,
-            num_minted: 0,
-        });
-        let authority_pda_signer = &[&seeds[..]];
-        let cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.compression_program.to_account_info(),
-            spl_account_compression::cpi::accounts::Initialize {
-                authority: ctx.accounts.tree_authority.to_account_info(),
-                merkle_tree,
-                log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
-            },
-            authority_pda_signer,
-        );
-        spl_account_compression::cpi::init_empty_merkle_tree(cpi_ctx, max_depth, max_buffer_size)
+        handle_set_tree_metadata(ctx, name, uri)
     }
 
-    pub fn set_tree_delegate(ctx: Context<SetTreeDelegate>) -> Result<()> {
-        ctx.accounts.tree_authority.tree_delegate = ctx.accounts.new_tree_delegate.key();
-        Ok(())
+    pub fn verify_metadata_hash(
+        ctx: Context<VerifyMetadataHash>,
+        metadata: MetadataArgs,
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+    ) -> Result<bool> {
+        handle_verify_metadata_hash(ctx, metadata, data_hash, creator_hash)
     }
 
-    pub fn mint_v1(ctx: Context<MintV1>, message: MetadataArgs) -> Result<()> {
-        // TODO -> Separate V1 / V1 into seperate instructions
-        let payer = ctx.accounts.payer.key();
-        let incoming_tree_delegate = ctx.accounts.tree_delegate.key();
+    pub fn init_on_chain_metadata<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitOnChainMetadata<'info>>,
+        root: [u8; 32],
+        nonce: u64,
+        index: u32,
+        metadata: MetadataArgs,
+        asset_id: Pubkey,
+        json_hash: [u8; 32],
+        json_size: u32,
+    ) -> Result<()> {
+        handle_init_on_chain_metadata(
+            ctx, root, nonce, index, metadata, asset_id, json_hash, json_size,
+        )
+    }
 
-        let owner = ctx.accounts.leaf_owner.key();
-        let delegate = ctx.accounts.leaf_delegate.key();
-        let authority = &mut ctx.accounts.tree_authority;
-        let tree_creator = authority.tree_creator;
-        let tree_delegate = authority.tree_delegate;
-        let merkle_tree = &ctx.accounts.merkle_tree;
+    pub fn write_metadata_chunk(
+        ctx: Context<WriteMetadataChunk>,
+        offset: u32,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        handle_write_metadata_chunk(ctx, offset, chunk)
+    }
 
-        require!(
-            incoming_tree_delegate == tree_creator || incoming_tree_delegate == tree_delegate,
-            BubblegumError::TreeAuthorityIncorrect,
-        );
+    pub fn mint_v1<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintV1<'info>>,
+        message: MetadataArgs,
+    ) -> Result<()> {
+        handle_mint_v1(ctx, message)
+    }
 
-        if !authority.contains_mint_capacity(1) {
-            return Err(BubblegumError::InsufficientMintCapacity.into());
-        }
+    pub fn mint_v1_with_receipt(
+        ctx: Context<MintV1WithReceipt>,
+        message: MetadataArgs,
+    ) -> Result<()> {
+        handle_mint_v1_with_receipt(ctx, message)
+    }
 
-        // Create a HashSet to store signers to use with creator validation.  Any signer can be
-        // counted as a validated creator.
-        let mut metadata_auth = HashSet::<Pubkey>::new();
-        metadata_auth.insert(payer);
-        metadata_auth.insert(tree_delegate);
+    pub fn mint_to_collection_v1(
+        ctx: Context<MintToCollectionV1>,
+        message: MetadataArgs,
+    ) -> Result<()> {
+        handle_mint_to_collection_v1(ctx, message)
+    }
 
-        // If there are any remaining accounts that are also signers, they can also be used for
-        // creator validation.
-        metadata_auth.extend(
-            ctx.remaining_accounts
-                .iter()
-                .filter(|a| a.is_signer)
-                .map(|a| a.key()),
-        );
+    pub fn mint_batch_v1<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintV1<'info>>,
+        messages: Vec<MetadataArgs>,
+    ) -> Result<()> {
+        handle_mint_batch_v1(ctx, messages)
+    }
 
-        process_mint_v1(
-            message,
-            owner,
-            delegate,
-            metadata_auth,
-            *ctx.bumps.get("tree_authority").unwrap(),
-            authority,
-            merkle_tree,
-            &ctx.accounts.log_wrapper,
-            &ctx.accounts.compression_program,
-        )?;
+    pub fn close_asset_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseAssetReceipt<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        index: u32,
+        burned: bool,
+    ) -> Result<()> {
+        handle_close_asset_receipt(ctx, root, data_hash, creator_hash, index, burned)
+    }
 
-        authority.increment_mint_count();
+    pub fn mint_airdrop(
+        ctx: Context<MintAirdrop>,
+        message: MetadataArgs,
+        owners: Vec<Pubkey>,
+    ) -> Result<()> {
+        handle_mint_airdrop(ctx, message, owners)
+    }
 
-        Ok(())
+    pub fn mint_v1_hashed<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintV1<'info>>,
+        message: HashedMetadataArgs,
+    ) -> Result<()> {
+        handle_mint_v1_hashed(ctx, message)
     }
 
     pub fn verify_creator<'info>(
@@ -892,16 +369,7 @@ pub mod bubblegum {
         index: u32,
         message: MetadataArgs,
     ) -> Result<()> {
-        process_creator_verification(
-            ctx,
-            root,
-            data_hash,
-            creator_hash,
-            nonce,
-            index,
-            message,
-            true,
-        )
+        handle_verify_creator(ctx, root, data_hash, creator_hash, nonce, index, message)
     }
 
     pub fn unverify_creator<'info>(
@@ -913,16 +381,44 @@ pub mod bubblegum {
         index: u32,
         message: MetadataArgs,
     ) -> Result<()> {
-        process_creator_verification(
-            ctx,
-            root,
-            data_hash,
-            creator_hash,
-            nonce,
-            index,
-            message,
-            false,
-        )
+        handle_unverify_creator(ctx, root, data_hash, creator_hash, nonce, index, message)
+    }
+
+    pub fn update_metadata<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateMetadata<'info>>,
+        root: [u8; 32],
+        nonce: u64,
+        index: u32,
+        current_metadata: MetadataArgs,
+        new_metadata: MetadataArgs,
+    ) -> Result<()> {
+        handle_update_metadata(ctx, root, nonce, index, current_metadata, new_metadata)
+    }
+
+    pub fn propose_metadata_update(
+        ctx: Context<ProposeMetadataUpdate>,
+        nonce: u64,
+        new_metadata: MetadataArgs,
+    ) -> Result<()> {
+        handle_propose_metadata_update(ctx, nonce, new_metadata)
+    }
+
+    pub fn approve_metadata_update<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveMetadataUpdate<'info>>,
+        root: [u8; 32],
+        nonce: u64,
+        index: u32,
+        current_metadata: MetadataArgs,
+        new_metadata: MetadataArgs,
+    ) -> Result<()> {
+        handle_approve_metadata_update(ctx, root, nonce, index, current_metadata, new_metadata)
+    }
+
+    pub fn cancel_metadata_proposal(
+        ctx: Context<CancelMetadataProposal>,
+        nonce: u64,
+    ) -> Result<()> {
+        handle_cancel_metadata_proposal(ctx, nonce)
     }
 
     pub fn verify_collection<'info>(
@@ -934,17 +430,7 @@ pub mod bubblegum {
         index: u32,
         message: MetadataArgs,
     ) -> Result<()> {
-        process_collection_verification(
-            ctx,
-            root,
-            data_hash,
-            creator_hash,
-            nonce,
-            index,
-            message,
-            true,
-            None,
-        )
+        handle_verify_collection(ctx, root, data_hash, creator_hash, nonce, index, message)
     }
 
     pub fn unverify_collection<'info>(
@@ -956,17 +442,7 @@ pub mod bubblegum {
         index: u32,
         message: MetadataArgs,
     ) -> Result<()> {
-        process_collection_verification(
-            ctx,
-            root,
-            data_hash,
-            creator_hash,
-            nonce,
-            index,
-            message,
-            false,
-            None,
-        )
+        handle_unverify_collection(ctx, root, data_hash, creator_hash, nonce, index, message)
     }
 
     pub fn set_and_verify_collection<'info>(
@@ -979,33 +455,7 @@ pub mod bubblegum {
         message: MetadataArgs,
         collection: [u8; 32],
     ) -> Result<()> {
-        let incoming_tree_delegate = &ctx.accounts.tree_delegate;
-        let tree_creator = ctx.accounts.tree_authority.tree_creator;
-        let tree_delegate = ctx.accounts.tree_authority.tree_delegate;
-        let collection_metadata = &ctx.accounts.collection_metadata;
-
-        // Require that either the tree authority signed this transaction, or the tree authority is
-        // the collection update authority which means the leaf update is approved via proxy, when
-        // we later call `assert_has_collection_authority()`.
-        //
-        // This is similar to logic in token-metadata for `set_and_verify_collection()` except
-        // this logic also allows the tree authority (which we are treating as the leaf metadata
-        // authority) to be different than the collection authority (actual or delegated).  The
-        // token-metadata program required them to be the same.
-        let tree_authority_signed = incoming_tree_delegate.is_signer
-            && (incoming_tree_delegate.key() == tree_creator
-                || incoming_tree_delegate.key() == tree_delegate);
-
-        let tree_authority_is_collection_update_authority = collection_metadata.update_authority
-            == tree_creator
-            || collection_metadata.update_authority == tree_delegate;
-
-        require!(
-            tree_authority_signed || tree_authority_is_collection_update_authority,
-            BubblegumError::UpdateAuthorityIncorrect
-        );
-
-        process_collection_verification(
+        handle_set_and_verify_collection(
             ctx,
             root,
             data_hash,
@@ -1013,8 +463,7 @@ pub mod bubblegum {
             nonce,
             index,
             message,
-            true,
-            Some(collection),
+            collection,
         )
     }
 
@@ -1026,49 +475,14 @@ pub mod bubblegum {
         nonce: u64,
         index: u32,
     ) -> Result<()> {
-        // TODO add back version to select hash schema
-        let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
-        let owner = ctx.accounts.leaf_owner.to_account_info();
-        let delegate = ctx.accounts.leaf_delegate.to_account_info();
+        handle_transfer(ctx, root, data_hash, creator_hash, nonce, index)
+    }
 
-        // Transfers must be initiated by either the leaf owner or leaf delegate.
-        require!(
-            owner.is_signer || delegate.is_signer,
-            BubblegumError::LeafAuthorityMustSign
-        );
-        let new_owner = ctx.accounts.new_leaf_owner.key();
-        let asset_id = get_asset_id(&merkle_tree.key(), nonce);
-        let previous_leaf = LeafSchema::new_v0(
-            asset_id,
-            owner.key(),
-            delegate.key(),
-            nonce,
-            data_hash,
-            creator_hash,
-        );
-        // New leafs are instantiated with no delegate
-        let new_leaf = LeafSchema::new_v0(
-            asset_id,
-            new_owner,
-            new_owner,
-            nonce,
-            data_hash,
-            creator_hash,
-        );
-        emit!(new_leaf.to_event());
-        replace_leaf(
-            &merkle_tree.key(),
-            *ctx.bumps.get("tree_authority").unwrap(),
-            &ctx.accounts.compression_program.to_account_info(),
-            &ctx.accounts.tree_authority.to_account_info(),
-            &ctx.accounts.merkle_tree.to_account_info(),
-            &ctx.accounts.log_wrapper.to_account_info(),
-            ctx.remaining_accounts,
-            root,
-            previous_leaf.to_node(),
-            new_leaf.to_node(),
-            index,
-        )
+    pub fn transfer_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferBatch<'info>>,
+        transfers: Vec<BatchTransfer>,
+    ) -> Result<()> {
+        handle_transfer_batch(ctx, transfers)
     }
 
     pub fn delegate<'info>(
@@ -1079,44 +493,44 @@ pub mod bubblegum {
         nonce: u64,
         index: u32,
     ) -> Result<()> {
-        let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
-        let owner = ctx.accounts.leaf_owner.key();
-        let previous_delegate = ctx.accounts.previous_leaf_delegate.key();
-        let new_delegate = ctx.accounts.new_leaf_delegate.key();
-        let asset_id = get_asset_id(&merkle_tree.key(), nonce);
-        let previous_leaf = LeafSchema::new_v0(
-            asset_id,
-            owner,
-            previous_delegate,
-            nonce,
+        handle_delegate(ctx, root, data_hash, creator_hash, nonce, index)
+    }
+
+    pub fn delegate_with_expiry<'info>(
+        ctx: Context<'_, '_, '_, 'info, DelegateWithExpiry<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        nonce: u64,
+        index: u32,
+        expiry_slot: Option<u64>,
+    ) -> Result<()> {
+        handle_delegate_with_expiry(
+            ctx,
+            root,
             data_hash,
             creator_hash,
-        );
-        let new_leaf = LeafSchema::new_v0(
-            asset_id,
-            owner,
-            new_delegate,
             nonce,
-            data_hash,
-            creator_hash,
-        );
-        wrap_event(new_leaf.try_to_vec()?, &ctx.accounts.log_wrapper)?;
-        emit!(new_leaf.to_event());
-        replace_leaf(
-            &merkle_tree.key(),
-            *ctx.bumps.get("tree_authority").unwrap(),
-            &ctx.accounts.compression_program.to_account_info(),
-            &ctx.accounts.tree_authority.to_account_info(),
-            &ctx.accounts.merkle_tree.to_account_info(),
-            &ctx.accounts.log_wrapper.to_account_info(),
-            ctx.remaining_accounts,
-            root,
-            previous_leaf.to_node(),
-            new_leaf.to_node(),
             index,
+            expiry_slot,
         )
     }
 
+    pub fn freeze<'info>(
+        ctx: Context<'_, '_, '_, 'info, Freeze<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        nonce: u64,
+        index: u32,
+    ) -> Result<()> {
+        handle_freeze(ctx, root, data_hash, creator_hash, nonce, index)
+    }
+
+    pub fn thaw(ctx: Context<Thaw>) -> Result<()> {
+        handle_thaw(ctx)
+    }
+
     pub fn burn<'info>(
         ctx: Context<'_, '_, '_, 'info, Burn<'info>>,
         root: [u8; 32],
@@ -1125,41 +539,26 @@ pub mod bubblegum {
         nonce: u64,
         index: u32,
     ) -> Result<()> {
-        let owner = ctx.accounts.leaf_owner.to_account_info();
-        let delegate = ctx.accounts.leaf_delegate.to_account_info();
+        handle_burn(ctx, root, data_hash, creator_hash, nonce, index)
+    }
 
-        // Burn must be initiated by either the leaf owner or leaf delegate.
-        require!(
-            owner.is_signer || delegate.is_signer,
-            BubblegumError::LeafAuthorityMustSign
-        );
-        let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
-        let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+    pub fn burn_with_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, BurnWithReceipt<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        nonce: u64,
+        index: u32,
+    ) -> Result<()> {
+        handle_burn_with_receipt(ctx, root, data_hash, creator_hash, nonce, index)
+    }
 
-        let previous_leaf = LeafSchema::new_v0(
-            asset_id,
-            owner.key(),
-            delegate.key(),
-            nonce,
-            data_hash,
-            creator_hash,
-        );
-        emit!(previous_leaf.to_event());
-        let new_leaf = Node::default();
-        wrap_event(new_leaf.try_to_vec()?, &ctx.accounts.log_wrapper)?;
-        replace_leaf(
-            &merkle_tree.key(),
-            *ctx.bumps.get("tree_authority").unwrap(),
-            &ctx.accounts.compression_program.to_account_info(),
-            &ctx.accounts.tree_authority.to_account_info(),
-            &ctx.accounts.merkle_tree.to_account_info(),
-            &ctx.accounts.log_wrapper.to_account_info(),
-            ctx.remaining_accounts,
-            root,
-            previous_leaf.to_node(),
-            new_leaf,
-            index,
-        )
+    pub fn close_burn_receipt(ctx: Context<CloseBurnReceipt>) -> Result<()> {
+        handle_close_burn_receipt(ctx)
+    }
+
+    pub fn close_delegate_expiry(ctx: Context<CloseDelegateExpiry>) -> Result<()> {
+        handle_close_delegate_expiry(ctx)
     }
 
     pub fn redeem<'info>(
@@ -1170,259 +569,55 @@ pub mod bubblegum {
         nonce: u64,
         index: u32,
     ) -> Result<()> {
-        let owner = ctx.accounts.leaf_owner.key();
-        let delegate = ctx.accounts.leaf_delegate.key();
-        let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
-        let asset_id = get_asset_id(&merkle_tree.key(), nonce);
-        let previous_leaf =
-            LeafSchema::new_v0(asset_id, owner, delegate, nonce, data_hash, creator_hash);
-        emit!(previous_leaf.to_event());
-        let new_leaf = Node::default();
-        wrap_event(new_leaf.try_to_vec()?, &ctx.accounts.log_wrapper)?;
-        replace_leaf(
-            &merkle_tree.key(),
-            *ctx.bumps.get("tree_authority").unwrap(),
-            &ctx.accounts.compression_program.to_account_info(),
-            &ctx.accounts.tree_authority.to_account_info(),
-            &ctx.accounts.merkle_tree.to_account_info(),
-            &ctx.accounts.log_wrapper.to_account_info(),
-            ctx.remaining_accounts,
-            root,
-            previous_leaf.to_node(),
-            new_leaf,
-            index,
-        )?;
-        ctx.accounts
-            .voucher
-            .set_inner(Voucher::new(previous_leaf, index, merkle_tree.key()));
-
-        Ok(())
+        handle_redeem(ctx, root, data_hash, creator_hash, nonce, index)
     }
 
     pub fn cancel_redeem<'info>(
         ctx: Context<'_, '_, '_, 'info, CancelRedeem<'info>>,
         root: [u8; 32],
     ) -> Result<()> {
-        let voucher = &ctx.accounts.voucher;
-        match ctx.accounts.voucher.leaf_schema {
-            LeafSchema::V1 { owner, .. } => assert_pubkey_equal(
-                &ctx.accounts.leaf_owner.key(),
-                &owner,
-                Some(BubblegumError::AssetOwnerMismatch.into()),
-            ),
-        }?;
-        let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
-        emit!(voucher.leaf_schema.to_event());
-        wrap_event(voucher.leaf_schema.try_to_vec()?, &ctx.accounts.log_wrapper)?;
+        handle_cancel_redeem(ctx, root)
+    }
 
-        replace_leaf(
-            &merkle_tree.key(),
-            *ctx.bumps.get("tree_authority").unwrap(),
-            &ctx.accounts.compression_program.to_account_info(),
-            &ctx.accounts.tree_authority.to_account_info(),
-            &ctx.accounts.merkle_tree.to_account_info(),
-            &ctx.accounts.log_wrapper.to_account_info(),
-            ctx.remaining_accounts,
+    pub fn transfer_timelocked<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferTimelocked<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        nonce: u64,
+        index: u32,
+        unlock_slot: u64,
+    ) -> Result<()> {
+        handle_transfer_timelocked(
+            ctx,
             root,
-            [0; 32],
-            voucher.leaf_schema.to_node(),
-            voucher.index,
+            data_hash,
+            creator_hash,
+            nonce,
+            index,
+            unlock_slot,
         )
     }
 
-    pub fn decompress_v1(ctx: Context<DecompressV1>, metadata: MetadataArgs) -> Result<()> {
-        // Allocate and create mint
-        let incoming_data_hash = hash_metadata(&metadata)?;
-        let event = match ctx.accounts.voucher.leaf_schema {
-            LeafSchema::V1 {
-                owner,
-                data_hash,
-                nonce,
-                ..
-            } => {
-                if !cmp_bytes(&data_hash, &incoming_data_hash, 32) {
-                    return Err(BubblegumError::HashingMismatch.into());
-                }
-                if !cmp_pubkeys(&owner, ctx.accounts.leaf_owner.key) {
-                    return Err(BubblegumError::AssetOwnerMismatch.into());
-                }
-                NFTDecompressionEvent {
-                    version: Version::V1,
-                    tree_id: ctx.accounts.voucher.merkle_tree.key(),
-                    id: get_asset_id(&ctx.accounts.voucher.merkle_tree.key(), nonce),
-                    nonce,
-                }
-            }
-        };
-        let voucher = &ctx.accounts.voucher;
-        match metadata.token_program_version {
-            TokenProgramVersion::Original => {
-                if ctx.accounts.mint.data_is_empty() {
-                    invoke_signed(
-                        &system_instruction::create_account(
-                            &ctx.accounts.leaf_owner.key(),
-                            &ctx.accounts.mint.key(),
-                            Rent::get()?.minimum_balance(SplMint::LEN),
-                            SplMint::LEN as u64,
-                            &spl_token::id(),
-                        ),
-                        &[
-                            ctx.accounts.leaf_owner.to_account_info(),
-                            ctx.accounts.mint.to_account_info(),
-                            ctx.accounts.system_program.to_account_info(),
-                        ],
-                        &[&[
-                            ASSET_PREFIX.as_bytes(),
-                            voucher.merkle_tree.key().as_ref(),
-                            voucher.leaf_schema.nonce().to_le_bytes().as_ref(),
-                            &[*ctx.bumps.get("mint").unwrap()],
-                        ]],
-                    )?;
-                    invoke(
-                        &spl_token::instruction::initialize_mint2(
-                            &spl_token::id(),
-                            &ctx.accounts.mint.key(),
-                            &ctx.accounts.mint_authority.key(),
-                            None,
-                            0,
-                        )?,
-                        &[
-                            ctx.accounts.token_program.to_account_info(),
-                            ctx.accounts.mint.to_account_info(),
-                        ],
-                    )?;
-                }
-                if ctx.accounts.token_account.data_is_empty() {
-                    invoke(
-                        &spl_associated_token_account::instruction::create_associated_token_account(
-                            &ctx.accounts.leaf_owner.key(),
-                            &ctx.accounts.leaf_owner.key(),
-                            &ctx.accounts.mint.key(),
-                            &spl_token::id(),
-                        ),
-                        &[
-                            ctx.accounts.leaf_owner.to_account_info(),
-                            ctx.accounts.mint.to_account_info(),
-                            ctx.accounts.token_account.to_account_info(),
-                            ctx.accounts.token_program.to_account_info(),
-                            ctx.accounts.associated_token_program.to_account_info(),
-                            ctx.accounts.system_program.to_account_info(),
-                            ctx.accounts.sysvar_rent.to_account_info(),
-                        ],
-                    )?;
-                }
-                invoke_signed(
-                    &spl_token::instruction::mint_to(
-                        &spl_token::id(),
-                        &ctx.accounts.mint.key(),
-                        &ctx.accounts.token_account.key(),
-                        &ctx.accounts.mint_authority.key(),
-                        &[],
-                        1,
-                    )?,
-                    &[
-                        ctx.accounts.mint.to_account_info(),
-                        ctx.accounts.token_account.to_account_info(),
-                        ctx.accounts.mint_authority.to_account_info(),
-                        ctx.accounts.token_program.to_account_info(),
-                    ],
-                    &[&[
-                        ctx.accounts.mint.key().as_ref(),
-                        &[ctx.bumps["mint_authority"]],
-                    ]],
-                )?;
-            }
-            TokenProgramVersion::Token2022 => return Err(ProgramError::InvalidArgument.into()),
-        }
-
-        let metadata_infos = vec![
-            ctx.accounts.metadata.to_account_info(),
-            ctx.accounts.mint.to_account_info(),
-            ctx.accounts.mint_authority.to_account_info(),
-            ctx.accounts.leaf_owner.to_account_info(),
-            ctx.accounts.token_metadata_program.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.sysvar_rent.to_account_info(),
-        ];
-
-        let master_edition_infos = vec![
-            ctx.accounts.master_edition.to_account_info(),
-            ctx.accounts.mint.to_account_info(),
-            ctx.accounts.mint_authority.to_account_info(),
-            ctx.accounts.leaf_owner.to_account_info(),
-            ctx.accounts.metadata.to_account_info(),
-            ctx.accounts.token_metadata_program.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.sysvar_rent.to_account_info(),
-        ];
+    pub fn claim_timelocked_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimTimelockedTransfer<'info>>,
+        root: [u8; 32],
+    ) -> Result<()> {
+        handle_claim_timelocked_transfer(ctx, root)
+    }
 
-        msg!("Creating metadata!");
-        invoke_signed(
-            &mpl_token_metadata::instruction::create_metadata_accounts_v2(
-                ctx.accounts.token_metadata_program.key(),
-                ctx.accounts.metadata.key(),
-                ctx.accounts.mint.key(),
-                ctx.accounts.mint_authority.key(),
-                ctx.accounts.leaf_owner.key(),
-                ctx.accounts.mint_authority.key(),
-                metadata.name.clone(),
-                metadata.symbol.clone(),
-                metadata.uri.clone(),
-                if !metadata.creators.is_empty() {
-                    let mut amended_metadata_creators = metadata.creators;
-                    amended_metadata_creators.push(Creator {
-                        address: ctx.accounts.mint_authority.key(),
-                        verified: true,
-                        share: 0,
-                    });
-                    Some(
-                        amended_metadata_creators
-                            .iter()
-                            .map(|c| c.adapt())
-                            .collect(),
-                    )
-                } else {
-                    None
-                },
-                metadata.seller_fee_basis_points,
-                true,
-                metadata.is_mutable,
-                metadata.collection.map(|c| c.adapt()),
-                metadata.uses.map(|u| u.adapt()),
-            ),
-            metadata_infos.as_slice(),
-            &[&[
-                ctx.accounts.mint.key().as_ref(),
-                &[ctx.bumps["mint_authority"]],
-            ]],
-        )?;
+    pub fn cancel_timelocked_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelTimelockedTransfer<'info>>,
+        root: [u8; 32],
+    ) -> Result<()> {
+        handle_cancel_timelocked_transfer(ctx, root)
+    }
 
-        msg!("Creating master edition!");
-        invoke_signed(
-            &mpl_token_metadata::instruction::create_master_edition_v3(
-                ctx.accounts.token_metadata_program.key(),
-                ctx.accounts.master_edition.key(),
-                ctx.accounts.mint.key(),
-                ctx.accounts.mint_authority.key(),
-                ctx.accounts.mint_authority.key(),
-                ctx.accounts.metadata.key(),
-                ctx.accounts.leaf_owner.key(),
-                Some(0),
-            ),
-            master_edition_infos.as_slice(),
-            &[&[
-                ctx.accounts.mint.key().as_ref(),
-                &[ctx.bumps["mint_authority"]],
-            ]],
-        )?;
-        emit!(event);
-        Ok(())
+    pub fn decompress_v1(ctx: Context<DecompressV1>, metadata: MetadataArgs) -> Result<()> {
+        handle_decompress_v1(ctx, metadata)
     }
 
-    pub fn compress(_ctx: Context<Compress>) -> Result<()> {
-        // TODO
-        Ok(())
+    pub fn compress(ctx: Context<Compress>) -> Result<()> {
+        handle_compress(ctx)
     }
 }
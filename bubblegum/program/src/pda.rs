@@ -0,0 +1,107 @@
+//! Addresses of the PDAs of the program, for clients and integrating programs that have to pass
+//! them as accounts. Deriving an address is expensive on-chain, so programs should prefer
+//! passing them in and having Bubblegum check them.
+
+use crate::{
+    state::{
+        ASSET_RECEIPT_PREFIX, BURN_RECEIPT_PREFIX, COLLECTION_CPI_PREFIX, CREATOR_TRANSFER_PREFIX,
+        DELEGATE_EXPIRY_PREFIX, DENY_LIST_PREFIX, FEATURE_FLAGS_PREFIX, LEAF_FREEZE_PREFIX,
+        METADATA_PROPOSAL_PREFIX, MINT_PRICE_PREFIX, ON_CHAIN_METADATA_PREFIX,
+        PENDING_TRANSFER_PREFIX, SOULBOUND_OVERRIDE_PREFIX, TREE_DELEGATE_RECORD_PREFIX,
+        TREE_DENY_LIST_PREFIX, TREE_METADATA_PREFIX, VOUCHER_PREFIX,
+    },
+    utils::get_asset_id,
+};
+use anchor_lang::prelude::*;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &crate::id()).0
+}
+
+// The PDAs of a leaf are derived from the tree and the nonce of the leaf.
+fn find_for_leaf(prefix: &str, merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    find(&[prefix.as_ref(), merkle_tree.as_ref(), &nonce.to_le_bytes()])
+}
+
+/// The `TreeConfig` of a tree, which is also the authority of the tree account.
+pub fn find_tree_config(merkle_tree: &Pubkey) -> Pubkey {
+    find(&[merkle_tree.as_ref()])
+}
+
+pub fn find_tree_metadata(merkle_tree: &Pubkey) -> Pubkey {
+    find(&[TREE_METADATA_PREFIX.as_ref(), merkle_tree.as_ref()])
+}
+
+pub fn find_creator_transfer(merkle_tree: &Pubkey) -> Pubkey {
+    find(&[CREATOR_TRANSFER_PREFIX.as_ref(), merkle_tree.as_ref()])
+}
+
+pub fn find_tree_deny_list(merkle_tree: &Pubkey) -> Pubkey {
+    find(&[TREE_DENY_LIST_PREFIX.as_ref(), merkle_tree.as_ref()])
+}
+
+pub fn find_tree_delegate_record(merkle_tree: &Pubkey, delegate: &Pubkey) -> Pubkey {
+    find(&[
+        TREE_DELEGATE_RECORD_PREFIX.as_ref(),
+        merkle_tree.as_ref(),
+        delegate.as_ref(),
+    ])
+}
+
+pub fn find_deny_list(authority: &Pubkey) -> Pubkey {
+    find(&[DENY_LIST_PREFIX.as_ref(), authority.as_ref()])
+}
+
+pub fn find_mint_price(merkle_tree: &Pubkey) -> Pubkey {
+    find(&[MINT_PRICE_PREFIX.as_ref(), merkle_tree.as_ref()])
+}
+
+pub fn find_feature_flags() -> Pubkey {
+    find(&[FEATURE_FLAGS_PREFIX.as_ref()])
+}
+
+/// The signer Bubblegum uses when calling Token Metadata for collection operations.
+pub fn find_collection_cpi_signer() -> Pubkey {
+    find(&[COLLECTION_CPI_PREFIX.as_ref()])
+}
+
+pub fn find_voucher(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    find_for_leaf(VOUCHER_PREFIX, merkle_tree, nonce)
+}
+
+pub fn find_pending_transfer(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    find_for_leaf(PENDING_TRANSFER_PREFIX, merkle_tree, nonce)
+}
+
+pub fn find_soulbound_override(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    find_for_leaf(SOULBOUND_OVERRIDE_PREFIX, merkle_tree, nonce)
+}
+
+pub fn find_metadata_proposal(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    find_for_leaf(METADATA_PROPOSAL_PREFIX, merkle_tree, nonce)
+}
+
+pub fn find_delegate_expiry(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    find_for_leaf(DELEGATE_EXPIRY_PREFIX, merkle_tree, nonce)
+}
+
+pub fn find_leaf_freeze(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    find_for_leaf(LEAF_FREEZE_PREFIX, merkle_tree, nonce)
+}
+
+// The PDAs below are derived from the asset id instead.
+
+pub fn find_asset_receipt(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    let asset_id = get_asset_id(merkle_tree, nonce);
+    find(&[ASSET_RECEIPT_PREFIX.as_ref(), asset_id.as_ref()])
+}
+
+pub fn find_burn_receipt(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    let asset_id = get_asset_id(merkle_tree, nonce);
+    find(&[BURN_RECEIPT_PREFIX.as_ref(), asset_id.as_ref()])
+}
+
+pub fn find_on_chain_metadata(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    let asset_id = get_asset_id(merkle_tree, nonce);
+    find(&[ON_CHAIN_METADATA_PREFIX.as_ref(), asset_id.as_ref()])
+}
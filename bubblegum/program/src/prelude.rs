@@ -0,0 +1,30 @@
+//! The items most integrations need, under a single import path:
+//!
+//! ```ignore
+//! use mpl_bubblegum::prelude::*;
+//! ```
+//!
+//! The `accounts` and `instruction` modules generated by Anchor are included as well, to build
+//! instructions from clients. Programs have modules with the same names of their own, which
+//! take precedence over the glob import, so they should refer to these via `mpl_bubblegum::`.
+
+#[cfg(feature = "cpi")]
+pub use crate::cpi;
+pub use crate::{
+    accounts,
+    error::BubblegumError,
+    hash::{hash_creators, hash_leaf, hash_metadata, hash_metadata_args},
+    instruction,
+    pda::*,
+    program::Bubblegum,
+    state::{
+        leaf_schema::{LeafSchema, LeafSchemaEvent, Version},
+        metaplex_adapter::{
+            Collection, Creator, MetadataArgs, TokenProgramVersion, TokenStandard, UseMethod, Uses,
+        },
+        BatchTransfer, CompressionFailureEvent, DenyList, HashedMetadataArgs, LoggingMode,
+        MetadataAttestationEvent, MetadataProposal, MintPrice, NFTDecompressionEvent, NewNFTEvent,
+        TreeConfig, TreeCreatorTransferEvent, TreeDelegateRecord, TreeDenyList, TreeMetadataEvent,
+    },
+    utils::{get_asset_id, invoke_as_signer},
+};
@@ -0,0 +1,55 @@
+use crate::{
+    state::{
+        leaf_schema::Version, CreatorTransfer, CreatorTransferStage, TreeConfig,
+        TreeCreatorTransferEvent, CREATOR_TRANSFER_PREFIX,
+    },
+    utils::assert_tree_governance_mutable,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptTreeCreator<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    // The rent goes back to the previous creator, who paid for the account.
+    #[account(
+        mut,
+        seeds = [CREATOR_TRANSFER_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        bump,
+        has_one = new_tree_creator,
+        close = tree_creator,
+    )]
+    pub creator_transfer: Account<'info, CreatorTransfer>,
+    /// CHECK: this account is only written to when receiving the rent of `creator_transfer`
+    #[account(mut)]
+    pub tree_creator: UncheckedAccount<'info>,
+    pub new_tree_creator: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+}
+
+pub fn handle_accept_tree_creator(ctx: Context<AcceptTreeCreator>) -> Result<()> {
+    assert_tree_governance_mutable(&ctx.accounts.tree_authority)?;
+
+    let new_tree_creator = ctx.accounts.new_tree_creator.key();
+    ctx.accounts.tree_authority.tree_creator = new_tree_creator;
+    if ctx.accounts.creator_transfer.reset_tree_delegate {
+        ctx.accounts.tree_authority.tree_delegate = new_tree_creator;
+    }
+    ctx.accounts.tree_authority.record_program_version();
+
+    emit!(TreeCreatorTransferEvent {
+        version: Version::V1,
+        tree_id: ctx.accounts.merkle_tree.key(),
+        tree_creator: ctx.accounts.tree_creator.key(),
+        new_tree_creator,
+        stage: CreatorTransferStage::Accepted,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::{
+    state::{AccountSize, TreeConfig, TreeDelegateRecord, TREE_DELEGATE_RECORD_PREFIX},
+    utils::{assert_not_zero_address, assert_tree_governance_mutable},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddTreeDelegate<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub tree_creator: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub delegate: UncheckedAccount<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [
+            TREE_DELEGATE_RECORD_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            delegate.key().as_ref(),
+        ],
+        payer = tree_creator,
+        space = TreeDelegateRecord::SIZE,
+        bump,
+    )]
+    pub tree_delegate_record: Account<'info, TreeDelegateRecord>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_add_tree_delegate(ctx: Context<AddTreeDelegate>) -> Result<()> {
+    assert_tree_governance_mutable(&ctx.accounts.tree_authority)?;
+    assert_not_zero_address(ctx.accounts.delegate.key)?;
+
+    ctx.accounts
+        .tree_delegate_record
+        .set_inner(TreeDelegateRecord {
+            merkle_tree: ctx.accounts.merkle_tree.key(),
+            delegate: ctx.accounts.delegate.key(),
+        });
+    ctx.accounts.tree_authority.record_program_version();
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use crate::{
+    error::BubblegumError,
+    hash::{hash_creators, hash_metadata},
+    processor::update_metadata::{assert_metadata_update_is_valid, log_metadata_update},
+    state::{
+        leaf_schema::LeafSchema, metaplex_adapter::MetadataArgs, MetadataProposal, TreeConfig,
+        METADATA_PROPOSAL_PREFIX,
+    },
+    utils::{assert_index_matches_nonce, assert_tree_not_paused, get_asset_id, replace_leaf},
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+#[derive(Accounts)]
+#[instruction(root: [u8; 32], nonce: u64)]
+pub struct ApproveMetadataUpdate<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    pub leaf_owner: Signer<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_delegate: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    // The rent goes back to the proposer, who paid for the account.
+    #[account(
+        mut,
+        seeds = [
+            METADATA_PROPOSAL_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            &nonce.to_le_bytes()
+        ],
+        bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub metadata_proposal: Account<'info, MetadataProposal>,
+    /// CHECK: this account is only written to when receiving the rent of `metadata_proposal`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+}
+
+pub fn handle_approve_metadata_update<'info>(
+    ctx: Context<'_, '_, '_, 'info, ApproveMetadataUpdate<'info>>,
+    root: [u8; 32],
+    nonce: u64,
+    index: u32,
+    current_metadata: MetadataArgs,
+    new_metadata: MetadataArgs,
+) -> Result<()> {
+    assert_tree_not_paused(&ctx.accounts.tree_authority)?;
+    assert_index_matches_nonce(index, nonce)?;
+
+    // The proposal is void if the proposer stopped being an update authority of the tree.
+    let proposal = &ctx.accounts.metadata_proposal;
+    require!(
+        ctx.accounts.tree_authority.is_authority(&proposal.proposer),
+        BubblegumError::UpdateAuthorityIncorrect
+    );
+
+    let data_hash = hash_metadata(&new_metadata)?;
+    let creator_hash = hash_creators(&new_metadata.creators)?;
+    require!(
+        data_hash == proposal.data_hash && creator_hash == proposal.creator_hash,
+        BubblegumError::MetadataProposalMismatch
+    );
+
+    assert_metadata_update_is_valid(&current_metadata, &new_metadata, &proposal.proposer)?;
+
+    let owner = ctx.accounts.leaf_owner.key();
+    let delegate = ctx.accounts.leaf_delegate.key();
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+
+    // The leaf is only replaced if it matches the current metadata, which also proves that
+    // the signer owns it.
+    let asset_id = get_asset_id(&merkle_tree, nonce);
+    let previous_leaf = LeafSchema::new_v0(
+        asset_id,
+        owner,
+        delegate,
+        nonce,
+        hash_metadata(&current_metadata)?,
+        hash_creators(&current_metadata.creators)?,
+    );
+    let new_leaf = LeafSchema::new_v0(asset_id, owner, delegate, nonce, data_hash, creator_hash);
+    log_metadata_update(
+        &ctx.accounts.tree_authority,
+        &merkle_tree,
+        nonce,
+        new_metadata,
+        &ctx.accounts.log_wrapper,
+    )?;
+    emit!(new_leaf.to_event());
+    replace_leaf(
+        &merkle_tree,
+        *ctx.bumps.get("tree_authority").unwrap(),
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf.to_node(),
+        index,
+    )
+}
@@ -0,0 +1,159 @@
+use crate::{
+    error::BubblegumError,
+    state::{
+        leaf_schema::LeafSchema, TreeConfig, DELEGATE_EXPIRY_PREFIX, LEAF_FREEZE_PREFIX,
+        VOUCHER_PREFIX,
+    },
+    utils::{
+        assert_delegate_not_expired, assert_index_matches_nonce, assert_not_being_redeemed,
+        assert_not_frozen, assert_tree_not_paused, get_asset_id, log_event, replace_leaf,
+    },
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression, Node};
+
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    nonce: u64,
+    _index: u32,
+)]
+pub struct Burn<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_delegate: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [
+        VOUCHER_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub voucher: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+        DELEGATE_EXPIRY_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub delegate_expiry: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+        LEAF_FREEZE_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub leaf_freeze: UncheckedAccount<'info>,
+}
+
+pub fn handle_burn<'info>(
+    ctx: Context<'_, '_, '_, 'info, Burn<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Result<()> {
+    burn_leaf(
+        &ctx.accounts.tree_authority,
+        *ctx.bumps.get("tree_authority").unwrap(),
+        &ctx.accounts.leaf_owner,
+        &ctx.accounts.leaf_delegate,
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.voucher,
+        &ctx.accounts.delegate_expiry,
+        &ctx.accounts.leaf_freeze,
+        ctx.remaining_accounts,
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+    )
+}
+
+// Replaces the leaf with an empty node, which is shared with `burn_with_receipt`.
+pub(crate) fn burn_leaf<'info>(
+    tree_authority: &Account<'info, TreeConfig>,
+    authority_bump: u8,
+    owner: &AccountInfo<'info>,
+    delegate: &AccountInfo<'info>,
+    merkle_tree: &AccountInfo<'info>,
+    log_wrapper: &Program<'info, Wrapper>,
+    compression_program: &AccountInfo<'info>,
+    voucher: &AccountInfo<'info>,
+    delegate_expiry: &AccountInfo<'info>,
+    leaf_freeze: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Result<()> {
+    assert_tree_not_paused(tree_authority)?;
+    assert_index_matches_nonce(index, nonce)?;
+
+    // Burn must be initiated by either the leaf owner or leaf delegate.
+    require!(
+        owner.is_signer || delegate.is_signer,
+        BubblegumError::LeafAuthorityMustSign
+    );
+    assert_delegate_not_expired(owner, delegate, delegate_expiry)?;
+    assert_not_frozen(leaf_freeze)?;
+
+    assert_not_being_redeemed(voucher)?;
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+
+    let previous_leaf = LeafSchema::new_v0(
+        asset_id,
+        owner.key(),
+        delegate.key(),
+        nonce,
+        data_hash,
+        creator_hash,
+    );
+    emit!(previous_leaf.to_event());
+    let new_leaf = Node::default();
+    log_event(
+        new_leaf.try_to_vec()?,
+        log_wrapper,
+        tree_authority.logging_mode,
+    )?;
+    replace_leaf(
+        &merkle_tree.key(),
+        authority_bump,
+        compression_program,
+        &tree_authority.to_account_info(),
+        merkle_tree,
+        &log_wrapper.to_account_info(),
+        remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf,
+        index,
+    )
+}
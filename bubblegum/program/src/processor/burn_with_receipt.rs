@@ -0,0 +1,117 @@
+use super::burn::burn_leaf;
+use crate::{
+    state::{
+        AccountSize, BurnReceipt, TreeConfig, BURN_RECEIPT_PREFIX, DELEGATE_EXPIRY_PREFIX,
+        LEAF_FREEZE_PREFIX, VOUCHER_PREFIX,
+    },
+    utils::get_asset_id,
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+// Same as `Burn`, with the payer also paying for the receipt.
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    nonce: u64,
+    _index: u32,
+)]
+pub struct BurnWithReceipt<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_delegate: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [
+        VOUCHER_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub voucher: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+        DELEGATE_EXPIRY_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub delegate_expiry: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        seeds = [
+        BURN_RECEIPT_PREFIX.as_ref(),
+        get_asset_id(&merkle_tree.key(), nonce).as_ref()
+    ],
+    payer = payer,
+    space = BurnReceipt::SIZE,
+    bump
+    )]
+    pub burn_receipt: Account<'info, BurnReceipt>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [
+        LEAF_FREEZE_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub leaf_freeze: UncheckedAccount<'info>,
+}
+
+// Burns the asset like `burn`, and records who owned it at the time in a `BurnReceipt`.
+pub fn handle_burn_with_receipt<'info>(
+    ctx: Context<'_, '_, '_, 'info, BurnWithReceipt<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Result<()> {
+    burn_leaf(
+        &ctx.accounts.tree_authority,
+        *ctx.bumps.get("tree_authority").unwrap(),
+        &ctx.accounts.leaf_owner,
+        &ctx.accounts.leaf_delegate,
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.voucher,
+        &ctx.accounts.delegate_expiry,
+        &ctx.accounts.leaf_freeze,
+        ctx.remaining_accounts,
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+    )?;
+
+    ctx.accounts.burn_receipt.set_inner(BurnReceipt {
+        asset_id: get_asset_id(&ctx.accounts.merkle_tree.key(), nonce),
+        owner: ctx.accounts.leaf_owner.key(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
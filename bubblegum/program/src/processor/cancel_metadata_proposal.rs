@@ -0,0 +1,31 @@
+use crate::state::{MetadataProposal, METADATA_PROPOSAL_PREFIX};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct CancelMetadataProposal<'info> {
+    #[account(
+        mut,
+        seeds = [
+            METADATA_PROPOSAL_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            &nonce.to_le_bytes()
+        ],
+        bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub metadata_proposal: Account<'info, MetadataProposal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+}
+
+// Owners decline a proposal by not approving it, so only the proposer can close it.
+pub fn handle_cancel_metadata_proposal(
+    _ctx: Context<CancelMetadataProposal>,
+    _nonce: u64,
+) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::state::{
+    leaf_schema::Version, CreatorTransfer, CreatorTransferStage, TreeConfig,
+    TreeCreatorTransferEvent, CREATOR_TRANSFER_PREFIX,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelTreeCreatorTransfer<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(
+        mut,
+        seeds = [CREATOR_TRANSFER_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        bump,
+        close = tree_creator,
+    )]
+    pub creator_transfer: Account<'info, CreatorTransfer>,
+    #[account(mut)]
+    pub tree_creator: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+}
+
+pub fn handle_cancel_tree_creator_transfer(ctx: Context<CancelTreeCreatorTransfer>) -> Result<()> {
+    emit!(TreeCreatorTransferEvent {
+        version: Version::V1,
+        tree_id: ctx.accounts.merkle_tree.key(),
+        tree_creator: ctx.accounts.tree_creator.key(),
+        new_tree_creator: ctx.accounts.creator_transfer.new_tree_creator,
+        stage: CreatorTransferStage::Cancelled,
+    });
+
+    Ok(())
+}
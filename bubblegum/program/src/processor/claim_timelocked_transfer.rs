@@ -0,0 +1,114 @@
+use crate::{
+    error::BubblegumError,
+    state::{
+        leaf_schema::LeafSchema, PendingTransfer, TreeConfig, FEATURE_FLAGS_PREFIX,
+        PENDING_TRANSFER_PREFIX, TREE_DENY_LIST_PREFIX,
+    },
+    utils::{
+        assert_instruction_enabled, assert_not_flagged, assert_pubkey_equal,
+        assert_tree_not_paused, log_event, replace_leaf,
+    },
+    InstructionName,
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+#[derive(Accounts)]
+pub struct ClaimTimelockedTransfer<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    pub new_leaf_owner: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Receives the rent of the pending transfer; checked in the instruction
+    pub leaf_owner: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = leaf_owner,
+        seeds = [
+        PENDING_TRANSFER_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & pending_transfer.leaf_schema.nonce().to_le_bytes()
+    ],
+    bump
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [TREE_DENY_LIST_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub tree_deny_list: UncheckedAccount<'info>,
+    /// CHECK: This account is checked against `tree_deny_list`, and only read if the tree has one
+    pub deny_list: UncheckedAccount<'info>,
+    #[account(seeds = [FEATURE_FLAGS_PREFIX.as_ref()], bump)]
+    /// CHECK: This account is checked in the instruction
+    pub feature_flags: UncheckedAccount<'info>,
+}
+
+pub fn handle_claim_timelocked_transfer<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimTimelockedTransfer<'info>>,
+    root: [u8; 32],
+) -> Result<()> {
+    assert_instruction_enabled(
+        &ctx.accounts.feature_flags,
+        InstructionName::ClaimTimelockedTransfer,
+    )?;
+    assert_tree_not_paused(&ctx.accounts.tree_authority)?;
+    let pending_transfer = &ctx.accounts.pending_transfer;
+    let recipient = ctx.accounts.new_leaf_owner.key();
+    assert_pubkey_equal(&recipient, &pending_transfer.recipient, None)?;
+    match pending_transfer.leaf_schema {
+        LeafSchema::V1 { owner, .. } => assert_pubkey_equal(
+            &ctx.accounts.leaf_owner.key(),
+            &owner,
+            Some(BubblegumError::AssetOwnerMismatch.into()),
+        ),
+    }?;
+    if Clock::get()?.slot < pending_transfer.unlock_slot {
+        return Err(BubblegumError::TransferStillLocked.into());
+    }
+    // The asset might have been flagged while the transfer was pending.
+    assert_not_flagged(
+        &ctx.accounts.tree_deny_list,
+        &ctx.accounts.deny_list,
+        &pending_transfer.leaf_schema.id(),
+    )?;
+
+    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
+    let new_leaf = match pending_transfer.leaf_schema {
+        LeafSchema::V1 {
+            id,
+            nonce,
+            data_hash,
+            creator_hash,
+            ..
+        } => LeafSchema::new_v0(id, recipient, recipient, nonce, data_hash, creator_hash),
+    };
+    emit!(new_leaf.to_event());
+    log_event(
+        new_leaf.try_to_vec()?,
+        &ctx.accounts.log_wrapper,
+        ctx.accounts.tree_authority.logging_mode,
+    )?;
+    replace_leaf(
+        &merkle_tree.key(),
+        *ctx.bumps.get("tree_authority").unwrap(),
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        [0; 32],
+        new_leaf.to_node(),
+        pending_transfer.index,
+    )
+}
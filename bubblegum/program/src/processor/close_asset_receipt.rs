@@ -0,0 +1,93 @@
+use crate::{
+    error::BubblegumError,
+    state::{
+        leaf_schema::LeafSchema, AssetReceipt, BurnReceipt, ASSET_RECEIPT_PREFIX,
+        BURN_RECEIPT_PREFIX,
+    },
+    utils::{assert_index_matches_nonce, assert_owned_by, get_asset_id, verify_leaf},
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{program::SplAccountCompression, Node};
+
+#[derive(Accounts)]
+pub struct CloseAssetReceipt<'info> {
+    #[account(mut)]
+    pub leaf_owner: Signer<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            ASSET_RECEIPT_PREFIX.as_ref(),
+            get_asset_id(&merkle_tree.key(), asset_receipt.nonce).as_ref(),
+        ],
+        bump,
+        has_one = merkle_tree,
+        close = leaf_owner,
+    )]
+    pub asset_receipt: Account<'info, AssetReceipt>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [
+            BURN_RECEIPT_PREFIX.as_ref(),
+            get_asset_id(&merkle_tree.key(), asset_receipt.nonce).as_ref(),
+        ],
+        bump,
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub burn_receipt: UncheckedAccount<'info>,
+}
+
+// Closes the receipt of an asset, with the rent going to the current owner of the asset (who
+// is not necessarily the owner recorded in the receipt). Once the asset is `burned` its leaf is
+// empty, and the receipt goes to the owner who burned it instead (the hashes are ignored then).
+// An empty leaf alone is not enough, since assets that are redeemed, decompressed or in a
+// timelocked transfer have one too, so the asset must have been burned via `burn_with_receipt`,
+// and its `BurnReceipt` must not be closed before the asset receipt.
+pub fn handle_close_asset_receipt<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseAssetReceipt<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    index: u32,
+    burned: bool,
+) -> Result<()> {
+    let receipt = &ctx.accounts.asset_receipt;
+    assert_index_matches_nonce(index, receipt.nonce)?;
+
+    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
+    let node = if burned {
+        let burn_receipt = &ctx.accounts.burn_receipt;
+        if burn_receipt.data_is_empty() {
+            return Err(BubblegumError::BurnReceiptMissing.into());
+        }
+        assert_owned_by(burn_receipt, &crate::id())?;
+        let burn_receipt =
+            BurnReceipt::try_deserialize(&mut burn_receipt.try_borrow_data()?.as_ref())?;
+        if ctx.accounts.leaf_owner.key() != burn_receipt.owner {
+            return Err(BubblegumError::AssetOwnerMismatch.into());
+        }
+        Node::default()
+    } else {
+        LeafSchema::new_v0(
+            get_asset_id(&merkle_tree.key(), receipt.nonce),
+            ctx.accounts.leaf_owner.key(),
+            ctx.accounts.leaf_delegate.key(),
+            receipt.nonce,
+            data_hash,
+            creator_hash,
+        )
+        .to_node()
+    };
+
+    verify_leaf(
+        &ctx.accounts.compression_program.to_account_info(),
+        &merkle_tree,
+        ctx.remaining_accounts,
+        root,
+        node,
+        index,
+    )
+}
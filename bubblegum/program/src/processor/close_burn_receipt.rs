@@ -0,0 +1,20 @@
+use crate::state::{BurnReceipt, BURN_RECEIPT_PREFIX};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseBurnReceipt<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [BURN_RECEIPT_PREFIX.as_ref(), burn_receipt.asset_id.as_ref()],
+        bump,
+        has_one = owner,
+        close = owner,
+    )]
+    pub burn_receipt: Account<'info, BurnReceipt>,
+}
+
+pub fn handle_close_burn_receipt(_ctx: Context<CloseBurnReceipt>) -> Result<()> {
+    Ok(())
+}
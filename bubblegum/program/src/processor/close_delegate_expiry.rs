@@ -0,0 +1,26 @@
+use crate::state::{DelegateExpiry, DELEGATE_EXPIRY_PREFIX};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseDelegateExpiry<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            DELEGATE_EXPIRY_PREFIX.as_ref(),
+            delegate_expiry.merkle_tree.as_ref(),
+            &delegate_expiry.nonce.to_le_bytes(),
+        ],
+        bump,
+        has_one = owner,
+        close = owner,
+    )]
+    pub delegate_expiry: Account<'info, DelegateExpiry>,
+}
+
+// Returns the rent of an expiry record to the owner that created it. An owner that still holds
+// the leaf lifts the expiry of its delegate this way.
+pub fn handle_close_delegate_expiry(_ctx: Context<CloseDelegateExpiry>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,347 @@
+use crate::{
+    error::BubblegumError,
+    hash::hash_metadata,
+    state::{
+        leaf_schema::LeafSchema,
+        metaplex_adapter::{self, MetadataArgs},
+        metaplex_anchor::{MplTokenMetadata, TokenMetadata},
+        TreeConfig, COLLECTION_CPI_PREFIX,
+    },
+    utils::{assert_index_matches_nonce, get_asset_id, replace_leaf},
+};
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use mpl_token_metadata::{
+    assertions::collection::{assert_collection_verify_is_valid, assert_has_collection_authority},
+    state::CollectionDetails,
+};
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+#[derive(Accounts)]
+pub struct CollectionVerification<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_delegate: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub payer: Signer<'info>,
+    /// CHECK: This account is checked to be a signer in
+    /// the case of `set_and_verify_collection` where
+    /// we are actually changing the NFT metadata.
+    pub tree_delegate: UncheckedAccount<'info>,
+    pub collection_authority: Signer<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub collection_mint: UncheckedAccount<'info>,
+    pub collection_metadata: Box<Account<'info, TokenMetadata>>,
+    /// CHECK: This account is checked in the instruction
+    pub edition_account: UncheckedAccount<'info>,
+    /// CHECK: This is just used as a signing PDA.
+    #[account(
+        seeds = [COLLECTION_CPI_PREFIX.as_ref()],
+        bump,
+    )]
+    pub bubblegum_signer: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    pub token_metadata_program: Program<'info, MplTokenMetadata>,
+}
+
+/// Checks that `collection_authority` can (un)verify `collection`, the same way Token Metadata
+/// does, and updates the size of the collection if it is a sized one. The
+/// `collection_authority_record` is required when the authority is a delegate.
+pub(crate) fn assert_collection_authority_and_update_size<'info>(
+    collection: &metaplex_adapter::Collection,
+    verify: bool,
+    collection_metadata: &Account<'info, TokenMetadata>,
+    collection_mint: &AccountInfo<'info>,
+    edition_account: &AccountInfo<'info>,
+    collection_authority: &AccountInfo<'info>,
+    collection_authority_record: Option<&AccountInfo<'info>>,
+    bubblegum_signer: &AccountInfo<'info>,
+    bubblegum_signer_bump: u8,
+    token_metadata_program: &AccountInfo<'info>,
+) -> Result<()> {
+    // Verify correct account ownerships.
+    require!(
+        *collection_metadata.to_account_info().owner == token_metadata_program.key(),
+        BubblegumError::IncorrectOwner
+    );
+    require!(
+        *collection_mint.owner == spl_token::id(),
+        BubblegumError::IncorrectOwner
+    );
+    require!(
+        *edition_account.owner == token_metadata_program.key(),
+        BubblegumError::IncorrectOwner
+    );
+
+    // Collection verify assert from token-metadata program.
+    assert_collection_verify_is_valid(
+        &Some(collection.adapt()),
+        collection_metadata,
+        collection_mint,
+        edition_account,
+    )?;
+
+    // Collection authority assert from token-metadata.
+    assert_has_collection_authority(
+        collection_authority,
+        collection_metadata,
+        collection_mint.key,
+        collection_authority_record,
+    )?;
+
+    // If this is a sized collection, then increment or decrement collection size.
+    if let Some(details) = &collection_metadata.collection_details {
+        // Increment or decrement existing size.
+        let new_size = match details {
+            CollectionDetails::V1 { size } => {
+                if verify {
+                    size.checked_add(1)
+                        .ok_or(BubblegumError::NumericalOverflowError)?
+                } else {
+                    size.checked_sub(1)
+                        .ok_or(BubblegumError::NumericalOverflowError)?
+                }
+            }
+        };
+
+        // CPI into to token-metadata program to change the collection size.
+        let mut bubblegum_set_collection_size_infos = vec![
+            collection_metadata.to_account_info(),
+            collection_authority.clone(),
+            collection_mint.clone(),
+            bubblegum_signer.clone(),
+        ];
+
+        if let Some(record) = collection_authority_record {
+            bubblegum_set_collection_size_infos.push(record.clone());
+        }
+
+        invoke_signed(
+            &mpl_token_metadata::instruction::bubblegum_set_collection_size(
+                token_metadata_program.key(),
+                collection_metadata.to_account_info().key(),
+                collection_authority.key(),
+                collection_mint.key(),
+                bubblegum_signer.key(),
+                collection_authority_record.map(|r| r.key()),
+                new_size,
+            ),
+            bubblegum_set_collection_size_infos.as_slice(),
+            &[&[COLLECTION_CPI_PREFIX.as_bytes(), &[bubblegum_signer_bump]]],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn process_collection_verification<'info>(
+    ctx: Context<'_, '_, '_, 'info, CollectionVerification<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    mut message: MetadataArgs,
+    verify: bool,
+    new_collection: Option<[u8; 32]>,
+) -> Result<()> {
+    assert_index_matches_nonce(index, nonce)?;
+
+    let owner = ctx.accounts.leaf_owner.to_account_info();
+    let delegate = ctx.accounts.leaf_delegate.to_account_info();
+    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
+    let collection_metadata = &ctx.accounts.collection_metadata;
+    let collection_mint = ctx.accounts.collection_mint.to_account_info();
+    let edition_account = ctx.accounts.edition_account.to_account_info();
+    let collection_authority = ctx.accounts.collection_authority.to_account_info();
+    let bubblegum_signer = ctx.accounts.bubblegum_signer.to_account_info();
+    let token_metadata_program = ctx.accounts.token_metadata_program.to_account_info();
+
+    // Look for collection authority record PDA as a remaining account.
+    let collection_authority_record = if !ctx.remaining_accounts.is_empty() {
+        Some(&ctx.remaining_accounts[0])
+    } else {
+        None
+    };
+
+    // User-provided metadata must result in same user-provided data hash.
+    let incoming_data_hash = hash_metadata(&message)?;
+    if data_hash != incoming_data_hash {
+        return Err(BubblegumError::DataHashMismatch.into());
+    }
+
+    // If new collection was provided, set it in the NFT metadata.
+    if new_collection.is_some() {
+        message.collection = new_collection.map(|c| metaplex_adapter::Collection {
+            verified: false, // Set to true below.
+            key: Pubkey::new(&c),
+        });
+    }
+
+    // If the NFT has collection data, we set it to the correct value after doing some validation.
+    if let Some(collection) = &mut message.collection {
+        // Don't verify already verified items, or unverify unverified items, otherwise for sized
+        // collections we end up with invalid size data.
+        if verify && collection.verified {
+            return Err(BubblegumError::AlreadyVerified.into());
+        } else if !verify && !collection.verified {
+            return Err(BubblegumError::AlreadyUnverified.into());
+        }
+
+        assert_collection_authority_and_update_size(
+            collection,
+            verify,
+            collection_metadata,
+            &collection_mint,
+            &edition_account,
+            &collection_authority,
+            collection_authority_record,
+            &bubblegum_signer,
+            ctx.bumps["bubblegum_signer"],
+            &token_metadata_program,
+        )?;
+
+        // Update collection in metadata args.  Note since this is a mutable reference,
+        // it is still updating `message.collection` after being destructured.
+        collection.verified = verify;
+    } else {
+        return Err(BubblegumError::CollectionNotFound.into());
+    }
+
+    // Calculate new data hash.
+    let updated_data_hash = hash_metadata(&message)?;
+
+    // Build previous leaf struct, new leaf struct, and replace the leaf in the tree.
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+    let previous_leaf = LeafSchema::new_v0(
+        asset_id,
+        owner.key(),
+        delegate.key(),
+        nonce,
+        data_hash,
+        creator_hash,
+    );
+    let new_leaf = LeafSchema::new_v0(
+        asset_id,
+        owner.key(),
+        delegate.key(),
+        nonce,
+        updated_data_hash,
+        creator_hash,
+    );
+    emit!(new_leaf.to_event());
+    replace_leaf(
+        &merkle_tree.key(),
+        *ctx.bumps.get("tree_authority").unwrap(),
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf.to_node(),
+        index,
+    )
+}
+
+pub fn handle_verify_collection<'info>(
+    ctx: Context<'_, '_, '_, 'info, CollectionVerification<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    message: MetadataArgs,
+) -> Result<()> {
+    process_collection_verification(
+        ctx,
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+        message,
+        true,
+        None,
+    )
+}
+
+pub fn handle_unverify_collection<'info>(
+    ctx: Context<'_, '_, '_, 'info, CollectionVerification<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    message: MetadataArgs,
+) -> Result<()> {
+    process_collection_verification(
+        ctx,
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+        message,
+        false,
+        None,
+    )
+}
+
+pub fn handle_set_and_verify_collection<'info>(
+    ctx: Context<'_, '_, '_, 'info, CollectionVerification<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    message: MetadataArgs,
+    collection: [u8; 32],
+) -> Result<()> {
+    let incoming_tree_delegate = &ctx.accounts.tree_delegate;
+    let tree_creator = ctx.accounts.tree_authority.tree_creator;
+    let tree_delegate = ctx.accounts.tree_authority.tree_delegate;
+    let collection_metadata = &ctx.accounts.collection_metadata;
+
+    // Require that either the tree authority signed this transaction, or the tree authority is
+    // the collection update authority which means the leaf update is approved via proxy, when
+    // we later call `assert_has_collection_authority()`.
+    //
+    // This is similar to logic in token-metadata for `set_and_verify_collection()` except
+    // this logic also allows the tree authority (which we are treating as the leaf metadata
+    // authority) to be different than the collection authority (actual or delegated).  The
+    // token-metadata program required them to be the same.
+    let tree_authority_signed = incoming_tree_delegate.is_signer
+        && (incoming_tree_delegate.key() == tree_creator
+            || incoming_tree_delegate.key() == tree_delegate);
+
+    let tree_authority_is_collection_update_authority = collection_metadata.update_authority
+        == tree_creator
+        || collection_metadata.update_authority == tree_delegate;
+
+    require!(
+        tree_authority_signed || tree_authority_is_collection_update_authority,
+        BubblegumError::UpdateAuthorityIncorrect
+    );
+
+    process_collection_verification(
+        ctx,
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+        message,
+        true,
+        Some(collection),
+    )
+}
@@ -0,0 +1,25 @@
+use crate::state::{AccountSize, DenyList, DENY_LIST_PREFIX};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateDenyList<'info> {
+    #[account(
+        init,
+        seeds = [DENY_LIST_PREFIX.as_ref(), authority.key().as_ref()],
+        payer = authority,
+        space = DenyList::SIZE,
+        bump,
+    )]
+    pub deny_list: Account<'info, DenyList>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_deny_list(ctx: Context<CreateDenyList>) -> Result<()> {
+    ctx.accounts.deny_list.set_inner(DenyList {
+        authority: ctx.accounts.authority.key(),
+        asset_ids: Vec::new(),
+    });
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use crate::{
+    error::BubblegumError,
+    state::{AccountSize, LoggingMode, TreeConfig, PROGRAM_VERSION},
+    utils::assert_valid_tree_account_size,
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+#[derive(Accounts)]
+pub struct CreateTree<'info> {
+    #[account(
+        init,
+        seeds = [merkle_tree.key().as_ref()],
+        payer = payer,
+        space = TreeConfig::SIZE,
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(zero)]
+    /// CHECK: This account must be all zeros
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub tree_creator: Signer<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_tree(
+    ctx: Context<CreateTree>,
+    max_depth: u32,
+    max_buffer_size: u32,
+    logging_mode: LoggingMode,
+    creator_immutable: bool,
+    is_public: bool,
+) -> Result<()> {
+    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
+    assert_valid_tree_account_size(
+        merkle_tree.data_len(),
+        max_depth as usize,
+        max_buffer_size as usize,
+    )?;
+    let seed = merkle_tree.key();
+    let seeds = &[seed.as_ref(), &[*ctx.bumps.get("tree_authority").unwrap()]];
+    let authority = &mut ctx.accounts.tree_authority;
+    authority.set_inner(TreeConfig {
+        tree_creator: ctx.accounts.tree_creator.key(),
+        tree_delegate: ctx.accounts.tree_creator.key(),
+        total_mint_capacity: 1u64
+            .checked_shl(max_depth)
+            .ok_or(BubblegumError::NumericalOverflowError)?,
+        num_minted: 0,
+        is_hash_only: false,
+        logging_mode,
+        is_non_transferable: false,
+        is_creator_immutable: creator_immutable,
+        program_version: PROGRAM_VERSION,
+        is_public,
+        companion_accounts: 0,
+        is_paused: false,
+    });
+    let authority_pda_signer = &[&seeds[..]];
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.compression_program.to_account_info(),
+        spl_account_compression::cpi::accounts::Initialize {
+            authority: ctx.accounts.tree_authority.to_account_info(),
+            merkle_tree,
+            log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+        },
+        authority_pda_signer,
+    );
+    spl_account_compression::cpi::init_empty_merkle_tree(cpi_ctx, max_depth, max_buffer_size)
+}
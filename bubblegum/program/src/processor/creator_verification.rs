@@ -0,0 +1,175 @@
+use crate::{
+    error::BubblegumError,
+    hash::{hash_creators, hash_metadata},
+    state::{
+        leaf_schema::LeafSchema,
+        metaplex_adapter::{Creator, MetadataArgs},
+        TreeConfig,
+    },
+    utils::{assert_index_matches_nonce, get_asset_id, replace_leaf},
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+#[derive(Accounts)]
+pub struct CreatorVerification<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is chekced in the instruction
+    pub leaf_delegate: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub payer: Signer<'info>,
+    pub creator: Signer<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+}
+
+fn process_creator_verification<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreatorVerification<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    mut message: MetadataArgs,
+    verify: bool,
+) -> Result<()> {
+    assert_index_matches_nonce(index, nonce)?;
+
+    let owner = ctx.accounts.leaf_owner.to_account_info();
+    let delegate = ctx.accounts.leaf_delegate.to_account_info();
+    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
+
+    let creator = ctx.accounts.creator.key();
+
+    // Creator Vec must contain creators.
+    if message.creators.is_empty() {
+        return Err(BubblegumError::NoCreatorsPresent.into());
+    }
+
+    // Creator must be in user-provided creator Vec.
+    if !message.creators.iter().any(|c| c.address == creator) {
+        return Err(BubblegumError::CreatorNotFound.into());
+    }
+
+    // User-provided creator Vec must result in same user-provided creator hash.
+    let incoming_creator_hash = hash_creators(&message.creators)?;
+    if creator_hash != incoming_creator_hash {
+        return Err(BubblegumError::CreatorHashMismatch.into());
+    }
+
+    // User-provided metadata must result in same user-provided data hash.
+    let incoming_data_hash = hash_metadata(&message)?;
+    if data_hash != incoming_data_hash {
+        return Err(BubblegumError::DataHashMismatch.into());
+    }
+
+    // Calculate new creator Vec with `verified` set to true for signing creator.
+    let updated_creator_vec = message
+        .creators
+        .iter()
+        .map(|c| {
+            let verified = if c.address == creator.key() {
+                verify
+            } else {
+                c.verified
+            };
+            Creator {
+                address: c.address,
+                verified,
+                share: c.share,
+            }
+        })
+        .collect::<Vec<Creator>>();
+
+    // Calculate new creator hash.
+    let updated_creator_hash = hash_creators(&updated_creator_vec)?;
+
+    // Update creator Vec in metadata args.
+    message.creators = updated_creator_vec;
+
+    // Calculate new data hash.
+    let updated_data_hash = hash_metadata(&message)?;
+
+    // Build previous leaf struct, new leaf struct, and replace the leaf in the tree.
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+    let previous_leaf = LeafSchema::new_v0(
+        asset_id,
+        owner.key(),
+        delegate.key(),
+        nonce,
+        data_hash,
+        creator_hash,
+    );
+    let new_leaf = LeafSchema::new_v0(
+        asset_id,
+        owner.key(),
+        delegate.key(),
+        nonce,
+        updated_data_hash,
+        updated_creator_hash,
+    );
+    emit!(new_leaf.to_event());
+    replace_leaf(
+        &merkle_tree.key(),
+        *ctx.bumps.get("tree_authority").unwrap(),
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf.to_node(),
+        index,
+    )
+}
+
+pub fn handle_verify_creator<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreatorVerification<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    message: MetadataArgs,
+) -> Result<()> {
+    process_creator_verification(
+        ctx,
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+        message,
+        true,
+    )
+}
+
+pub fn handle_unverify_creator<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreatorVerification<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    message: MetadataArgs,
+) -> Result<()> {
+    process_creator_verification(
+        ctx,
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+        message,
+        false,
+    )
+}
@@ -0,0 +1,149 @@
+use crate::{
+    error::BubblegumError,
+    state::{leaf_schema::LeafSchema, TreeConfig, LEAF_FREEZE_PREFIX, VOUCHER_PREFIX},
+    utils::{
+        assert_index_matches_nonce, assert_not_being_redeemed, assert_not_frozen,
+        assert_not_zero_address, assert_tree_not_paused, get_asset_id, log_event, replace_leaf,
+    },
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    nonce: u64,
+    _index: u32,
+)]
+pub struct Delegate<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    pub leaf_owner: Signer<'info>,
+    /// CHECK: This account is neither written to nor read from.
+    pub previous_leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: This account is neither written to nor read from.
+    pub new_leaf_delegate: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [
+        VOUCHER_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub voucher: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+        LEAF_FREEZE_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub leaf_freeze: UncheckedAccount<'info>,
+}
+
+pub fn handle_delegate<'info>(
+    ctx: Context<'_, '_, '_, 'info, Delegate<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Result<()> {
+    assert_tree_not_paused(&ctx.accounts.tree_authority)?;
+    assert_index_matches_nonce(index, nonce)?;
+    assert_not_being_redeemed(&ctx.accounts.voucher)?;
+    assert_not_frozen(&ctx.accounts.leaf_freeze)?;
+    // Unlike `delegate_with_expiry`, which can update the expiry of the current delegate,
+    // there is nothing to change when the delegate stays the same.
+    require!(
+        ctx.accounts.previous_leaf_delegate.key() != ctx.accounts.new_leaf_delegate.key(),
+        BubblegumError::RedundantOperation
+    );
+
+    replace_delegate(
+        &ctx.accounts.tree_authority,
+        *ctx.bumps.get("tree_authority").unwrap(),
+        &ctx.accounts.leaf_owner.key(),
+        &ctx.accounts.previous_leaf_delegate.key(),
+        &ctx.accounts.new_leaf_delegate.key(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+    )
+}
+
+// Replaces the delegate of the leaf, which is shared with `delegate_with_expiry`.
+pub(crate) fn replace_delegate<'info>(
+    tree_authority: &Account<'info, TreeConfig>,
+    authority_bump: u8,
+    owner: &Pubkey,
+    previous_delegate: &Pubkey,
+    new_delegate: &Pubkey,
+    merkle_tree: &AccountInfo<'info>,
+    log_wrapper: &Program<'info, Wrapper>,
+    compression_program: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Result<()> {
+    assert_not_zero_address(new_delegate)?;
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+    let previous_leaf = LeafSchema::new_v0(
+        asset_id,
+        *owner,
+        *previous_delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+    );
+    let new_leaf = LeafSchema::new_v0(
+        asset_id,
+        *owner,
+        *new_delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+    );
+    log_event(
+        new_leaf.try_to_vec()?,
+        log_wrapper,
+        tree_authority.logging_mode,
+    )?;
+    emit!(new_leaf.to_event());
+    replace_leaf(
+        &merkle_tree.key(),
+        authority_bump,
+        compression_program,
+        &tree_authority.to_account_info(),
+        merkle_tree,
+        &log_wrapper.to_account_info(),
+        remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf.to_node(),
+        index,
+    )
+}
@@ -0,0 +1,146 @@
+use super::delegate::replace_delegate;
+use crate::{
+    error::BubblegumError,
+    state::{
+        AccountSize, DelegateExpiry, TreeConfig, DELEGATE_EXPIRY_PREFIX, LEAF_FREEZE_PREFIX,
+        VOUCHER_PREFIX,
+    },
+    utils::{
+        assert_index_matches_nonce, assert_not_being_redeemed, assert_not_frozen,
+        assert_tree_not_paused,
+    },
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+// Same as `Delegate`, with the owner also paying for the expiry record.
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    nonce: u64,
+    _index: u32,
+)]
+pub struct DelegateWithExpiry<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub leaf_owner: Signer<'info>,
+    /// CHECK: This account is neither written to nor read from.
+    pub previous_leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: This account is neither written to nor read from.
+    pub new_leaf_delegate: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [
+        VOUCHER_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub voucher: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        seeds = [
+        DELEGATE_EXPIRY_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    payer = leaf_owner,
+    space = DelegateExpiry::SIZE,
+    bump
+    )]
+    pub delegate_expiry: Account<'info, DelegateExpiry>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [
+        LEAF_FREEZE_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub leaf_freeze: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is checked in the instruction
+    pub previous_expiry_owner: UncheckedAccount<'info>,
+}
+
+// Sets a new delegate for the leaf that can no longer transfer or burn it on its own starting
+// with `expiry_slot`. Passing `None` lifts a previous expiry for the same delegate, which a
+// plain `delegate` does not do. A record left behind by a previous owner of the leaf is taken
+// over, with the owner paying back its rent to `previous_expiry_owner` (which is ignored, and
+// can be the owner itself, otherwise).
+pub fn handle_delegate_with_expiry<'info>(
+    ctx: Context<'_, '_, '_, 'info, DelegateWithExpiry<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    expiry_slot: Option<u64>,
+) -> Result<()> {
+    assert_tree_not_paused(&ctx.accounts.tree_authority)?;
+    assert_index_matches_nonce(index, nonce)?;
+    assert_not_being_redeemed(&ctx.accounts.voucher)?;
+    assert_not_frozen(&ctx.accounts.leaf_freeze)?;
+
+    let leaf_owner = ctx.accounts.leaf_owner.key();
+    let previous_owner = ctx.accounts.delegate_expiry.owner;
+    // Records that were just created have no owner yet.
+    if previous_owner != Pubkey::default() && previous_owner != leaf_owner {
+        if ctx.accounts.previous_expiry_owner.key() != previous_owner {
+            return Err(BubblegumError::PublicKeyMismatch.into());
+        }
+        let rent = ctx.accounts.delegate_expiry.to_account_info().lamports();
+        invoke(
+            &system_instruction::transfer(&leaf_owner, &previous_owner, rent),
+            &[
+                ctx.accounts.leaf_owner.to_account_info(),
+                ctx.accounts.previous_expiry_owner.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let new_delegate = ctx.accounts.new_leaf_delegate.key();
+    ctx.accounts.delegate_expiry.set_inner(DelegateExpiry {
+        merkle_tree,
+        nonce,
+        owner: leaf_owner,
+        delegate: new_delegate,
+        expiry_slot,
+    });
+
+    replace_delegate(
+        &ctx.accounts.tree_authority,
+        *ctx.bumps.get("tree_authority").unwrap(),
+        &ctx.accounts.leaf_owner.key(),
+        &ctx.accounts.previous_leaf_delegate.key(),
+        &new_delegate,
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+    )
+}
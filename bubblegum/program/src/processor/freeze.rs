@@ -0,0 +1,116 @@
+use crate::{
+    state::{
+        leaf_schema::LeafSchema, AccountSize, LeafFreeze, TreeConfig, DELEGATE_EXPIRY_PREFIX,
+        LEAF_FREEZE_PREFIX, VOUCHER_PREFIX,
+    },
+    utils::{
+        assert_delegate_not_expired, assert_index_matches_nonce, assert_not_being_redeemed,
+        assert_tree_not_paused, get_asset_id, verify_leaf,
+    },
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::program::SplAccountCompression;
+
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    nonce: u64,
+    _index: u32,
+)]
+pub struct Freeze<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_owner: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub leaf_delegate: Signer<'info>,
+    /// CHECK: This account is only read by the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [
+        VOUCHER_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub voucher: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+        DELEGATE_EXPIRY_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub delegate_expiry: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [
+        LEAF_FREEZE_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    payer = leaf_delegate,
+    space = LeafFreeze::SIZE,
+    bump
+    )]
+    pub leaf_freeze: Account<'info, LeafFreeze>,
+    pub system_program: Program<'info, System>,
+}
+
+// Lets the delegate of a leaf lock it in the wallet of its owner, e.g. while it's staked or
+// rented out. The leaf itself does not change, so the proof only shows that the signer is its
+// delegate. Freezing a leaf that is already frozen fails, since the record already exists.
+pub fn handle_freeze<'info>(
+    ctx: Context<'_, '_, '_, 'info, Freeze<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Result<()> {
+    assert_tree_not_paused(&ctx.accounts.tree_authority)?;
+    assert_index_matches_nonce(index, nonce)?;
+    assert_not_being_redeemed(&ctx.accounts.voucher)?;
+    assert_delegate_not_expired(
+        &ctx.accounts.leaf_owner,
+        &ctx.accounts.leaf_delegate,
+        &ctx.accounts.delegate_expiry,
+    )?;
+
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let delegate = ctx.accounts.leaf_delegate.key();
+    let leaf = LeafSchema::new_v0(
+        get_asset_id(&merkle_tree, nonce),
+        ctx.accounts.leaf_owner.key(),
+        delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+    );
+    verify_leaf(
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        leaf.to_node(),
+        index,
+    )?;
+
+    ctx.accounts.leaf_freeze.set_inner(LeafFreeze {
+        merkle_tree,
+        nonce,
+        delegate,
+        owner: ctx.accounts.leaf_owner.key(),
+    });
+    Ok(())
+}
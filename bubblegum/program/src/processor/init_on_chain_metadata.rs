@@ -0,0 +1,105 @@
+use crate::{
+    error::BubblegumError,
+    hash::{hash_creators, hash_metadata},
+    state::{
+        leaf_schema::LeafSchema, metaplex_adapter::MetadataArgs, OnChainMetadata,
+        MAX_ON_CHAIN_METADATA_SIZE, ON_CHAIN_METADATA_HEADER_SIZE, ON_CHAIN_METADATA_PREFIX,
+    },
+    utils::{assert_index_matches_nonce, get_asset_id, verify_leaf},
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::program::SplAccountCompression;
+
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _nonce: u64,
+    _index: u32,
+    _metadata: MetadataArgs,
+    asset_id: Pubkey,
+    _json_hash: [u8; 32],
+    json_size: u32,
+)]
+pub struct InitOnChainMetadata<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [ON_CHAIN_METADATA_PREFIX.as_ref(), asset_id.as_ref()],
+        payer = creator,
+        space = ON_CHAIN_METADATA_HEADER_SIZE + json_size as usize,
+        bump
+    )]
+    pub on_chain_metadata: Account<'info, OnChainMetadata>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    pub system_program: Program<'info, System>,
+}
+
+// Allocates the account that holds the JSON metadata of an asset, which one of its creators
+// then fills in via `write_metadata_chunk`. The asset must be in the tree, and its uri must
+// commit to `json_hash` (see `OnChainMetadata::uri`).
+pub fn handle_init_on_chain_metadata<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitOnChainMetadata<'info>>,
+    root: [u8; 32],
+    nonce: u64,
+    index: u32,
+    metadata: MetadataArgs,
+    asset_id: Pubkey,
+    json_hash: [u8; 32],
+    json_size: u32,
+) -> Result<()> {
+    assert_index_matches_nonce(index, nonce)?;
+
+    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
+    require!(
+        asset_id == get_asset_id(&merkle_tree.key(), nonce),
+        BubblegumError::PublicKeyMismatch
+    );
+    require!(
+        json_size as usize <= MAX_ON_CHAIN_METADATA_SIZE,
+        BubblegumError::OnChainMetadataTooLarge
+    );
+    require!(
+        metadata.uri == OnChainMetadata::uri(&json_hash),
+        BubblegumError::OnChainMetadataUriMismatch
+    );
+
+    let creator = ctx.accounts.creator.key();
+    if !metadata.creators.iter().any(|c| c.address == creator) {
+        return Err(BubblegumError::CreatorNotFound.into());
+    }
+
+    let leaf = LeafSchema::new_v0(
+        asset_id,
+        ctx.accounts.leaf_owner.key(),
+        ctx.accounts.leaf_delegate.key(),
+        nonce,
+        hash_metadata(&metadata)?,
+        hash_creators(&metadata.creators)?,
+    );
+    verify_leaf(
+        &ctx.accounts.compression_program.to_account_info(),
+        &merkle_tree,
+        ctx.remaining_accounts,
+        root,
+        leaf.to_node(),
+        index,
+    )?;
+
+    ctx.accounts.on_chain_metadata.set_inner(OnChainMetadata {
+        asset_id,
+        authority: creator,
+        json_hash,
+        json_size,
+        bytes_written: 0,
+        is_complete: false,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,338 @@
+use crate::{
+    error::BubblegumError,
+    hash::{hash_creators, hash_data, hash_metadata},
+    state::{
+        leaf_schema::{LeafSchema, Version},
+        metaplex_adapter::MetadataArgs,
+        HashedMetadataArgs, MetadataAttestationEvent, MintPrice, NewNFTEvent, TreeConfig,
+        TREE_DELEGATE_RECORD_PREFIX,
+    },
+    utils::{
+        append_leaf, assert_creators_are_sorted, assert_hashed_metadata_is_mpl_compatible,
+        assert_metadata_is_mintable, assert_owned_by, assert_tree_not_full,
+        assert_valid_leaf_delegate, assert_verified_creators_signed, charge_mint_price_lamports,
+        charge_mint_price_tokens, get_asset_id, is_registered_tree_delegate, log_event,
+        mint_signers, take_and_assert_instruction_enabled, take_companion_accounts,
+    },
+    InstructionName,
+};
+use anchor_lang::{prelude::*, solana_program::system_program};
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+use std::collections::HashSet;
+
+#[derive(Accounts)]
+pub struct MintV1<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: This account is neither written to nor read from.
+    pub leaf_owner: AccountInfo<'info>,
+    /// CHECK: This account is neither written to nor read from.
+    pub leaf_delegate: AccountInfo<'info>,
+    #[account(mut)]
+    /// CHECK: unsafe
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub tree_delegate: Signer<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [
+            TREE_DELEGATE_RECORD_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            tree_delegate.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub tree_delegate_record: UncheckedAccount<'info>,
+}
+
+impl<'info> MintV1<'info> {
+    // Charges the mint price for `quantity` assets, if this is a public mint into a tree that
+    // has one. The accounts that pay it are taken from the companion accounts (see
+    // `utils::take_companion_accounts`).
+    pub(crate) fn charge_mint_price(
+        &self,
+        remaining_accounts: &mut &[AccountInfo<'info>],
+        quantity: u64,
+    ) -> Result<()> {
+        if !self
+            .tree_authority
+            .charges_mint_price(&self.tree_delegate.key())
+            || is_registered_tree_delegate(&self.tree_delegate_record)?
+        {
+            return Ok(());
+        }
+
+        let accounts = take_companion_accounts::<6>(remaining_accounts)
+            .ok_or(BubblegumError::MintPriceNotPaid)?;
+        let [mint_price, treasury, system_program, payer_tokens, treasury_tokens, token_program] =
+            accounts;
+        assert_owned_by(mint_price, &crate::id())?;
+        let price = MintPrice::try_deserialize(&mut mint_price.try_borrow_data()?.as_ref())?;
+        require!(
+            price.merkle_tree == self.merkle_tree.key(),
+            BubblegumError::MintPriceNotPaid
+        );
+        require!(
+            system_program.key() == system_program::ID && token_program.key() == spl_token::id(),
+            ErrorCode::InvalidProgramId
+        );
+
+        charge_mint_price_lamports(&price, quantity, treasury, &self.payer, system_program)?;
+        charge_mint_price_tokens(
+            &price,
+            quantity,
+            payer_tokens,
+            treasury_tokens,
+            &self.payer,
+            token_program,
+        )
+    }
+}
+
+pub(crate) fn process_mint_v1<'info>(
+    message: MetadataArgs,
+    owner: Pubkey,
+    delegate: Pubkey,
+    metadata_auth: HashSet<Pubkey>,
+    authority_bump: u8,
+    authority: &mut Account<'info, TreeConfig>,
+    merkle_tree: &AccountInfo<'info>,
+    wrapper: &Program<'info, Wrapper>,
+    compression_program: &AccountInfo<'info>,
+) -> Result<()> {
+    // TODO -> Separate V1 / V1 into seperate instructions
+    assert_metadata_is_mintable(&message, &metadata_auth)?;
+
+    append_new_nft(
+        message,
+        owner,
+        delegate,
+        authority_bump,
+        authority,
+        merkle_tree,
+        wrapper,
+        compression_program,
+    )
+}
+
+// Appends a leaf for metadata that was already validated, along with the `NewNFTEvent`.
+pub(crate) fn append_new_nft<'info>(
+    message: MetadataArgs,
+    owner: Pubkey,
+    delegate: Pubkey,
+    authority_bump: u8,
+    authority: &mut Account<'info, TreeConfig>,
+    merkle_tree: &AccountInfo<'info>,
+    wrapper: &Program<'info, Wrapper>,
+    compression_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let data_hash = hash_metadata(&message)?;
+    let creator_hash = hash_creators(&message.creators)?;
+
+    let new_nft = NewNFTEvent {
+        version: Version::V1,
+        metadata: message,
+        nonce: authority.num_minted,
+    };
+
+    emit!(new_nft);
+    log_event(new_nft.try_to_vec()?, wrapper, authority.logging_mode)?;
+
+    append_new_leaf(
+        owner,
+        delegate,
+        data_hash,
+        creator_hash,
+        authority_bump,
+        authority,
+        merkle_tree,
+        wrapper,
+        compression_program,
+    )
+}
+
+// Same as `process_mint_v1`, except the bulk of the metadata is only provided via its hash.
+// The program cannot look inside it, so it's up to indexers to match the full metadata they
+// receive off-chain against the attestation event (which also means the collection cannot be
+// checked for being unverified here).
+fn process_mint_v1_hashed<'info>(
+    message: HashedMetadataArgs,
+    owner: Pubkey,
+    delegate: Pubkey,
+    metadata_auth: HashSet<Pubkey>,
+    authority_bump: u8,
+    authority: &mut Account<'info, TreeConfig>,
+    merkle_tree: &AccountInfo<'info>,
+    wrapper: &Program<'info, Wrapper>,
+    compression_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if !authority.is_hash_only {
+        return Err(BubblegumError::TreeNotHashOnly.into());
+    }
+
+    assert_hashed_metadata_is_mpl_compatible(&message)?;
+    assert_creators_are_sorted(&message.creators)?;
+    assert_verified_creators_signed(&message.creators, &metadata_auth)?;
+
+    let data_hash = hash_data(&message.metadata_args_hash, message.seller_fee_basis_points);
+    let creator_hash = hash_creators(&message.creators)?;
+
+    let attestation = MetadataAttestationEvent {
+        version: Version::V1,
+        metadata: message,
+        nonce: authority.num_minted,
+    };
+
+    emit!(attestation);
+    log_event(attestation.try_to_vec()?, wrapper, authority.logging_mode)?;
+
+    append_new_leaf(
+        owner,
+        delegate,
+        data_hash,
+        creator_hash,
+        authority_bump,
+        authority,
+        merkle_tree,
+        wrapper,
+        compression_program,
+    )
+}
+
+pub(crate) fn append_new_leaf<'info>(
+    owner: Pubkey,
+    delegate: Pubkey,
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    authority_bump: u8,
+    authority: &mut Account<'info, TreeConfig>,
+    merkle_tree: &AccountInfo<'info>,
+    wrapper: &Program<'info, Wrapper>,
+    compression_program: &AccountInfo<'info>,
+) -> Result<()> {
+    assert_tree_not_full(authority, merkle_tree)?;
+
+    let asset_id = get_asset_id(&merkle_tree.key(), authority.num_minted);
+    let leaf = LeafSchema::new_v0(
+        asset_id,
+        owner,
+        delegate,
+        authority.num_minted,
+        data_hash,
+        creator_hash,
+    );
+
+    emit!(leaf.to_event());
+
+    append_leaf(
+        &merkle_tree.key(),
+        authority_bump,
+        &compression_program.to_account_info(),
+        &authority.to_account_info(),
+        &merkle_tree.to_account_info(),
+        &wrapper.to_account_info(),
+        leaf.to_node(),
+    )
+}
+
+pub fn handle_mint_v1<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintV1<'info>>,
+    message: MetadataArgs,
+) -> Result<()> {
+    let mut remaining_accounts = ctx.remaining_accounts;
+    take_and_assert_instruction_enabled(
+        &ctx.accounts.tree_authority,
+        &mut remaining_accounts,
+        InstructionName::MintV1,
+    )?;
+    // TODO -> Separate V1 / V1 into seperate instructions
+    let metadata_auth = mint_signers(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.payer,
+        &ctx.accounts.tree_delegate,
+        &ctx.accounts.tree_delegate_record,
+        remaining_accounts,
+    )?;
+    assert_valid_leaf_delegate(&ctx.accounts.leaf_owner, &ctx.accounts.leaf_delegate)?;
+    ctx.accounts.charge_mint_price(&mut remaining_accounts, 1)?;
+
+    let owner = ctx.accounts.leaf_owner.key();
+    let delegate = ctx.accounts.leaf_delegate.key();
+    let authority = &mut ctx.accounts.tree_authority;
+    let merkle_tree = &ctx.accounts.merkle_tree;
+
+    if !authority.contains_mint_capacity(1) {
+        return Err(BubblegumError::InsufficientMintCapacity.into());
+    }
+
+    process_mint_v1(
+        message,
+        owner,
+        delegate,
+        metadata_auth,
+        *ctx.bumps.get("tree_authority").unwrap(),
+        authority,
+        merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+    )?;
+
+    authority.increment_mint_count();
+    authority.record_program_version();
+
+    Ok(())
+}
+
+pub fn handle_mint_v1_hashed<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintV1<'info>>,
+    message: HashedMetadataArgs,
+) -> Result<()> {
+    let mut remaining_accounts = ctx.remaining_accounts;
+    take_and_assert_instruction_enabled(
+        &ctx.accounts.tree_authority,
+        &mut remaining_accounts,
+        InstructionName::MintV1Hashed,
+    )?;
+    let metadata_auth = mint_signers(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.payer,
+        &ctx.accounts.tree_delegate,
+        &ctx.accounts.tree_delegate_record,
+        remaining_accounts,
+    )?;
+    assert_valid_leaf_delegate(&ctx.accounts.leaf_owner, &ctx.accounts.leaf_delegate)?;
+    ctx.accounts.charge_mint_price(&mut remaining_accounts, 1)?;
+
+    let owner = ctx.accounts.leaf_owner.key();
+    let delegate = ctx.accounts.leaf_delegate.key();
+    let authority = &mut ctx.accounts.tree_authority;
+    let merkle_tree = &ctx.accounts.merkle_tree;
+
+    if !authority.contains_mint_capacity(1) {
+        return Err(BubblegumError::InsufficientMintCapacity.into());
+    }
+
+    process_mint_v1_hashed(
+        message,
+        owner,
+        delegate,
+        metadata_auth,
+        *ctx.bumps.get("tree_authority").unwrap(),
+        authority,
+        merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+    )?;
+
+    authority.increment_mint_count();
+    authority.record_program_version();
+
+    Ok(())
+}
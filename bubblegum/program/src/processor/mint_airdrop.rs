@@ -0,0 +1,121 @@
+use super::mint::append_new_leaf;
+use crate::{
+    error::BubblegumError,
+    hash::{hash_creators, hash_metadata},
+    state::{
+        leaf_schema::Version, metaplex_adapter::MetadataArgs, NewNFTEvent, TreeConfig,
+        FEATURE_FLAGS_PREFIX, MAX_AIRDROP_BATCH_SIZE, TREE_DELEGATE_RECORD_PREFIX,
+    },
+    utils::{
+        assert_instruction_enabled, assert_metadata_is_mintable, assert_no_mint_price_due,
+        assert_not_zero_address, log_event, mint_signers,
+    },
+    InstructionName,
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+// Same as `MintV1`, except the leaf owners are passed as instruction data.
+#[derive(Accounts)]
+pub struct MintAirdrop<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    /// CHECK: unsafe
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub payer: Signer<'info>,
+    pub tree_delegate: Signer<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [
+            TREE_DELEGATE_RECORD_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            tree_delegate.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub tree_delegate_record: UncheckedAccount<'info>,
+    #[account(seeds = [FEATURE_FLAGS_PREFIX.as_ref()], bump)]
+    /// CHECK: This account is checked in the instruction
+    pub feature_flags: UncheckedAccount<'info>,
+}
+
+// Mints an asset with the same metadata to each of the owners, which also act as the leaf
+// delegates. Metadata is validated and hashed only once, but every asset gets its own
+// `NewNFTEvent`, so indexers don't have to tell airdrops apart from regular mints.
+pub fn handle_mint_airdrop(
+    ctx: Context<MintAirdrop>,
+    message: MetadataArgs,
+    owners: Vec<Pubkey>,
+) -> Result<()> {
+    assert_instruction_enabled(&ctx.accounts.feature_flags, InstructionName::MintAirdrop)?;
+    let metadata_auth = mint_signers(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.payer,
+        &ctx.accounts.tree_delegate,
+        &ctx.accounts.tree_delegate_record,
+        ctx.remaining_accounts,
+    )?;
+    assert_no_mint_price_due(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.tree_delegate.key(),
+        &ctx.accounts.tree_delegate_record,
+    )?;
+
+    if owners.is_empty() || owners.len() > MAX_AIRDROP_BATCH_SIZE {
+        return Err(BubblegumError::InvalidAirdropBatchSize.into());
+    }
+
+    let authority = &mut ctx.accounts.tree_authority;
+    let merkle_tree = &ctx.accounts.merkle_tree;
+
+    if !authority.contains_mint_capacity(owners.len() as u64) {
+        return Err(BubblegumError::InsufficientMintCapacity.into());
+    }
+
+    assert_metadata_is_mintable(&message, &metadata_auth)?;
+    for owner in owners.iter() {
+        assert_not_zero_address(owner)?;
+    }
+
+    let data_hash = hash_metadata(&message)?;
+    let creator_hash = hash_creators(&message.creators)?;
+
+    for owner in owners {
+        let new_nft = NewNFTEvent {
+            version: Version::V1,
+            metadata: message.clone(),
+            nonce: authority.num_minted,
+        };
+
+        emit!(new_nft);
+        log_event(
+            new_nft.try_to_vec()?,
+            &ctx.accounts.log_wrapper,
+            authority.logging_mode,
+        )?;
+
+        append_new_leaf(
+            owner,
+            owner,
+            data_hash,
+            creator_hash,
+            *ctx.bumps.get("tree_authority").unwrap(),
+            authority,
+            merkle_tree,
+            &ctx.accounts.log_wrapper,
+            &ctx.accounts.compression_program,
+        )?;
+
+        authority.increment_mint_count();
+    }
+    authority.record_program_version();
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use super::mint::{append_new_nft, MintV1};
+use crate::{
+    error::BubblegumError,
+    state::{metaplex_adapter::MetadataArgs, MAX_MINT_BATCH_SIZE},
+    utils::{
+        assert_metadata_is_mintable, assert_valid_leaf_delegate, mint_signers,
+        take_and_assert_instruction_enabled,
+    },
+    InstructionName,
+};
+use anchor_lang::prelude::*;
+
+// Mints an asset for each of the messages, all with the same owner and delegate. Either all of
+// them are minted or none, and each gets its own `NewNFTEvent` and nonce, like regular mints.
+pub fn handle_mint_batch_v1<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintV1<'info>>,
+    messages: Vec<MetadataArgs>,
+) -> Result<()> {
+    let mut remaining_accounts = ctx.remaining_accounts;
+    take_and_assert_instruction_enabled(
+        &ctx.accounts.tree_authority,
+        &mut remaining_accounts,
+        InstructionName::MintBatchV1,
+    )?;
+    let metadata_auth = mint_signers(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.payer,
+        &ctx.accounts.tree_delegate,
+        &ctx.accounts.tree_delegate_record,
+        remaining_accounts,
+    )?;
+    assert_valid_leaf_delegate(&ctx.accounts.leaf_owner, &ctx.accounts.leaf_delegate)?;
+
+    if messages.is_empty() || messages.len() > MAX_MINT_BATCH_SIZE {
+        return Err(BubblegumError::InvalidMintBatchSize.into());
+    }
+    ctx.accounts
+        .charge_mint_price(&mut remaining_accounts, messages.len() as u64)?;
+
+    let owner = ctx.accounts.leaf_owner.key();
+    let delegate = ctx.accounts.leaf_delegate.key();
+    let authority = &mut ctx.accounts.tree_authority;
+
+    if !authority.contains_mint_capacity(messages.len() as u64) {
+        return Err(BubblegumError::InsufficientMintCapacity.into());
+    }
+
+    for message in messages {
+        assert_metadata_is_mintable(&message, &metadata_auth)?;
+        append_new_nft(
+            message,
+            owner,
+            delegate,
+            *ctx.bumps.get("tree_authority").unwrap(),
+            authority,
+            &ctx.accounts.merkle_tree,
+            &ctx.accounts.log_wrapper,
+            &ctx.accounts.compression_program,
+        )?;
+        authority.increment_mint_count();
+    }
+    authority.record_program_version();
+
+    Ok(())
+}
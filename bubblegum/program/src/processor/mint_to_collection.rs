@@ -0,0 +1,148 @@
+use super::{
+    collection_verification::assert_collection_authority_and_update_size, mint::append_new_nft,
+};
+use crate::{
+    error::BubblegumError,
+    state::{
+        metaplex_adapter::MetadataArgs,
+        metaplex_anchor::{MplTokenMetadata, TokenMetadata},
+        TreeConfig, COLLECTION_CPI_PREFIX, FEATURE_FLAGS_PREFIX, TREE_DELEGATE_RECORD_PREFIX,
+    },
+    utils::{
+        assert_instruction_enabled, assert_metadata_is_mintable, assert_no_mint_price_due,
+        assert_valid_leaf_delegate, mint_signers,
+    },
+    InstructionName,
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+// Same as `MintV1`, plus the accounts of `CollectionVerification` that are needed to verify the
+// collection.
+#[derive(Accounts)]
+pub struct MintToCollectionV1<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: This account is neither written to nor read from.
+    pub leaf_owner: AccountInfo<'info>,
+    /// CHECK: This account is neither written to nor read from.
+    pub leaf_delegate: AccountInfo<'info>,
+    #[account(mut)]
+    /// CHECK: unsafe
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub payer: Signer<'info>,
+    pub tree_delegate: Signer<'info>,
+    pub collection_authority: Signer<'info>,
+    /// CHECK: Optional collection authority record PDA. If there is no collection authority
+    /// record PDA, then this must be the Bubblegum program address.
+    pub collection_authority_record_pda: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub collection_mint: UncheckedAccount<'info>,
+    pub collection_metadata: Box<Account<'info, TokenMetadata>>,
+    /// CHECK: This account is checked in the instruction
+    pub edition_account: UncheckedAccount<'info>,
+    /// CHECK: This is just used as a signing PDA.
+    #[account(
+        seeds = [COLLECTION_CPI_PREFIX.as_ref()],
+        bump,
+    )]
+    pub bubblegum_signer: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    pub token_metadata_program: Program<'info, MplTokenMetadata>,
+    #[account(
+        seeds = [
+            TREE_DELEGATE_RECORD_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            tree_delegate.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub tree_delegate_record: UncheckedAccount<'info>,
+    #[account(seeds = [FEATURE_FLAGS_PREFIX.as_ref()], bump)]
+    /// CHECK: This account is checked in the instruction
+    pub feature_flags: UncheckedAccount<'info>,
+}
+
+// Same as `mint_v1` followed by `verify_collection`, without having to send the proof for the
+// new leaf. The collection of `message` must be set to `collection_mint`, and be unverified.
+pub fn handle_mint_to_collection_v1(
+    ctx: Context<MintToCollectionV1>,
+    mut message: MetadataArgs,
+) -> Result<()> {
+    assert_instruction_enabled(
+        &ctx.accounts.feature_flags,
+        InstructionName::MintToCollectionV1,
+    )?;
+    let metadata_auth = mint_signers(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.payer,
+        &ctx.accounts.tree_delegate,
+        &ctx.accounts.tree_delegate_record,
+        ctx.remaining_accounts,
+    )?;
+    assert_no_mint_price_due(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.tree_delegate.key(),
+        &ctx.accounts.tree_delegate_record,
+    )?;
+    assert_valid_leaf_delegate(&ctx.accounts.leaf_owner, &ctx.accounts.leaf_delegate)?;
+    assert_metadata_is_mintable(&message, &metadata_auth)?;
+
+    if !ctx.accounts.tree_authority.contains_mint_capacity(1) {
+        return Err(BubblegumError::InsufficientMintCapacity.into());
+    }
+
+    let collection_authority_record = ctx
+        .accounts
+        .collection_authority_record_pda
+        .to_account_info();
+    let collection_authority_record = if collection_authority_record.key() == crate::id() {
+        None
+    } else {
+        Some(&collection_authority_record)
+    };
+
+    let collection = message
+        .collection
+        .as_mut()
+        .ok_or(BubblegumError::CollectionNotFound)?;
+    assert_collection_authority_and_update_size(
+        collection,
+        true,
+        &ctx.accounts.collection_metadata,
+        &ctx.accounts.collection_mint,
+        &ctx.accounts.edition_account,
+        &ctx.accounts.collection_authority,
+        collection_authority_record,
+        &ctx.accounts.bubblegum_signer,
+        ctx.bumps["bubblegum_signer"],
+        &ctx.accounts.token_metadata_program,
+    )?;
+    collection.verified = true;
+
+    let owner = ctx.accounts.leaf_owner.key();
+    let delegate = ctx.accounts.leaf_delegate.key();
+    let authority = &mut ctx.accounts.tree_authority;
+
+    append_new_nft(
+        message,
+        owner,
+        delegate,
+        *ctx.bumps.get("tree_authority").unwrap(),
+        authority,
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+    )?;
+
+    authority.increment_mint_count();
+    authority.record_program_version();
+
+    Ok(())
+}
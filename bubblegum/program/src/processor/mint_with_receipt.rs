@@ -0,0 +1,121 @@
+use super::mint::process_mint_v1;
+use crate::{
+    error::BubblegumError,
+    state::{
+        metaplex_adapter::MetadataArgs, AccountSize, AssetReceipt, TreeConfig,
+        ASSET_RECEIPT_PREFIX, FEATURE_FLAGS_PREFIX, TREE_DELEGATE_RECORD_PREFIX,
+    },
+    utils::{
+        assert_instruction_enabled, assert_no_mint_price_due, assert_valid_leaf_delegate,
+        get_asset_id, mint_signers,
+    },
+    InstructionName,
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+// Same as `MintV1`, with the payer also paying for the receipt.
+#[derive(Accounts)]
+pub struct MintV1WithReceipt<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: This account is neither written to nor read from.
+    pub leaf_owner: AccountInfo<'info>,
+    /// CHECK: This account is neither written to nor read from.
+    pub leaf_delegate: AccountInfo<'info>,
+    #[account(mut)]
+    /// CHECK: unsafe
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub tree_delegate: Signer<'info>,
+    #[account(
+        init,
+        seeds = [
+            ASSET_RECEIPT_PREFIX.as_ref(),
+            get_asset_id(&merkle_tree.key(), tree_authority.num_minted).as_ref(),
+        ],
+        payer = payer,
+        space = AssetReceipt::SIZE,
+        bump
+    )]
+    pub asset_receipt: Account<'info, AssetReceipt>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [
+            TREE_DELEGATE_RECORD_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            tree_delegate.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub tree_delegate_record: UncheckedAccount<'info>,
+    #[account(seeds = [FEATURE_FLAGS_PREFIX.as_ref()], bump)]
+    /// CHECK: This account is checked in the instruction
+    pub feature_flags: UncheckedAccount<'info>,
+}
+
+// Same as `mint_v1`, but also creates an `AssetReceipt` for the new asset.
+pub fn handle_mint_v1_with_receipt(
+    ctx: Context<MintV1WithReceipt>,
+    message: MetadataArgs,
+) -> Result<()> {
+    assert_instruction_enabled(
+        &ctx.accounts.feature_flags,
+        InstructionName::MintV1WithReceipt,
+    )?;
+    let metadata_auth = mint_signers(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.payer,
+        &ctx.accounts.tree_delegate,
+        &ctx.accounts.tree_delegate_record,
+        ctx.remaining_accounts,
+    )?;
+    assert_no_mint_price_due(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.tree_delegate.key(),
+        &ctx.accounts.tree_delegate_record,
+    )?;
+    assert_valid_leaf_delegate(&ctx.accounts.leaf_owner, &ctx.accounts.leaf_delegate)?;
+
+    let owner = ctx.accounts.leaf_owner.key();
+    let delegate = ctx.accounts.leaf_delegate.key();
+    let authority = &mut ctx.accounts.tree_authority;
+    let merkle_tree = &ctx.accounts.merkle_tree;
+
+    if !authority.contains_mint_capacity(1) {
+        return Err(BubblegumError::InsufficientMintCapacity.into());
+    }
+
+    let nonce = authority.num_minted;
+
+    process_mint_v1(
+        message,
+        owner,
+        delegate,
+        metadata_auth,
+        *ctx.bumps.get("tree_authority").unwrap(),
+        authority,
+        merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+    )?;
+
+    authority.increment_mint_count();
+    authority.record_program_version();
+
+    ctx.accounts.asset_receipt.set_inner(AssetReceipt {
+        merkle_tree: merkle_tree.key(),
+        nonce,
+        owner,
+    });
+
+    Ok(())
+}
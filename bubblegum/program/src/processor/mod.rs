@@ -0,0 +1,105 @@
+pub mod accept_tree_creator;
+pub mod add_tree_delegate;
+pub mod approve_metadata_update;
+pub mod burn;
+pub mod burn_with_receipt;
+pub mod cancel_metadata_proposal;
+pub mod cancel_redeem;
+pub mod cancel_timelocked_transfer;
+pub mod cancel_tree_creator_transfer;
+pub mod claim_timelocked_transfer;
+pub mod close_asset_receipt;
+pub mod close_burn_receipt;
+pub mod close_delegate_expiry;
+pub mod collection_verification;
+pub mod compress;
+pub mod create_deny_list;
+pub mod create_tree;
+pub mod creator_verification;
+pub mod decompress_v1;
+pub mod delegate;
+pub mod delegate_with_expiry;
+pub mod freeze;
+pub mod init_on_chain_metadata;
+pub mod mint;
+pub mod mint_airdrop;
+pub mod mint_batch;
+pub mod mint_to_collection;
+pub mod mint_with_receipt;
+pub mod propose_metadata_update;
+pub mod propose_tree_creator;
+pub mod redeem;
+pub mod remove_tree_delegate;
+pub mod set_feature_flags;
+pub mod set_logging_mode;
+pub mod set_mint_price;
+pub mod set_soulbound_override;
+pub mod set_tree_delegate;
+pub mod set_tree_deny_list;
+pub mod set_tree_hash_only;
+pub mod set_tree_metadata;
+pub mod set_tree_non_transferable;
+pub mod set_tree_paused;
+pub mod set_tree_public;
+pub mod thaw;
+pub mod transfer;
+pub mod transfer_batch;
+pub mod transfer_timelocked;
+pub mod update_deny_list;
+pub mod update_metadata;
+pub mod verify_metadata_hash;
+pub mod withdraw_from_tree_authority;
+pub mod write_metadata_chunk;
+
+pub use accept_tree_creator::*;
+pub use add_tree_delegate::*;
+pub use approve_metadata_update::*;
+pub use burn::*;
+pub use burn_with_receipt::*;
+pub use cancel_metadata_proposal::*;
+pub use cancel_redeem::*;
+pub use cancel_timelocked_transfer::*;
+pub use cancel_tree_creator_transfer::*;
+pub use claim_timelocked_transfer::*;
+pub use close_asset_receipt::*;
+pub use close_burn_receipt::*;
+pub use close_delegate_expiry::*;
+pub use collection_verification::*;
+pub use compress::*;
+pub use create_deny_list::*;
+pub use create_tree::*;
+pub use creator_verification::*;
+pub use decompress_v1::*;
+pub use delegate::*;
+pub use delegate_with_expiry::*;
+pub use freeze::*;
+pub use init_on_chain_metadata::*;
+pub use mint::*;
+pub use mint_airdrop::*;
+pub use mint_batch::*;
+pub use mint_to_collection::*;
+pub use mint_with_receipt::*;
+pub use propose_metadata_update::*;
+pub use propose_tree_creator::*;
+pub use redeem::*;
+pub use remove_tree_delegate::*;
+pub use set_feature_flags::*;
+pub use set_logging_mode::*;
+pub use set_mint_price::*;
+pub use set_soulbound_override::*;
+pub use set_tree_delegate::*;
+pub use set_tree_deny_list::*;
+pub use set_tree_hash_only::*;
+pub use set_tree_metadata::*;
+pub use set_tree_non_transferable::*;
+pub use set_tree_paused::*;
+pub use set_tree_public::*;
+pub use thaw::*;
+pub use transfer::*;
+pub use transfer_batch::*;
+pub use transfer_timelocked::*;
+pub use update_deny_list::*;
+pub use update_metadata::*;
+pub use verify_metadata_hash::*;
+pub use withdraw_from_tree_authority::*;
+pub use write_metadata_chunk::*;
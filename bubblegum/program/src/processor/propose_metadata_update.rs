@@ -0,0 +1,75 @@
+use crate::{
+    error::BubblegumError,
+    hash::{hash_creators, hash_metadata},
+    state::{
+        metaplex_adapter::MetadataArgs, AccountSize, MetadataProposal, TreeConfig,
+        METADATA_PROPOSAL_PREFIX,
+    },
+    utils::{assert_creators_are_sorted, assert_metadata_is_mpl_compatible},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ProposeMetadataUpdate<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    // The tree creator or delegate, which act as the update authority of the assets in the
+    // tree.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    // Proposing again replaces the pending proposal of the same authority, if there is one.
+    #[account(
+        init_if_needed,
+        seeds = [
+            METADATA_PROPOSAL_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            &nonce.to_le_bytes()
+        ],
+        payer = authority,
+        space = MetadataProposal::SIZE,
+        bump
+    )]
+    pub metadata_proposal: Account<'info, MetadataProposal>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// The rules of `update_metadata` are only checked against the current metadata of the leaf
+// once the owner approves, since the proposal doesn't prove what the leaf holds. A proposal
+// from the other authority has to be cancelled by its proposer first, since they paid for it.
+pub fn handle_propose_metadata_update(
+    ctx: Context<ProposeMetadataUpdate>,
+    nonce: u64,
+    new_metadata: MetadataArgs,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    require!(
+        ctx.accounts.tree_authority.is_authority(&authority),
+        BubblegumError::UpdateAuthorityIncorrect
+    );
+
+    assert_metadata_is_mpl_compatible(&new_metadata)?;
+    assert_creators_are_sorted(&new_metadata.creators)?;
+
+    // Proposals that were just created have no proposer yet.
+    let proposer = ctx.accounts.metadata_proposal.proposer;
+    require!(
+        proposer == Pubkey::default() || proposer == authority,
+        BubblegumError::MetadataProposalPending
+    );
+
+    ctx.accounts.metadata_proposal.set_inner(MetadataProposal {
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        nonce,
+        proposer: authority,
+        data_hash: hash_metadata(&new_metadata)?,
+        creator_hash: hash_creators(&new_metadata.creators)?,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::{
+    state::{
+        leaf_schema::Version, AccountSize, CreatorTransfer, CreatorTransferStage, TreeConfig,
+        TreeCreatorTransferEvent, CREATOR_TRANSFER_PREFIX,
+    },
+    utils::assert_tree_governance_mutable,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeTreeCreator<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    // Proposing again replaces the pending transfer, if there is one.
+    #[account(
+        init_if_needed,
+        seeds = [CREATOR_TRANSFER_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        payer = tree_creator,
+        space = CreatorTransfer::SIZE,
+        bump,
+    )]
+    pub creator_transfer: Account<'info, CreatorTransfer>,
+    #[account(mut)]
+    pub tree_creator: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub new_tree_creator: UncheckedAccount<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_propose_tree_creator(
+    ctx: Context<ProposeTreeCreator>,
+    reset_tree_delegate: bool,
+) -> Result<()> {
+    assert_tree_governance_mutable(&ctx.accounts.tree_authority)?;
+
+    let tree_id = ctx.accounts.merkle_tree.key();
+    let new_tree_creator = ctx.accounts.new_tree_creator.key();
+
+    ctx.accounts.creator_transfer.set_inner(CreatorTransfer {
+        merkle_tree: tree_id,
+        new_tree_creator,
+        reset_tree_delegate,
+    });
+
+    emit!(TreeCreatorTransferEvent {
+        version: Version::V1,
+        tree_id,
+        tree_creator: ctx.accounts.tree_creator.key(),
+        new_tree_creator,
+        stage: CreatorTransferStage::Proposed,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use crate::{
+    state::{
+        leaf_schema::LeafSchema, AccountSize, TreeConfig, Voucher, LEAF_FREEZE_PREFIX,
+        SOULBOUND_OVERRIDE_PREFIX, VOUCHER_PREFIX,
+    },
+    utils::{
+        assert_index_matches_nonce, assert_not_frozen, assert_transferable, assert_tree_not_paused,
+        get_asset_id, log_event, replace_leaf, take_and_assert_not_flagged,
+    },
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression, Node};
+
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    nonce: u64,
+    _index: u32,
+)]
+pub struct Redeem<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub leaf_owner: Signer<'info>,
+    /// CHECK: This account is chekced in the instruction
+    pub leaf_delegate: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: checked in cpi
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [
+        VOUCHER_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    payer = leaf_owner,
+    space = Voucher::SIZE,
+    bump
+    )]
+    pub voucher: Account<'info, Voucher>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [
+        SOULBOUND_OVERRIDE_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub soulbound_override: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [
+        LEAF_FREEZE_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub leaf_freeze: UncheckedAccount<'info>,
+}
+
+pub fn handle_redeem<'info>(
+    ctx: Context<'_, '_, '_, 'info, Redeem<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Result<()> {
+    assert_tree_not_paused(&ctx.accounts.tree_authority)?;
+    assert_index_matches_nonce(index, nonce)?;
+    assert_not_frozen(&ctx.accounts.leaf_freeze)?;
+    // Redeemed leaves can be decompressed into regular, transferable tokens.
+    assert_transferable(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.soulbound_override,
+    )?;
+
+    let owner = ctx.accounts.leaf_owner.key();
+    let delegate = ctx.accounts.leaf_delegate.key();
+    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+    // Flagged assets can't leave the tree as regular tokens either.
+    let mut remaining_accounts = ctx.remaining_accounts;
+    take_and_assert_not_flagged(
+        &ctx.accounts.tree_authority,
+        &merkle_tree.key(),
+        &mut remaining_accounts,
+        &asset_id,
+    )?;
+    let previous_leaf =
+        LeafSchema::new_v0(asset_id, owner, delegate, nonce, data_hash, creator_hash);
+    emit!(previous_leaf.to_event());
+    let new_leaf = Node::default();
+    log_event(
+        new_leaf.try_to_vec()?,
+        &ctx.accounts.log_wrapper,
+        ctx.accounts.tree_authority.logging_mode,
+    )?;
+    replace_leaf(
+        &merkle_tree.key(),
+        *ctx.bumps.get("tree_authority").unwrap(),
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf,
+        index,
+    )?;
+    ctx.accounts
+        .voucher
+        .set_inner(Voucher::new(previous_leaf, index, merkle_tree.key()));
+
+    Ok(())
+}
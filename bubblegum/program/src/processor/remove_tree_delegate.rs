@@ -0,0 +1,40 @@
+use crate::{
+    state::{TreeConfig, TreeDelegateRecord, TREE_DELEGATE_RECORD_PREFIX},
+    utils::assert_tree_governance_mutable,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveTreeDelegate<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub tree_creator: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub delegate: UncheckedAccount<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+    // The rent goes to the current creator, even if a previous one added the delegate.
+    #[account(
+        mut,
+        seeds = [
+            TREE_DELEGATE_RECORD_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            delegate.key().as_ref(),
+        ],
+        bump,
+        close = tree_creator,
+    )]
+    pub tree_delegate_record: Account<'info, TreeDelegateRecord>,
+}
+
+pub fn handle_remove_tree_delegate(ctx: Context<RemoveTreeDelegate>) -> Result<()> {
+    assert_tree_governance_mutable(&ctx.accounts.tree_authority)?;
+    ctx.accounts.tree_authority.record_program_version();
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::{
+    error::BubblegumError,
+    state::{AccountSize, FeatureFlags, TreeConfig, FEATURE_FLAGS_PREFIX, TREE_HAS_FEATURE_FLAGS},
+    utils::assert_owned_by,
+};
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+#[derive(Accounts)]
+pub struct SetFeatureFlags<'info> {
+    #[account(
+        init_if_needed,
+        seeds = [FEATURE_FLAGS_PREFIX.as_ref()],
+        payer = authority,
+        space = FeatureFlags::SIZE,
+        bump,
+    )]
+    pub feature_flags: Account<'info, FeatureFlags>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    // The flags apply to the whole deployment, so they are governed by whoever can upgrade it.
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(authority.key()),
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+// The `TreeConfig` accounts passed as remaining accounts are marked as having the flags, after
+// which instructions with a fixed account layout fail in those trees unless they are passed the
+// flags as well (see `utils::take_and_assert_instruction_enabled`). Trees that are not marked
+// keep working with clients that don't know about the flags.
+pub fn handle_set_feature_flags(
+    ctx: Context<SetFeatureFlags>,
+    disabled_instructions: u64,
+) -> Result<()> {
+    // A bit without an instruction that checks it would look like it disables something.
+    require!(
+        disabled_instructions & !FeatureFlags::valid_mask() == 0,
+        BubblegumError::InvalidFeatureFlags
+    );
+    ctx.accounts.feature_flags.disabled_instructions = disabled_instructions;
+
+    for account in ctx.remaining_accounts {
+        assert_owned_by(account, &crate::id())?;
+        let mut tree_authority =
+            TreeConfig::try_deserialize(&mut account.try_borrow_data()?.as_ref())?;
+        tree_authority.set_companion_account(TREE_HAS_FEATURE_FLAGS, true);
+        let mut data = account.try_borrow_mut_data()?;
+        tree_authority.try_serialize(&mut &mut data[..])?;
+    }
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::{
+    state::{LoggingMode, TreeConfig},
+    utils::assert_tree_governance_mutable,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetLoggingMode<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    pub tree_creator: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+}
+
+// Events keep being emitted as program logs in every mode, so indexers that follow those
+// are unaffected when a tree switches between modes.
+pub fn handle_set_logging_mode(
+    ctx: Context<SetLoggingMode>,
+    logging_mode: LoggingMode,
+) -> Result<()> {
+    assert_tree_governance_mutable(&ctx.accounts.tree_authority)?;
+    ctx.accounts.tree_authority.logging_mode = logging_mode;
+    ctx.accounts.tree_authority.record_program_version();
+    Ok(())
+}
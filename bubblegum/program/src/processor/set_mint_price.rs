@@ -0,0 +1,57 @@
+use crate::{
+    state::{MintPrice, TreeConfig, MINT_PRICE_PREFIX, MINT_PRICE_SIZE, TREE_HAS_MINT_PRICE},
+    utils::{assert_not_zero_address, assert_tree_governance_mutable},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetMintPrice<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub tree_creator: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        seeds = [MINT_PRICE_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        payer = tree_creator,
+        space = MINT_PRICE_SIZE,
+        bump,
+    )]
+    pub mint_price: Account<'info, MintPrice>,
+    pub system_program: Program<'info, System>,
+}
+
+// A price of zero lamports and zero tokens makes public mints free again.
+pub fn handle_set_mint_price(
+    ctx: Context<SetMintPrice>,
+    lamports: u64,
+    treasury: Pubkey,
+    token_mint: Pubkey,
+    token_amount: u64,
+) -> Result<()> {
+    assert_tree_governance_mutable(&ctx.accounts.tree_authority)?;
+    assert_not_zero_address(&treasury)?;
+    if token_amount > 0 {
+        assert_not_zero_address(&token_mint)?;
+    }
+
+    ctx.accounts.mint_price.set_inner(MintPrice {
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        lamports,
+        treasury,
+        token_mint,
+        token_amount,
+    });
+    ctx.accounts
+        .tree_authority
+        .set_companion_account(TREE_HAS_MINT_PRICE, lamports > 0 || token_amount > 0);
+    ctx.accounts.tree_authority.record_program_version();
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use crate::{
+    error::BubblegumError,
+    state::{AccountSize, SoulboundOverride, TreeConfig, SOULBOUND_OVERRIDE_PREFIX},
+    utils::assert_tree_governance_mutable,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct SetSoulboundOverride<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    pub tree_delegate: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        seeds = [
+        SOULBOUND_OVERRIDE_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    payer = payer,
+    space = SoulboundOverride::SIZE,
+    bump
+    )]
+    pub soulbound_override: Account<'info, SoulboundOverride>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Lets the tree creator or delegate decide whether a single leaf is soulbound, regardless
+// of the setting of the tree. Only allowed for the last minted leaf, and only once. Nothing
+// stops the tree authorities from setting it on a leaf that was already handed out until the
+// next mint, so clients should send it in the same transaction as the mint itself.
+pub fn handle_set_soulbound_override(
+    ctx: Context<SetSoulboundOverride>,
+    nonce: u64,
+    is_non_transferable: bool,
+) -> Result<()> {
+    let authority = &ctx.accounts.tree_authority;
+    let incoming_tree_delegate = ctx.accounts.tree_delegate.key();
+    require!(
+        incoming_tree_delegate == authority.tree_creator
+            || incoming_tree_delegate == authority.tree_delegate,
+        BubblegumError::TreeAuthorityIncorrect,
+    );
+    assert_tree_governance_mutable(authority)?;
+    require!(
+        nonce.checked_add(1) == Some(authority.num_minted),
+        BubblegumError::SoulboundOverrideNotAtMint,
+    );
+
+    ctx.accounts
+        .soulbound_override
+        .set_inner(SoulboundOverride {
+            merkle_tree: ctx.accounts.merkle_tree.key(),
+            nonce,
+            is_non_transferable,
+        });
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::{
+    state::{AccountSize, TreeConfig, TreeDenyList, TREE_DENY_LIST_PREFIX, TREE_HAS_DENY_LIST},
+    utils::assert_tree_governance_mutable,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetTreeDenyList<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub tree_creator: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        seeds = [TREE_DENY_LIST_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        payer = tree_creator,
+        space = TreeDenyList::SIZE,
+        bump,
+    )]
+    pub tree_deny_list: Account<'info, TreeDenyList>,
+    pub system_program: Program<'info, System>,
+}
+
+// The list itself isn't checked here, since it may be created later on (it doesn't flag
+// anything until then). Setting the default address opts the tree out again.
+pub fn handle_set_tree_deny_list(ctx: Context<SetTreeDenyList>, deny_list: Pubkey) -> Result<()> {
+    assert_tree_governance_mutable(&ctx.accounts.tree_authority)?;
+
+    ctx.accounts.tree_deny_list.set_inner(TreeDenyList {
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        deny_list,
+    });
+    ctx.accounts
+        .tree_authority
+        .set_companion_account(TREE_HAS_DENY_LIST, deny_list != Pubkey::default());
+    ctx.accounts.tree_authority.record_program_version();
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use crate::{state::TreeConfig, utils::assert_tree_governance_mutable};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetTreeHashOnly<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    pub tree_creator: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+}
+
+pub fn handle_set_tree_hash_only(ctx: Context<SetTreeHashOnly>, is_hash_only: bool) -> Result<()> {
+    assert_tree_governance_mutable(&ctx.accounts.tree_authority)?;
+    ctx.accounts.tree_authority.is_hash_only = is_hash_only;
+    ctx.accounts.tree_authority.record_program_version();
+    Ok(())
+}
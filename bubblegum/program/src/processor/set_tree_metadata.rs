@@ -0,0 +1,68 @@
+use crate::{
+    error::BubblegumError,
+    state::{
+        leaf_schema::Version, AccountSize, TreeConfig, TreeMetadata, TreeMetadataEvent,
+        MAX_TREE_NAME_LENGTH, MAX_TREE_URI_LENGTH, TREE_METADATA_PREFIX,
+    },
+    utils::assert_tree_governance_mutable,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetTreeMetadata<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(
+        init_if_needed,
+        seeds = [TREE_METADATA_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        payer = payer,
+        space = TreeMetadata::SIZE,
+        bump,
+    )]
+    pub tree_metadata: Account<'info, TreeMetadata>,
+    pub tree_creator: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_set_tree_metadata(
+    ctx: Context<SetTreeMetadata>,
+    name: String,
+    uri: String,
+) -> Result<()> {
+    if name.len() > MAX_TREE_NAME_LENGTH {
+        return Err(BubblegumError::TreeNameTooLong.into());
+    }
+
+    if uri.len() > MAX_TREE_URI_LENGTH {
+        return Err(BubblegumError::TreeUriTooLong.into());
+    }
+
+    let tree_id = ctx.accounts.merkle_tree.key();
+    // Trees with an immutable creator can still be labeled once, right after creation.
+    if ctx.accounts.tree_metadata.merkle_tree == tree_id {
+        assert_tree_governance_mutable(&ctx.accounts.tree_authority)?;
+    }
+
+    ctx.accounts.tree_metadata.set_inner(TreeMetadata {
+        merkle_tree: tree_id,
+        name: name.clone(),
+        uri: uri.clone(),
+    });
+
+    emit!(TreeMetadataEvent {
+        version: Version::V1,
+        tree_id,
+        name,
+        uri,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::{error::BubblegumError, state::TreeConfig, utils::assert_tree_governance_mutable};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetTreeNonTransferable<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    pub tree_creator: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+}
+
+// Leaves are minted under the setting they are meant to keep, so it can't change once the tree
+// has any (per-leaf exceptions go through `set_soulbound_override`).
+pub fn handle_set_tree_non_transferable(
+    ctx: Context<SetTreeNonTransferable>,
+    is_non_transferable: bool,
+) -> Result<()> {
+    assert_tree_governance_mutable(&ctx.accounts.tree_authority)?;
+    require!(
+        ctx.accounts.tree_authority.num_minted == 0,
+        BubblegumError::NonTransferableAfterMint,
+    );
+    ctx.accounts.tree_authority.is_non_transferable = is_non_transferable;
+    ctx.accounts.tree_authority.record_program_version();
+    Ok(())
+}
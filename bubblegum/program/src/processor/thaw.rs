@@ -0,0 +1,70 @@
+use crate::{
+    error::BubblegumError,
+    state::{LeafFreeze, DELEGATE_EXPIRY_PREFIX, LEAF_FREEZE_PREFIX},
+    utils::is_delegate_expired,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct Thaw<'info> {
+    /// CHECK: This account is checked in the instruction
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    #[account(mut)]
+    pub leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: This account is neither read from nor written to
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            LEAF_FREEZE_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            &leaf_freeze.nonce.to_le_bytes(),
+        ],
+        bump,
+        has_one = merkle_tree,
+        close = leaf_delegate,
+    )]
+    pub leaf_freeze: Account<'info, LeafFreeze>,
+    #[account(
+        seeds = [
+            DELEGATE_EXPIRY_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            &leaf_freeze.nonce.to_le_bytes(),
+        ],
+        bump,
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub delegate_expiry: UncheckedAccount<'info>,
+}
+
+// Unlocks a leaf frozen via `freeze`, with the rent of the record going back to the delegate.
+// Frozen leaves cannot be delegated to anyone else, so the delegate that froze the leaf is
+// still its delegate, and no proof is needed. The owner can thaw the leaf as well once the
+// delegate has expired, so an abandoned freeze does not lock the leaf forever.
+pub fn handle_thaw(ctx: Context<Thaw>) -> Result<()> {
+    let leaf_freeze = &ctx.accounts.leaf_freeze;
+    let owner = &ctx.accounts.leaf_owner;
+    let delegate = &ctx.accounts.leaf_delegate;
+    require!(
+        leaf_freeze.delegate == delegate.key(),
+        BubblegumError::LeafAuthorityMustSign
+    );
+    if delegate.is_signer {
+        return Ok(());
+    }
+
+    require!(
+        owner.is_signer && leaf_freeze.owner == owner.key(),
+        BubblegumError::LeafAuthorityMustSign
+    );
+    require!(
+        is_delegate_expired(
+            &leaf_freeze.owner,
+            &leaf_freeze.delegate,
+            &ctx.accounts.delegate_expiry,
+        )?,
+        BubblegumError::AssetIsFrozen
+    );
+    Ok(())
+}
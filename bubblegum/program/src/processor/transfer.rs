@@ -0,0 +1,160 @@
+use crate::{
+    error::BubblegumError,
+    state::{
+        leaf_schema::LeafSchema, TreeConfig, DELEGATE_EXPIRY_PREFIX, LEAF_FREEZE_PREFIX,
+        SOULBOUND_OVERRIDE_PREFIX, VOUCHER_PREFIX,
+    },
+    utils::{
+        assert_delegate_not_expired, assert_index_matches_nonce, assert_not_being_redeemed,
+        assert_not_frozen, assert_not_zero_address, assert_transfer_changes_leaf,
+        assert_transferable, assert_tree_not_paused, get_asset_id, replace_leaf,
+        take_and_assert_instruction_enabled, take_and_assert_not_flagged,
+    },
+    InstructionName,
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    nonce: u64,
+    _index: u32,
+)]
+pub struct Transfer<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is chekced in the instruction
+    pub leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: This account is neither written to nor read from.
+    pub new_leaf_owner: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [
+        VOUCHER_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub voucher: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+        SOULBOUND_OVERRIDE_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub soulbound_override: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+        DELEGATE_EXPIRY_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub delegate_expiry: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+        LEAF_FREEZE_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub leaf_freeze: UncheckedAccount<'info>,
+}
+
+pub fn handle_transfer<'info>(
+    ctx: Context<'_, '_, '_, 'info, Transfer<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Result<()> {
+    let mut remaining_accounts = ctx.remaining_accounts;
+    take_and_assert_instruction_enabled(
+        &ctx.accounts.tree_authority,
+        &mut remaining_accounts,
+        InstructionName::Transfer,
+    )?;
+    assert_tree_not_paused(&ctx.accounts.tree_authority)?;
+    assert_index_matches_nonce(index, nonce)?;
+    assert_not_being_redeemed(&ctx.accounts.voucher)?;
+    assert_not_frozen(&ctx.accounts.leaf_freeze)?;
+    assert_transferable(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.soulbound_override,
+    )?;
+
+    // TODO add back version to select hash schema
+    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
+    let owner = ctx.accounts.leaf_owner.to_account_info();
+    let delegate = ctx.accounts.leaf_delegate.to_account_info();
+
+    // Transfers must be initiated by either the leaf owner or leaf delegate.
+    require!(
+        owner.is_signer || delegate.is_signer,
+        BubblegumError::LeafAuthorityMustSign
+    );
+    assert_delegate_not_expired(&owner, &delegate, &ctx.accounts.delegate_expiry)?;
+    let new_owner = ctx.accounts.new_leaf_owner.key();
+    assert_not_zero_address(&new_owner)?;
+    assert_transfer_changes_leaf(&owner.key(), &delegate.key(), &new_owner)?;
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+    take_and_assert_not_flagged(
+        &ctx.accounts.tree_authority,
+        &merkle_tree.key(),
+        &mut remaining_accounts,
+        &asset_id,
+    )?;
+    let previous_leaf = LeafSchema::new_v0(
+        asset_id,
+        owner.key(),
+        delegate.key(),
+        nonce,
+        data_hash,
+        creator_hash,
+    );
+    // New leafs are instantiated with no delegate
+    let new_leaf = LeafSchema::new_v0(
+        asset_id,
+        new_owner,
+        new_owner,
+        nonce,
+        data_hash,
+        creator_hash,
+    );
+    emit!(new_leaf.to_event());
+    replace_leaf(
+        &merkle_tree.key(),
+        *ctx.bumps.get("tree_authority").unwrap(),
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf.to_node(),
+        index,
+    )
+}
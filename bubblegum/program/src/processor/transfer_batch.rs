@@ -0,0 +1,140 @@
+use crate::{
+    error::BubblegumError,
+    state::{
+        leaf_schema::LeafSchema, BatchTransfer, TreeConfig, FEATURE_FLAGS_PREFIX,
+        LEAF_FREEZE_PREFIX, MAX_TRANSFER_BATCH_SIZE, SOULBOUND_OVERRIDE_PREFIX,
+        TREE_DENY_LIST_PREFIX,
+    },
+    utils::{
+        assert_derivation, assert_index_matches_nonce, assert_instruction_enabled,
+        assert_not_flagged, assert_not_frozen, assert_not_zero_address,
+        assert_transfer_changes_leaf, assert_transferable, assert_tree_not_paused, get_asset_id,
+        replace_leaf,
+    },
+    InstructionName,
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+#[derive(Accounts)]
+pub struct TransferBatch<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    pub leaf_owner: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [TREE_DENY_LIST_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub tree_deny_list: UncheckedAccount<'info>,
+    /// CHECK: This account is checked against `tree_deny_list`, and only read if the tree has one
+    pub deny_list: UncheckedAccount<'info>,
+    #[account(seeds = [FEATURE_FLAGS_PREFIX.as_ref()], bump)]
+    /// CHECK: This account is checked in the instruction
+    pub feature_flags: UncheckedAccount<'info>,
+}
+
+// Transfers several leaves of the same owner, in order. Only the owner can batch transfers, so
+// delegate expiry does not apply, and leaves that are being redeemed fail the proof check since
+// they were already removed from the tree.
+pub fn handle_transfer_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferBatch<'info>>,
+    transfers: Vec<BatchTransfer>,
+) -> Result<()> {
+    assert_instruction_enabled(&ctx.accounts.feature_flags, InstructionName::TransferBatch)?;
+    assert_tree_not_paused(&ctx.accounts.tree_authority)?;
+    if transfers.is_empty() || transfers.len() > MAX_TRANSFER_BATCH_SIZE {
+        return Err(BubblegumError::InvalidTransferBatchSize.into());
+    }
+
+    let expected_accounts: usize = transfers.iter().map(|t| 2 + t.proof_length as usize).sum();
+    if ctx.remaining_accounts.len() != expected_accounts {
+        return Err(BubblegumError::InvalidBatchAccounts.into());
+    }
+
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let owner = ctx.accounts.leaf_owner.key();
+    let mut accounts = ctx.remaining_accounts;
+
+    for transfer in transfers {
+        assert_index_matches_nonce(transfer.index, transfer.nonce)?;
+        assert_not_zero_address(&transfer.new_leaf_owner)?;
+        assert_transfer_changes_leaf(&owner, &transfer.leaf_delegate, &transfer.new_leaf_owner)?;
+
+        let (leaf_accounts, rest) = accounts.split_at(2);
+        let (soulbound_override, leaf_freeze) = (&leaf_accounts[0], &leaf_accounts[1]);
+        let (proof, rest) = rest.split_at(transfer.proof_length as usize);
+        accounts = rest;
+
+        assert_derivation(
+            &crate::id(),
+            soulbound_override,
+            &[
+                SOULBOUND_OVERRIDE_PREFIX.as_ref(),
+                merkle_tree.as_ref(),
+                &transfer.nonce.to_le_bytes(),
+            ],
+            Some(BubblegumError::InvalidBatchAccounts.into()),
+        )?;
+        assert_derivation(
+            &crate::id(),
+            leaf_freeze,
+            &[
+                LEAF_FREEZE_PREFIX.as_ref(),
+                merkle_tree.as_ref(),
+                &transfer.nonce.to_le_bytes(),
+            ],
+            Some(BubblegumError::InvalidBatchAccounts.into()),
+        )?;
+        assert_not_frozen(leaf_freeze)?;
+        assert_transferable(&ctx.accounts.tree_authority, soulbound_override)?;
+
+        let asset_id = get_asset_id(&merkle_tree, transfer.nonce);
+        assert_not_flagged(
+            &ctx.accounts.tree_deny_list,
+            &ctx.accounts.deny_list,
+            &asset_id,
+        )?;
+        let previous_leaf = LeafSchema::new_v0(
+            asset_id,
+            owner,
+            transfer.leaf_delegate,
+            transfer.nonce,
+            transfer.data_hash,
+            transfer.creator_hash,
+        );
+        // New leafs are instantiated with no delegate
+        let new_leaf = LeafSchema::new_v0(
+            asset_id,
+            transfer.new_leaf_owner,
+            transfer.new_leaf_owner,
+            transfer.nonce,
+            transfer.data_hash,
+            transfer.creator_hash,
+        );
+        emit!(new_leaf.to_event());
+        replace_leaf(
+            &merkle_tree,
+            *ctx.bumps.get("tree_authority").unwrap(),
+            &ctx.accounts.compression_program.to_account_info(),
+            &ctx.accounts.tree_authority.to_account_info(),
+            &ctx.accounts.merkle_tree.to_account_info(),
+            &ctx.accounts.log_wrapper.to_account_info(),
+            proof,
+            transfer.root,
+            previous_leaf.to_node(),
+            new_leaf.to_node(),
+            transfer.index,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use crate::{
+    state::{
+        leaf_schema::LeafSchema, AccountSize, PendingTransfer, TreeConfig, FEATURE_FLAGS_PREFIX,
+        LEAF_FREEZE_PREFIX, PENDING_TRANSFER_PREFIX, SOULBOUND_OVERRIDE_PREFIX,
+        TREE_DENY_LIST_PREFIX,
+    },
+    utils::{
+        assert_index_matches_nonce, assert_instruction_enabled, assert_not_flagged,
+        assert_not_frozen, assert_not_zero_address, assert_transfer_changes_leaf,
+        assert_transferable, assert_tree_not_paused, get_asset_id, log_event, replace_leaf,
+    },
+    InstructionName,
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression, Node};
+
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    nonce: u64,
+    _index: u32,
+)]
+pub struct TransferTimelocked<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub leaf_owner: Signer<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: This account is neither written to nor read from.
+    pub new_leaf_owner: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [
+        PENDING_TRANSFER_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    payer = leaf_owner,
+    space = PendingTransfer::SIZE,
+    bump
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    #[account(
+        seeds = [
+        SOULBOUND_OVERRIDE_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub soulbound_override: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [
+        LEAF_FREEZE_PREFIX.as_ref(),
+        merkle_tree.key().as_ref(),
+        & nonce.to_le_bytes()
+    ],
+    bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub leaf_freeze: UncheckedAccount<'info>,
+    #[account(
+        seeds = [TREE_DENY_LIST_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This account is checked in the instruction
+    pub tree_deny_list: UncheckedAccount<'info>,
+    /// CHECK: This account is checked against `tree_deny_list`, and only read if the tree has one
+    pub deny_list: UncheckedAccount<'info>,
+    #[account(seeds = [FEATURE_FLAGS_PREFIX.as_ref()], bump)]
+    /// CHECK: This account is checked in the instruction
+    pub feature_flags: UncheckedAccount<'info>,
+}
+
+// Takes the leaf out of the tree until the recipient claims it, which is only possible
+// starting with `unlock_slot`. Until then, the owner can cancel the transfer instead.
+pub fn handle_transfer_timelocked<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferTimelocked<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    unlock_slot: u64,
+) -> Result<()> {
+    assert_instruction_enabled(
+        &ctx.accounts.feature_flags,
+        InstructionName::TransferTimelocked,
+    )?;
+    assert_tree_not_paused(&ctx.accounts.tree_authority)?;
+    assert_index_matches_nonce(index, nonce)?;
+    assert_not_frozen(&ctx.accounts.leaf_freeze)?;
+    assert_transferable(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.soulbound_override,
+    )?;
+
+    let owner = ctx.accounts.leaf_owner.key();
+    let delegate = ctx.accounts.leaf_delegate.key();
+    assert_not_zero_address(&ctx.accounts.new_leaf_owner.key())?;
+    assert_transfer_changes_leaf(&owner, &delegate, &ctx.accounts.new_leaf_owner.key())?;
+    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+    assert_not_flagged(
+        &ctx.accounts.tree_deny_list,
+        &ctx.accounts.deny_list,
+        &asset_id,
+    )?;
+    let previous_leaf =
+        LeafSchema::new_v0(asset_id, owner, delegate, nonce, data_hash, creator_hash);
+    emit!(previous_leaf.to_event());
+    let new_leaf = Node::default();
+    log_event(
+        new_leaf.try_to_vec()?,
+        &ctx.accounts.log_wrapper,
+        ctx.accounts.tree_authority.logging_mode,
+    )?;
+    replace_leaf(
+        &merkle_tree.key(),
+        *ctx.bumps.get("tree_authority").unwrap(),
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf,
+        index,
+    )?;
+    ctx.accounts.pending_transfer.set_inner(PendingTransfer {
+        leaf_schema: previous_leaf,
+        index,
+        merkle_tree: merkle_tree.key(),
+        recipient: ctx.accounts.new_leaf_owner.key(),
+        unlock_slot,
+    });
+
+    Ok(())
+}
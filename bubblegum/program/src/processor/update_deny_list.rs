@@ -0,0 +1,44 @@
+use crate::{
+    error::BubblegumError,
+    state::{DenyList, DENY_LIST_PREFIX, MAX_DENY_LIST_LENGTH},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateDenyList<'info> {
+    #[account(
+        mut,
+        seeds = [DENY_LIST_PREFIX.as_ref(), authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub deny_list: Account<'info, DenyList>,
+    pub authority: Signer<'info>,
+}
+
+// Removals are applied before additions, and both ignore assets that are already absent or
+// present, respectively.
+pub fn handle_update_deny_list(
+    ctx: Context<UpdateDenyList>,
+    add: Vec<Pubkey>,
+    remove: Vec<Pubkey>,
+) -> Result<()> {
+    let asset_ids = &mut ctx.accounts.deny_list.asset_ids;
+
+    for asset_id in remove {
+        if let Ok(position) = asset_ids.binary_search(&asset_id) {
+            asset_ids.remove(position);
+        }
+    }
+
+    for asset_id in add {
+        if let Err(position) = asset_ids.binary_search(&asset_id) {
+            if asset_ids.len() == MAX_DENY_LIST_LENGTH {
+                return Err(BubblegumError::DenyListFull.into());
+            }
+            asset_ids.insert(position, asset_id);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use crate::{
+    error::BubblegumError,
+    hash::{hash_creators, hash_metadata},
+    state::{
+        leaf_schema::{LeafSchema, Version},
+        metaplex_adapter::MetadataArgs,
+        MetadataUpdateEvent, TreeConfig,
+    },
+    utils::{
+        assert_creators_are_sorted, assert_index_matches_nonce, assert_metadata_is_mpl_compatible,
+        assert_tree_not_paused, get_asset_id, log_event, replace_leaf,
+    },
+};
+use anchor_lang::prelude::*;
+use spl_account_compression::{data_wrapper::Wrapper, program::SplAccountCompression};
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    // The tree creator or delegate, which act as the update authority of the assets in the
+    // tree.
+    pub authority: Signer<'info>,
+    // The owner has to agree to the update as well. Otherwise the authority proposes it, and
+    // the owner approves it later (see `propose_metadata_update`).
+    pub leaf_owner: Signer<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_delegate: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, Wrapper>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+}
+
+/// Same rules as `update_metadata_accounts_v2` in Token Metadata, except that the collection
+/// can only be changed via the collection verification instructions. `authority` is the only
+/// signer that can verify a creator, or unverify one that was verified before.
+pub(crate) fn assert_metadata_update_is_valid(
+    current: &MetadataArgs,
+    new: &MetadataArgs,
+    authority: &Pubkey,
+) -> Result<()> {
+    if !current.is_mutable {
+        return Err(BubblegumError::MetadataImmutable.into());
+    }
+
+    assert_metadata_is_mpl_compatible(new)?;
+    assert_creators_are_sorted(&new.creators)?;
+
+    if current.primary_sale_happened && !new.primary_sale_happened {
+        return Err(BubblegumError::PrimarySaleCanOnlyBeFlippedToTrue.into());
+    }
+
+    if current.collection != new.collection {
+        return Err(BubblegumError::CollectionCannotBeChanged.into());
+    }
+
+    let was_verified = |address: &Pubkey| {
+        current
+            .creators
+            .iter()
+            .any(|c| c.verified && c.address == *address)
+    };
+
+    for creator in new.creators.iter() {
+        if creator.verified && !was_verified(&creator.address) && creator.address != *authority {
+            return Err(BubblegumError::CreatorDidNotVerify.into());
+        }
+    }
+
+    for creator in current.creators.iter().filter(|c| c.verified) {
+        let still_verified = new
+            .creators
+            .iter()
+            .any(|c| c.verified && c.address == creator.address);
+        if !still_verified && creator.address != *authority {
+            return Err(BubblegumError::CannotUnverifyAnotherCreator.into());
+        }
+    }
+
+    Ok(())
+}
+
+// Logs the full metadata that the new leaf of an update was hashed from.
+pub(crate) fn log_metadata_update<'info>(
+    tree_authority: &TreeConfig,
+    merkle_tree: &Pubkey,
+    nonce: u64,
+    metadata: MetadataArgs,
+    log_wrapper: &Program<'info, Wrapper>,
+) -> Result<()> {
+    let event = MetadataUpdateEvent {
+        version: Version::V1,
+        tree_id: *merkle_tree,
+        nonce,
+        metadata,
+    };
+    emit!(event);
+    log_event(
+        event.try_to_vec()?,
+        log_wrapper,
+        tree_authority.logging_mode,
+    )
+}
+
+pub fn handle_update_metadata<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateMetadata<'info>>,
+    root: [u8; 32],
+    nonce: u64,
+    index: u32,
+    current_metadata: MetadataArgs,
+    new_metadata: MetadataArgs,
+) -> Result<()> {
+    assert_tree_not_paused(&ctx.accounts.tree_authority)?;
+    assert_index_matches_nonce(index, nonce)?;
+
+    let authority = ctx.accounts.authority.key();
+    let tree_authority = &ctx.accounts.tree_authority;
+    require!(
+        authority == tree_authority.tree_creator || authority == tree_authority.tree_delegate,
+        BubblegumError::UpdateAuthorityIncorrect
+    );
+
+    assert_metadata_update_is_valid(&current_metadata, &new_metadata, &authority)?;
+
+    let owner = ctx.accounts.leaf_owner.key();
+    let delegate = ctx.accounts.leaf_delegate.key();
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+
+    // The leaf is only replaced if it matches the current metadata, which also proves that
+    // the signer owns it.
+    let asset_id = get_asset_id(&merkle_tree, nonce);
+    let previous_leaf = LeafSchema::new_v0(
+        asset_id,
+        owner,
+        delegate,
+        nonce,
+        hash_metadata(&current_metadata)?,
+        hash_creators(&current_metadata.creators)?,
+    );
+    let new_leaf = LeafSchema::new_v0(
+        asset_id,
+        owner,
+        delegate,
+        nonce,
+        hash_metadata(&new_metadata)?,
+        hash_creators(&new_metadata.creators)?,
+    );
+    log_metadata_update(
+        tree_authority,
+        &merkle_tree,
+        nonce,
+        new_metadata,
+        &ctx.accounts.log_wrapper,
+    )?;
+    emit!(new_leaf.to_event());
+    replace_leaf(
+        &merkle_tree,
+        *ctx.bumps.get("tree_authority").unwrap(),
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf.to_node(),
+        index,
+    )
+}
@@ -0,0 +1,22 @@
+use crate::{
+    hash::{hash_creators, hash_metadata},
+    state::metaplex_adapter::MetadataArgs,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct VerifyMetadataHash {}
+
+// Read-only helper for integrators to validate their hashing off-chain logic. The result
+// of the comparison is returned via the return data of the transaction.
+pub fn handle_verify_metadata_hash(
+    _ctx: Context<VerifyMetadataHash>,
+    metadata: MetadataArgs,
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+) -> Result<bool> {
+    Ok(
+        hash_metadata(&metadata)? == data_hash
+            && hash_creators(&metadata.creators)? == creator_hash,
+    )
+}
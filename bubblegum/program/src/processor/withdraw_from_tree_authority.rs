@@ -0,0 +1,44 @@
+use crate::{error::BubblegumError, state::TreeConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct WithdrawFromTreeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    pub tree_creator: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: this account only receives lamports
+    pub destination: UncheckedAccount<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub merkle_tree: UncheckedAccount<'info>,
+}
+
+// Lamports that accumulate on the tree authority (i.e. from fees or sponsorships) can be
+// withdrawn by the tree creator, as long as the account remains rent exempt, so it's
+// always able to sign for the tree.
+pub fn handle_withdraw_from_tree_authority(
+    ctx: Context<WithdrawFromTreeAuthority>,
+    amount: u64,
+) -> Result<()> {
+    let tree_authority = ctx.accounts.tree_authority.to_account_info();
+    let destination = ctx.accounts.destination.to_account_info();
+
+    let min_balance = Rent::get()?.minimum_balance(tree_authority.data_len());
+    let available = tree_authority.lamports().saturating_sub(min_balance);
+    if amount > available {
+        return Err(BubblegumError::InsufficientTreeAuthorityFunds.into());
+    }
+
+    **tree_authority.try_borrow_mut_lamports()? -= amount;
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(amount)
+        .ok_or(BubblegumError::NumericalOverflowError)?;
+
+    Ok(())
+}
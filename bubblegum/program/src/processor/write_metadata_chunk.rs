@@ -0,0 +1,57 @@
+use crate::{
+    error::BubblegumError,
+    state::{OnChainMetadata, ON_CHAIN_METADATA_HEADER_SIZE},
+};
+use anchor_lang::{prelude::*, solana_program::keccak};
+
+#[derive(Accounts)]
+pub struct WriteMetadataChunk<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut, has_one = authority)]
+    pub on_chain_metadata: Account<'info, OnChainMetadata>,
+}
+
+// Writes `chunk` to the JSON at `offset`, which can't be past the number of bytes written so far,
+// and discards anything written after the chunk. Until the JSON is complete, the authority can
+// thus rewrite it from any earlier offset, i.e. to replace a chunk that was written with the
+// wrong bytes. The last chunk checks the whole JSON against its hash, after which the JSON no
+// longer changes.
+pub fn handle_write_metadata_chunk(
+    ctx: Context<WriteMetadataChunk>,
+    offset: u32,
+    chunk: Vec<u8>,
+) -> Result<()> {
+    let on_chain_metadata = &mut ctx.accounts.on_chain_metadata;
+    require!(
+        !on_chain_metadata.is_complete,
+        BubblegumError::OnChainMetadataComplete
+    );
+    require!(
+        offset <= on_chain_metadata.bytes_written,
+        BubblegumError::InvalidMetadataChunkOffset
+    );
+
+    let end = (offset as usize)
+        .checked_add(chunk.len())
+        .ok_or(BubblegumError::NumericalOverflowError)?;
+    require!(
+        end <= on_chain_metadata.json_size as usize,
+        BubblegumError::OnChainMetadataTooLarge
+    );
+
+    let account = on_chain_metadata.to_account_info();
+    let mut data = account.try_borrow_mut_data()?;
+    let json = &mut data[ON_CHAIN_METADATA_HEADER_SIZE..];
+    json[offset as usize..end].copy_from_slice(&chunk);
+
+    if end == on_chain_metadata.json_size as usize {
+        require!(
+            keccak::hash(&json[..end]).to_bytes() == on_chain_metadata.json_hash,
+            BubblegumError::OnChainMetadataHashMismatch
+        );
+        on_chain_metadata.is_complete = true;
+    }
+    on_chain_metadata.bytes_written = end as u32;
+
+    Ok(())
+}
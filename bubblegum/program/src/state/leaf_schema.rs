@@ -1,15 +1,18 @@
-use anchor_lang::{prelude::*, solana_program::keccak};
+use crate::hash::hash_leaf;
+use anchor_lang::prelude::*;
 use spl_account_compression::Node;
 
 #[event]
+#[derive(Clone)]
 pub struct LeafSchemaEvent {
     pub version: Version,
     pub schema: LeafSchema,
     pub leaf_hash: [u8; 32],
 }
 
+// Every event starts with a `Version`, so clients can recognize events from newer releases
+// of the program (see `client::UnknownVersionEvent`). New events must follow suit.
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug)]
-
 pub enum Version {
     V1,
 }
@@ -105,25 +108,6 @@ impl LeafSchema {
     }
 
     pub fn to_node(&self) -> Node {
-        let hashed_leaf = match self {
-            LeafSchema::V1 {
-                id,
-                owner,
-                delegate,
-                nonce,
-                data_hash,
-                creator_hash,
-            } => keccak::hashv(&[
-                &[self.version().to_bytes()],
-                id.as_ref(),
-                owner.as_ref(),
-                delegate.as_ref(),
-                nonce.to_le_bytes().as_ref(),
-                data_hash.as_ref(),
-                creator_hash.as_ref(),
-            ])
-            .to_bytes(),
-        };
-        hashed_leaf
+        hash_leaf(self)
     }
 }
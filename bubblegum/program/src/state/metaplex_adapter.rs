@@ -102,3 +102,45 @@ pub struct MetadataArgs {
     pub token_program_version: TokenProgramVersion,
     pub creators: Vec<Creator>,
 }
+
+impl MetadataArgs {
+    /// The metadata as stored by Token Metadata once the asset is decompressed, with the name,
+    /// symbol, and uri padded to their maximum lengths.
+    pub fn puffed(&self) -> Self {
+        Self {
+            name: puff(&self.name, mpl_token_metadata::state::MAX_NAME_LENGTH),
+            symbol: puff(&self.symbol, mpl_token_metadata::state::MAX_SYMBOL_LENGTH),
+            uri: puff(&self.uri, mpl_token_metadata::state::MAX_URI_LENGTH),
+            ..self.clone()
+        }
+    }
+
+    /// Reverses `puffed`, i.e. to hash metadata read back from a Token Metadata account.
+    pub fn unpuffed(&self) -> Self {
+        Self {
+            name: unpuff(&self.name).to_owned(),
+            symbol: unpuff(&self.symbol).to_owned(),
+            uri: unpuff(&self.uri).to_owned(),
+            ..self.clone()
+        }
+    }
+
+    pub fn is_unpuffed(&self) -> bool {
+        [&self.name, &self.symbol, &self.uri]
+            .iter()
+            .all(|value| unpuff(value).len() == value.len())
+    }
+}
+
+/// Token Metadata pads (puffs) strings with null bytes up to `max_length`. Values that are
+/// already longer are left as they are.
+pub fn puff(value: &str, max_length: usize) -> String {
+    let mut puffed = value.to_owned();
+    let padding = max_length.saturating_sub(value.len());
+    puffed.extend(std::iter::repeat('\0').take(padding));
+    puffed
+}
+
+pub fn unpuff(value: &str) -> &str {
+    value.trim_end_matches('\0')
+}
@@ -2,15 +2,103 @@ pub mod leaf_schema;
 pub mod metaplex_adapter;
 pub mod metaplex_anchor;
 
-use anchor_lang::prelude::*;
+use crate::{error::BubblegumError, pda, InstructionName};
+use anchor_lang::{prelude::*, Discriminator};
 use leaf_schema::{LeafSchema, Version};
-use metaplex_adapter::MetadataArgs;
+use metaplex_adapter::{Creator, MetadataArgs};
 
+// Included some spare room at the end for new `TreeConfig` fields, which is now used up, so
+// further per-tree settings have to live in accounts of their own (like `MintPrice`).
 pub const TREE_AUTHORITY_SIZE: usize = 88 + 8;
+// Bumped at every release that changes the semantics of existing trees.
+pub const PROGRAM_VERSION: u8 = 1;
 pub const VOUCHER_SIZE: usize = 8 + 1 + 32 + 32 + 32 + 8 + 32 + 32 + 4 + 32;
 pub const VOUCHER_PREFIX: &str = "voucher";
+pub const PENDING_TRANSFER_SIZE: usize = VOUCHER_SIZE + 32 + 8;
+pub const PENDING_TRANSFER_PREFIX: &str = "pending_transfer";
+pub const SOULBOUND_OVERRIDE_SIZE: usize = 8 + 32 + 8 + 1;
+pub const SOULBOUND_OVERRIDE_PREFIX: &str = "soulbound_override";
+pub const DELEGATE_EXPIRY_SIZE: usize = 8 + 32 + 8 + 32 + 32 + 1 + 8;
+pub const DELEGATE_EXPIRY_PREFIX: &str = "delegate_expiry";
+pub const LEAF_FREEZE_SIZE: usize = 8 + 32 + 8 + 32 + 32;
+pub const LEAF_FREEZE_PREFIX: &str = "leaf_freeze";
+pub const ON_CHAIN_METADATA_HEADER_SIZE: usize = 8 + 32 + 32 + 32 + 4 + 4 + 1;
+pub const ON_CHAIN_METADATA_PREFIX: &str = "on_chain_metadata";
+// Accounts created by a program are limited to 10 KiB.
+pub const MAX_ON_CHAIN_METADATA_SIZE: usize = 10_240 - ON_CHAIN_METADATA_HEADER_SIZE;
+// Assets with on-chain metadata have a uri consisting of this prefix followed by the base58
+// encoded keccak hash of the JSON, so the data hash of the leaf commits to the JSON as well.
+pub const ON_CHAIN_METADATA_URI_PREFIX: &str = "onchain://";
+pub const ASSET_RECEIPT_SIZE: usize = 8 + 32 + 8 + 32;
+pub const ASSET_RECEIPT_PREFIX: &str = "asset_receipt";
+pub const BURN_RECEIPT_SIZE: usize = 8 + 32 + 32 + 8;
+pub const BURN_RECEIPT_PREFIX: &str = "burn_receipt";
+pub const CREATOR_TRANSFER_SIZE: usize = 8 + 32 + 32 + 1;
+pub const CREATOR_TRANSFER_PREFIX: &str = "creator_transfer";
+pub const FEATURE_FLAGS_SIZE: usize = 8 + 8;
+pub const FEATURE_FLAGS_PREFIX: &str = "feature_flags";
+pub const MINT_PRICE_SIZE: usize = 8 + 32 + 8 + 32 + 32 + 8;
+pub const MINT_PRICE_PREFIX: &str = "mint_price";
+pub const METADATA_PROPOSAL_SIZE: usize = 8 + 32 + 8 + 32 + 32 + 32;
+pub const METADATA_PROPOSAL_PREFIX: &str = "metadata_proposal";
+// Keeps the list within the size of accounts created by a program, and cheap enough to
+// deserialize on every transfer.
+pub const MAX_DENY_LIST_LENGTH: usize = 256;
+pub const DENY_LIST_SIZE: usize = 8 + 32 + 4 + 32 * MAX_DENY_LIST_LENGTH;
+pub const DENY_LIST_PREFIX: &str = "deny_list";
+pub const TREE_DENY_LIST_SIZE: usize = 8 + 32 + 32;
+pub const TREE_DENY_LIST_PREFIX: &str = "tree_deny_list";
+pub const TREE_DELEGATE_RECORD_SIZE: usize = 8 + 32 + 32;
+pub const TREE_DELEGATE_RECORD_PREFIX: &str = "tree_delegate_record";
+// `TreeConfig::companion_accounts` bits.
+pub const TREE_HAS_MINT_PRICE: u8 = 1 << 0;
+pub const TREE_HAS_DENY_LIST: u8 = 1 << 1;
+pub const TREE_HAS_FEATURE_FLAGS: u8 = 1 << 2;
 pub const ASSET_PREFIX: &str = "asset";
 pub const COLLECTION_CPI_PREFIX: &str = "collection_cpi";
+pub const TREE_METADATA_PREFIX: &str = "tree_metadata";
+pub const MAX_TREE_NAME_LENGTH: usize = 32;
+pub const MAX_TREE_URI_LENGTH: usize = 200;
+// Keeps `mint_airdrop` within the compute and transaction size limits.
+pub const MAX_AIRDROP_BATCH_SIZE: usize = 16;
+// Same as above for `mint_batch_v1`. Transactions fit fewer messages than this when the
+// metadata has several creators or long fields.
+pub const MAX_MINT_BATCH_SIZE: usize = 16;
+// Same as above for `transfer_batch`, where proofs usually limit the batch size first.
+pub const MAX_TRANSFER_BATCH_SIZE: usize = 8;
+pub const TREE_METADATA_SIZE: usize = 8 + 32 + 4 + MAX_TREE_NAME_LENGTH + 4 + MAX_TREE_URI_LENGTH;
+
+/// Accounts with a fixed size (including the discriminator) that are created by the program.
+pub trait AccountSize {
+    const SIZE: usize;
+}
+
+macro_rules! account_size {
+    ($($account:ty => $size:expr),* $(,)?) => {
+        $(impl AccountSize for $account {
+            const SIZE: usize = $size;
+        })*
+    };
+}
+
+account_size! {
+    TreeConfig => TREE_AUTHORITY_SIZE,
+    TreeMetadata => TREE_METADATA_SIZE,
+    Voucher => VOUCHER_SIZE,
+    PendingTransfer => PENDING_TRANSFER_SIZE,
+    SoulboundOverride => SOULBOUND_OVERRIDE_SIZE,
+    DelegateExpiry => DELEGATE_EXPIRY_SIZE,
+    LeafFreeze => LEAF_FREEZE_SIZE,
+    AssetReceipt => ASSET_RECEIPT_SIZE,
+    BurnReceipt => BURN_RECEIPT_SIZE,
+    CreatorTransfer => CREATOR_TRANSFER_SIZE,
+    FeatureFlags => FEATURE_FLAGS_SIZE,
+    MintPrice => MINT_PRICE_SIZE,
+    MetadataProposal => METADATA_PROPOSAL_SIZE,
+    DenyList => DENY_LIST_SIZE,
+    TreeDenyList => TREE_DENY_LIST_SIZE,
+    TreeDelegateRecord => TREE_DELEGATE_RECORD_SIZE,
+}
 
 #[account]
 #[derive(Copy, Debug)]
@@ -19,17 +107,152 @@ pub struct TreeConfig {
     pub tree_delegate: Pubkey,
     pub total_mint_capacity: u64,
     pub num_minted: u64,
+    // Whether `mint_v1_hashed` can be used to mint into the tree.
+    pub is_hash_only: bool,
+    pub logging_mode: LoggingMode,
+    // Whether leaves of the tree are soulbound, unless a `SoulboundOverride` says otherwise. Can
+    // only be changed before the first mint.
+    pub is_non_transferable: bool,
+    // Set at creation only. Permanently locks the creator, the delegate, and the tree-level
+    // settings and metadata (see `utils::assert_tree_governance_mutable`).
+    pub is_creator_immutable: bool,
+    // The latest `PROGRAM_VERSION` that wrote to the config, starting with the one the tree was
+    // created under. Zero for trees that were not written to since versions were introduced.
+    pub program_version: u8,
+    // Whether anyone can mint into the tree, instead of only the tree creator and delegate.
+    pub is_public: bool,
+    // The companion PDAs of the tree that instructions with a fixed account layout have to be
+    // passed at the front of their remaining accounts (see `utils::take_companion_accounts`), as
+    // a set of `TREE_HAS_*` bits. `TREE_HAS_MINT_PRICE` is set while the `MintPrice` of the tree
+    // is non-zero (in lamports or tokens), `TREE_HAS_DENY_LIST` while the `TreeDenyList` of
+    // the tree points to a deny list, and `TREE_HAS_FEATURE_FLAGS` once `set_feature_flags`
+    // applied the flags of the deployment to the tree.
+    pub companion_accounts: u8,
+    // Emergency stop set by the creator, which blocks mints, transfers, burns and delegations
+    // until it's cleared (see `utils::assert_tree_not_paused`).
+    pub is_paused: bool,
+}
+
+/// Selects whether the events emitted for a tree are also sent as instruction data to the
+/// noop program, where indexers can read them without running into log truncation. The
+/// compression program logs change logs via the noop program regardless of the mode.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoggingMode {
+    // Trees created before logging modes were introduced have a zero in place of this field,
+    // so this variant must remain first.
+    Noop,
+    // Events only show up in the program logs.
+    None,
+}
+
+impl Default for LoggingMode {
+    fn default() -> Self {
+        LoggingMode::Noop
+    }
 }
 
 impl TreeConfig {
+    pub fn record_program_version(&mut self) {
+        self.program_version = self.program_version.max(PROGRAM_VERSION);
+    }
+
     pub fn increment_mint_count(&mut self) {
         self.num_minted = self.num_minted.saturating_add(1);
     }
 
     pub fn contains_mint_capacity(&self, requested_capacity: u64) -> bool {
-        let remaining_mints = self.total_mint_capacity.saturating_sub(self.num_minted);
-        requested_capacity <= remaining_mints
+        requested_capacity <= self.remaining_capacity()
+    }
+
+    /// How many more assets can be minted into the tree.
+    pub fn remaining_capacity(&self) -> u64 {
+        self.total_mint_capacity.saturating_sub(self.num_minted)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.remaining_capacity() == 0
     }
+
+    pub fn is_public(&self) -> bool {
+        self.is_public
+    }
+
+    /// Whether `key` is the creator or the delegate of the tree, i.e. mints signed by it are
+    /// not public mints.
+    pub fn is_authority(&self, key: &Pubkey) -> bool {
+        *key == self.tree_creator || *key == self.tree_delegate
+    }
+
+    pub fn has_mint_price(&self) -> bool {
+        self.companion_accounts & TREE_HAS_MINT_PRICE != 0
+    }
+
+    pub fn has_deny_list(&self) -> bool {
+        self.companion_accounts & TREE_HAS_DENY_LIST != 0
+    }
+
+    pub fn has_feature_flags(&self) -> bool {
+        self.companion_accounts & TREE_HAS_FEATURE_FLAGS != 0
+    }
+
+    pub fn set_companion_account(&mut self, bit: u8, is_present: bool) {
+        if is_present {
+            self.companion_accounts |= bit;
+        } else {
+            self.companion_accounts &= !bit;
+        }
+    }
+
+    /// Whether a mint signed by `key` has to pay the mint price of the tree.
+    pub fn charges_mint_price(&self, key: &Pubkey) -> bool {
+        self.has_mint_price() && !self.is_authority(key)
+    }
+
+    /// The `MintPrice` PDA with the fees that public mints into `merkle_tree` pay, if there are
+    /// any.
+    pub fn fees(&self, merkle_tree: &Pubkey) -> Option<Pubkey> {
+        self.has_mint_price()
+            .then(|| pda::find_mint_price(merkle_tree))
+    }
+}
+
+/// Human-readable label for a tree, stored in a companion PDA so that the size of
+/// `TreeConfig` stays fixed.
+#[account]
+#[derive(Debug)]
+pub struct TreeMetadata {
+    pub merkle_tree: Pubkey,
+    pub name: String,
+    pub uri: String,
+}
+
+/// The fields of `MetadataArgs` that are passed in the clear to `mint_v1_hashed`, while the
+/// complete metadata is only committed to via its hash. Creators and the fee are included so
+/// that the program can still compute the leaf hashes itself (and enforce that verified
+/// creators have signed).
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Clone)]
+pub struct HashedMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub seller_fee_basis_points: u16,
+    pub creators: Vec<Creator>,
+    // Output of `hash::hash_metadata_args` for the full metadata.
+    pub metadata_args_hash: [u8; 32],
+}
+
+/// One of the transfers of `transfer_batch`. Its accounts are passed as remaining accounts, in
+/// the same order as the transfers: the `SoulboundOverride` and `LeafFreeze` PDAs of the leaf,
+/// followed by the `proof_length` nodes of its proof.
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct BatchTransfer {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+    pub leaf_delegate: Pubkey,
+    pub new_leaf_owner: Pubkey,
+    pub proof_length: u8,
 }
 
 #[account]
@@ -48,19 +271,397 @@ impl Voucher {
             merkle_tree,
         }
     }
+
+    /// Deserializes the data of a voucher account without trusting it. The length and the
+    /// discriminator are checked before decoding, and malformed data results in an error
+    /// instead of a panic or a partially decoded voucher.
+    pub fn safe_deserialize(data: &[u8]) -> Result<Self> {
+        if data.len() < 8 {
+            return Err(ErrorCode::AccountDiscriminatorNotFound.into());
+        }
+
+        let (disc, rest) = data.split_at(8);
+        if disc != Voucher::discriminator() {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+
+        if data.len() != VOUCHER_SIZE {
+            return Err(ErrorCode::AccountDidNotDeserialize.into());
+        }
+
+        Voucher::try_from_slice(rest).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
+/// Holds a leaf that was taken out of the tree by `transfer_timelocked`, until it is either
+/// claimed by the recipient (at or after `unlock_slot`) or returned to the owner by
+/// cancelling the transfer (before `unlock_slot`).
+#[account]
+#[derive(Copy)]
+pub struct PendingTransfer {
+    pub leaf_schema: LeafSchema,
+    pub index: u32,
+    pub merkle_tree: Pubkey,
+    pub recipient: Pubkey,
+    pub unlock_slot: u64,
+}
+
+/// Overrides the `is_non_transferable` setting of the tree for a single leaf. Only exists for
+/// leaves where the tree creator or delegate have called `set_soulbound_override` right after
+/// minting them, and never changes afterwards.
+#[account]
+#[derive(Copy, Debug)]
+pub struct SoulboundOverride {
+    pub merkle_tree: Pubkey,
+    pub nonce: u64,
+    pub is_non_transferable: bool,
+}
+
+/// Limits how long `delegate` can act on behalf of the owner of a leaf. Only applies while
+/// `owner` still owns the leaf and `delegate` is still its delegate, so a record left behind by
+/// a previous owner never affects later delegations. `owner` paid for the record (or paid the
+/// rent back to the previous owner when taking it over) and can close it with
+/// `close_delegate_expiry`.
+#[account]
+#[derive(Copy, Debug)]
+pub struct DelegateExpiry {
+    pub merkle_tree: Pubkey,
+    pub nonce: u64,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    // `None` means the delegate never expires.
+    pub expiry_slot: Option<u64>,
+}
+
+/// Marks a leaf as frozen by `delegate`, which can thaw it at any time. `owner` can only thaw it
+/// once the delegate has expired (see `DelegateExpiry`). Frozen leaves cannot be transferred,
+/// burned, redeemed, or delegated, so the owner and delegate stay the same until the record is
+/// closed by `thaw`.
+#[account]
+#[derive(Copy, Debug)]
+pub struct LeafFreeze {
+    pub merkle_tree: Pubkey,
+    pub nonce: u64,
+    pub delegate: Pubkey,
+    pub owner: Pubkey,
+}
+
+/// The price of a public mint into a tree, which `mint_v1` (and its variants that take the same
+/// accounts) transfer from the payer to `treasury`. The price can be in lamports, in SPL tokens
+/// of `token_mint`, or both. Tokens go to a token account owned by `treasury`. Mints signed by
+/// the tree creator or delegate are free.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct MintPrice {
+    pub merkle_tree: Pubkey,
+    pub lamports: u64,
+    pub treasury: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_amount: u64,
+}
+
+impl MintPrice {
+    /// The lamports due for minting `quantity` assets.
+    pub fn lamports_for(&self, quantity: u64) -> Result<u64> {
+        self.lamports
+            .checked_mul(quantity)
+            .ok_or_else(|| BubblegumError::NumericalOverflowError.into())
+    }
+
+    /// The amount of `token_mint` tokens due for minting `quantity` assets.
+    pub fn token_amount_for(&self, quantity: u64) -> Result<u64> {
+        self.token_amount
+            .checked_mul(quantity)
+            .ok_or_else(|| BubblegumError::NumericalOverflowError.into())
+    }
+}
+
+/// Stores the full JSON metadata of an asset on-chain. The JSON itself follows this header in
+/// the account data, and is written via `write_metadata_chunk`, which can rewrite earlier parts
+/// of it until it's complete. Readers must ignore the contents until `is_complete` is set, which
+/// only happens once the JSON matches `json_hash`.
+#[account]
+#[derive(Copy, Debug)]
+pub struct OnChainMetadata {
+    pub asset_id: Pubkey,
+    // The creator that is allowed to write the JSON.
+    pub authority: Pubkey,
+    pub json_hash: [u8; 32],
+    pub json_size: u32,
+    pub bytes_written: u32,
+    pub is_complete: bool,
+}
+
+impl OnChainMetadata {
+    /// The uri that assets with the given JSON must have.
+    pub fn uri(json_hash: &[u8; 32]) -> String {
+        format!(
+            "{}{}",
+            ON_CHAIN_METADATA_URI_PREFIX,
+            Pubkey::new_from_array(*json_hash)
+        )
+    }
+}
+
+/// An optional account that is created alongside an asset (see `mint_v1_with_receipt`), for
+/// programs that need an address to attach data to for a given asset id. The owner is only a
+/// snapshot taken at mint time, and is not updated when the asset changes hands.
+///
+/// Receipts are rent exempt, which costs about 0.00145 SOL each, versus nothing at all for
+/// plain compressed assets. The current owner of the asset can close the receipt to get the
+/// rent back, or the owner who burned it once the asset is burned via `burn_with_receipt` (see
+/// `close_asset_receipt`).
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct AssetReceipt {
+    pub merkle_tree: Pubkey,
+    pub nonce: u64,
+    pub owner: Pubkey,
+}
+
+/// Proof that `owner` burned the asset with the given id, for programs that reward burning
+/// assets (see `burn_with_receipt`). The owner can close the receipt at any time, so programs
+/// that act on receipts must keep track of the ones they already honored.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct BurnReceipt {
+    pub asset_id: Pubkey,
+    pub owner: Pubkey,
+    pub slot: u64,
+}
+
+/// A pending change of the creator of a tree, proposed by the current creator via
+/// `propose_tree_creator`. The change only takes effect once `new_tree_creator` signs
+/// `accept_tree_creator`, so a typo in the new key cannot lock the creator out of the tree.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct CreatorTransfer {
+    pub merkle_tree: Pubkey,
+    pub new_tree_creator: Pubkey,
+    // Whether accepting also makes the new creator the tree delegate, so that a delegate set up
+    // by the previous creator doesn't outlive the transfer.
+    pub reset_tree_delegate: bool,
+}
+
+/// A metadata update proposed by the tree creator or delegate, which only gets applied once the
+/// owner of the leaf approves it via `approve_metadata_update`. Only the hashes of the new
+/// metadata are stored; the owner passes the full metadata when approving.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct MetadataProposal {
+    pub merkle_tree: Pubkey,
+    pub nonce: u64,
+    // Receives the rent once the proposal is approved or cancelled.
+    pub proposer: Pubkey,
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+}
+
+/// Asset ids that are blocked from being transferred, i.e. because they were reported stolen.
+/// Maintained by `authority`, which can be a different party than the creators of the trees
+/// that opt into the list via `set_tree_deny_list`. Burning flagged assets is still allowed.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct DenyList {
+    pub authority: Pubkey,
+    // Sorted, so that transfers can binary search it.
+    pub asset_ids: Vec<Pubkey>,
+}
+
+impl DenyList {
+    pub fn contains(&self, asset_id: &Pubkey) -> bool {
+        self.asset_ids.binary_search(asset_id).is_ok()
+    }
+}
+
+/// The deny list that the transfers of a tree consult. Trees without this account, or with
+/// `deny_list` set to the default address, don't have one.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct TreeDenyList {
+    pub merkle_tree: Pubkey,
+    pub deny_list: Pubkey,
+}
+
+/// Registers `delegate` as an additional delegate of the tree, which can mint like the
+/// `tree_delegate` of the `TreeConfig`. Added and removed by the tree creator via
+/// `add_tree_delegate` and `remove_tree_delegate`, so that several signers can mint into the
+/// tree in parallel.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct TreeDelegateRecord {
+    pub merkle_tree: Pubkey,
+    pub delegate: Pubkey,
+}
+
+/// Instructions disabled for the whole deployment of the program, so that legacy instruction
+/// paths can be turned off in stages once their replacements have shipped, without having to
+/// redeploy. There is a single instance per deployment, which only the upgrade authority of
+/// the program can change (see `set_feature_flags`). Until it is first set, every instruction
+/// is enabled.
+///
+/// Only the instructions in `FeatureFlags::ENFORCED` can be disabled. Disabling `mint_v1` or
+/// `transfer` also disables the other instructions that mint or transfer assets, so that they
+/// can't be used to get around it. Instructions with a fixed account layout only see the flags
+/// when they are passed along (see `utils::take_companion_accounts`), which is required for the
+/// trees that `set_feature_flags` was called with.
+#[account]
+#[derive(Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeatureFlags {
+    // Bit `instruction_bit(instruction)` is set when `instruction` is disabled.
+    pub disabled_instructions: u64,
+}
+
+impl FeatureFlags {
+    /// The instructions that check the flags, and so have a bit assigned.
+    pub const ENFORCED: [InstructionName; 10] = [
+        InstructionName::MintV1,
+        InstructionName::Transfer,
+        InstructionName::MintV1Hashed,
+        InstructionName::TransferTimelocked,
+        InstructionName::ClaimTimelockedTransfer,
+        InstructionName::MintAirdrop,
+        InstructionName::MintV1WithReceipt,
+        InstructionName::MintToCollectionV1,
+        InstructionName::MintBatchV1,
+        InstructionName::TransferBatch,
+    ];
+
+    // Bits are assigned explicitly rather than derived from the order of `InstructionName`, so
+    // that masks which are already set keep disabling the same instructions. New instructions
+    // take the next free bit, and there is room for 64 of them.
+    pub fn instruction_bit(instruction: InstructionName) -> Option<u32> {
+        let bit = match instruction {
+            InstructionName::MintV1 => 1,
+            InstructionName::Transfer => 4,
+            InstructionName::MintV1Hashed => 17,
+            InstructionName::TransferTimelocked => 21,
+            InstructionName::ClaimTimelockedTransfer => 22,
+            InstructionName::MintAirdrop => 24,
+            InstructionName::MintV1WithReceipt => 30,
+            InstructionName::MintToCollectionV1 => 39,
+            InstructionName::MintBatchV1 => 40,
+            InstructionName::TransferBatch => 41,
+            _ => return None,
+        };
+        Some(bit)
+    }
+
+    pub fn instruction_mask(instruction: InstructionName) -> u64 {
+        Self::instruction_bit(instruction).map_or(0, |bit| 1 << bit)
+    }
+
+    /// The bits that can be set, one for each of the `ENFORCED` instructions.
+    pub fn valid_mask() -> u64 {
+        Self::ENFORCED.iter().fold(0, |mask, instruction| {
+            mask | Self::instruction_mask(*instruction)
+        })
+    }
+
+    // The legacy instruction that `instruction` mints or transfers assets like.
+    fn base_instruction(instruction: InstructionName) -> InstructionName {
+        match instruction {
+            InstructionName::MintV1Hashed
+            | InstructionName::MintAirdrop
+            | InstructionName::MintV1WithReceipt
+            | InstructionName::MintToCollectionV1
+            | InstructionName::MintBatchV1 => InstructionName::MintV1,
+            InstructionName::TransferTimelocked
+            | InstructionName::ClaimTimelockedTransfer
+            | InstructionName::TransferBatch => InstructionName::Transfer,
+            _ => instruction,
+        }
+    }
+
+    pub fn is_enabled(&self, instruction: InstructionName) -> bool {
+        let mask = Self::instruction_mask(instruction)
+            | Self::instruction_mask(Self::base_instruction(instruction));
+        self.disabled_instructions & mask == 0
+    }
 }
 
 #[event]
+#[derive(Clone)]
 pub struct NewNFTEvent {
     pub version: Version,
     pub metadata: MetadataArgs,
     pub nonce: u64,
 }
 
+/// Emitted by `mint_v1_hashed` in place of `NewNFTEvent`. The full metadata is delivered to
+/// indexers off-chain, and must hash to `metadata.metadata_args_hash`.
 #[event]
+#[derive(Clone)]
+pub struct MetadataAttestationEvent {
+    pub version: Version,
+    pub metadata: HashedMetadataArgs,
+    pub nonce: u64,
+}
+
+/// Emitted by `update_metadata` and `approve_metadata_update` along with the new leaf, which
+/// only holds the hashes, so that indexers can follow the metadata of assets after minting.
+#[event]
+#[derive(Clone)]
+pub struct MetadataUpdateEvent {
+    pub version: Version,
+    pub tree_id: Pubkey,
+    pub nonce: u64,
+    pub metadata: MetadataArgs,
+}
+
+/// Compression program instructions that Bubblegum invokes on a tree.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressionOperation {
+    Append,
+    Replace,
+    Verify,
+}
+
+/// Emitted right before failing an instruction whose change would be rejected by the
+/// compression program, for the failures that can be detected up front (currently appending
+/// to a tree that already holds `num_leaves == 2^max_depth` leaves, see
+/// `utils::assert_tree_not_full`). Anything else is reported by the compression program.
+#[event]
+#[derive(Clone)]
+pub struct CompressionFailureEvent {
+    pub version: Version,
+    pub tree_id: Pubkey,
+    pub operation: CompressionOperation,
+    pub num_leaves: u64,
+    pub max_depth: u32,
+}
+
+#[event]
+#[derive(Clone)]
 pub struct NFTDecompressionEvent {
     pub version: Version,
     pub id: Pubkey,
     pub tree_id: Pubkey,
     pub nonce: u64,
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CreatorTransferStage {
+    Proposed,
+    Accepted,
+    Cancelled,
+}
+
+#[event]
+#[derive(Clone)]
+pub struct TreeCreatorTransferEvent {
+    pub version: Version,
+    pub tree_id: Pubkey,
+    pub tree_creator: Pubkey,
+    pub new_tree_creator: Pubkey,
+    pub stage: CreatorTransferStage,
+}
+
+#[event]
+#[derive(Clone)]
+pub struct TreeMetadataEvent {
+    pub version: Version,
+    pub tree_id: Pubkey,
+    pub name: String,
+    pub uri: String,
+}
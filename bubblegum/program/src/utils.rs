@@ -1,9 +1,31 @@
-use crate::{error::BubblegumError, state::metaplex_adapter::MetadataArgs, ASSET_PREFIX};
+use crate::{
+    error::BubblegumError,
+    state::{
+        leaf_schema::Version,
+        metaplex_adapter::{unpuff, Creator, MetadataArgs},
+        CompressionFailureEvent, CompressionOperation, DelegateExpiry, DenyList, FeatureFlags,
+        HashedMetadataArgs, LoggingMode, MintPrice, SoulboundOverride, TreeConfig, TreeDenyList,
+    },
+    InstructionName, ASSET_PREFIX,
+};
 use anchor_lang::{
     prelude::*,
-    solana_program::{program_memory::sol_memcmp, pubkey::PUBKEY_BYTES},
+    solana_program::{
+        instruction::Instruction,
+        program::{invoke, invoke_signed},
+        program_memory::sol_memcmp,
+        program_pack::Pack,
+        pubkey::PUBKEY_BYTES,
+        system_instruction::{self, MAX_PERMITTED_DATA_LENGTH},
+    },
+    InstructionData,
+};
+use spl_account_compression::{
+    data_wrapper::{wrap_event, Wrapper},
+    state::{ConcurrentMerkleTreeHeader, CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1},
+    Node,
 };
-use spl_account_compression::Node;
+use std::{collections::HashSet, mem::size_of};
 
 /// Assert that the provided MetadataArgs are compatible with MPL `Data`
 pub fn assert_metadata_is_mpl_compatible(metadata: &MetadataArgs) -> Result<()> {
@@ -19,18 +41,57 @@ pub fn assert_metadata_is_mpl_compatible(metadata: &MetadataArgs) -> Result<()>
         return Err(BubblegumError::MetadataUriTooLong.into());
     }
 
+    // Token Metadata pads these with null bytes, which would be trimmed when reading them
+    // back, so they must not be part of the hashed metadata.
+    if !metadata.is_unpuffed() {
+        return Err(BubblegumError::MetadataPadded.into());
+    }
+
     if metadata.seller_fee_basis_points > 10000 {
         return Err(BubblegumError::MetadataBasisPointsTooHigh.into());
     }
-    if !metadata.creators.is_empty() {
-        if metadata.creators.len() > mpl_token_metadata::state::MAX_CREATOR_LIMIT - 1 {
+
+    assert_creators_are_mpl_compatible(&metadata.creators)
+}
+
+/// Same as `assert_metadata_is_mpl_compatible`, for the fields that are available in the clear
+/// when minting with pre-hashed metadata.
+pub fn assert_hashed_metadata_is_mpl_compatible(metadata: &HashedMetadataArgs) -> Result<()> {
+    if metadata.name.len() > mpl_token_metadata::state::MAX_NAME_LENGTH {
+        return Err(BubblegumError::MetadataNameTooLong.into());
+    }
+
+    if metadata.symbol.len() > mpl_token_metadata::state::MAX_SYMBOL_LENGTH {
+        return Err(BubblegumError::MetadataSymbolTooLong.into());
+    }
+
+    if unpuff(&metadata.name).len() != metadata.name.len()
+        || unpuff(&metadata.symbol).len() != metadata.symbol.len()
+    {
+        return Err(BubblegumError::MetadataPadded.into());
+    }
+
+    if metadata.seller_fee_basis_points > 10000 {
+        return Err(BubblegumError::MetadataBasisPointsTooHigh.into());
+    }
+
+    assert_creators_are_mpl_compatible(&metadata.creators)
+}
+
+// Creators with a zero share are allowed, like they are by Token Metadata, where they are used
+// to have a verified creator that does not receive royalties. They are hashed like any other
+// creator (see `hash_creators`).
+fn assert_creators_are_mpl_compatible(creators: &[Creator]) -> Result<()> {
+    if !creators.is_empty() {
+        if creators.len() > mpl_token_metadata::state::MAX_CREATOR_LIMIT - 1 {
             return Err(BubblegumError::CreatorsTooLong.into());
         }
 
         let mut total: u8 = 0;
-        for i in 0..metadata.creators.len() {
-            let creator = metadata.creators[i];
-            for iter in metadata.creators.iter().skip(i + 1) {
+        for i in 0..creators.len() {
+            let creator = creators[i];
+            assert_not_zero_address(&creator.address)?;
+            for iter in creators.iter().skip(i + 1) {
                 if iter.address == creator.address {
                     return Err(BubblegumError::DuplicateCreatorAddress.into());
                 }
@@ -46,6 +107,119 @@ pub fn assert_metadata_is_mpl_compatible(metadata: &MetadataArgs) -> Result<()>
     Ok(())
 }
 
+/// Returns the number of proof nodes expected for leaves of `merkle_tree`, which is the depth
+/// of the tree minus the depth of its canopy (the upper levels of the tree that are stored
+/// in the account, after the tree itself).
+pub fn get_proof_length(merkle_tree: &AccountInfo) -> Result<usize> {
+    let data = merkle_tree.try_borrow_data()?;
+    let (max_depth, max_buffer_size) = read_tree_dimensions(&data)?;
+    let canopy_depth = canopy_depth(data.len(), max_depth, max_buffer_size)?;
+    Ok(max_depth.saturating_sub(canopy_depth))
+}
+
+/// Checks that a merkle tree account of `account_size` bytes fits within the maximum account
+/// size, and that the canopy it leaves room for is shallower than the tree itself.
+pub fn assert_valid_tree_account_size(
+    account_size: usize,
+    max_depth: usize,
+    max_buffer_size: usize,
+) -> Result<()> {
+    if account_size > MAX_PERMITTED_DATA_LENGTH as usize {
+        return Err(BubblegumError::AccountTooLarge.into());
+    }
+    let canopy_depth = canopy_depth(account_size, max_depth, max_buffer_size)?;
+    if canopy_depth > 0 && canopy_depth >= max_depth {
+        return Err(BubblegumError::CanopyTooDeep.into());
+    }
+    Ok(())
+}
+
+/// Returns the depth of the canopy stored after a tree with the given dimensions in an account
+/// of `account_size` bytes. Fails if the room left after the tree does not hold a whole canopy,
+/// including when the dimensions are so large that the size of the tree overflows.
+fn canopy_depth(account_size: usize, max_depth: usize, max_buffer_size: usize) -> Result<usize> {
+    let tree_size = path_size(max_depth)
+        .and_then(|path_size| path_size.checked_mul(max_buffer_size.checked_add(1)?))
+        .and_then(|size| size.checked_add(3 * size_of::<u64>()))
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let canopy_size = account_size
+        .checked_sub(CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1)
+        .and_then(|size| size.checked_sub(tree_size))
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    // A canopy of depth `d` stores the `2^(d + 1) - 2` nodes below the root.
+    let canopy_nodes = canopy_size / size_of::<Node>();
+    if canopy_size % size_of::<Node>() != 0 || !(canopy_nodes + 2).is_power_of_two() {
+        return Err(ProgramError::InvalidAccountData.into());
+    }
+    Ok((canopy_nodes + 2).trailing_zeros() as usize - 1)
+}
+
+/// Returns the max depth and max buffer size of the tree stored in `data`.
+fn read_tree_dimensions(data: &[u8]) -> Result<(usize, usize)> {
+    if data.len() < CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1 {
+        return Err(ProgramError::InvalidAccountData.into());
+    }
+    let header =
+        ConcurrentMerkleTreeHeader::try_from_slice(&data[..CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1])?;
+    Ok((
+        header.get_max_depth() as usize,
+        header.get_max_buffer_size() as usize,
+    ))
+}
+
+// The tree consists of three `u64` fields (the sequence number, the active index, and the
+// buffer size), followed by the change log buffer and the rightmost proof, which both take
+// up a root/leaf, `max_depth` nodes, and a padded index.
+fn path_size(max_depth: usize) -> Option<usize> {
+    max_depth
+        .checked_add(1)?
+        .checked_mul(size_of::<Node>())?
+        .checked_add(2 * size_of::<u32>())
+}
+
+/// Checks that `merkle_tree` has room for the next leaf of `tree_authority`, which mints append
+/// one leaf each. A failed CPI aborts the whole transaction, so this emits a
+/// `CompressionFailureEvent` before returning the error instead of leaving it to the compression
+/// program.
+pub fn assert_tree_not_full(tree_authority: &TreeConfig, merkle_tree: &AccountInfo) -> Result<()> {
+    let (max_depth, _) = read_tree_dimensions(&merkle_tree.try_borrow_data()?)?;
+    if tree_authority.num_minted < 1 << max_depth {
+        return Ok(());
+    }
+
+    emit!(CompressionFailureEvent {
+        version: Version::V1,
+        tree_id: merkle_tree.key(),
+        operation: CompressionOperation::Append,
+        num_leaves: tree_authority.num_minted,
+        max_depth: max_depth as u32,
+    });
+    Err(BubblegumError::TreeFull.into())
+}
+
+/// Checks that the proof has a node for every level of the tree below its canopy. The
+/// compression program would fill in missing trailing nodes with empty subtree roots, but
+/// that turns a truncated proof into an opaque root mismatch once the tree fills up. The error
+/// carries the number of nodes found and expected.
+pub fn assert_proof_length(merkle_tree: &AccountInfo, proof: &[AccountInfo]) -> Result<()> {
+    let expected = get_proof_length(merkle_tree)?;
+    require_eq!(proof.len(), expected, BubblegumError::InvalidProofLength);
+    Ok(())
+}
+
+/// Sends `data` to the noop program, unless the tree has opted out of it.
+pub fn log_event<'info>(
+    data: Vec<u8>,
+    log_wrapper: &Program<'info, Wrapper>,
+    logging_mode: LoggingMode,
+) -> Result<()> {
+    match logging_mode {
+        LoggingMode::Noop => wrap_event(data, log_wrapper),
+        LoggingMode::None => Ok(()),
+    }
+}
+
 pub fn replace_leaf<'info>(
     seed: &Pubkey,
     bump: u8,
@@ -59,6 +233,8 @@ pub fn replace_leaf<'info>(
     new_leaf: Node,
     index: u32,
 ) -> Result<()> {
+    assert_proof_length(merkle_tree, remaining_accounts)?;
+
     let seeds = &[seed.as_ref(), &[bump]];
     let authority_pda_signer = &[&seeds[..]];
     let cpi_ctx = CpiContext::new_with_signer(
@@ -74,6 +250,26 @@ pub fn replace_leaf<'info>(
     spl_account_compression::cpi::replace_leaf(cpi_ctx, root_node, previous_leaf, new_leaf, index)
 }
 
+pub fn verify_leaf<'info>(
+    compression_program: &AccountInfo<'info>,
+    merkle_tree: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    root_node: Node,
+    leaf: Node,
+    index: u32,
+) -> Result<()> {
+    assert_proof_length(merkle_tree, remaining_accounts)?;
+
+    let cpi_ctx = CpiContext::new(
+        compression_program.clone(),
+        spl_account_compression::cpi::accounts::VerifyLeaf {
+            merkle_tree: merkle_tree.clone(),
+        },
+    )
+    .with_remaining_accounts(remaining_accounts.to_vec());
+    spl_account_compression::cpi::verify_leaf(cpi_ctx, root_node, leaf, index)
+}
+
 pub fn append_leaf<'info>(
     seed: &Pubkey,
     bump: u8,
@@ -97,6 +293,192 @@ pub fn append_leaf<'info>(
     spl_account_compression::cpi::append(cpi_ctx, leaf_node)
 }
 
+/// Whether the incoming tree delegate was registered via `add_tree_delegate`.
+/// `tree_delegate_record` must be its `TreeDelegateRecord` PDA, which the mint instructions
+/// check via their seeds constraints, so the account only has data if the record exists.
+pub fn is_registered_tree_delegate(tree_delegate_record: &AccountInfo) -> Result<bool> {
+    if tree_delegate_record.data_is_empty() {
+        return Ok(false);
+    }
+    assert_owned_by(tree_delegate_record, &crate::id())?;
+    Ok(true)
+}
+
+/// Checks that the tree is not paused, and that either the tree creator, the tree delegate, or a
+/// registered delegate signed for the mint, unless the tree is public. Returns the keys that are
+/// allowed to be marked as verified creators.
+pub fn mint_signers(
+    authority: &TreeConfig,
+    payer: &Signer,
+    incoming_tree_delegate: &Signer,
+    tree_delegate_record: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+) -> Result<HashSet<Pubkey>> {
+    let payer = payer.key();
+    let incoming_tree_delegate = incoming_tree_delegate.key();
+    let tree_delegate = authority.tree_delegate;
+    let is_authority = authority.is_authority(&incoming_tree_delegate);
+
+    assert_tree_not_paused(authority)?;
+    require!(
+        authority.is_public() || is_authority || is_registered_tree_delegate(tree_delegate_record)?,
+        BubblegumError::TreeAuthorityIncorrect,
+    );
+
+    // Create a HashSet to store signers to use with creator validation.  Any signer can be
+    // counted as a validated creator.
+    let mut metadata_auth = HashSet::<Pubkey>::new();
+    metadata_auth.insert(payer);
+    metadata_auth.insert(incoming_tree_delegate);
+    // Anyone can mint into a public tree, so its delegate did not necessarily sign. The same goes
+    // for mints signed by a registered delegate.
+    if !authority.is_public() && is_authority {
+        metadata_auth.insert(tree_delegate);
+    }
+
+    // If there are any remaining accounts that are also signers, they can also be used for
+    // creator validation.
+    metadata_auth.extend(
+        remaining_accounts
+            .iter()
+            .filter(|a| a.is_signer)
+            .map(|a| a.key()),
+    );
+
+    Ok(metadata_auth)
+}
+
+/// Transfers the lamport price of `quantity` assets from the payer to the treasury of the tree.
+pub fn charge_mint_price_lamports<'info>(
+    price: &MintPrice,
+    quantity: u64,
+    treasury: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if price.lamports == 0 {
+        return Ok(());
+    }
+    require!(
+        price.treasury == treasury.key(),
+        BubblegumError::InvalidTreasury
+    );
+    let lamports = price.lamports_for(quantity)?;
+
+    invoke(
+        &system_instruction::transfer(&payer.key(), &treasury.key(), lamports),
+        &[payer.clone(), treasury.clone(), system_program.clone()],
+    )?;
+    Ok(())
+}
+
+/// Transfers the token price of `quantity` assets from the token account of the payer to a
+/// token account of `token_mint` owned by the treasury of the tree.
+pub fn charge_mint_price_tokens<'info>(
+    price: &MintPrice,
+    quantity: u64,
+    payer_token_account: &AccountInfo<'info>,
+    treasury_token_account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if price.token_amount == 0 {
+        return Ok(());
+    }
+    require!(
+        *treasury_token_account.owner == spl_token::id(),
+        BubblegumError::InvalidTreasury
+    );
+    let treasury_account =
+        spl_token::state::Account::unpack(&treasury_token_account.try_borrow_data()?)?;
+    require!(
+        treasury_account.owner == price.treasury && treasury_account.mint == price.token_mint,
+        BubblegumError::InvalidTreasury
+    );
+    let amount = price.token_amount_for(quantity)?;
+
+    // The token program checks that the payer owns the source account, and that both accounts
+    // hold the same mint.
+    invoke(
+        &spl_token::instruction::transfer(
+            &spl_token::id(),
+            &payer_token_account.key(),
+            &treasury_token_account.key(),
+            &payer.key(),
+            &[],
+            amount,
+        )?,
+        &[
+            payer_token_account.clone(),
+            treasury_token_account.clone(),
+            payer.clone(),
+            token_program.clone(),
+        ],
+    )?;
+    Ok(())
+}
+
+/// Mint instructions that do not take the accounts needed to charge the mint price call this,
+/// so that public mints into a tree with a mint price have to go through the ones that do.
+pub fn assert_no_mint_price_due(
+    authority: &TreeConfig,
+    incoming_tree_delegate: &Pubkey,
+    tree_delegate_record: &AccountInfo,
+) -> Result<()> {
+    require!(
+        !authority.charges_mint_price(incoming_tree_delegate)
+            || is_registered_tree_delegate(tree_delegate_record)?,
+        BubblegumError::MintPriceNotPaid
+    );
+    Ok(())
+}
+
+/// Use the metadata auth to check whether we can allow `verified` to be set to true in the
+/// creator Vec.
+pub fn assert_verified_creators_signed(
+    creators: &[Creator],
+    metadata_auth: &HashSet<Pubkey>,
+) -> Result<()> {
+    for c in creators.iter() {
+        if c.verified && !metadata_auth.contains(&c.address) {
+            return Err(BubblegumError::CreatorDidNotVerify.into());
+        }
+    }
+    Ok(())
+}
+
+/// The creators of new assets must be sorted by address (in ascending order), since the creator
+/// hash depends on their order. This way, the same set of creators always results in the same
+/// hash (see `client::canonicalize_creators`). Existing assets are not affected, so this is only
+/// checked when minting.
+pub fn assert_creators_are_sorted(creators: &[Creator]) -> Result<()> {
+    if creators
+        .windows(2)
+        .any(|pair| pair[0].address >= pair[1].address)
+    {
+        return Err(BubblegumError::CreatorsNotSorted.into());
+    }
+    Ok(())
+}
+
+pub fn assert_metadata_is_mintable(
+    message: &MetadataArgs,
+    metadata_auth: &HashSet<Pubkey>,
+) -> Result<()> {
+    assert_metadata_is_mpl_compatible(message)?;
+    assert_creators_are_sorted(&message.creators)?;
+
+    // Currently, not allowing a collection to be verified outside of `verify_collection`
+    // instruction to have parity with token-metadata.
+    if let Some(collection) = &message.collection {
+        if collection.verified {
+            return Err(BubblegumError::CollectionCannotBeVerifiedInThisInstruction.into());
+        }
+    }
+
+    assert_verified_creators_signed(&message.creators, metadata_auth)
+}
+
 pub fn cmp_pubkeys(a: &Pubkey, b: &Pubkey) -> bool {
     sol_memcmp(a.as_ref(), b.as_ref(), PUBKEY_BYTES) == 0
 }
@@ -150,6 +532,305 @@ pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> Result<()> {
     }
 }
 
+/// Leaves are appended in nonce order, so the index of a leaf is always equal to its nonce.
+pub fn assert_index_matches_nonce(index: u32, nonce: u64) -> Result<()> {
+    if u64::from(index) != nonce {
+        return Err(BubblegumError::LeafIndexNonceMismatch.into());
+    }
+    Ok(())
+}
+
+/// Transferring a leaf to its current owner only changes it when there is a delegate to clear,
+/// so otherwise it is rejected instead of being logged as a change that did not happen.
+pub fn assert_transfer_changes_leaf(
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    new_owner: &Pubkey,
+) -> Result<()> {
+    if new_owner == owner && delegate == owner {
+        return Err(BubblegumError::RedundantOperation.into());
+    }
+    Ok(())
+}
+
+/// A leaf that is being redeemed is no longer in the tree, and can only be brought back via
+/// `cancel_redeem`, so it cannot be changed until then. `voucher` must be the voucher PDA
+/// derived for the nonce of the leaf.
+pub fn assert_not_being_redeemed(voucher: &AccountInfo) -> Result<()> {
+    if !voucher.data_is_empty() {
+        return Err(BubblegumError::LeafBeingRedeemed.into());
+    }
+    Ok(())
+}
+
+/// The default address is also the id of the system program, so assets owned by or delegated
+/// to it are lost for good. Passing it is most likely a client bug, so it's rejected for
+/// owners, delegates, and creators.
+pub fn assert_not_zero_address(key: &Pubkey) -> Result<()> {
+    if cmp_pubkeys(key, &Pubkey::default()) {
+        return Err(BubblegumError::InvalidZeroAddress.into());
+    }
+    Ok(())
+}
+
+/// Frozen leaves have a `LeafFreeze` record, which `leaf_freeze` must be the PDA of. It only
+/// exists until the delegate thaws the leaf. A record closed earlier in the same transaction
+/// keeps its data until the transaction ends, but no longer holds any lamports.
+pub fn assert_not_frozen(leaf_freeze: &AccountInfo) -> Result<()> {
+    if !leaf_freeze.data_is_empty() && leaf_freeze.lamports() > 0 {
+        return Err(BubblegumError::AssetIsFrozen.into());
+    }
+    Ok(())
+}
+
+/// Transfers, claims and redemptions in trees that opted into a deny list fail for the assets on
+/// it. `tree_deny_list` must be the `TreeDenyList` PDA of the tree, and `deny_list` the list it
+/// points to (it's not read when the tree has none).
+pub fn assert_not_flagged(
+    tree_deny_list: &AccountInfo,
+    deny_list: &AccountInfo,
+    asset_id: &Pubkey,
+) -> Result<()> {
+    if tree_deny_list.data_is_empty() {
+        return Ok(());
+    }
+    assert_owned_by(tree_deny_list, &crate::id())?;
+    let expected =
+        TreeDenyList::try_deserialize(&mut tree_deny_list.try_borrow_data()?.as_ref())?.deny_list;
+    assert_not_on_deny_list(&expected, deny_list, asset_id)
+}
+
+/// Same as `assert_not_flagged`, for instructions with a fixed account layout (`transfer` and
+/// `redeem`). They take the `TreeDenyList` of the tree and the deny list it points to as
+/// companion accounts (see `take_companion_accounts`), which are only passed while the tree has
+/// a deny list.
+pub fn take_and_assert_not_flagged(
+    tree_authority: &TreeConfig,
+    merkle_tree: &Pubkey,
+    remaining_accounts: &mut &[AccountInfo],
+    asset_id: &Pubkey,
+) -> Result<()> {
+    if !tree_authority.has_deny_list() {
+        return Ok(());
+    }
+
+    let (tree_deny_list, deny_list) = match take_companion_accounts::<2>(remaining_accounts) {
+        Some([tree_deny_list, deny_list]) => (tree_deny_list, deny_list),
+        None => return Err(BubblegumError::InvalidDenyList.into()),
+    };
+    // Clients that leave them out would pass a proof node (or nothing) in their place.
+    if !cmp_pubkeys(tree_deny_list.owner, &crate::id()) {
+        return Err(BubblegumError::InvalidDenyList.into());
+    }
+    let expected = TreeDenyList::try_deserialize(&mut tree_deny_list.try_borrow_data()?.as_ref())?;
+    if !cmp_pubkeys(&expected.merkle_tree, merkle_tree) {
+        return Err(BubblegumError::InvalidDenyList.into());
+    }
+    assert_not_on_deny_list(&expected.deny_list, deny_list, asset_id)
+}
+
+// `expected` is the deny list set for the tree, which is the default address if there is none.
+// A list that wasn't created yet has nothing on it. Lists are PDAs of this program, so only
+// `create_deny_list` can put data at that address.
+fn assert_not_on_deny_list(
+    expected: &Pubkey,
+    deny_list: &AccountInfo,
+    asset_id: &Pubkey,
+) -> Result<()> {
+    if cmp_pubkeys(expected, &Pubkey::default()) {
+        return Ok(());
+    }
+
+    if !cmp_pubkeys(deny_list.key, expected) {
+        return Err(BubblegumError::InvalidDenyList.into());
+    }
+    if deny_list.data_is_empty() {
+        return Ok(());
+    }
+    assert_owned_by(deny_list, &crate::id())?;
+    let list = DenyList::try_deserialize(&mut deny_list.try_borrow_data()?.as_ref())?;
+    if list.contains(asset_id) {
+        return Err(BubblegumError::AssetFlagged.into());
+    }
+    Ok(())
+}
+
+/// Minting with a leaf delegate other than the owner hands the delegate the right to transfer
+/// or burn the asset right away, so the owner has to agree to it. The default address (which
+/// is also the id of the system program) is rejected, as it's most likely a client bug.
+pub fn assert_valid_leaf_delegate(owner: &AccountInfo, delegate: &AccountInfo) -> Result<()> {
+    assert_not_zero_address(owner.key)?;
+    if cmp_pubkeys(owner.key, delegate.key) {
+        return Ok(());
+    }
+
+    if cmp_pubkeys(delegate.key, &Pubkey::default()) {
+        return Err(BubblegumError::InvalidLeafDelegate.into());
+    }
+
+    if !owner.is_signer {
+        return Err(BubblegumError::LeafOwnerMustSignForDelegate.into());
+    }
+    Ok(())
+}
+
+/// Trees created with `is_creator_immutable` set reject every instruction that changes who
+/// governs the tree or how, so collectors can rely on the settings they minted under.
+pub fn assert_tree_governance_mutable(tree_authority: &TreeConfig) -> Result<()> {
+    if tree_authority.is_creator_immutable {
+        return Err(BubblegumError::TreeCreatorImmutable.into());
+    }
+    Ok(())
+}
+
+/// Mints, transfers, burns, delegations, freezes, redemptions, decompressions and metadata
+/// updates call this, so the creator can halt activity in the tree while sorting out a bad drop.
+pub fn assert_tree_not_paused(tree_authority: &TreeConfig) -> Result<()> {
+    if tree_authority.is_paused {
+        return Err(BubblegumError::TreePaused.into());
+    }
+    Ok(())
+}
+
+/// The instructions in `FeatureFlags::ENFORCED` call this with the `FeatureFlags` PDA of the
+/// deployment before doing anything else, so they can be turned off once their replacements
+/// have shipped. Every instruction is enabled until the flags are first set.
+pub fn assert_instruction_enabled(
+    feature_flags: &AccountInfo,
+    instruction: InstructionName,
+) -> Result<()> {
+    if feature_flags.data_is_empty() {
+        return Ok(());
+    }
+
+    assert_owned_by(feature_flags, &crate::id())?;
+    let data = feature_flags.try_borrow_data()?;
+    if !FeatureFlags::try_deserialize(&mut data.as_ref())?.is_enabled(instruction) {
+        return Err(BubblegumError::InstructionDisabled.into());
+    }
+    Ok(())
+}
+
+/// Same as `assert_instruction_enabled`, for instructions with a fixed account layout
+/// (`mint_v1`, `mint_v1_hashed`, `mint_batch_v1` and `transfer`). They take the `FeatureFlags`
+/// as the first of their companion accounts (see `take_companion_accounts`), which is required
+/// once `set_feature_flags` applied the flags to the tree, and optional until then.
+pub fn take_and_assert_instruction_enabled(
+    tree_authority: &TreeConfig,
+    remaining_accounts: &mut &[AccountInfo],
+    instruction: InstructionName,
+) -> Result<()> {
+    let accounts = *remaining_accounts;
+    match accounts.split_first() {
+        Some((feature_flags, rest)) if is_feature_flags(feature_flags) => {
+            *remaining_accounts = rest;
+            assert_instruction_enabled(feature_flags, instruction)
+        }
+        _ if tree_authority.has_feature_flags() => Err(BubblegumError::FeatureFlagsMissing.into()),
+        _ => Ok(()),
+    }
+}
+
+// There is a single `FeatureFlags` account, which only `set_feature_flags` creates, so it's
+// recognized by its owner and discriminator without deriving its address.
+fn is_feature_flags(account: &AccountInfo) -> bool {
+    cmp_pubkeys(account.owner, &crate::id())
+        && account.try_borrow_data().map_or(false, |data| {
+            data.starts_with(&FeatureFlags::discriminator())
+        })
+}
+
+/// The optional PDAs of instructions with a fixed account layout (`mint_v1`, `mint_v1_hashed`,
+/// `mint_batch_v1`, `transfer` and `redeem`) are passed at the front of their remaining
+/// accounts, ahead of the extra creator signers or the proof. Existing clients and CPI callers
+/// don't pass any, which keeps working for trees that don't require them. In order:
+/// - for mints and transfers: the `FeatureFlags` of the deployment, once it exists;
+/// - for mints that are charged the price of the tree: the `MintPrice` of the tree, the
+///   treasury, the system program, the token account of the payer, the token account of the
+///   treasury, and the token program;
+/// - for transfers and redemptions in a tree with a deny list: the `TreeDenyList` of the tree,
+///   and the deny list it points to.
+///
+/// The ones recorded in `TreeConfig::companion_accounts` are required while the tree has them,
+/// and can be left out otherwise. This takes the next `N` of them, if there are enough.
+pub fn take_companion_accounts<'a, 'info, const N: usize>(
+    remaining_accounts: &mut &'a [AccountInfo<'info>],
+) -> Option<&'a [AccountInfo<'info>; N]> {
+    let accounts = *remaining_accounts;
+    if accounts.len() < N {
+        return None;
+    }
+    let (companions, rest) = accounts.split_at(N);
+    *remaining_accounts = rest;
+    companions.try_into().ok()
+}
+
+/// Soulbound leaves can still be burned or delegated, but never change owners. The
+/// `SoulboundOverride` of the leaf, if it was ever created, takes precedence over the setting
+/// of the tree. `soulbound_override` must be the override PDA derived for the nonce of the
+/// leaf.
+pub fn assert_transferable(
+    tree_authority: &TreeConfig,
+    soulbound_override: &AccountInfo,
+) -> Result<()> {
+    let is_non_transferable = if soulbound_override.data_is_empty() {
+        tree_authority.is_non_transferable
+    } else {
+        assert_owned_by(soulbound_override, &crate::id())?;
+        let data = soulbound_override.try_borrow_data()?;
+        SoulboundOverride::try_deserialize(&mut data.as_ref())?.is_non_transferable
+    };
+
+    if is_non_transferable {
+        return Err(BubblegumError::AssetIsSoulbound.into());
+    }
+    Ok(())
+}
+
+/// Checks that a leaf delegate acting on its own is not past the expiry set for it via
+/// `delegate_with_expiry`. The owner is never affected, and neither is a delegate approved after
+/// the leaf changed hands. `delegate_expiry` must be the expiry PDA derived for the nonce of the
+/// leaf.
+///
+/// The expiry is honoured by every instruction a delegate can sign on its own: `transfer`,
+/// `burn`, `burn_with_receipt` and `freeze` check it here, while `thaw` lets the owner thaw the
+/// leaf once the delegate that froze it has expired (see `is_delegate_expired`). The remaining
+/// leaf instructions only accept the owner's signature.
+pub fn assert_delegate_not_expired(
+    owner: &AccountInfo,
+    delegate: &AccountInfo,
+    delegate_expiry: &AccountInfo,
+) -> Result<()> {
+    if owner.is_signer || !is_delegate_expired(owner.key, delegate.key, delegate_expiry)? {
+        return Ok(());
+    }
+    Err(BubblegumError::DelegateExpired.into())
+}
+
+/// Whether `delegate_expiry` holds an expiry for `delegate` of the leaf of `owner` that has
+/// passed. Records for another owner or delegate are ignored, as are missing records.
+pub fn is_delegate_expired(
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    delegate_expiry: &AccountInfo,
+) -> Result<bool> {
+    if delegate_expiry.data_is_empty() {
+        return Ok(false);
+    }
+
+    assert_owned_by(delegate_expiry, &crate::id())?;
+    let data = delegate_expiry.try_borrow_data()?;
+    let expiry = DelegateExpiry::try_deserialize(&mut data.as_ref())?;
+    if !cmp_pubkeys(&expiry.owner, owner) || !cmp_pubkeys(&expiry.delegate, delegate) {
+        return Ok(false);
+    }
+
+    match expiry.expiry_slot {
+        Some(slot) => Ok(Clock::get()?.slot >= slot),
+        None => Ok(false),
+    }
+}
+
 pub fn get_asset_id(tree_id: &Pubkey, nonce: u64) -> Pubkey {
     Pubkey::find_program_address(
         &[
@@ -161,3 +842,303 @@ pub fn get_asset_id(tree_id: &Pubkey, nonce: u64) -> Pubkey {
     )
     .0
 }
+
+/// Invokes Bubblegum like the helpers in the `cpi` module do, except that `signer` is flagged
+/// as a signer of the instruction. The generated helpers only flag accounts declared as
+/// `Signer`, while instructions such as `transfer` and `burn` accept a signature from either the
+/// leaf owner or the leaf delegate, so a program signing for one of them (directly, or as a PDA
+/// via the signer seeds of `ctx`) has to use this instead.
+pub fn invoke_as_signer<'info, T: ToAccountMetas + ToAccountInfos<'info>>(
+    ctx: CpiContext<'_, '_, '_, 'info, T>,
+    data: impl InstructionData,
+    signer: &Pubkey,
+) -> Result<()> {
+    let mut accounts = ctx.to_account_metas(None);
+    for meta in accounts.iter_mut().filter(|meta| meta.pubkey == *signer) {
+        meta.is_signer = true;
+    }
+    let instruction = Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: data.data(),
+    };
+    invoke_signed(&instruction, &ctx.to_account_infos(), ctx.signer_seeds)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::metaplex_adapter::{Collection, TokenProgramVersion};
+
+    fn creator(seed: u8, share: u8, verified: bool) -> Creator {
+        Creator {
+            address: Pubkey::new_from_array([seed; 32]),
+            verified,
+            share,
+        }
+    }
+
+    fn metadata() -> MetadataArgs {
+        MetadataArgs {
+            name: "test".to_owned(),
+            symbol: "tst".to_owned(),
+            uri: "https://example.com".to_owned(),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: None,
+            collection: None,
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators: vec![creator(1, 60, false), creator(2, 40, false)],
+        }
+    }
+
+    fn expect(result: Result<()>, expected: Option<BubblegumError>, case: &str) {
+        match expected {
+            None => assert!(result.is_ok(), "{}: unexpected error", case),
+            Some(err) => assert_eq!(result, Err(err.into()), "{}", case),
+        }
+    }
+
+    #[test]
+    fn metadata_mpl_compatibility() {
+        let cases: Vec<(&str, fn(&mut MetadataArgs), Option<BubblegumError>)> = vec![
+            ("valid", |_| {}, None),
+            ("no creators", |m| m.creators.clear(), None),
+            (
+                "longest name",
+                |m| m.name = "a".repeat(mpl_token_metadata::state::MAX_NAME_LENGTH),
+                None,
+            ),
+            (
+                "name too long",
+                |m| m.name = "a".repeat(mpl_token_metadata::state::MAX_NAME_LENGTH + 1),
+                Some(BubblegumError::MetadataNameTooLong),
+            ),
+            (
+                "symbol too long",
+                |m| m.symbol = "a".repeat(mpl_token_metadata::state::MAX_SYMBOL_LENGTH + 1),
+                Some(BubblegumError::MetadataSymbolTooLong),
+            ),
+            (
+                "uri too long",
+                |m| m.uri = "a".repeat(mpl_token_metadata::state::MAX_URI_LENGTH + 1),
+                Some(BubblegumError::MetadataUriTooLong),
+            ),
+            (
+                "padded name",
+                |m| m.name.push('\0'),
+                Some(BubblegumError::MetadataPadded),
+            ),
+            (
+                "padded symbol",
+                |m| m.symbol.push('\0'),
+                Some(BubblegumError::MetadataPadded),
+            ),
+            (
+                "puffed",
+                |m| *m = m.puffed(),
+                Some(BubblegumError::MetadataPadded),
+            ),
+            ("inner null byte", |m| m.name.insert(0, '\0'), None),
+            ("highest fee", |m| m.seller_fee_basis_points = 10000, None),
+            (
+                "fee too high",
+                |m| m.seller_fee_basis_points = 10001,
+                Some(BubblegumError::MetadataBasisPointsTooHigh),
+            ),
+            (
+                "too many creators",
+                |m| m.creators = (1..=5).map(|i| creator(i, 20, false)).collect(),
+                Some(BubblegumError::CreatorsTooLong),
+            ),
+            (
+                "zero address creator",
+                |m| m.creators[1].address = Pubkey::default(),
+                Some(BubblegumError::InvalidZeroAddress),
+            ),
+            (
+                "duplicate creators",
+                |m| m.creators = vec![creator(1, 50, false), creator(1, 50, false)],
+                Some(BubblegumError::DuplicateCreatorAddress),
+            ),
+            (
+                "zero share creator",
+                |m| m.creators = vec![creator(1, 0, true), creator(2, 100, false)],
+                None,
+            ),
+            (
+                "only zero share creators",
+                |m| m.creators = vec![creator(1, 0, false), creator(2, 0, false)],
+                Some(BubblegumError::CreatorShareTotalMustBe100),
+            ),
+            (
+                "shares below 100",
+                |m| m.creators[0].share = 50,
+                Some(BubblegumError::CreatorShareTotalMustBe100),
+            ),
+            (
+                "shares overflow",
+                |m| m.creators = vec![creator(1, 255, false), creator(2, 1, false)],
+                Some(BubblegumError::CreatorShareTotalMustBe100),
+            ),
+        ];
+
+        for (case, update, expected) in cases {
+            let mut metadata = metadata();
+            update(&mut metadata);
+            expect(assert_metadata_is_mpl_compatible(&metadata), expected, case);
+
+            // The hashed variant applies the same checks, except for the uri which it never
+            // gets to see.
+            let hashed = HashedMetadataArgs {
+                name: metadata.name.clone(),
+                symbol: metadata.symbol.clone(),
+                seller_fee_basis_points: metadata.seller_fee_basis_points,
+                creators: metadata.creators.clone(),
+                metadata_args_hash: [0; 32],
+            };
+            let expected = expected.filter(|e| !matches!(e, BubblegumError::MetadataUriTooLong));
+            expect(
+                assert_hashed_metadata_is_mpl_compatible(&hashed),
+                expected,
+                case,
+            );
+        }
+    }
+
+    #[test]
+    fn canopy_depth_of_account_sizes() {
+        let size =
+            |canopy_depth| crate::client::rent::merkle_tree_account_size(14, 64, canopy_depth);
+        let node = size_of::<Node>();
+        let cases = vec![
+            ("no canopy", size(0), Some(0)),
+            ("depth 1 canopy", size(1), Some(1)),
+            ("depth 13 canopy", size(13), Some(13)),
+            ("smaller than the tree", size(0) - 1, None),
+            ("partial node", size(0) + 1, None),
+            ("single node", size(0) + node, None),
+            ("one node short", size(5) - node, None),
+            ("one node over", size(5) + node, None),
+            ("one byte short", size(5) - 1, None),
+            ("one byte over", size(5) + 1, None),
+        ];
+
+        for (case, account_size, expected) in cases {
+            assert_eq!(
+                canopy_depth(account_size, 14, 64).ok(),
+                expected,
+                "{}",
+                case
+            );
+        }
+    }
+
+    #[test]
+    fn oversized_tree_dimensions() {
+        let account_size = MAX_PERMITTED_DATA_LENGTH as usize;
+        for (max_depth, max_buffer_size) in [
+            (u32::MAX as usize, u32::MAX as usize),
+            (usize::MAX, 64),
+            (14, usize::MAX),
+        ] {
+            assert!(canopy_depth(account_size, max_depth, max_buffer_size).is_err());
+            assert!(
+                assert_valid_tree_account_size(account_size, max_depth, max_buffer_size).is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn metadata_mintability() {
+        let signer = creator(1, 0, false).address;
+        let metadata_auth = HashSet::from([signer]);
+
+        let cases: Vec<(&str, fn(&mut MetadataArgs), Option<BubblegumError>)> = vec![
+            ("unverified creators", |_| {}, None),
+            ("verified signer", |m| m.creators[0].verified = true, None),
+            (
+                "unsorted creators",
+                |m| m.creators.swap(0, 1),
+                Some(BubblegumError::CreatorsNotSorted),
+            ),
+            (
+                "verified zero share signer",
+                |m| {
+                    m.creators = vec![creator(1, 0, true), creator(2, 100, false)];
+                },
+                None,
+            ),
+            (
+                "verified non signer",
+                |m| m.creators[1].verified = true,
+                Some(BubblegumError::CreatorDidNotVerify),
+            ),
+            (
+                "unverified collection",
+                |m| {
+                    m.collection = Some(Collection {
+                        verified: false,
+                        key: Pubkey::new_from_array([9; 32]),
+                    })
+                },
+                None,
+            ),
+            (
+                "verified collection",
+                |m| {
+                    m.collection = Some(Collection {
+                        verified: true,
+                        key: Pubkey::new_from_array([9; 32]),
+                    })
+                },
+                Some(BubblegumError::CollectionCannotBeVerifiedInThisInstruction),
+            ),
+            (
+                "incompatible metadata",
+                |m| m.seller_fee_basis_points = 10001,
+                Some(BubblegumError::MetadataBasisPointsTooHigh),
+            ),
+        ];
+
+        for (case, update, expected) in cases {
+            let mut metadata = metadata();
+            update(&mut metadata);
+            expect(
+                assert_metadata_is_mintable(&metadata, &metadata_auth),
+                expected,
+                case,
+            );
+        }
+    }
+
+    #[test]
+    fn index_and_nonce() {
+        let cases = [
+            (0, 0, true),
+            (7, 7, true),
+            (u32::MAX, u64::from(u32::MAX), true),
+            (0, 1, false),
+            (1, 0, false),
+            // The nonce must not be truncated to the width of the index.
+            (0, 1 << 32, false),
+        ];
+
+        for (index, nonce, valid) in cases {
+            let expected = if valid {
+                None
+            } else {
+                Some(BubblegumError::LeafIndexNonceMismatch)
+            };
+            expect(
+                assert_index_matches_nonce(index, nonce),
+                expected,
+                &format!("index {} nonce {}", index, nonce),
+            );
+        }
+    }
+}
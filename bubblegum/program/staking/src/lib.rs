@@ -0,0 +1,40 @@
+//! A minimal staking program for Bubblegum assets, which serves as an example of composing with
+//! Bubblegum via CPI. Staking an asset makes a program account its delegate, and rewards (which
+//! are only tracked as a counter here) accrue for every slot the asset stays staked.
+//!
+//! Staked assets are frozen (see Bubblegum's `freeze`), so the owner can't move them until they
+//! unstake them, which thaws them and makes the owner their delegate again.
+use anchor_lang::prelude::*;
+
+pub mod processor;
+pub mod state;
+
+pub use processor::*;
+
+declare_id!("9GQintQG8m3qceFy1mRk8fWgbsxCeY867Yhrp6Hd8ZQS");
+
+#[program]
+pub mod bubblegum_staking {
+    use super::*;
+
+    pub fn stake<'info>(
+        ctx: Context<'_, '_, '_, 'info, Stake<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        nonce: u64,
+        index: u32,
+    ) -> Result<()> {
+        handle_stake(ctx, root, data_hash, creator_hash, nonce, index)
+    }
+
+    pub fn unstake<'info>(
+        ctx: Context<'_, '_, '_, 'info, Unstake<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        index: u32,
+    ) -> Result<()> {
+        handle_unstake(ctx, root, data_hash, creator_hash, index)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod stake;
+pub mod unstake;
+
+pub use stake::*;
+pub use unstake::*;
@@ -0,0 +1,144 @@
+use crate::state::{StakeEntry, STAKE_AUTHORITY_PREFIX, STAKE_ENTRY_PREFIX, STAKE_ENTRY_SIZE};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use mpl_bubblegum::{program::Bubblegum, state::LEAF_FREEZE_SIZE};
+
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    nonce: u64,
+)]
+pub struct Stake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        init,
+        seeds = [STAKE_ENTRY_PREFIX.as_ref(), merkle_tree.key().as_ref(), &nonce.to_le_bytes()],
+        payer = owner,
+        space = STAKE_ENTRY_SIZE,
+        bump,
+    )]
+    pub stake_entry: Account<'info, StakeEntry>,
+    #[account(
+        mut,
+        seeds = [STAKE_AUTHORITY_PREFIX.as_ref()],
+        bump,
+    )]
+    pub stake_authority: SystemAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub tree_authority: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub previous_leaf_delegate: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified by Bubblegum
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub voucher: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is checked by Bubblegum
+    pub delegate_expiry: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is created by Bubblegum
+    pub leaf_freeze: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is checked by Bubblegum
+    pub previous_expiry_owner: UncheckedAccount<'info>,
+    pub bubblegum_program: Program<'info, Bubblegum>,
+    pub system_program: Program<'info, System>,
+}
+
+// Makes the stake authority the delegate of the asset, and then freezes it. The delegation never
+// expires, which also lifts any expiry the owner may have set for a previous delegate of the
+// asset. The owner pays for the `LeafFreeze` record, and gets the rent back when unstaking.
+//
+// Delegating changes the leaf, but not the rest of its proof, and the root it was verified
+// against is still in the changelog of the tree, so the same arguments are used to freeze it.
+pub fn handle_stake<'info>(
+    ctx: Context<'_, '_, '_, 'info, Stake<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let cpi_ctx = CpiContext::new(
+        accounts.bubblegum_program.to_account_info(),
+        mpl_bubblegum::cpi::accounts::DelegateWithExpiry {
+            tree_authority: accounts.tree_authority.to_account_info(),
+            leaf_owner: accounts.owner.to_account_info(),
+            previous_leaf_delegate: accounts.previous_leaf_delegate.to_account_info(),
+            new_leaf_delegate: accounts.stake_authority.to_account_info(),
+            merkle_tree: accounts.merkle_tree.to_account_info(),
+            log_wrapper: accounts.log_wrapper.to_account_info(),
+            compression_program: accounts.compression_program.to_account_info(),
+            voucher: accounts.voucher.to_account_info(),
+            delegate_expiry: accounts.delegate_expiry.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            leaf_freeze: accounts.leaf_freeze.to_account_info(),
+            previous_expiry_owner: accounts.previous_expiry_owner.to_account_info(),
+        },
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    mpl_bubblegum::cpi::delegate_with_expiry(
+        cpi_ctx,
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+        None,
+    )?;
+
+    invoke(
+        &system_instruction::transfer(
+            &accounts.owner.key(),
+            &accounts.stake_authority.key(),
+            Rent::get()?.minimum_balance(LEAF_FREEZE_SIZE),
+        ),
+        &[
+            accounts.owner.to_account_info(),
+            accounts.stake_authority.to_account_info(),
+            accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let bump = [*ctx.bumps.get("stake_authority").unwrap()];
+    let seeds = &[STAKE_AUTHORITY_PREFIX.as_ref(), &bump];
+    let signer = &[&seeds[..]];
+    let cpi_ctx = CpiContext::new_with_signer(
+        accounts.bubblegum_program.to_account_info(),
+        mpl_bubblegum::cpi::accounts::Freeze {
+            tree_authority: accounts.tree_authority.to_account_info(),
+            leaf_owner: accounts.owner.to_account_info(),
+            leaf_delegate: accounts.stake_authority.to_account_info(),
+            merkle_tree: accounts.merkle_tree.to_account_info(),
+            compression_program: accounts.compression_program.to_account_info(),
+            voucher: accounts.voucher.to_account_info(),
+            delegate_expiry: accounts.delegate_expiry.to_account_info(),
+            leaf_freeze: accounts.leaf_freeze.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+        },
+        signer,
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    mpl_bubblegum::cpi::freeze(cpi_ctx, root, data_hash, creator_hash, nonce, index)?;
+
+    ctx.accounts.stake_entry.set_inner(StakeEntry {
+        owner: ctx.accounts.owner.key(),
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        nonce,
+        staked_slot: Clock::get()?.slot,
+        bump: *ctx.bumps.get("stake_entry").unwrap(),
+    });
+
+    Ok(())
+}
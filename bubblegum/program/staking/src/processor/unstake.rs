@@ -0,0 +1,138 @@
+use crate::state::{
+    Rewards, StakeEntry, REWARDS_PER_SLOT, REWARDS_PREFIX, REWARDS_SIZE, STAKE_AUTHORITY_PREFIX,
+    STAKE_ENTRY_PREFIX,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+use mpl_bubblegum::program::Bubblegum;
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            STAKE_ENTRY_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            &stake_entry.nonce.to_le_bytes(),
+        ],
+        bump = stake_entry.bump,
+        has_one = owner,
+        has_one = merkle_tree,
+        close = owner,
+    )]
+    pub stake_entry: Account<'info, StakeEntry>,
+    #[account(
+        init_if_needed,
+        seeds = [REWARDS_PREFIX.as_ref(), owner.key().as_ref()],
+        payer = owner,
+        space = REWARDS_SIZE,
+        bump,
+    )]
+    pub rewards: Account<'info, Rewards>,
+    #[account(
+        mut,
+        seeds = [STAKE_AUTHORITY_PREFIX.as_ref()],
+        bump,
+    )]
+    pub stake_authority: SystemAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub tree_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is modified by Bubblegum
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub voucher: UncheckedAccount<'info>,
+    /// CHECK: This account is checked by Bubblegum
+    pub delegate_expiry: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: This account is closed by Bubblegum
+    pub leaf_freeze: UncheckedAccount<'info>,
+    pub bubblegum_program: Program<'info, Bubblegum>,
+    pub system_program: Program<'info, System>,
+}
+
+// Thaws the asset, makes the owner its delegate again, and credits the rewards accrued since the
+// asset was staked. The rent of the `LeafFreeze` record goes back to the owner, who paid for it.
+pub fn handle_unstake<'info>(
+    ctx: Context<'_, '_, '_, 'info, Unstake<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    index: u32,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let bump = [*ctx.bumps.get("stake_authority").unwrap()];
+    let seeds = &[STAKE_AUTHORITY_PREFIX.as_ref(), &bump];
+    let signer = &[&seeds[..]];
+
+    let rent = accounts.leaf_freeze.lamports();
+    let cpi_ctx = CpiContext::new_with_signer(
+        accounts.bubblegum_program.to_account_info(),
+        mpl_bubblegum::cpi::accounts::Thaw {
+            leaf_owner: accounts.owner.to_account_info(),
+            leaf_delegate: accounts.stake_authority.to_account_info(),
+            merkle_tree: accounts.merkle_tree.to_account_info(),
+            leaf_freeze: accounts.leaf_freeze.to_account_info(),
+            delegate_expiry: accounts.delegate_expiry.to_account_info(),
+        },
+        signer,
+    );
+    mpl_bubblegum::utils::invoke_as_signer(
+        cpi_ctx,
+        mpl_bubblegum::instruction::Thaw {},
+        &accounts.stake_authority.key(),
+    )?;
+
+    invoke_signed(
+        &system_instruction::transfer(&accounts.stake_authority.key(), &accounts.owner.key(), rent),
+        &[
+            accounts.stake_authority.to_account_info(),
+            accounts.owner.to_account_info(),
+            accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+
+    let cpi_ctx = CpiContext::new(
+        accounts.bubblegum_program.to_account_info(),
+        mpl_bubblegum::cpi::accounts::Delegate {
+            tree_authority: accounts.tree_authority.to_account_info(),
+            leaf_owner: accounts.owner.to_account_info(),
+            previous_leaf_delegate: accounts.stake_authority.to_account_info(),
+            new_leaf_delegate: accounts.owner.to_account_info(),
+            merkle_tree: accounts.merkle_tree.to_account_info(),
+            log_wrapper: accounts.log_wrapper.to_account_info(),
+            compression_program: accounts.compression_program.to_account_info(),
+            voucher: accounts.voucher.to_account_info(),
+            leaf_freeze: accounts.leaf_freeze.to_account_info(),
+        },
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    mpl_bubblegum::cpi::delegate(
+        cpi_ctx,
+        root,
+        data_hash,
+        creator_hash,
+        accounts.stake_entry.nonce,
+        index,
+    )?;
+
+    let staked_slots = Clock::get()?
+        .slot
+        .saturating_sub(ctx.accounts.stake_entry.staked_slot);
+    let rewards = &mut ctx.accounts.rewards;
+    rewards.owner = ctx.accounts.owner.key();
+    rewards.accrued = rewards
+        .accrued
+        .saturating_add(staked_slots.saturating_mul(REWARDS_PER_SLOT));
+
+    Ok(())
+}
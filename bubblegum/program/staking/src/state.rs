@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+pub const STAKE_ENTRY_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+pub const STAKE_ENTRY_PREFIX: &str = "stake_entry";
+// The PDA that is the delegate of every staked asset. It holds no data, so that it can pay for
+// the `LeafFreeze` records of the assets it freezes.
+pub const STAKE_AUTHORITY_PREFIX: &str = "stake_authority";
+pub const REWARDS_SIZE: usize = 8 + 32 + 8;
+pub const REWARDS_PREFIX: &str = "rewards";
+// The amount of rewards each staked asset accrues per slot.
+pub const REWARDS_PER_SLOT: u64 = 10;
+
+/// A staked asset. The asset stays frozen, with the stake authority as its delegate, while the
+/// entry exists.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct StakeEntry {
+    pub owner: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub nonce: u64,
+    pub staked_slot: u64,
+    pub bump: u8,
+}
+
+/// The rewards accrued by an owner across all the assets they unstaked.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct Rewards {
+    pub owner: Pubkey,
+    pub accrued: u64,
+}
@@ -0,0 +1,252 @@
+// Reuses the Bubblegum test harness, so that the operations performed by the staking program
+// via CPI are tracked like any other.
+#[path = "../../tests/utils/mod.rs"]
+pub mod utils;
+
+use mpl_bubblegum::error::BubblegumError;
+use mpl_bubblegum_staking::state::{
+    Rewards, StakeEntry, REWARDS_PER_SLOT, REWARDS_PREFIX, STAKE_AUTHORITY_PREFIX,
+    STAKE_ENTRY_PREFIX,
+};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+use utils::{context::BubblegumTestContext, LeafArgs, Result, Tree, TxBuilder};
+
+const MAX_DEPTH: usize = 14;
+const MAX_BUF_SIZE: usize = 64;
+
+type StakeBuilder =
+    TxBuilder<mpl_bubblegum_staking::accounts::Stake, mpl_bubblegum_staking::instruction::Stake>;
+
+type UnstakeBuilder = TxBuilder<
+    mpl_bubblegum_staking::accounts::Unstake,
+    mpl_bubblegum_staking::instruction::Unstake,
+>;
+
+// Creates a tree with two assets that belong to the payer of the context.
+async fn context_tree_and_leaves() -> Result<(
+    BubblegumTestContext,
+    Tree<MAX_DEPTH, MAX_BUF_SIZE>,
+    LeafArgs,
+    LeafArgs,
+)> {
+    let context = BubblegumTestContext::with_programs(&[(
+        "mpl_bubblegum_staking",
+        mpl_bubblegum_staking::id(),
+    )])
+    .await?;
+
+    let (tree, first) = context
+        .default_create_and_mint::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await?;
+
+    let mut second = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("second", "tst"),
+    );
+    second.nonce = 1;
+    tree.mint_v1(&tree.tree_delegate, &second).await?;
+
+    Ok((context, tree, first, second))
+}
+
+fn stake_entry(tree: &Tree<MAX_DEPTH, MAX_BUF_SIZE>, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            STAKE_ENTRY_PREFIX.as_ref(),
+            tree.tree_pubkey().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &mpl_bubblegum_staking::id(),
+    )
+    .0
+}
+
+fn stake_authority() -> Pubkey {
+    Pubkey::find_program_address(
+        &[STAKE_AUTHORITY_PREFIX.as_ref()],
+        &mpl_bubblegum_staking::id(),
+    )
+    .0
+}
+
+fn rewards(owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[REWARDS_PREFIX.as_ref(), owner.as_ref()],
+        &mpl_bubblegum_staking::id(),
+    )
+    .0
+}
+
+async fn stake_tx(tree: &Tree<MAX_DEPTH, MAX_BUF_SIZE>, args: &LeafArgs) -> Result<StakeBuilder> {
+    let accounts = mpl_bubblegum_staking::accounts::Stake {
+        owner: args.owner.pubkey(),
+        stake_entry: stake_entry(tree, args.nonce),
+        stake_authority: stake_authority(),
+        tree_authority: tree.authority(),
+        previous_leaf_delegate: args.delegate.pubkey(),
+        merkle_tree: tree.tree_pubkey(),
+        log_wrapper: spl_noop::id(),
+        compression_program: spl_account_compression::id(),
+        voucher: tree.voucher(args.nonce),
+        leaf_freeze: tree.leaf_freeze(args.nonce),
+        delegate_expiry: tree.delegate_expiry(args.nonce),
+        previous_expiry_owner: args.owner.pubkey(),
+        bubblegum_program: mpl_bubblegum::id(),
+        system_program: system_program::id(),
+    };
+
+    let data = mpl_bubblegum_staking::instruction::Stake {
+        root: tree.decode_root().await?,
+        data_hash: args.data_hash,
+        creator_hash: args.creator_hash,
+        nonce: args.nonce,
+        index: args.index(),
+    };
+
+    Ok(tree.external_leaf_tx(
+        mpl_bubblegum_staking::id(),
+        accounts,
+        data,
+        args.index(),
+        args.owner.pubkey(),
+        &[&args.owner],
+    ))
+}
+
+async fn unstake_tx(
+    tree: &Tree<MAX_DEPTH, MAX_BUF_SIZE>,
+    args: &LeafArgs,
+) -> Result<UnstakeBuilder> {
+    let accounts = mpl_bubblegum_staking::accounts::Unstake {
+        owner: args.owner.pubkey(),
+        stake_entry: stake_entry(tree, args.nonce),
+        rewards: rewards(&args.owner.pubkey()),
+        stake_authority: stake_authority(),
+        tree_authority: tree.authority(),
+        merkle_tree: tree.tree_pubkey(),
+        log_wrapper: spl_noop::id(),
+        compression_program: spl_account_compression::id(),
+        voucher: tree.voucher(args.nonce),
+        delegate_expiry: tree.delegate_expiry(args.nonce),
+        leaf_freeze: tree.leaf_freeze(args.nonce),
+        bubblegum_program: mpl_bubblegum::id(),
+        system_program: system_program::id(),
+    };
+
+    let data = mpl_bubblegum_staking::instruction::Unstake {
+        root: tree.decode_root().await?,
+        data_hash: args.data_hash,
+        creator_hash: args.creator_hash,
+        index: args.index(),
+    };
+
+    Ok(tree.external_leaf_tx(
+        mpl_bubblegum_staking::id(),
+        accounts,
+        data,
+        args.index(),
+        args.owner.pubkey(),
+        &[&args.owner],
+    ))
+}
+
+#[tokio::test]
+async fn test_rewards_accrue_per_staked_asset() {
+    let (mut context, tree, first, second) = context_tree_and_leaves().await.unwrap();
+    let owner = first.owner.pubkey();
+
+    for leaf in [&first, &second] {
+        stake_tx(&tree, leaf)
+            .await
+            .unwrap()
+            .execute()
+            .await
+            .unwrap();
+    }
+    let first_entry: StakeEntry = tree
+        .read_account_data(stake_entry(&tree, first.nonce))
+        .await
+        .unwrap();
+    let second_entry: StakeEntry = tree
+        .read_account_data(stake_entry(&tree, second.nonce))
+        .await
+        .unwrap();
+    assert_eq!(first_entry.owner, owner);
+
+    let first_unstake_slot = first_entry.staked_slot + 100;
+    context.warp_to_slot(first_unstake_slot).unwrap();
+    unstake_tx(&tree, &first)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+
+    let accrued: Rewards = tree.read_account_data(rewards(&owner)).await.unwrap();
+    let expected = (first_unstake_slot - first_entry.staked_slot) * REWARDS_PER_SLOT;
+    assert_eq!(accrued.accrued, expected);
+
+    // The second asset kept accruing rewards in the meantime.
+    let second_unstake_slot = first_unstake_slot + 50;
+    context.warp_to_slot(second_unstake_slot).unwrap();
+    unstake_tx(&tree, &second)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+
+    let accrued: Rewards = tree.read_account_data(rewards(&owner)).await.unwrap();
+    let expected = expected + (second_unstake_slot - second_entry.staked_slot) * REWARDS_PER_SLOT;
+    assert_eq!(accrued.accrued, expected);
+
+    // Both assets are thawed and back with their owner, which is their delegate again.
+    for leaf in [&first, &second] {
+        for address in [stake_entry(&tree, leaf.nonce), tree.leaf_freeze(leaf.nonce)] {
+            assert!(tree.client().get_account(address).await.unwrap().is_none());
+        }
+        tree.transfer(leaf, Keypair::new().pubkey()).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_staked_assets_are_frozen() {
+    let (_context, tree, first, _) = context_tree_and_leaves().await.unwrap();
+
+    stake_tx(&tree, &first)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+
+    // The owner can't move the asset while it's staked, so the stake entry can't be orphaned.
+    let err = tree
+        .transfer(&first, Keypair::new().pubkey())
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::AssetIsFrozen));
+
+    unstake_tx(&tree, &first)
+        .await
+        .unwrap()
+        .execute()
+        .await
+        .unwrap();
+
+    // The rent of the freeze record went back to the owner, rather than staying with the stake
+    // authority.
+    assert!(tree
+        .client()
+        .get_account(stake_authority())
+        .await
+        .unwrap()
+        .is_none());
+
+    tree.transfer(&first, Keypair::new().pubkey())
+        .await
+        .unwrap();
+}
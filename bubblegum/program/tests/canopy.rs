@@ -0,0 +1,124 @@
+// Measures how the canopy depth of a tree affects the cost of operations that require proofs,
+// in order to help with picking canopy sizes. The results are printed as a table, so run with
+// `cargo test --test canopy -- --nocapture` to see them.
+pub mod utils;
+
+use mpl_bubblegum::client::{estimate, rent};
+use solana_program_test::tokio;
+use solana_sdk::{
+    message::Message,
+    packet::PACKET_DATA_SIZE,
+    signature::{Keypair, Signer},
+};
+
+use utils::{context::BubblegumTestContext, metrics, LeafArgs, Tree};
+
+const MAX_DEPTH: usize = 14;
+const MAX_BUF_SIZE: usize = 64;
+const MAX_CANOPY_DEPTH: u32 = 10;
+const NUM_LEAVES: u64 = 16;
+const MINT_PARALLELISM: usize = 8;
+
+struct Measurement {
+    canopy_depth: u32,
+    account_size: usize,
+    proof_accounts: usize,
+    num_accounts: usize,
+    tx_size: usize,
+    compute_units: u64,
+}
+
+#[tokio::test]
+async fn test_transfer_cost_by_canopy_depth() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let payer = context.payer();
+    let mut measurements = Vec::new();
+
+    for canopy_depth in 0..=MAX_CANOPY_DEPTH {
+        let mut tree = Tree::<MAX_DEPTH, MAX_BUF_SIZE>::with_creator(&payer, context.client());
+        tree.canopy_depth = canopy_depth;
+        assert_eq!(
+            rent::merkle_tree_account_size(MAX_DEPTH as u32, MAX_BUF_SIZE as u32, canopy_depth),
+            tree.merkle_tree_account_size()
+        );
+        tree.alloc(&payer).await.unwrap();
+        tree.create(&payer).await.unwrap();
+
+        let mut leaves: Vec<LeafArgs> = (0..NUM_LEAVES)
+            .map(|nonce| {
+                LeafArgs::new(
+                    &payer,
+                    context.default_metadata_args(format!("test{}", nonce), "tst"),
+                )
+            })
+            .collect();
+        tree.mint_concurrent(&mut leaves, MINT_PARALLELISM)
+            .await
+            .unwrap();
+
+        let leaf = &leaves[0];
+        let proof = tree.proof(leaf.index());
+        let mut remaining = tree.transfer_companions();
+        remaining.extend(proof.iter().copied());
+        let mut tx = tree
+            .transfer_tx(leaf, Keypair::new().pubkey())
+            .await
+            .unwrap();
+        tx.set_additional_accounts(&remaining);
+
+        let message = Message::new(&[tx.instruction()], Some(&payer.pubkey()));
+        let num_signatures = usize::from(message.header.num_required_signatures);
+        // One byte for the number of signatures, followed by the signatures and the message.
+        let tx_size = 1 + num_signatures * 64 + message.serialize().len();
+
+        let logs = tx.execute_with_logs().await.unwrap();
+
+        // The static estimate matches the actual transaction.
+        let params = estimate::TreeParams {
+            max_depth: MAX_DEPTH as u32,
+            max_buffer_size: MAX_BUF_SIZE as u32,
+        };
+        let cost = estimate::transfer_cost(params, canopy_depth, 0).unwrap();
+        assert_eq!(cost.tx_size, tx_size);
+        assert_eq!(cost.num_accounts, message.account_keys.len());
+        assert_eq!(cost.proof_accounts, proof.len());
+
+        measurements.push(Measurement {
+            canopy_depth,
+            account_size: tree.merkle_tree_account_size(),
+            proof_accounts: proof.len(),
+            num_accounts: message.account_keys.len(),
+            tx_size,
+            compute_units: metrics::compute_units(&logs, &mpl_bubblegum::id().to_string()),
+        });
+    }
+
+    println!(
+        "Transfers for a tree with max_depth = {} and max_buffer_size = {}:",
+        MAX_DEPTH, MAX_BUF_SIZE
+    );
+    println!("canopy_depth  account_size  proof_accounts  num_accounts  tx_size  compute_units");
+    for m in measurements.iter() {
+        println!(
+            "{:>12}  {:>12}  {:>14}  {:>12}  {:>7}  {:>13}",
+            m.canopy_depth,
+            m.account_size,
+            m.proof_accounts,
+            m.num_accounts,
+            m.tx_size,
+            m.compute_units
+        );
+    }
+
+    for m in measurements.iter() {
+        assert_eq!(m.proof_accounts, MAX_DEPTH - m.canopy_depth as usize);
+        assert!(m.tx_size <= PACKET_DATA_SIZE);
+    }
+
+    for (m, next) in measurements.iter().zip(measurements.iter().skip(1)) {
+        // Each level of canopy saves a proof account, and costs space in the tree account.
+        assert_eq!(next.num_accounts + 1, m.num_accounts);
+        assert!(next.tx_size < m.tx_size);
+        assert!(next.account_size > m.account_size);
+    }
+}
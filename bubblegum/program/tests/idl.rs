@@ -0,0 +1,79 @@
+// Checks that the IDL generated from the program source matches the checked-in copy that the
+// JS client is generated from, so that interface changes are always deliberate. After such a
+// change, run `UPDATE_IDL=1 cargo test --test idl` to update the copy, and regenerate the
+// client with `yarn api:gen` in `../js`.
+use serde_json::Value;
+use std::{env, fs, path::Path};
+
+// How many lines to show around the first difference.
+const DIFF_CONTEXT: usize = 5;
+
+// Returns the IDL generated from `src/lib.rs` as JSON, docs included as `anchor build` does,
+// without the `metadata` field, which solita adds when generating the client.
+fn generate_idl(version: String) -> Value {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let idl = anchor_syn::idl::file::parse(manifest_dir.join("src/lib.rs"), version, false, false)
+        .expect("failed to parse the program")
+        .expect("the program has no IDL");
+    serde_json::to_value(idl).unwrap()
+}
+
+// Describes where the pretty printed `expected` and `actual` first differ.
+fn describe_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let first = expected
+        .iter()
+        .zip(actual.iter())
+        .position(|(e, a)| e != a)
+        .unwrap_or_else(|| expected.len().min(actual.len()));
+
+    let start = first.saturating_sub(DIFF_CONTEXT);
+    let mut out = format!("first difference at line {}:\n", first + 1);
+    for (label, lines) in [("checked-in", &expected), ("generated", &actual)] {
+        out.push_str(&format!("--- {}\n", label));
+        for (index, line) in lines
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(2 * DIFF_CONTEXT + 1)
+        {
+            let marker = if index == first { '>' } else { ' ' };
+            out.push_str(&format!("{}{:5} {}\n", marker, index + 1, line));
+        }
+    }
+    out
+}
+
+#[test]
+fn idl_matches_checked_in_copy() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../js/idl/bubblegum.json");
+    let mut checked_in: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    let metadata = checked_in.as_object_mut().unwrap().remove("metadata");
+    let version = checked_in["version"].as_str().unwrap().to_owned();
+
+    let mut generated = generate_idl(version);
+    if generated == checked_in {
+        return;
+    }
+
+    if env::var_os("UPDATE_IDL").is_some() {
+        if let Some(metadata) = metadata {
+            generated
+                .as_object_mut()
+                .unwrap()
+                .insert("metadata".to_owned(), metadata);
+        }
+        fs::write(&path, serde_json::to_string_pretty(&generated).unwrap()).unwrap();
+        return;
+    }
+
+    panic!(
+        "the IDL no longer matches {} (run with UPDATE_IDL=1 to update it)\n{}",
+        path.display(),
+        describe_diff(
+            &serde_json::to_string_pretty(&checked_in).unwrap(),
+            &serde_json::to_string_pretty(&generated).unwrap(),
+        )
+    );
+}
@@ -0,0 +1,521 @@
+// Round-trips the data of every instruction through Borsh and through the `InstructionData`
+// path of Anchor, with arguments drawn from a fixed pseudo-random sequence. Integrators that
+// build transactions by hand rely on the encoding staying the same, and on malformed data being
+// rejected instead of crashing the program.
+use anchor_lang::{AnchorDeserialize, AnchorSerialize, Discriminator, InstructionData};
+use mpl_bubblegum::{
+    client::instruction_from_log_name,
+    get_instruction_type, instruction,
+    state::{
+        metaplex_adapter::{
+            Collection, Creator, MetadataArgs, TokenProgramVersion, TokenStandard, UseMethod, Uses,
+        },
+        BatchTransfer, FeatureFlags, HashedMetadataArgs, LoggingMode,
+    },
+    InstructionName,
+};
+use solana_program::pubkey::Pubkey;
+use std::{cell::RefCell, collections::HashMap};
+
+const ITERATIONS: usize = 32;
+// Upper bound for the length of generated strings and vectors.
+const MAX_LEN: u64 = 12;
+
+thread_local! {
+    // The instruction each `FeatureFlags` bit was seen for, so that no two share one.
+    static FEATURE_BITS: RefCell<HashMap<u32, InstructionName>> = RefCell::new(HashMap::new());
+}
+
+// There's no dependency on a fuzzing or property testing crate, so a xorshift generator with a
+// fixed seed provides the inputs, which keeps failures reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn flag(&mut self) -> bool {
+        self.below(2) == 1
+    }
+
+    fn bytes(&mut self, len: usize) -> Vec<u8> {
+        (0..len).map(|_| self.next() as u8).collect()
+    }
+
+    fn hash(&mut self) -> [u8; 32] {
+        self.bytes(32).try_into().unwrap()
+    }
+
+    fn pubkey(&mut self) -> Pubkey {
+        Pubkey::new_from_array(self.hash())
+    }
+
+    // Includes multi-byte characters, so that lengths in bytes and in characters differ.
+    fn string(&mut self) -> String {
+        let len = self.below(MAX_LEN);
+        (0..len)
+            .map(|_| match self.below(3) {
+                0 => 'é',
+                1 => '🌳',
+                _ => char::from(b'a' + self.below(26) as u8),
+            })
+            .collect()
+    }
+
+    fn vec<T>(&mut self, f: impl Fn(&mut Self) -> T) -> Vec<T> {
+        let len = self.below(MAX_LEN);
+        (0..len).map(|_| f(self)).collect()
+    }
+
+    fn option<T>(&mut self, f: impl Fn(&mut Self) -> T) -> Option<T> {
+        self.flag().then(|| f(self))
+    }
+}
+
+fn creator(rng: &mut Rng) -> Creator {
+    Creator {
+        address: rng.pubkey(),
+        verified: rng.flag(),
+        share: rng.next() as u8,
+    }
+}
+
+fn metadata(rng: &mut Rng) -> MetadataArgs {
+    MetadataArgs {
+        name: rng.string(),
+        symbol: rng.string(),
+        uri: rng.string(),
+        seller_fee_basis_points: rng.next() as u16,
+        primary_sale_happened: rng.flag(),
+        is_mutable: rng.flag(),
+        edition_nonce: rng.option(|rng| rng.next() as u8),
+        token_standard: rng.option(|rng| match rng.below(4) {
+            0 => TokenStandard::NonFungible,
+            1 => TokenStandard::FungibleAsset,
+            2 => TokenStandard::Fungible,
+            _ => TokenStandard::NonFungibleEdition,
+        }),
+        collection: rng.option(|rng| Collection {
+            verified: rng.flag(),
+            key: rng.pubkey(),
+        }),
+        uses: rng.option(|rng| Uses {
+            use_method: match rng.below(3) {
+                0 => UseMethod::Burn,
+                1 => UseMethod::Multiple,
+                _ => UseMethod::Single,
+            },
+            remaining: rng.next(),
+            total: rng.next(),
+        }),
+        token_program_version: if rng.flag() {
+            TokenProgramVersion::Original
+        } else {
+            TokenProgramVersion::Token2022
+        },
+        creators: rng.vec(creator),
+    }
+}
+
+fn hashed_metadata(rng: &mut Rng) -> HashedMetadataArgs {
+    HashedMetadataArgs {
+        name: rng.string(),
+        symbol: rng.string(),
+        seller_fee_basis_points: rng.next() as u16,
+        creators: rng.vec(creator),
+        metadata_args_hash: rng.hash(),
+    }
+}
+
+fn batch_transfer(rng: &mut Rng) -> BatchTransfer {
+    BatchTransfer {
+        root: rng.hash(),
+        data_hash: rng.hash(),
+        creator_hash: rng.hash(),
+        nonce: rng.next(),
+        index: rng.next() as u32,
+        leaf_delegate: rng.pubkey(),
+        new_leaf_owner: rng.pubkey(),
+        proof_length: rng.next() as u8,
+    }
+}
+
+fn logging_mode(rng: &mut Rng) -> LoggingMode {
+    if rng.flag() {
+        LoggingMode::Noop
+    } else {
+        LoggingMode::None
+    }
+}
+
+// `name` is the one Anchor logs for the instruction, which indexers map back to the
+// instruction via `instruction_from_log_name`.
+fn check<T>(name: &str, data: T, rng: &mut Rng)
+where
+    T: AnchorSerialize + AnchorDeserialize + Discriminator + InstructionData,
+{
+    let bytes = data.try_to_vec().unwrap();
+    let decoded = T::try_from_slice(&bytes).unwrap();
+    assert_eq!(decoded.try_to_vec().unwrap(), bytes, "{}", name);
+
+    let full = data.data();
+    assert_eq!(full[..8], T::discriminator(), "{}", name);
+    assert_eq!(full[8..], bytes[..], "{}", name);
+    let expected = instruction_from_log_name(name);
+    assert_ne!(expected, InstructionName::Unknown, "{}", name);
+    assert_eq!(get_instruction_type(&full), expected, "{}", name);
+
+    // Instructions that check the `FeatureFlags` mask are disabled via a bit of their own.
+    assert_eq!(
+        FeatureFlags::instruction_bit(expected).is_some(),
+        FeatureFlags::ENFORCED.contains(&expected),
+        "{}",
+        name
+    );
+    if let Some(bit) = FeatureFlags::instruction_bit(expected) {
+        assert!(bit < u64::BITS, "{}", name);
+        FEATURE_BITS.with(|bits| {
+            let previous = *bits.borrow_mut().entry(bit).or_insert(expected);
+            assert_eq!(previous, expected, "{}", name);
+        });
+    }
+
+    // Borsh encodings are not prefixes of one another, so truncated data never decodes, and
+    // neither does data with trailing bytes.
+    for len in 0..bytes.len() {
+        assert!(T::try_from_slice(&bytes[..len]).is_err(), "{}", name);
+    }
+    let mut extended = bytes.clone();
+    extended.push(rng.next() as u8);
+    assert!(T::try_from_slice(&extended).is_err(), "{}", name);
+
+    // Arbitrary data may or may not decode, but must not panic.
+    let garbage = rng.bytes(bytes.len());
+    let _ = T::try_from_slice(&garbage);
+}
+
+// Leaf instructions share the arguments that identify the leaf and its proof.
+macro_rules! leaf_instruction {
+    ($rng:expr, $name:ident $(, $field:ident: $value:expr)*) => {
+        check(
+            stringify!($name),
+            instruction::$name {
+                root: $rng.hash(),
+                data_hash: $rng.hash(),
+                creator_hash: $rng.hash(),
+                nonce: $rng.next(),
+                index: $rng.next() as u32,
+                $($field: $value,)*
+            },
+            $rng,
+        )
+    };
+}
+
+#[test]
+fn instruction_data_round_trips() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..ITERATIONS {
+        let rng = &mut rng;
+
+        check(
+            "CreateTree",
+            instruction::CreateTree {
+                max_depth: rng.next() as u32,
+                max_buffer_size: rng.next() as u32,
+                logging_mode: logging_mode(rng),
+                creator_immutable: rng.flag(),
+                is_public: rng.flag(),
+            },
+            rng,
+        );
+        check("SetTreeDelegate", instruction::SetTreeDelegate, rng);
+        check(
+            "ProposeTreeCreator",
+            instruction::ProposeTreeCreator {
+                reset_tree_delegate: rng.flag(),
+            },
+            rng,
+        );
+        check("AcceptTreeCreator", instruction::AcceptTreeCreator, rng);
+        check(
+            "CancelTreeCreatorTransfer",
+            instruction::CancelTreeCreatorTransfer,
+            rng,
+        );
+        check(
+            "SetFeatureFlags",
+            instruction::SetFeatureFlags {
+                disabled_instructions: rng.next(),
+            },
+            rng,
+        );
+        check(
+            "WithdrawFromTreeAuthority",
+            instruction::WithdrawFromTreeAuthority { amount: rng.next() },
+            rng,
+        );
+        check(
+            "SetTreeHashOnly",
+            instruction::SetTreeHashOnly {
+                is_hash_only: rng.flag(),
+            },
+            rng,
+        );
+        check(
+            "SetLoggingMode",
+            instruction::SetLoggingMode {
+                logging_mode: logging_mode(rng),
+            },
+            rng,
+        );
+        check(
+            "SetTreeNonTransferable",
+            instruction::SetTreeNonTransferable {
+                is_non_transferable: rng.flag(),
+            },
+            rng,
+        );
+        check(
+            "SetTreePublic",
+            instruction::SetTreePublic {
+                is_public: rng.flag(),
+            },
+            rng,
+        );
+        check(
+            "SetTreePaused",
+            instruction::SetTreePaused {
+                is_paused: rng.flag(),
+            },
+            rng,
+        );
+        check(
+            "SetMintPrice",
+            instruction::SetMintPrice {
+                lamports: rng.next(),
+                treasury: rng.pubkey(),
+                token_mint: rng.pubkey(),
+                token_amount: rng.next(),
+            },
+            rng,
+        );
+        check("CreateDenyList", instruction::CreateDenyList, rng);
+        check(
+            "UpdateDenyList",
+            instruction::UpdateDenyList {
+                add: rng.vec(Rng::pubkey),
+                remove: rng.vec(Rng::pubkey),
+            },
+            rng,
+        );
+        check(
+            "SetTreeDenyList",
+            instruction::SetTreeDenyList {
+                deny_list: rng.pubkey(),
+            },
+            rng,
+        );
+        check("AddTreeDelegate", instruction::AddTreeDelegate, rng);
+        check("RemoveTreeDelegate", instruction::RemoveTreeDelegate, rng);
+        check(
+            "SetSoulboundOverride",
+            instruction::SetSoulboundOverride {
+                nonce: rng.next(),
+                is_non_transferable: rng.flag(),
+            },
+            rng,
+        );
+        check(
+            "SetTreeMetadata",
+            instruction::SetTreeMetadata {
+                name: rng.string(),
+                uri: rng.string(),
+            },
+            rng,
+        );
+        check(
+            "VerifyMetadataHash",
+            instruction::VerifyMetadataHash {
+                metadata: metadata(rng),
+                data_hash: rng.hash(),
+                creator_hash: rng.hash(),
+            },
+            rng,
+        );
+        check(
+            "InitOnChainMetadata",
+            instruction::InitOnChainMetadata {
+                root: rng.hash(),
+                nonce: rng.next(),
+                index: rng.next() as u32,
+                metadata: metadata(rng),
+                asset_id: rng.pubkey(),
+                json_hash: rng.hash(),
+                json_size: rng.next() as u32,
+            },
+            rng,
+        );
+        check(
+            "WriteMetadataChunk",
+            instruction::WriteMetadataChunk {
+                offset: rng.next() as u32,
+                chunk: rng.vec(|rng| rng.next() as u8),
+            },
+            rng,
+        );
+        check(
+            "MintV1",
+            instruction::MintV1 {
+                message: metadata(rng),
+            },
+            rng,
+        );
+        check(
+            "MintV1WithReceipt",
+            instruction::MintV1WithReceipt {
+                message: metadata(rng),
+            },
+            rng,
+        );
+        check(
+            "MintToCollectionV1",
+            instruction::MintToCollectionV1 {
+                message: metadata(rng),
+            },
+            rng,
+        );
+        check(
+            "MintBatchV1",
+            instruction::MintBatchV1 {
+                messages: rng.vec(metadata),
+            },
+            rng,
+        );
+        check(
+            "CloseAssetReceipt",
+            instruction::CloseAssetReceipt {
+                root: rng.hash(),
+                data_hash: rng.hash(),
+                creator_hash: rng.hash(),
+                index: rng.next() as u32,
+                burned: rng.flag(),
+            },
+            rng,
+        );
+        check(
+            "MintAirdrop",
+            instruction::MintAirdrop {
+                message: metadata(rng),
+                owners: rng.vec(Rng::pubkey),
+            },
+            rng,
+        );
+        check(
+            "MintV1Hashed",
+            instruction::MintV1Hashed {
+                message: hashed_metadata(rng),
+            },
+            rng,
+        );
+        leaf_instruction!(rng, VerifyCreator, message: metadata(rng));
+        leaf_instruction!(rng, UnverifyCreator, message: metadata(rng));
+        check(
+            "UpdateMetadata",
+            instruction::UpdateMetadata {
+                root: rng.hash(),
+                nonce: rng.next(),
+                index: rng.next() as u32,
+                current_metadata: metadata(rng),
+                new_metadata: metadata(rng),
+            },
+            rng,
+        );
+        check(
+            "ProposeMetadataUpdate",
+            instruction::ProposeMetadataUpdate {
+                nonce: rng.next(),
+                new_metadata: metadata(rng),
+            },
+            rng,
+        );
+        check(
+            "ApproveMetadataUpdate",
+            instruction::ApproveMetadataUpdate {
+                root: rng.hash(),
+                nonce: rng.next(),
+                index: rng.next() as u32,
+                current_metadata: metadata(rng),
+                new_metadata: metadata(rng),
+            },
+            rng,
+        );
+        check(
+            "CancelMetadataProposal",
+            instruction::CancelMetadataProposal { nonce: rng.next() },
+            rng,
+        );
+        leaf_instruction!(rng, VerifyCollection, message: metadata(rng));
+        leaf_instruction!(rng, UnverifyCollection, message: metadata(rng));
+        leaf_instruction!(
+            rng,
+            SetAndVerifyCollection,
+            message: metadata(rng),
+            collection: rng.hash()
+        );
+        leaf_instruction!(rng, Transfer);
+        check(
+            "TransferBatch",
+            instruction::TransferBatch {
+                transfers: rng.vec(batch_transfer),
+            },
+            rng,
+        );
+        leaf_instruction!(rng, Delegate);
+        leaf_instruction!(
+            rng,
+            DelegateWithExpiry,
+            expiry_slot: rng.option(Rng::next)
+        );
+        leaf_instruction!(rng, Freeze);
+        check("Thaw", instruction::Thaw, rng);
+        leaf_instruction!(rng, Burn);
+        leaf_instruction!(rng, BurnWithReceipt);
+        check("CloseBurnReceipt", instruction::CloseBurnReceipt, rng);
+        check("CloseDelegateExpiry", instruction::CloseDelegateExpiry, rng);
+        leaf_instruction!(rng, Redeem);
+        check(
+            "CancelRedeem",
+            instruction::CancelRedeem { root: rng.hash() },
+            rng,
+        );
+        leaf_instruction!(rng, TransferTimelocked, unlock_slot: rng.next());
+        check(
+            "ClaimTimelockedTransfer",
+            instruction::ClaimTimelockedTransfer { root: rng.hash() },
+            rng,
+        );
+        check(
+            "CancelTimelockedTransfer",
+            instruction::CancelTimelockedTransfer { root: rng.hash() },
+            rng,
+        );
+        check(
+            "DecompressV1",
+            instruction::DecompressV1 {
+                metadata: metadata(rng),
+            },
+            rng,
+        );
+        check("Compress", instruction::Compress, rng);
+    }
+}
@@ -1,12 +1,37 @@
 pub mod utils;
 
+use anchor_lang::{error::ErrorCode, AccountSerialize};
+use futures::{future::join_all, StreamExt};
+use mpl_bubblegum::{
+    client::{
+        list_collection_assets, metas::unique_keys, verify_attestation, AssetState, BubblegumEvent,
+    },
+    error::BubblegumError,
+    hash::{hash_creators, hash_leaf, hash_metadata},
+    pda,
+    state::{
+        leaf_schema::LeafSchema, metaplex_adapter::Collection, FeatureFlags, LoggingMode,
+        OnChainMetadata, TreeConfig, MAX_AIRDROP_BATCH_SIZE, MAX_MINT_BATCH_SIZE, PROGRAM_VERSION,
+    },
+    utils::get_asset_id,
+    InstructionName,
+};
+use mpl_token_metadata::error::MetadataError;
 use solana_program_test::tokio;
-use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::{
+    instruction::Instruction,
+    keccak,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction::{self, SystemError},
+    transaction::Transaction,
+};
+use spl_account_compression::error::AccountCompressionError;
 
 use utils::{
     clone_keypair,
-    context::{BubblegumTestContext, DEFAULT_LAMPORTS_FUND_AMOUNT},
-    LeafArgs, Result, Tree,
+    context::{BubblegumTestContext, CollectionNft, DEFAULT_LAMPORTS_FUND_AMOUNT},
+    diff_accounts, replay_transaction, Error, FieldDiff, LeafArgs, Result, TransferBuilder, Tree,
 };
 
 // Test for multiple combinations?
@@ -35,6 +60,38 @@ async fn context_tree_and_leaf() -> Result<(
     Ok((context, tree, leaf))
 }
 
+// Creates a `BubblegumTestContext`, an empty `Tree` with default arguments, and a collection NFT
+// whose update authority is the tree delegate.
+async fn context_tree_and_collection(
+    sized: bool,
+) -> Result<(
+    BubblegumTestContext,
+    Tree<MAX_DEPTH, MAX_BUF_SIZE>,
+    CollectionNft,
+)> {
+    let mut context = BubblegumTestContext::new().await?;
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await?;
+    let collection = context
+        .create_collection(&tree.tree_delegate, sized)
+        .await?;
+
+    Ok((context, tree, collection))
+}
+
+// The `LeafArgs` of an asset that is part of `collection`, which is not verified yet.
+fn collection_member(context: &BubblegumTestContext, collection: Pubkey, nonce: u64) -> LeafArgs {
+    let mut metadata = context.default_metadata_args(format!("member {}", nonce), "tst");
+    metadata.collection = Some(Collection {
+        verified: false,
+        key: collection,
+    });
+    let mut args = LeafArgs::new(&context.payer(), metadata);
+    args.nonce = nonce;
+    args
+}
+
 #[tokio::test]
 async fn test_create_tree_and_mint_passes() {
     context_tree_and_leaf().await.unwrap();
@@ -42,18 +99,22 @@ async fn test_create_tree_and_mint_passes() {
 
 #[tokio::test]
 async fn test_creator_verify_and_unverify_passes() {
-    let (context, tree, mut leaf) = context_tree_and_leaf().await.unwrap();
+    let (context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let mut asset = tree.asset(&leaf);
 
-    tree.verify_creator(&leaf, &context.default_creators[0])
+    asset
+        .verify_creator(&context.default_creators[0])
         .await
         .unwrap();
-    // Reflect change.
-    leaf.metadata.creators[0].verified = true;
+    assert!(asset.leaf().metadata.creators[0].verified);
 
-    tree.unverify_creator(&leaf, &context.default_creators[0])
+    asset
+        .unverify_creator(&context.default_creators[0])
         .await
         .unwrap();
-    leaf.metadata.creators[0].verified = false;
+
+    // The leaf is usable after the round trip only if the hashes were refreshed.
+    asset.burn().await.unwrap();
 }
 
 #[tokio::test]
@@ -63,12 +124,16 @@ async fn test_delegate_passes() {
 
     tree.delegate(&leaf, new_delegate.pubkey()).await.unwrap();
     // Reflect changes.
-    leaf.delegate = new_delegate;
+    leaf = leaf.with_new_delegate(&new_delegate);
+    assert_eq!(leaf.delegate.pubkey(), new_delegate.pubkey());
+
+    // The owner can still act on the leaf after delegating it.
+    tree.burn(&leaf).await.unwrap();
 }
 
 #[tokio::test]
 async fn test_transfer_passes() {
-    let (mut context, tree, mut leaf) = context_tree_and_leaf().await.unwrap();
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
 
     let new_owner = Keypair::new();
     context
@@ -76,10 +141,13 @@ async fn test_transfer_passes() {
         .await
         .unwrap();
 
-    tree.transfer(&leaf, new_owner.pubkey()).await.unwrap();
+    let mut asset = tree.asset(&leaf);
+    asset.transfer_to(&new_owner).await.unwrap();
     // Both owner and delegate change post transfer.
-    leaf.owner = clone_keypair(&new_owner);
-    leaf.delegate = new_owner;
+    assert_eq!(asset.leaf().delegate.pubkey(), new_owner.pubkey());
+
+    // The new owner is now able to burn the leaf.
+    asset.burn().await.unwrap();
 }
 
 #[tokio::test]
@@ -93,5 +161,3450 @@ async fn test_set_tree_delegate_passes() {
     let (_, mut tree, _) = context_tree_and_leaf().await.unwrap();
     let new_tree_delegate = Keypair::new();
 
+    let before = tree.read_account(tree.authority()).await.unwrap();
     tree.set_tree_delegate(&new_tree_delegate).await.unwrap();
+    let after = tree.read_account(tree.authority()).await.unwrap();
+
+    let diff = diff_accounts::<TreeConfig>(&before, &after).unwrap();
+    assert_eq!(
+        diff,
+        vec![FieldDiff {
+            field: "tree_delegate".to_owned(),
+            before: format!("{:?}", tree.creator_pubkey()),
+            after: format!("{:?}", new_tree_delegate.pubkey()),
+        }]
+    );
+
+    // Handing the tree back only changes the delegate again.
+    let before = tree.read_tree_config().await.unwrap();
+    let creator = clone_keypair(&tree.tree_creator);
+    tree.set_tree_delegate(&creator).await.unwrap();
+    assert_only_changed!(
+        before,
+        tree.read_tree_config().await.unwrap(),
+        [tree_delegate]
+    );
+}
+
+#[tokio::test]
+async fn test_set_tree_metadata_passes() {
+    let (context, tree, _) = context_tree_and_leaf().await.unwrap();
+
+    tree.set_tree_metadata("My Tree", "https://example.com/tree.json")
+        .await
+        .unwrap();
+
+    let tree_metadata = tree.read_tree_metadata().await.unwrap();
+    assert_eq!(tree_metadata.merkle_tree, tree.tree_pubkey());
+    assert_eq!(tree_metadata.name, "My Tree");
+    assert_eq!(tree_metadata.uri, "https://example.com/tree.json");
+
+    // The metadata can be updated after the companion account has been created.
+    tree.set_tree_metadata("Renamed", "").await.unwrap();
+    let tree_metadata = tree.read_tree_metadata().await.unwrap();
+    assert_eq!(tree_metadata.name, "Renamed");
+    assert!(tree_metadata.uri.is_empty());
+
+    // Only the tree creator is allowed to change the metadata.
+    let mut tx = tree.set_tree_metadata_tx("Hijacked", "");
+    let impostor = context.default_creators[0].pubkey();
+    tx.accounts.tree_creator = impostor;
+    tx.accounts.payer = impostor;
+    tx.set_payer(impostor)
+        .set_signers(&[&context.default_creators[0]]);
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_custom_error(ErrorCode::ConstraintHasOne as u32));
+
+    // Names and uris above the maximum length are rejected.
+    let long_name = "x".repeat(mpl_bubblegum::state::MAX_TREE_NAME_LENGTH + 1);
+    let err = tree.set_tree_metadata(long_name, "").await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::TreeNameTooLong));
+    let long_uri = "x".repeat(mpl_bubblegum::state::MAX_TREE_URI_LENGTH + 1);
+    let err = tree.set_tree_metadata("", long_uri).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::TreeUriTooLong));
+}
+
+#[tokio::test]
+async fn test_mint_metadata_boundaries() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let mut nonce = 0;
+    for (case, metadata, expected) in context.boundary_metadata() {
+        let mut leaf = LeafArgs::new(&context.payer(), metadata);
+        leaf.nonce = nonce;
+        let result = tree.mint_v1(&tree.tree_delegate, &leaf).await;
+
+        match expected {
+            None => {
+                result.unwrap_or_else(|err| panic!("{}: {:?}", case, err));
+                nonce += 1;
+            }
+            Some(err) => assert!(result.unwrap_err().is_bubblegum_error(err), "{}", case),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_zero_share_creator() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let mut metadata = context.default_metadata_args("zero share", "tst");
+    metadata.creators.truncate(2);
+    metadata.creators[0].share = 0;
+    metadata.creators[1].share = 100;
+    let mut leaf = LeafArgs::new(&context.payer(), metadata);
+    tree.mint_v1(&tree.tree_delegate, &leaf).await.unwrap();
+
+    // Zero share creators can verify themselves, and the leaf is only usable afterwards if its
+    // creator hash accounts for them like for any other creator.
+    tree.verify_creator(&leaf, &context.default_creators[0])
+        .await
+        .unwrap();
+    leaf = leaf.with_updated_metadata(|metadata| metadata.creators[0].verified = true);
+    tree.burn(&leaf).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_verify_metadata_hash() {
+    let (_, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    assert!(tree.verify_metadata_hash(&leaf).await.unwrap());
+
+    // Stale hashes are reported as a mismatch rather than an error.
+    let mut stale = leaf.clone();
+    stale.metadata.name = "changed".to_owned();
+    assert!(!tree.verify_metadata_hash(&stale).await.unwrap());
+
+    let mut tx = tree.verify_metadata_hash_tx(&leaf);
+    tx.data.creator_hash = [0; 32];
+    assert_eq!(tx.execute_with_return::<bool>().await.unwrap(), Some(false));
+
+    // Refreshing the hashes makes them match again.
+    let updated = leaf.with_updated_metadata(|metadata| metadata.name = "changed".to_owned());
+    assert!(tree.verify_metadata_hash(&updated).await.unwrap());
+}
+
+// Checks that the hashes computed by the program, the ones reported via events, and the ones
+// used by the off-chain mirror all follow the same spec.
+#[tokio::test]
+async fn test_hashes_match_across_program_and_client() {
+    let (context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    let ops = tree.replay_ops();
+    let event = ops
+        .last()
+        .unwrap()
+        .events
+        .iter()
+        .find_map(|event| match event {
+            BubblegumEvent::LeafSchema(event) => Some(event.clone()),
+            _ => None,
+        })
+        .unwrap();
+
+    let expected = LeafSchema::new_v0(
+        tree.asset_id(leaf.nonce),
+        leaf.owner.pubkey(),
+        leaf.delegate.pubkey(),
+        leaf.nonce,
+        hash_metadata(&leaf.metadata).unwrap(),
+        hash_creators(&leaf.metadata.creators).unwrap(),
+    );
+
+    assert_eq!(event.schema.data_hash(), leaf.data_hash);
+    assert_eq!(event.leaf_hash, hash_leaf(&expected));
+    assert_eq!(event.leaf_hash, hash_leaf(&event.schema));
+    assert_eq!(tree.mirror_root(), tree.decode_root().await.unwrap());
+
+    // Same after a change to the creator hash.
+    tree.verify_creator(&leaf, &context.default_creators[0])
+        .await
+        .unwrap();
+    let leaf = leaf.with_updated_metadata(|metadata| metadata.creators[0].verified = true);
+    assert_eq!(
+        tree.replay_ops().last().unwrap().leaf().unwrap().to_node(),
+        hash_leaf(&LeafSchema::new_v0(
+            tree.asset_id(leaf.nonce),
+            leaf.owner.pubkey(),
+            leaf.delegate.pubkey(),
+            leaf.nonce,
+            leaf.data_hash,
+            leaf.creator_hash,
+        ))
+    );
+    assert_eq!(tree.mirror_root(), tree.decode_root().await.unwrap());
+}
+
+#[tokio::test]
+async fn test_mint_v1_hashed() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+    let payer = context.payer();
+
+    let mut metadata = context.default_metadata_args("hashed", "hsh");
+    metadata.uri = "https://example.com/".repeat(10);
+    let leaf = LeafArgs::new(&payer, metadata);
+
+    // Trees have to opt into hashed mints.
+    let rejected = LeafArgs::new(&payer, context.default_metadata_args("rejected", "hsh"));
+    assert!(tree
+        .mint_v1_hashed(&tree.tree_delegate, &rejected)
+        .await
+        .is_err());
+
+    let before = tree.read_tree_config().await.unwrap();
+    tree.set_tree_hash_only(true).await.unwrap();
+    let after = tree.read_tree_config().await.unwrap();
+    assert!(after.is_hash_only);
+    assert_only_changed!(before, after, [is_hash_only]);
+    tree.mint_v1_hashed(&tree.tree_delegate, &leaf)
+        .await
+        .unwrap();
+    assert_eq!(tree.mirror_root(), tree.decode_root().await.unwrap());
+
+    // The attestation matches the full metadata, but not a tampered one.
+    let ops = tree.replay_ops();
+    let attestation = ops.last().unwrap().attestation().unwrap();
+    assert_eq!(attestation.nonce, 0);
+    assert!(verify_attestation(attestation, &leaf.metadata));
+    let mut tampered = leaf.metadata.clone();
+    tampered.uri = "https://example.com/other".to_owned();
+    assert!(!verify_attestation(attestation, &tampered));
+
+    // The leaf is the same as if it had been minted with the full metadata.
+    tree.transfer(&leaf, Keypair::new().pubkey()).await.unwrap();
+
+    // Hashed assets don't show up when listing, since their metadata is not known.
+    assert!(tree.list_assets(0..1).is_empty());
+}
+
+#[tokio::test]
+async fn test_withdraw_from_tree_authority() {
+    let (context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let payer = context.payer();
+    let destination = Keypair::new().pubkey();
+
+    // The tree authority starts out with exactly the rent exemption minimum.
+    assert_eq!(tree.tree_authority_surplus().await.unwrap(), 0);
+    assert!(tree
+        .withdraw_from_tree_authority(destination, 1)
+        .await
+        .is_err());
+
+    // Large enough for the destination to end up rent exempt after the first withdrawal.
+    const FUNDS: u64 = 1_000_000_000;
+    tree.fund_tree_authority(&payer, FUNDS).await.unwrap();
+    assert_eq!(tree.tree_authority_surplus().await.unwrap(), FUNDS);
+
+    tree.withdraw_from_tree_authority(destination, FUNDS / 4)
+        .await
+        .unwrap();
+    assert_eq!(tree.balance(destination).await.unwrap(), FUNDS / 4);
+    assert_eq!(tree.tree_authority_surplus().await.unwrap(), FUNDS * 3 / 4);
+
+    // Cannot dip into the rent exemption minimum.
+    assert!(tree
+        .withdraw_from_tree_authority(destination, FUNDS * 3 / 4 + 1)
+        .await
+        .is_err());
+
+    // Only the tree creator can withdraw.
+    let impostor = &context.default_creators[0];
+    let mut tx = tree.withdraw_from_tree_authority_tx(impostor.pubkey(), 1);
+    tx.accounts.tree_creator = impostor.pubkey();
+    tx.set_payer(impostor.pubkey()).set_signers(&[impostor]);
+    assert!(tx.execute().await.is_err());
+
+    tree.withdraw_from_tree_authority(destination, FUNDS * 3 / 4)
+        .await
+        .unwrap();
+    assert_eq!(tree.balance(destination).await.unwrap(), FUNDS);
+    assert_eq!(tree.tree_authority_surplus().await.unwrap(), 0);
+
+    // The tree authority can still sign for operations on the tree.
+    tree.transfer(&leaf, destination).await.unwrap();
+    let mut args = LeafArgs::new(&payer, context.default_metadata_args("second", "tst"));
+    args.nonce = 1;
+    tree.mint_v1(&tree.tree_delegate, &args).await.unwrap();
+    assert_eq!(tree.mirror_root(), tree.decode_root().await.unwrap());
+}
+
+// Whether Bubblegum itself invoked the noop program (the compression program does so at the
+// next level of nesting).
+fn wraps_events(logs: &[String]) -> bool {
+    let invoke = format!("Program {} invoke [2]", spl_noop::id());
+    logs.iter().any(|log| *log == invoke)
+}
+
+#[tokio::test]
+async fn test_parallel_mints() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let owners: Vec<Keypair> = (0..8).map(|_| Keypair::new()).collect();
+    for owner in owners.iter() {
+        context
+            .fund_account(owner.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    let leaves: Vec<LeafArgs> = owners
+        .iter()
+        .enumerate()
+        .map(|(index, owner)| {
+            LeafArgs::new(
+                owner,
+                context.default_metadata_args(format!("test{}", index), "tst"),
+            )
+        })
+        .collect();
+
+    // The on-chain state changes while other transactions are still in flight, so it's
+    // only checked once all of them went through.
+    let txs: Vec<_> = leaves
+        .iter()
+        .map(|leaf| {
+            let mut tx = tree.mint_v1_tx(&tree.tree_delegate, leaf);
+            tx.set_check_consistency(false);
+            tx
+        })
+        .collect();
+    for result in join_all(txs.iter().map(|tx| tx.execute())).await {
+        result.unwrap();
+    }
+    tree.assert_consistent().await.unwrap();
+
+    assert_eq!(
+        tree.read_tree_config().await.unwrap().num_minted,
+        owners.len() as u64
+    );
+    let distribution = tree.holder_distribution();
+    for owner in owners.iter() {
+        assert_eq!(distribution.counts.get(&owner.pubkey()), Some(&1));
+    }
+}
+
+#[tokio::test]
+async fn test_mint_concurrent() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let payer = context.payer();
+    let mut leaves: Vec<LeafArgs> = (0..12)
+        .map(|index| {
+            LeafArgs::new(
+                &payer,
+                context.default_metadata_args(format!("test{}", index), "tst"),
+            )
+        })
+        .collect();
+    tree.mint_concurrent(&mut leaves, 4).await.unwrap();
+
+    let mut nonces: Vec<u64> = leaves.iter().map(|leaf| leaf.nonce).collect();
+    nonces.sort_unstable();
+    assert_eq!(nonces, (0..12).collect::<Vec<_>>());
+
+    // The nonces match the leaves they were assigned to.
+    for leaf in leaves.iter() {
+        tree.transfer(leaf, Keypair::new().pubkey()).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_long_running_context() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let recipient = Keypair::new().pubkey();
+
+    // The blockhash the context started with is long gone by now.
+    let slot = context.current_slot().await.unwrap() + 1_000;
+    context.warp_to_slot(slot).unwrap();
+    context.fund_account(recipient, 1_000_000).await.unwrap();
+
+    // Sending the exact same transfer again only works in a new slot.
+    assert_eq!(context.advance_slot().await.unwrap(), slot + 1);
+    context.fund_account(recipient, 1_000_000).await.unwrap();
+    assert_eq!(
+        context.client().get_balance(recipient).await.unwrap(),
+        2_000_000
+    );
+}
+
+#[tokio::test]
+async fn test_unique_transactions() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    // Minting the same metadata twice in a row results in the same instruction.
+    let leaf = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("test", "tst"),
+    );
+    for _ in 0..2 {
+        tree.mint_v1_tx(&tree.tree_delegate, &leaf)
+            .set_unique(true)
+            .execute()
+            .await
+            .unwrap();
+    }
+    assert_eq!(tree.read_tree_config().await.unwrap().num_minted, 2);
+}
+
+#[tokio::test]
+async fn test_failed_transaction_context() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    let stranger = Keypair::new();
+    context
+        .fund_account(stranger.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    // Signed by someone other than the owner, together with a second instruction.
+    let mut tx = tree
+        .transfer_tx(&leaf, Keypair::new().pubkey())
+        .await
+        .unwrap();
+    tx.set_unique(true)
+        .set_payer(stranger.pubkey())
+        .set_signers(&[&stranger]);
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::LeafAuthorityMustSign));
+
+    let failure = match &err {
+        Error::Transaction(failure) => failure,
+        _ => panic!("unexpected error: {:?}", err),
+    };
+    assert_eq!(failure.instruction_index(), Some(0));
+    assert_eq!(failure.instructions.len(), 2);
+    assert!(failure
+        .logs
+        .iter()
+        .any(|log| log.contains("LeafAuthorityMustSign")));
+    assert!(err.to_string().starts_with("instruction 0 failed"));
+}
+
+#[tokio::test]
+async fn test_replay_transaction() {
+    let (context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    // Capture the accounts a transfer depends on, and the signed transfer itself.
+    let mut fixture_accounts = Vec::new();
+    for address in [leaf.owner.pubkey(), tree.tree_pubkey(), tree.authority()] {
+        let account = context
+            .client()
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap();
+        fixture_accounts.push((address, account));
+    }
+    let ix = tree
+        .transfer_tx(&leaf, Keypair::new().pubkey())
+        .await
+        .unwrap()
+        .instruction();
+    let blockhash = context.client().get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&leaf.owner.pubkey()),
+        &[&leaf.owner],
+        blockhash,
+    );
+    let raw_tx = base64::encode(bincode::serialize(&tx).unwrap());
+
+    let logs = replay_transaction(&fixture_accounts, &raw_tx)
+        .await
+        .unwrap();
+    assert!(logs.iter().any(|log| log.contains("Instruction: Transfer")));
+
+    // Without the tree, the transfer fails, and the failure has the logs attached.
+    let err = replay_transaction(&fixture_accounts[..1], &raw_tx)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Transaction(failure) if !failure.logs.is_empty()));
+
+    assert!(matches!(
+        replay_transaction(&fixture_accounts, "not a transaction").await,
+        Err(Error::InvalidTransaction(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_logging_mode() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let payer = context.payer();
+
+    for logging_mode in [LoggingMode::Noop, LoggingMode::None] {
+        let tree = Tree::<MAX_DEPTH, MAX_BUF_SIZE>::with_creator(&payer, context.client());
+        tree.alloc(&payer).await.unwrap();
+        let mut tx = tree.create_tree_tx(&payer);
+        tx.data.logging_mode = logging_mode;
+        tx.execute().await.unwrap();
+        assert_eq!(
+            tree.read_tree_config().await.unwrap().logging_mode,
+            logging_mode
+        );
+
+        let leaf = LeafArgs::new(&payer, context.default_metadata_args("test", "tst"));
+        let logs = tree
+            .mint_v1_tx(&tree.tree_delegate, &leaf)
+            .execute_with_logs()
+            .await
+            .unwrap();
+        assert_eq!(wraps_events(&logs), logging_mode == LoggingMode::Noop);
+
+        // Events are still emitted, so the harness keeps track of the tree either way.
+        let logs = tree
+            .burn_tx(&leaf)
+            .await
+            .unwrap()
+            .execute_with_logs()
+            .await
+            .unwrap();
+        assert_eq!(wraps_events(&logs), logging_mode == LoggingMode::Noop);
+    }
+}
+
+#[tokio::test]
+async fn test_switching_logging_mode_preserves_replay() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let payer = context.payer();
+
+    let tree = Tree::<MAX_DEPTH, MAX_BUF_SIZE>::with_creator(&payer, context.client());
+    tree.alloc(&payer).await.unwrap();
+    let mut tx = tree.create_tree_tx(&payer);
+    tx.data.logging_mode = LoggingMode::None;
+    tx.execute().await.unwrap();
+
+    let mut leaves = Vec::new();
+    for nonce in 0..4 {
+        // Switch modes halfway through.
+        if nonce == 2 {
+            // Only the tree creator can change the mode.
+            let impostor = &context.default_creators[0];
+            let mut tx = tree.set_logging_mode_tx(LoggingMode::Noop);
+            tx.accounts.tree_creator = impostor.pubkey();
+            tx.set_payer(impostor.pubkey()).set_signers(&[impostor]);
+            assert!(tx.execute().await.is_err());
+
+            tree.set_logging_mode(LoggingMode::Noop).await.unwrap();
+            assert_eq!(
+                tree.read_tree_config().await.unwrap().logging_mode,
+                LoggingMode::Noop
+            );
+        }
+
+        let mut args = LeafArgs::new(
+            &payer,
+            context.default_metadata_args(format!("test{}", nonce), "tst"),
+        );
+        args.nonce = nonce;
+        let logs = tree
+            .mint_v1_tx(&tree.tree_delegate, &args)
+            .execute_with_logs()
+            .await
+            .unwrap();
+        assert_eq!(wraps_events(&logs), nonce >= 2);
+        leaves.push(args);
+    }
+
+    // Change assets minted under the previous mode after the switch.
+    let new_owner = Keypair::new();
+    tree.transfer(&leaves[0], new_owner.pubkey()).await.unwrap();
+    tree.burn(&leaves[1]).await.unwrap();
+    tree.burn(&leaves[3]).await.unwrap();
+
+    let assets = tree.list_assets(0..4);
+    assert_eq!(assets.len(), 4);
+    for (nonce, asset) in assets.iter().enumerate() {
+        assert_eq!(asset.nonce, nonce as u64);
+        assert_eq!(asset.metadata.name, format!("test{}", nonce));
+        let expected = if nonce % 2 == 0 {
+            AssetState::Live
+        } else {
+            AssetState::Burned
+        };
+        assert_eq!(asset.state, expected);
+    }
+    match assets[0].leaf {
+        LeafSchema::V1 { owner, .. } => assert_eq!(owner, new_owner.pubkey()),
+    }
+
+    tree.assert_consistent().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_index_must_match_nonce() {
+    let (_, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    let mut tx = tree
+        .transfer_tx(&leaf, Keypair::new().pubkey())
+        .await
+        .unwrap();
+    tx.data.index = leaf.index() + 1;
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::LeafIndexNonceMismatch));
+
+    let mut tx = tree.burn_tx(&leaf).await.unwrap();
+    tx.data.nonce = leaf.nonce + 1;
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::LeafIndexNonceMismatch));
+
+    tree.burn(&leaf).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_leaf_being_redeemed_cannot_be_changed() {
+    let (context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    tree.redeem(&leaf).await.unwrap();
+    let err = tree.burn(&leaf).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::LeafBeingRedeemed));
+    let err = tree
+        .transfer(&leaf, Keypair::new().pubkey())
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::LeafBeingRedeemed));
+    let err = tree
+        .delegate(&leaf, Keypair::new().pubkey())
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::LeafBeingRedeemed));
+
+    // The leaf can be changed once it's back in the tree.
+    tree.cancel_redeem(&leaf).await.unwrap();
+    context
+        .assert_no_leaked_accounts(&[tree.voucher(leaf.nonce)])
+        .await
+        .unwrap();
+    tree.burn(&leaf).await.unwrap();
+    assert_eq!(tree.list_assets(0..1)[0].state, AssetState::Burned);
+}
+
+#[tokio::test]
+async fn test_transfer_timelocked() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let recipient = Keypair::new();
+    context
+        .fund_account(recipient.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    let unlock_slot = context.current_slot().await.unwrap() + 100;
+    tree.transfer_timelocked(&leaf, recipient.pubkey(), unlock_slot)
+        .await
+        .unwrap();
+    assert_eq!(tree.list_assets(0..1)[0].state, AssetState::PendingTransfer);
+
+    let err = tree
+        .claim_timelocked_transfer(&leaf, &recipient)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::TransferStillLocked));
+
+    // The owner can take the asset back before the unlock slot.
+    tree.cancel_timelocked_transfer(&leaf).await.unwrap();
+    assert_eq!(tree.list_assets(0..1)[0].state, AssetState::Live);
+    context
+        .assert_no_leaked_accounts(&[tree.pending_transfer(leaf.nonce)])
+        .await
+        .unwrap();
+
+    tree.transfer_timelocked(&leaf, recipient.pubkey(), unlock_slot + 1)
+        .await
+        .unwrap();
+    context.warp_to_slot(unlock_slot + 1).unwrap();
+
+    let err = tree.cancel_timelocked_transfer(&leaf).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::TransferUnlocked));
+
+    // Only the recipient can claim the asset.
+    let mut tx = tree
+        .claim_timelocked_transfer_tx(&leaf, &recipient)
+        .await
+        .unwrap();
+    let impostor = &context.default_creators[0];
+    tx.accounts.new_leaf_owner = impostor.pubkey();
+    tx.set_payer(impostor.pubkey()).set_signers(&[impostor]);
+    assert!(tx.execute().await.is_err());
+
+    tree.claim_timelocked_transfer(&leaf, &recipient)
+        .await
+        .unwrap();
+    let asset = &tree.list_assets(0..1)[0];
+    assert_eq!(asset.state, AssetState::Live);
+    match asset.leaf {
+        LeafSchema::V1 { owner, .. } => assert_eq!(owner, recipient.pubkey()),
+    }
+    context
+        .assert_no_leaked_accounts(&[tree.pending_transfer(leaf.nonce)])
+        .await
+        .unwrap();
+
+    // The recipient now owns the asset.
+    tree.burn(&leaf.with_new_owner(&recipient)).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_mint_airdrop() {
+    let (mut context, tree, _) = context_tree_and_leaf().await.unwrap();
+    let metadata = context.default_metadata_args("airdrop", "tst");
+    let owners = (0..5).map(|_| Keypair::new()).collect::<Vec<_>>();
+    let owner_keys = owners.iter().map(|o| o.pubkey()).collect::<Vec<_>>();
+
+    tree.mint_airdrop(&tree.tree_delegate, &metadata, &owner_keys)
+        .await
+        .unwrap();
+    assert_eq!(tree.read_tree_config().await.unwrap().num_minted, 6);
+
+    let assets = tree.list_assets(1..6);
+    assert_eq!(assets.len(), owners.len());
+    for (asset, owner) in assets.iter().zip(owner_keys.iter()) {
+        assert_eq!(asset.metadata.name, "airdrop");
+        match asset.leaf {
+            LeafSchema::V1 {
+                owner: leaf_owner,
+                delegate,
+                ..
+            } => {
+                assert_eq!(leaf_owner, *owner);
+                assert_eq!(delegate, *owner);
+            }
+        }
+    }
+
+    // Airdropped assets are regular leaves.
+    context
+        .fund_account(owners[2].pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    let mut leaf = LeafArgs::new(&owners[2], metadata.clone());
+    leaf.nonce = 3;
+    tree.burn(&leaf).await.unwrap();
+
+    // Batches must not be empty, nor larger than the maximum size.
+    let err = tree
+        .mint_airdrop(&tree.tree_delegate, &metadata, &[])
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidAirdropBatchSize));
+    let too_many = vec![Keypair::new().pubkey(); MAX_AIRDROP_BATCH_SIZE + 1];
+    let err = tree
+        .mint_airdrop(&tree.tree_delegate, &metadata, &too_many)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidAirdropBatchSize));
+
+    // Only the tree creator or delegate can airdrop.
+    let impostor = &context.default_creators[0];
+    assert!(tree
+        .mint_airdrop(impostor, &metadata, &owner_keys)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_mint_batch() {
+    let (context, tree, _) = context_tree_and_leaf().await.unwrap();
+    let payer = context.payer();
+    let messages = ["one", "two", "three"]
+        .iter()
+        .map(|name| {
+            let mut metadata = context.default_metadata_args(name, "tst");
+            metadata.creators.clear();
+            metadata
+        })
+        .collect::<Vec<_>>();
+
+    tree.mint_batch_v1(&tree.tree_delegate, &payer, &messages)
+        .await
+        .unwrap();
+    assert_eq!(tree.read_tree_config().await.unwrap().num_minted, 4);
+    let assets = tree.list_assets(1..4);
+    let names = assets
+        .iter()
+        .map(|asset| asset.metadata.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["one", "two", "three"]);
+
+    // Batched assets are regular leaves.
+    let mut leaf = LeafArgs::new(&payer, messages[1].clone());
+    leaf.nonce = 2;
+    tree.burn(&leaf).await.unwrap();
+
+    // A single invalid message fails the whole batch.
+    let mut invalid = messages.clone();
+    invalid[2].seller_fee_basis_points = 10_001;
+    let err = tree
+        .mint_batch_v1(&tree.tree_delegate, &payer, &invalid)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::MetadataBasisPointsTooHigh));
+    assert_eq!(tree.read_tree_config().await.unwrap().num_minted, 4);
+
+    // Batches must not be empty, nor larger than the maximum size.
+    let err = tree
+        .mint_batch_v1(&tree.tree_delegate, &payer, &[])
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidMintBatchSize));
+    let mut small = messages[0].clone();
+    small.uri.clear();
+    let too_many = vec![small; MAX_MINT_BATCH_SIZE + 1];
+    let err = tree
+        .mint_batch_v1(&tree.tree_delegate, &payer, &too_many)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidMintBatchSize));
+}
+
+#[tokio::test]
+async fn test_transfer_batch() {
+    let (mut context, tree, first) = context_tree_and_leaf().await.unwrap();
+    let payer = context.payer();
+    let mut second = LeafArgs::new(&payer, context.default_metadata_args("second", "tst"));
+    second.nonce = 1;
+    tree.mint_v1(&tree.tree_delegate, &second).await.unwrap();
+
+    let recipients = [Keypair::new(), Keypair::new()];
+    for recipient in recipients.iter() {
+        context
+            .fund_account(recipient.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    // Batches must not be empty, and only the owner can move its leaves.
+    let err = tree.transfer_batch(&payer, &[]).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidTransferBatchSize));
+    assert!(tree
+        .transfer_batch(&recipients[0], &[(&first, recipients[0].pubkey())])
+        .await
+        .is_err());
+
+    // Each transfer must come with exactly the accounts it declares.
+    let mut tx = tree
+        .transfer_batch_tx(&payer, &[(&first, recipients[0].pubkey())])
+        .await
+        .unwrap();
+    tx.data.transfers[0].proof_length -= 1;
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidBatchAccounts));
+
+    tree.transfer_batch(
+        &payer,
+        &[
+            (&first, recipients[0].pubkey()),
+            (&second, recipients[1].pubkey()),
+        ],
+    )
+    .await
+    .unwrap();
+
+    // Indexers pick up every leaf of the batch, not just the first one.
+    let assets = tree.list_assets(0..2);
+    assert_eq!(assets.len(), 2);
+    for (asset, recipient) in assets.iter().zip(recipients.iter()) {
+        assert_eq!(asset.state, AssetState::Live);
+        match asset.leaf {
+            LeafSchema::V1 { owner, .. } => assert_eq!(owner, recipient.pubkey()),
+        }
+    }
+    let distribution = tree.holder_distribution();
+    assert_eq!(distribution.unique_holders(), 2);
+    assert_eq!(distribution.count(&payer.pubkey()), 0);
+    assert_eq!(distribution.count(&recipients[0].pubkey()), 1);
+    assert_eq!(distribution.count(&recipients[1].pubkey()), 1);
+
+    // The recipients now own the leaves, and the previous owner no longer does.
+    assert!(tree
+        .transfer(&first, Keypair::new().pubkey())
+        .await
+        .is_err());
+    tree.burn(&first.with_new_owner(&recipients[0]))
+        .await
+        .unwrap();
+    tree.burn(&second.with_new_owner(&recipients[1]))
+        .await
+        .unwrap();
+
+    // Soulbound leaves cannot be part of a batch.
+    let mut third = LeafArgs::new(&payer, context.default_metadata_args("third", "tst"));
+    third.nonce = 2;
+    tree.mint_v1(&tree.tree_delegate, &third).await.unwrap();
+    tree.set_soulbound_override(&tree.tree_delegate, third.nonce, true)
+        .await
+        .unwrap();
+    let err = tree
+        .transfer_batch(&payer, &[(&third, recipients[0].pubkey())])
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::AssetIsSoulbound));
+}
+
+#[tokio::test]
+async fn test_duplicate_proof_accounts_are_normalized() {
+    let (mut context, tree, first) = context_tree_and_leaf().await.unwrap();
+    let payer = context.payer();
+    let mut leaves = vec![first];
+    for nonce in 1..4 {
+        let mut leaf = LeafArgs::new(&payer, context.default_metadata_args("leaf", "tst"));
+        leaf.nonce = nonce;
+        tree.mint_v1(&tree.tree_delegate, &leaf).await.unwrap();
+        leaves.push(leaf);
+    }
+
+    // The proofs of two sibling leaves share every node above them, so the accounts of a batch
+    // moving both contain the same nodes twice.
+    let recipient = Keypair::new();
+    context
+        .fund_account(recipient.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    let mut tx = tree
+        .transfer_batch_tx(
+            &payer,
+            &[
+                (&leaves[0], recipient.pubkey()),
+                (&leaves[1], recipient.pubkey()),
+            ],
+        )
+        .await
+        .unwrap();
+    let keys: Vec<Pubkey> = tx.additional_accounts.iter().map(|m| m.pubkey).collect();
+    let shared = tree.proof(leaves[0].index())[1];
+    assert_eq!(keys.iter().filter(|key| **key == shared).count(), 2);
+
+    // Flag a single occurrence as writable. Every occurrence gets the same flags, and the
+    // accounts stay in place, so the proofs still verify.
+    let first_occurrence = keys.iter().position(|key| *key == shared).unwrap();
+    tx.additional_accounts[first_occurrence].is_writable = true;
+    let ix = tx.instruction();
+    let tail = &ix.accounts[ix.accounts.len() - keys.len()..];
+    assert_eq!(tail.iter().map(|m| m.pubkey).collect::<Vec<_>>(), keys);
+    assert!(ix
+        .accounts
+        .iter()
+        .filter(|meta| meta.pubkey == shared)
+        .all(|meta| meta.is_writable));
+    assert_eq!(unique_keys(&ix.accounts).len(), ix.accounts.len() - 1);
+
+    tx.execute().await.unwrap();
+    for leaf in &leaves[..2] {
+        tree.burn(&leaf.clone().with_new_owner(&recipient))
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_redundant_operations_are_rejected() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let owner = context.payer().pubkey();
+
+    // The leaf has no delegate (i.e. its owner is the delegate), so neither of these
+    // would change it.
+    let err = tree.transfer(&leaf, owner).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::RedundantOperation));
+    let err = tree
+        .transfer_timelocked(&leaf, owner, 1_000)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::RedundantOperation));
+    let err = tree
+        .transfer_batch(&context.payer(), &[(&leaf, owner)])
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::RedundantOperation));
+    let err = tree.delegate(&leaf, owner).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::RedundantOperation));
+
+    let delegate = Keypair::new();
+    context
+        .fund_account(delegate.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    tree.delegate(&leaf, delegate.pubkey()).await.unwrap();
+    let leaf = leaf.with_new_delegate(&delegate);
+    let err = tree.delegate(&leaf, delegate.pubkey()).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::RedundantOperation));
+
+    // Transferring to the current owner clears the delegate, which is a change.
+    tree.transfer(&leaf, owner).await.unwrap();
+    tree.burn(&leaf.with_new_owner(&context.payer()))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_zero_address_is_rejected() {
+    let (context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let zero = Pubkey::default();
+
+    let err = tree.transfer(&leaf, zero).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidZeroAddress));
+    let err = tree
+        .transfer_timelocked(&leaf, zero, 1_000)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidZeroAddress));
+    let err = tree.delegate(&leaf, zero).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidZeroAddress));
+
+    let metadata = context.default_metadata_args("airdrop", "tst");
+    let err = tree
+        .mint_airdrop(
+            &tree.tree_delegate,
+            &metadata,
+            &[Keypair::new().pubkey(), zero],
+        )
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidZeroAddress));
+
+    let mut second = LeafArgs::new(&context.payer(), metadata);
+    second.nonce = 1;
+    let mut tx = tree.mint_v1_tx(&tree.tree_delegate, &second);
+    tx.accounts.leaf_owner = zero;
+    tx.accounts.leaf_delegate = zero;
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidZeroAddress));
+
+    // None of the above changed the tree.
+    assert_eq!(tree.read_tree_config().await.unwrap().num_minted, 1);
+    tree.transfer(&leaf, Keypair::new().pubkey()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_freeze_and_thaw() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let staker = Keypair::new();
+    context
+        .fund_account(staker.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    // Only the delegate of the leaf can freeze it.
+    let impostor = leaf.clone().with_new_delegate(&staker);
+    assert!(tree.freeze(&impostor).await.is_err());
+
+    tree.delegate(&leaf, staker.pubkey()).await.unwrap();
+    let leaf = leaf.with_new_delegate(&staker);
+    tree.freeze(&leaf).await.unwrap();
+    assert!(tree.freeze(&leaf).await.is_err());
+
+    // Neither the owner nor the delegate can move, burn, or redelegate a frozen leaf.
+    let recipient = Keypair::new().pubkey();
+    let results = vec![
+        tree.transfer(&leaf, recipient).await,
+        tree.transfer_timelocked(&leaf, recipient, 1_000).await,
+        tree.transfer_batch(&context.payer(), &[(&leaf, recipient)])
+            .await,
+        tree.burn(&leaf).await,
+        tree.redeem(&leaf).await,
+        tree.delegate(&leaf, recipient).await,
+        tree.delegate_with_expiry(&leaf, recipient, None).await,
+    ];
+    for result in results {
+        assert!(result
+            .unwrap_err()
+            .is_bubblegum_error(BubblegumError::AssetIsFrozen));
+    }
+    let mut tx = tree.transfer_tx(&leaf, recipient).await.unwrap();
+    let err = tx
+        .set_payer(staker.pubkey())
+        .set_signers(&[&staker])
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::AssetIsFrozen));
+
+    // Only the delegate that froze the leaf can thaw it.
+    let other = Keypair::new();
+    context
+        .fund_account(other.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    let mut tx = tree.thaw_tx(&leaf);
+    tx.accounts.leaf_delegate = other.pubkey();
+    let err = tx
+        .set_payer(other.pubkey())
+        .set_signers(&[&other])
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::LeafAuthorityMustSign));
+
+    tree.thaw(&leaf).await.unwrap();
+    assert!(tree
+        .read_account(tree.leaf_freeze(leaf.nonce))
+        .await
+        .is_err());
+    tree.transfer(&leaf, recipient).await.unwrap();
+}
+
+// A delegate that froze a leaf and then expired must not keep it locked forever.
+#[tokio::test]
+async fn test_owner_thaws_after_delegate_expiry() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let staker = Keypair::new();
+    context
+        .fund_account(staker.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    let expiry_slot = context.current_slot().await.unwrap() + 100;
+    tree.delegate_with_expiry(&leaf, staker.pubkey(), Some(expiry_slot))
+        .await
+        .unwrap();
+    let leaf = leaf.with_new_delegate(&staker);
+    tree.freeze(&leaf).await.unwrap();
+
+    // The owner can't thaw the leaf while the delegate is still valid.
+    let mut tx = tree.thaw_tx(&leaf);
+    let err = tx
+        .set_payer(leaf.owner.pubkey())
+        .set_signers(&[&leaf.owner])
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::AssetIsFrozen));
+
+    context.warp_to_slot(expiry_slot).unwrap();
+
+    // Nobody else can thaw it once the delegate expired.
+    let other = Keypair::new();
+    context
+        .fund_account(other.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    let mut tx = tree.thaw_tx(&leaf);
+    tx.accounts.leaf_owner = other.pubkey();
+    let err = tx
+        .set_payer(other.pubkey())
+        .set_signers(&[&other])
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::LeafAuthorityMustSign));
+
+    // The owner thaws it, and the rent of the record still goes back to the delegate.
+    let leaf_freeze = tree.leaf_freeze(leaf.nonce);
+    let rent = context.client().get_balance(leaf_freeze).await.unwrap();
+    let staker_balance = context.client().get_balance(staker.pubkey()).await.unwrap();
+    let mut tx = tree.thaw_tx(&leaf);
+    tx.set_payer(leaf.owner.pubkey())
+        .set_signers(&[&leaf.owner])
+        .execute()
+        .await
+        .unwrap();
+    assert!(tree.read_account(leaf_freeze).await.is_err());
+    assert_eq!(
+        context.client().get_balance(staker.pubkey()).await.unwrap(),
+        staker_balance + rent
+    );
+
+    tree.transfer(&leaf, Keypair::new().pubkey()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_deny_list() {
+    let (mut context, mut tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let authority = Keypair::new();
+    let stranger = Keypair::new();
+    for key in [authority.pubkey(), stranger.pubkey()] {
+        context
+            .fund_account(key, DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    let mut second = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("second", "tst"),
+    );
+    second.nonce = 1;
+    tree.mint_v1(&tree.tree_delegate, &second).await.unwrap();
+    let flagged = [
+        get_asset_id(&tree.tree_pubkey(), leaf.nonce),
+        get_asset_id(&tree.tree_pubkey(), second.nonce),
+    ];
+
+    tree.create_deny_list(&authority).await.unwrap();
+
+    // Only the authority of the list can change it.
+    let mut tx = tree.update_deny_list_tx(&stranger, &flagged, &[]);
+    tx.accounts.deny_list = pda::find_deny_list(&authority.pubkey());
+    assert!(tx.execute().await.is_err());
+
+    tree.update_deny_list(&authority, &flagged, &[])
+        .await
+        .unwrap();
+    let list = tree.read_deny_list(authority.pubkey()).await.unwrap();
+    assert!(flagged.iter().all(|asset_id| list.contains(asset_id)));
+
+    // Only the tree creator can opt the tree into a list.
+    let deny_list = pda::find_deny_list(&authority.pubkey());
+    let mut tx = tree.set_tree_deny_list_tx(deny_list);
+    tx.accounts.tree_creator = stranger.pubkey();
+    tx.set_signers(&[&stranger]).set_payer(stranger.pubkey());
+    assert!(tx.execute().await.is_err());
+
+    tree.set_tree_deny_list(deny_list).await.unwrap();
+
+    let recipient = Keypair::new().pubkey();
+    let results = vec![
+        tree.transfer(&leaf, recipient).await,
+        tree.transfer_timelocked(&leaf, recipient, 1_000).await,
+        tree.transfer_batch(&context.payer(), &[(&leaf, recipient)])
+            .await,
+    ];
+    for result in results {
+        assert!(result
+            .unwrap_err()
+            .is_bubblegum_error(BubblegumError::AssetFlagged));
+    }
+
+    // Transfers can't skip the check by passing some other list, or none at all.
+    let mut tx = tree.transfer_tx(&leaf, recipient).await.unwrap();
+    let mut remaining = tree.feature_flags_companions();
+    remaining.extend([
+        tree.tree_deny_list(),
+        pda::find_deny_list(&stranger.pubkey()),
+    ]);
+    remaining.extend(tree.proof(leaf.index()));
+    tx.set_additional_accounts(&remaining);
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidDenyList));
+    let mut remaining = tree.feature_flags_companions();
+    remaining.extend(tree.proof(leaf.index()));
+    tx.set_additional_accounts(&remaining);
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidDenyList));
+
+    // Flagged assets can still be burned.
+    tree.burn(&second).await.unwrap();
+
+    tree.update_deny_list(&authority, &[], &flagged[..1])
+        .await
+        .unwrap();
+    tree.transfer(&leaf, recipient).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_deny_list_set_before_created() {
+    let (mut context, mut tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let authority = Keypair::new();
+    let recipient = Keypair::new();
+    for key in [authority.pubkey(), recipient.pubkey()] {
+        context
+            .fund_account(key, DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    // A list that doesn't exist yet flags nothing.
+    tree.set_tree_deny_list(pda::find_deny_list(&authority.pubkey()))
+        .await
+        .unwrap();
+    tree.transfer(&leaf, recipient.pubkey()).await.unwrap();
+    let leaf = leaf.with_new_owner(&recipient);
+
+    tree.create_deny_list(&authority).await.unwrap();
+    let asset_id = get_asset_id(&tree.tree_pubkey(), leaf.nonce);
+    tree.update_deny_list(&authority, &[asset_id], &[])
+        .await
+        .unwrap();
+    let err = tree
+        .transfer(&leaf, Keypair::new().pubkey())
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::AssetFlagged));
+}
+
+// Assets flagged after they were put up for a timelocked transfer, or while still in the tree,
+// can't be claimed or redeemed.
+#[tokio::test]
+async fn test_deny_list_blocks_claim_and_redeem() {
+    let (mut context, mut tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let (authority, recipient) = (Keypair::new(), Keypair::new());
+    for key in [authority.pubkey(), recipient.pubkey()] {
+        context
+            .fund_account(key, DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    let mut second = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("second", "tst"),
+    );
+    second.nonce = 1;
+    tree.mint_v1(&tree.tree_delegate, &second).await.unwrap();
+
+    let unlock_slot = context.current_slot().await.unwrap() + 100;
+    tree.transfer_timelocked(&leaf, recipient.pubkey(), unlock_slot)
+        .await
+        .unwrap();
+
+    let flagged = [
+        get_asset_id(&tree.tree_pubkey(), leaf.nonce),
+        get_asset_id(&tree.tree_pubkey(), second.nonce),
+    ];
+    tree.create_deny_list(&authority).await.unwrap();
+    tree.update_deny_list(&authority, &flagged, &[])
+        .await
+        .unwrap();
+    tree.set_tree_deny_list(pda::find_deny_list(&authority.pubkey()))
+        .await
+        .unwrap();
+    context.warp_to_slot(unlock_slot).unwrap();
+
+    let results = vec![
+        tree.claim_timelocked_transfer(&leaf, &recipient).await,
+        tree.redeem(&second).await,
+    ];
+    for result in results {
+        assert!(result
+            .unwrap_err()
+            .is_bubblegum_error(BubblegumError::AssetFlagged));
+    }
+
+    tree.update_deny_list(&authority, &[], &flagged)
+        .await
+        .unwrap();
+    tree.claim_timelocked_transfer(&leaf, &recipient)
+        .await
+        .unwrap();
+    tree.redeem(&second).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delegate_expiry() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let delegate = Keypair::new();
+    context
+        .fund_account(delegate.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    let expiry_slot = context.current_slot().await.unwrap() + 100;
+    tree.delegate_with_expiry(&leaf, delegate.pubkey(), Some(expiry_slot))
+        .await
+        .unwrap();
+    let leaf = leaf.with_new_delegate(&delegate);
+    context.warp_to_slot(expiry_slot).unwrap();
+
+    // The delegate can no longer act on its own.
+    let mut tx = tree
+        .transfer_tx(&leaf, Keypair::new().pubkey())
+        .await
+        .unwrap();
+    let err = tx
+        .set_payer(delegate.pubkey())
+        .set_signers(&[&delegate])
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::DelegateExpired));
+
+    let mut tx = tree.burn_tx(&leaf).await.unwrap();
+    let err = tx
+        .set_payer(delegate.pubkey())
+        .set_signers(&[&delegate])
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::DelegateExpired));
+
+    // The owner extends the delegation, after which the delegate can transfer the asset.
+    tree.delegate_with_expiry(&leaf, delegate.pubkey(), Some(expiry_slot + 1_000))
+        .await
+        .unwrap();
+    let recipient = Keypair::new();
+    context
+        .fund_account(recipient.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    let mut tx = tree.transfer_tx(&leaf, recipient.pubkey()).await.unwrap();
+    tx.set_payer(delegate.pubkey())
+        .set_signers(&[&delegate])
+        .execute()
+        .await
+        .unwrap();
+
+    // The recipient now owns the asset.
+    tree.burn(&leaf.with_new_owner(&recipient)).await.unwrap();
+}
+
+// A record left behind by a previous owner must not shorten delegations made by the next one,
+// such as a marketplace listing that reuses the same deterministic delegate.
+#[tokio::test]
+async fn test_delegate_expiry_after_transfer() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let (delegate, buyer) = (Keypair::new(), Keypair::new());
+    for key in [delegate.pubkey(), buyer.pubkey()] {
+        context
+            .fund_account(key, DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    let expiry_slot = context.current_slot().await.unwrap() + 100;
+    tree.delegate_with_expiry(&leaf, delegate.pubkey(), Some(expiry_slot))
+        .await
+        .unwrap();
+    let seller = clone_keypair(&leaf.owner);
+    let leaf = leaf.with_new_delegate(&delegate);
+    tree.transfer(&leaf, buyer.pubkey()).await.unwrap();
+
+    // The buyer delegates to the same key without an expiry.
+    let leaf = leaf.with_new_owner(&buyer);
+    tree.delegate(&leaf, delegate.pubkey()).await.unwrap();
+    let leaf = leaf.with_new_delegate(&delegate);
+    context.warp_to_slot(expiry_slot).unwrap();
+
+    // Only the owner that paid for the record can close it.
+    let err = tree
+        .close_delegate_expiry(&buyer, leaf.nonce)
+        .await
+        .unwrap_err();
+    assert!(err.is_custom_error(ErrorCode::ConstraintHasOne as u32));
+
+    let mut tx = tree
+        .transfer_tx(&leaf, Keypair::new().pubkey())
+        .await
+        .unwrap();
+    tx.set_payer(delegate.pubkey())
+        .set_signers(&[&delegate])
+        .execute()
+        .await
+        .unwrap();
+
+    tree.close_delegate_expiry(&seller, leaf.nonce)
+        .await
+        .unwrap();
+    context
+        .assert_no_leaked_accounts(&[tree.delegate_expiry(leaf.nonce)])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_delegate_expiry_taken_over_by_new_owner() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let (delegate, buyer) = (Keypair::new(), Keypair::new());
+    context
+        .fund_account(buyer.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    let expiry_slot = context.current_slot().await.unwrap() + 100;
+    tree.delegate_with_expiry(&leaf, delegate.pubkey(), Some(expiry_slot))
+        .await
+        .unwrap();
+    let seller = leaf.owner.pubkey();
+    let rent = tree
+        .read_account(tree.delegate_expiry(leaf.nonce))
+        .await
+        .unwrap()
+        .lamports;
+    let leaf = leaf.with_new_delegate(&delegate);
+    tree.transfer(&leaf, buyer.pubkey()).await.unwrap();
+    let leaf = leaf.with_new_owner(&buyer);
+
+    // The buyer reuses the record of the seller, and pays its rent back to them.
+    let mut tx = tree
+        .delegate_with_expiry_tx(&leaf, delegate.pubkey(), Some(expiry_slot))
+        .await
+        .unwrap();
+    tx.accounts.previous_expiry_owner = buyer.pubkey();
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::PublicKeyMismatch));
+
+    let before = context.client().get_balance(seller).await.unwrap();
+    tree.delegate_with_expiry(&leaf, delegate.pubkey(), Some(expiry_slot))
+        .await
+        .unwrap();
+    let after = context.client().get_balance(seller).await.unwrap();
+    assert_eq!(after, before + rent);
+    let record = tree.read_delegate_expiry(leaf.nonce).await.unwrap();
+    assert_eq!(record.owner, buyer.pubkey());
+
+    // The record is the buyer's to close now.
+    let err = tree
+        .close_delegate_expiry(&context.payer(), leaf.nonce)
+        .await
+        .unwrap_err();
+    assert!(err.is_custom_error(ErrorCode::ConstraintHasOne as u32));
+    tree.close_delegate_expiry(&buyer, leaf.nonce)
+        .await
+        .unwrap();
+    context
+        .assert_no_leaked_accounts(&[tree.delegate_expiry(leaf.nonce)])
+        .await
+        .unwrap();
+}
+
+// Transfers the asset to `buyer` on behalf of the marketplace, along with the payments.
+async fn sale_tx(
+    tree: &Tree<MAX_DEPTH, MAX_BUF_SIZE>,
+    leaf: &LeafArgs,
+    buyer: &Keypair,
+    marketplace: &Keypair,
+    payments: &[Instruction],
+) -> TransferBuilder {
+    let mut tx = tree.transfer_tx(leaf, buyer.pubkey()).await.unwrap();
+    tx.set_payer(buyer.pubkey())
+        .set_signers(&[marketplace, buyer]);
+    for payment in payments {
+        tx.add_companion_instruction(payment.clone());
+    }
+    tx
+}
+
+// A complete secondary sale: the seller lists the asset by delegating it to the marketplace,
+// which then settles the sale by transferring the asset and paying the seller and creators in
+// a single transaction. A real marketplace would delegate to a PDA of its program; here a
+// keypair stands in for it.
+#[tokio::test]
+async fn test_marketplace_sale() {
+    let (mut context, tree, _leaf) = context_tree_and_leaf().await.unwrap();
+    let (seller, buyer, marketplace) = (Keypair::new(), Keypair::new(), Keypair::new());
+    for key in [seller.pubkey(), buyer.pubkey(), marketplace.pubkey()] {
+        context
+            .fund_account(key, DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    let mut metadata = context.default_metadata_args("sale", "sal");
+    metadata.seller_fee_basis_points = 500;
+    let mut leaf = LeafArgs::new(&seller, metadata);
+    leaf.nonce = 1;
+    tree.mint_v1(&tree.tree_delegate, &leaf).await.unwrap();
+
+    // Royalties are split between the creators according to their shares, and the seller
+    // gets the rest.
+    let price = DEFAULT_LAMPORTS_FUND_AMOUNT / 2;
+    let royalties = price * u64::from(leaf.metadata.seller_fee_basis_points) / 10_000;
+    let payouts: Vec<_> = leaf
+        .metadata
+        .creators
+        .iter()
+        .map(|creator| (creator.address, royalties * u64::from(creator.share) / 100))
+        .chain([(seller.pubkey(), price - royalties)])
+        .collect();
+    let payments: Vec<_> = payouts
+        .iter()
+        .map(|(key, amount)| system_instruction::transfer(&buyer.pubkey(), key, *amount))
+        .collect();
+
+    let balances = || async {
+        let mut balances = Vec::new();
+        for (key, _) in payouts.iter() {
+            balances.push(tree.balance(*key).await.unwrap());
+        }
+        balances
+    };
+    let before = balances().await;
+
+    // Until the asset is listed, the marketplace cannot move it, and the buyer pays nothing.
+    let unlisted = leaf.clone().with_new_delegate(&marketplace);
+    let tx = sale_tx(&tree, &unlisted, &buyer, &marketplace, &payments).await;
+    assert!(tx.execute().await.is_err());
+    assert_eq!(balances().await, before);
+
+    // Listing costs the seller a transaction fee, which is left out of the payouts below.
+    tree.delegate(&leaf, marketplace.pubkey()).await.unwrap();
+    let listed = leaf.with_new_delegate(&marketplace);
+    let before = balances().await;
+    let tx = sale_tx(&tree, &listed, &buyer, &marketplace, &payments).await;
+    tx.execute().await.unwrap();
+
+    let after = balances().await;
+    for ((before, after), (_, amount)) in before.iter().zip(after).zip(payouts.iter()) {
+        assert_eq!(after - before, *amount);
+    }
+
+    // The buyer owns the asset, and the marketplace is no longer its delegate.
+    let sold = listed.with_new_owner(&buyer);
+    tree.transfer(&sold, seller.pubkey()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_collection_across_trees() {
+    let (context, first_tree, _leaf) = context_tree_and_leaf().await.unwrap();
+    let second_tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+    let collection = Keypair::new().pubkey();
+
+    let member = |name: &str, nonce: u64| {
+        let mut metadata = context.default_metadata_args(name, "tst");
+        metadata.collection = Some(Collection {
+            verified: false,
+            key: collection,
+        });
+        let mut args = LeafArgs::new(&context.payer(), metadata);
+        args.nonce = nonce;
+        args
+    };
+
+    // The first tree already holds a leaf that is not part of the collection.
+    let first = member("first", 1);
+    let second = member("second", 0);
+    let third = member("third", 1);
+    first_tree
+        .mint_v1(&first_tree.tree_delegate, &first)
+        .await
+        .unwrap();
+    second_tree
+        .mint_v1(&second_tree.tree_delegate, &second)
+        .await
+        .unwrap();
+    second_tree
+        .mint_v1(&second_tree.tree_delegate, &third)
+        .await
+        .unwrap();
+    second_tree.burn(&third).await.unwrap();
+
+    let first_ops = first_tree.replay_ops();
+    let second_ops = second_tree.replay_ops();
+    let assets = list_collection_assets(
+        &collection,
+        &[
+            (first_tree.tree_pubkey(), &first_ops),
+            (second_tree.tree_pubkey(), &second_ops),
+        ],
+    );
+
+    let ids: Vec<_> = assets.iter().map(|asset| asset.asset_id).collect();
+    assert_eq!(
+        ids,
+        vec![
+            first_tree.asset_id(1),
+            second_tree.asset_id(0),
+            second_tree.asset_id(1),
+        ]
+    );
+    assert_eq!(assets[2].state, AssetState::Burned);
+}
+
+#[tokio::test]
+async fn test_mint_to_collection() {
+    let (context, tree, collection) = context_tree_and_collection(false).await.unwrap();
+    let leaf = collection_member(&context, collection.mint, 0);
+    tree.mint_to_collection_v1(
+        &tree.tree_delegate,
+        &leaf,
+        collection.mint,
+        collection.metadata,
+    )
+    .await
+    .unwrap();
+
+    // The asset was minted with its collection verified, which the proof of a transfer shows.
+    let leaf = leaf.with_updated_metadata(|m| m.collection.as_mut().unwrap().verified = true);
+    tree.transfer(&leaf, Keypair::new().pubkey()).await.unwrap();
+    assert_eq!(context.collection_size(&collection).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_mint_to_collection_wrong_authority() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+    let collection = context
+        .create_collection(&Keypair::new(), false)
+        .await
+        .unwrap();
+
+    // The tree delegate signs as the collection authority, which it is not.
+    let leaf = collection_member(&context, collection.mint, 0);
+    let err = tree
+        .mint_to_collection_v1(
+            &tree.tree_delegate,
+            &leaf,
+            collection.mint,
+            collection.metadata,
+        )
+        .await
+        .unwrap_err();
+    assert!(err.is_custom_error(MetadataError::InvalidCollectionUpdateAuthority as u32));
+    assert_eq!(tree.read_tree_config().await.unwrap().num_minted, 0);
+}
+
+#[tokio::test]
+async fn test_mint_to_collection_mismatched_collection() {
+    let (mut context, tree, collection) = context_tree_and_collection(false).await.unwrap();
+    let other = context
+        .create_collection(&tree.tree_delegate, false)
+        .await
+        .unwrap();
+
+    // The collection of the message has to be the one the accounts are passed for.
+    let leaf = collection_member(&context, other.mint, 0);
+    let err = tree
+        .mint_to_collection_v1(
+            &tree.tree_delegate,
+            &leaf,
+            collection.mint,
+            collection.metadata,
+        )
+        .await
+        .unwrap_err();
+    assert!(err.is_custom_error(MetadataError::CollectionNotFound as u32));
+
+    // And the message has to have a collection in the first place.
+    let leaf = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("no collection", "tst"),
+    );
+    let err = tree
+        .mint_to_collection_v1(
+            &tree.tree_delegate,
+            &leaf,
+            collection.mint,
+            collection.metadata,
+        )
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::CollectionNotFound));
+    assert_eq!(tree.read_tree_config().await.unwrap().num_minted, 0);
+}
+
+#[tokio::test]
+async fn test_mint_to_sized_collection() {
+    let (context, tree, collection) = context_tree_and_collection(true).await.unwrap();
+    assert_eq!(context.collection_size(&collection).await.unwrap(), Some(0));
+
+    for nonce in 0..2 {
+        let leaf = collection_member(&context, collection.mint, nonce);
+        tree.mint_to_collection_v1(
+            &tree.tree_delegate,
+            &leaf,
+            collection.mint,
+            collection.metadata,
+        )
+        .await
+        .unwrap();
+    }
+    assert_eq!(context.collection_size(&collection).await.unwrap(), Some(2));
+}
+
+#[tokio::test]
+async fn test_holder_distribution() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let metadata = context.default_metadata_args("airdrop", "tst");
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+
+    tree.mint_airdrop(
+        &tree.tree_delegate,
+        &metadata,
+        &[alice.pubkey(), alice.pubkey(), bob.pubkey()],
+    )
+    .await
+    .unwrap();
+
+    let distribution = tree.holder_distribution();
+    assert_eq!(distribution.unique_holders(), 3);
+    assert_eq!(distribution.total_assets(), 4);
+    assert_eq!(distribution.count(&leaf.owner.pubkey()), 1);
+    assert_eq!(distribution.count(&alice.pubkey()), 2);
+    assert_eq!(distribution.count(&bob.pubkey()), 1);
+
+    // Alice sends one of her assets to Bob, and the original leaf gets burned.
+    context
+        .fund_account(alice.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    let mut alice_leaf = LeafArgs::new(&alice, metadata.clone());
+    alice_leaf.nonce = 1;
+    tree.transfer(&alice_leaf, bob.pubkey()).await.unwrap();
+    tree.burn(&leaf).await.unwrap();
+
+    let distribution = tree.holder_distribution();
+    assert_eq!(distribution.unique_holders(), 2);
+    assert_eq!(distribution.total_assets(), 3);
+    assert_eq!(distribution.count(&leaf.owner.pubkey()), 0);
+    assert_eq!(distribution.count(&alice.pubkey()), 1);
+    assert_eq!(distribution.count(&bob.pubkey()), 2);
+}
+
+#[tokio::test]
+async fn test_resolve_owner_matches_mirror() {
+    let (mut context, tree, first) = context_tree_and_leaf().await.unwrap();
+    let payer = context.payer();
+
+    let pool: Vec<Keypair> = (0..4).map(|_| Keypair::new()).collect();
+    for keypair in pool.iter() {
+        context
+            .fund_account(keypair.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    let mut leaves = vec![Some(first)];
+    for nonce in 1..6 {
+        let mut leaf = LeafArgs::new(&payer, context.default_metadata_args("leaf", "tst"));
+        leaf.nonce = nonce;
+        tree.mint_v1(&tree.tree_delegate, &leaf).await.unwrap();
+        leaves.push(Some(leaf));
+    }
+
+    // A fixed xorshift sequence keeps the operations random looking but reproducible.
+    let mut seed = 0x9e37_79b9_7f4a_7c15u64;
+    let mut next = move |bound: usize| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % bound as u64) as usize
+    };
+
+    for _ in 0..24 {
+        let slot = next(leaves.len());
+        let leaf = match leaves[slot].take() {
+            Some(leaf) => leaf,
+            None => continue,
+        };
+        let target = &pool[next(pool.len())];
+        leaves[slot] = match next(5) {
+            0 | 1 => {
+                tree.transfer(&leaf, target.pubkey()).await.unwrap();
+                Some(leaf.with_new_owner(target))
+            }
+            2 | 3 => {
+                tree.delegate(&leaf, target.pubkey()).await.unwrap();
+                Some(leaf.with_new_delegate(target))
+            }
+            _ => {
+                tree.burn(&leaf).await.unwrap();
+                None
+            }
+        };
+    }
+
+    for (nonce, leaf) in leaves.iter().enumerate() {
+        let nonce = nonce as u64;
+        let resolved = tree.resolve_owner(nonce).unwrap();
+        let index = u32::try_from(nonce).unwrap();
+
+        match leaf {
+            Some(leaf) => {
+                assert_eq!(resolved.state, AssetState::Live);
+                assert_eq!(resolved.owner, leaf.owner.pubkey());
+                assert_eq!(resolved.delegate, leaf.delegate.pubkey());
+
+                let schema = LeafSchema::new_v0(
+                    get_asset_id(&tree.tree_pubkey(), nonce),
+                    resolved.owner,
+                    resolved.delegate,
+                    nonce,
+                    leaf.data_hash,
+                    leaf.creator_hash,
+                );
+                assert_eq!(schema.to_node(), tree.mirror_leaf(index));
+            }
+            None => {
+                assert_eq!(resolved.state, AssetState::Burned);
+                assert_eq!(tree.mirror_leaf(index), [0; 32]);
+            }
+        }
+    }
+
+    // Nothing was ever minted at this nonce.
+    assert!(tree.resolve_owner(leaves.len() as u64).is_none());
+    assert_eq!(tree.mirror_root(), tree.decode_root().await.unwrap());
+}
+
+#[tokio::test]
+async fn test_event_stream() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let early = tree.event_stream();
+    let mut events = tree.event_stream();
+
+    // Dropped subscribers don't get in the way of the remaining ones.
+    drop(early);
+
+    let recipient = Keypair::new();
+    context
+        .fund_account(recipient.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    tree.transfer(&leaf, recipient.pubkey()).await.unwrap();
+    let leaf = leaf.with_new_owner(&recipient);
+    tree.burn(&leaf).await.unwrap();
+
+    // The mint happened before subscribing, so it's not part of the stream.
+    let op = events.next().await.unwrap();
+    assert_eq!(op.instruction, InstructionName::Transfer);
+    assert_eq!(op.leaf().unwrap().id(), tree.asset_id(leaf.nonce));
+    let op = events.next().await.unwrap();
+    assert_eq!(op.instruction, InstructionName::Burn);
+
+    // Everything observed is still available via the replay.
+    let instructions: Vec<_> = tree.replay_ops().iter().map(|op| op.instruction).collect();
+    assert!(instructions.ends_with(&[
+        InstructionName::MintV1,
+        InstructionName::Transfer,
+        InstructionName::Burn,
+    ]));
+
+    drop(tree);
+    assert!(events.next().await.is_none());
+}
+
+// Bubblegum has no hooks, and the programs it invokes are fixed, so there's no way for a caller
+// to get Bubblegum to call back into itself (or into any other program of their choosing) while
+// an instruction is in progress. This checks that substituting the invoked programs is rejected.
+#[tokio::test]
+async fn test_invoked_programs_cannot_be_substituted() {
+    let (context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let new_owner = Keypair::new().pubkey();
+    let root = tree.decode_root().await.unwrap();
+
+    let mut tx = tree.transfer_tx(&leaf, new_owner).await.unwrap();
+    tx.accounts.compression_program = mpl_bubblegum::id();
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_custom_error(ErrorCode::InvalidProgramId as u32));
+
+    let mut tx = tree.transfer_tx(&leaf, new_owner).await.unwrap();
+    tx.accounts.log_wrapper = mpl_bubblegum::id();
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_custom_error(ErrorCode::InvalidProgramId as u32));
+
+    let mut tx = tree.burn_tx(&leaf).await.unwrap();
+    tx.accounts.compression_program = mpl_bubblegum::id();
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_custom_error(ErrorCode::InvalidProgramId as u32));
+
+    // A mint that would pay its price through a program other than SPL Token.
+    let mut args = LeafArgs::new(&context.payer(), context.default_metadata_args("x", "tst"));
+    args.nonce = 1;
+    let mut tx = tree.mint_v1_tx(&tree.tree_delegate, &args);
+    tx.accounts.token_program = mpl_bubblegum::id();
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_custom_error(ErrorCode::ConstraintAddress as u32));
+
+    assert_eq!(tree.decode_root().await.unwrap(), root);
+    tree.transfer(&leaf, new_owner).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_soulbound() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let before = tree.read_tree_config().await.unwrap();
+    tree.set_tree_non_transferable(true).await.unwrap();
+    let after = tree.read_tree_config().await.unwrap();
+    assert!(after.is_non_transferable);
+    assert_only_changed!(before, after, [is_non_transferable]);
+
+    let leaf = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("test", "tst"),
+    );
+    tree.mint_v1(&tree.tree_delegate, &leaf).await.unwrap();
+
+    // The setting applies to the leaves minted under it, so it's fixed once there are any.
+    let err = tree.set_tree_non_transferable(false).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::NonTransferableAfterMint));
+
+    // Leaves of a soulbound tree cannot change owners in any way.
+    let err = tree
+        .transfer(&leaf, Keypair::new().pubkey())
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::AssetIsSoulbound));
+    let err = tree
+        .transfer_timelocked(&leaf, Keypair::new().pubkey(), 1_000)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::AssetIsSoulbound));
+    let err = tree.redeem(&leaf).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::AssetIsSoulbound));
+
+    // Only the tree creator or delegate can override the setting for a leaf.
+    let impostor = &context.default_creators[0];
+    assert!(tree
+        .set_soulbound_override(impostor, leaf.nonce, false)
+        .await
+        .is_err());
+
+    tree.set_soulbound_override(&tree.tree_delegate, leaf.nonce, false)
+        .await
+        .unwrap();
+    let recipient = Keypair::new();
+    context
+        .fund_account(recipient.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    tree.transfer(&leaf, recipient.pubkey()).await.unwrap();
+
+    // The other way around, a single leaf can be soulbound in a regular tree.
+    let regular = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+    let second = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("second", "tst"),
+    );
+    regular
+        .mint_v1(&regular.tree_delegate, &second)
+        .await
+        .unwrap();
+    regular
+        .set_soulbound_override(&regular.tree_delegate, second.nonce, true)
+        .await
+        .unwrap();
+
+    let err = regular
+        .transfer(&second, Keypair::new().pubkey())
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::AssetIsSoulbound));
+
+    // Overrides are set once, when minting, and never change afterwards.
+    let err = regular
+        .set_soulbound_override(&regular.tree_delegate, second.nonce, false)
+        .await
+        .unwrap_err();
+    assert!(err.is_custom_error(SystemError::AccountAlreadyInUse as u32));
+    let mut third = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("third", "tst"),
+    );
+    third.nonce = 1;
+    regular
+        .mint_v1(&regular.tree_delegate, &third)
+        .await
+        .unwrap();
+    let mut fourth = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("fourth", "tst"),
+    );
+    fourth.nonce = 2;
+    regular
+        .mint_v1(&regular.tree_delegate, &fourth)
+        .await
+        .unwrap();
+    let err = regular
+        .set_soulbound_override(&regular.tree_delegate, third.nonce, true)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::SoulboundOverrideNotAtMint));
+
+    // Soulbound leaves can still be burned by their owners.
+    regular.burn(&second).await.unwrap();
+    tree.burn(&leaf.with_new_owner(&recipient)).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_on_chain_metadata() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let json = br#"{"name":"on-chain","description":"metadata that lives in an account","attributes":[{"trait_type":"storage","value":"chunked"}]}"#;
+    let mut metadata = context.default_metadata_args("on-chain", "tst");
+    metadata.uri = OnChainMetadata::uri(&keccak::hash(json).to_bytes());
+    let leaf = LeafArgs::new(&context.payer(), metadata);
+    tree.mint_v1(&tree.tree_delegate, &leaf).await.unwrap();
+
+    // Only creators of the asset can store its metadata, and only the JSON its uri commits to.
+    let err = tree
+        .init_on_chain_metadata(&context.payer(), &leaf, json)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::CreatorNotFound));
+
+    let creator = &context.default_creators[1];
+    let err = tree
+        .init_on_chain_metadata(creator, &leaf, b"{}")
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::OnChainMetadataUriMismatch));
+
+    tree.init_on_chain_metadata(creator, &leaf, json)
+        .await
+        .unwrap();
+
+    // Chunks can't leave gaps in the JSON.
+    tree.write_metadata_chunk(creator, leaf.nonce, 0, &json[..32])
+        .await
+        .unwrap();
+    let err = tree
+        .write_metadata_chunk(creator, leaf.nonce, 40, &json[40..48])
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidMetadataChunkOffset));
+
+    // The JSON is checked against its hash once the last chunk is written.
+    let mut corrupted = json[32..].to_vec();
+    corrupted[0] ^= 1;
+    let err = tree
+        .write_metadata_chunk(creator, leaf.nonce, 32, &corrupted)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::OnChainMetadataHashMismatch));
+
+    let (header, _) = tree.read_on_chain_metadata(leaf.nonce).await.unwrap();
+    assert_eq!(header.bytes_written, 32);
+    assert!(!header.is_complete);
+
+    // A bad chunk that isn't the last one lands, but can be rewritten before the JSON is complete.
+    tree.write_metadata_chunk(creator, leaf.nonce, 32, &corrupted[..40])
+        .await
+        .unwrap();
+    let err = tree
+        .write_metadata_chunk(creator, leaf.nonce, 72, &json[72..])
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::OnChainMetadataHashMismatch));
+
+    let mut offset = 32;
+    for chunk in json[32..].chunks(40) {
+        tree.write_metadata_chunk(creator, leaf.nonce, offset, chunk)
+            .await
+            .unwrap();
+        offset += chunk.len() as u32;
+    }
+
+    let (header, written) = tree.read_on_chain_metadata(leaf.nonce).await.unwrap();
+    assert!(header.is_complete);
+    assert_eq!(header.bytes_written as usize, json.len());
+    assert_eq!(written, json.to_vec());
+
+    // Complete metadata can neither be appended to nor rewritten.
+    for (offset, chunk) in [(offset, &b" "[..]), (0, &json[..32])] {
+        let err = tree
+            .write_metadata_chunk(creator, leaf.nonce, offset, chunk)
+            .await
+            .unwrap_err();
+        assert!(err.is_bubblegum_error(BubblegumError::OnChainMetadataComplete));
+    }
+}
+
+#[tokio::test]
+async fn test_asset_receipt() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+    let recipient = Keypair::new();
+    context
+        .fund_account(recipient.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    // Sending lamports to the address of a receipt ahead of time doesn't block the mint.
+    let first = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("first", "tst"),
+    );
+    context
+        .fund_account(tree.asset_receipt(first.nonce), 1_000_000)
+        .await
+        .unwrap();
+    tree.mint_v1_with_receipt(&tree.tree_delegate, &first)
+        .await
+        .unwrap();
+    let receipt = tree.read_asset_receipt(first.nonce).await.unwrap();
+    assert_eq!(receipt.merkle_tree, tree.tree_pubkey());
+    assert_eq!(receipt.nonce, first.nonce);
+    assert_eq!(receipt.owner, first.owner.pubkey());
+
+    // Only the current owner of the asset can close the receipt.
+    tree.transfer(&first, recipient.pubkey()).await.unwrap();
+    assert!(tree.close_asset_receipt(&first, false).await.is_err());
+
+    let first = first.with_new_owner(&recipient);
+    tree.close_asset_receipt(&first, false).await.unwrap();
+    context
+        .assert_no_leaked_accounts(&[tree.asset_receipt(first.nonce)])
+        .await
+        .unwrap();
+
+    // Once the asset is burned with a receipt, the owner who burned it can close the asset
+    // receipt instead.
+    let mut second = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("second", "tst"),
+    );
+    second.nonce = 1;
+    tree.mint_v1_with_receipt(&tree.tree_delegate, &second)
+        .await
+        .unwrap();
+    let err = tree.close_asset_receipt(&second, true).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::BurnReceiptMissing));
+
+    tree.burn_with_receipt(&second).await.unwrap();
+    let mut tx = tree.close_asset_receipt_tx(&second, true).await.unwrap();
+    tx.accounts.leaf_owner = recipient.pubkey();
+    tx.set_payer(recipient.pubkey()).set_signers(&[&recipient]);
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::AssetOwnerMismatch));
+    tree.close_asset_receipt(&second, true).await.unwrap();
+    context
+        .assert_no_leaked_accounts(&[tree.asset_receipt(second.nonce)])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_asset_receipt_of_redeemed_asset() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+    let leaf = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("first", "tst"),
+    );
+    tree.mint_v1_with_receipt(&tree.tree_delegate, &leaf)
+        .await
+        .unwrap();
+
+    // Redeeming empties the leaf as well, but the asset is not burned.
+    tree.redeem(&leaf).await.unwrap();
+    let err = tree.close_asset_receipt(&leaf, true).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::BurnReceiptMissing));
+    assert!(tree.read_asset_receipt(leaf.nonce).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_burn_receipt() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    tree.burn_with_receipt(&leaf).await.unwrap();
+    let receipt = tree.read_burn_receipt(leaf.nonce).await.unwrap();
+    assert_eq!(receipt.asset_id, tree.asset_id(leaf.nonce));
+    assert_eq!(receipt.owner, leaf.owner.pubkey());
+
+    // Someone other than the owner at the time of the burn cannot close the receipt.
+    let other = Keypair::new();
+    context
+        .fund_account(other.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    assert!(tree.close_burn_receipt(&other, leaf.nonce).await.is_err());
+
+    tree.close_burn_receipt(&leaf.owner, leaf.nonce)
+        .await
+        .unwrap();
+    context
+        .assert_no_leaked_accounts(&[tree.burn_receipt(leaf.nonce)])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_tree_creator_transfer() {
+    let (mut context, mut tree, _leaf) = context_tree_and_leaf().await.unwrap();
+    let old_creator = clone_keypair(&tree.tree_creator);
+
+    let new_creator = Keypair::new();
+    context
+        .fund_account(new_creator.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    // Only the current creator can propose a transfer.
+    let mut tx = tree.propose_tree_creator_tx(new_creator.pubkey());
+    tx.accounts.tree_creator = new_creator.pubkey();
+    tx.set_signers(&[&new_creator])
+        .set_payer(new_creator.pubkey());
+    assert!(tx.execute().await.is_err());
+
+    // A cancelled transfer can no longer be accepted, and leaves the creator unchanged.
+    tree.propose_tree_creator(new_creator.pubkey())
+        .await
+        .unwrap();
+    tree.cancel_tree_creator_transfer().await.unwrap();
+    context
+        .assert_no_leaked_accounts(&[tree.creator_transfer()])
+        .await
+        .unwrap();
+    assert!(tree.accept_tree_creator(&new_creator).await.is_err());
+    assert_eq!(
+        tree.read_tree_config().await.unwrap().tree_creator,
+        old_creator.pubkey()
+    );
+
+    // Proposing again replaces the pending transfer, and only the proposed key can accept.
+    let typo = Keypair::new();
+    tree.propose_tree_creator(typo.pubkey()).await.unwrap();
+    tree.propose_tree_creator(new_creator.pubkey())
+        .await
+        .unwrap();
+    let transfer = tree.read_creator_transfer().await.unwrap();
+    assert_eq!(transfer.new_tree_creator, new_creator.pubkey());
+    context
+        .fund_account(typo.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    assert!(tree.accept_tree_creator(&typo).await.is_err());
+
+    let before = tree.read_tree_config().await.unwrap();
+    tree.accept_tree_creator(&new_creator).await.unwrap();
+    let after = tree.read_tree_config().await.unwrap();
+    assert_only_changed!(before, after, [tree_creator]);
+    assert_eq!(after.tree_creator, new_creator.pubkey());
+    context
+        .assert_no_leaked_accounts(&[tree.creator_transfer()])
+        .await
+        .unwrap();
+
+    // The previous creator lost access to creator-only instructions.
+    let mut tx = tree.set_tree_delegate_tx(old_creator.pubkey());
+    tx.accounts.tree_creator = old_creator.pubkey();
+    tx.set_signers(&[&old_creator])
+        .set_payer(old_creator.pubkey());
+    assert!(tx.execute().await.is_err());
+
+    tree.set_tree_delegate(&new_creator).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_tree_creator_transfer_resets_delegate() {
+    let (mut context, mut tree, _leaf) = context_tree_and_leaf().await.unwrap();
+    let delegate = Keypair::new();
+    tree.set_tree_delegate(&delegate).await.unwrap();
+
+    let new_creator = Keypair::new();
+    context
+        .fund_account(new_creator.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    // By default, the delegate set up by the previous creator is kept.
+    tree.propose_tree_creator(new_creator.pubkey())
+        .await
+        .unwrap();
+    assert!(
+        !tree
+            .read_creator_transfer()
+            .await
+            .unwrap()
+            .reset_tree_delegate
+    );
+    tree.cancel_tree_creator_transfer().await.unwrap();
+
+    let mut tx = tree.propose_tree_creator_tx(new_creator.pubkey());
+    tx.data.reset_tree_delegate = true;
+    tx.execute().await.unwrap();
+
+    let before = tree.read_tree_config().await.unwrap();
+    tree.accept_tree_creator(&new_creator).await.unwrap();
+    let after = tree.read_tree_config().await.unwrap();
+    assert_only_changed!(before, after, [tree_creator, tree_delegate]);
+    assert_eq!(after.tree_creator, new_creator.pubkey());
+    assert_eq!(after.tree_delegate, new_creator.pubkey());
+
+    // The previous delegate can no longer mint.
+    let metadata = context.default_metadata_args("after", "tst");
+    let mut leaf = LeafArgs::new(&context.payer(), metadata);
+    leaf.nonce = 1;
+    assert!(tree.mint_v1(&delegate, &leaf).await.is_err());
+    tree.mint_v1(&new_creator, &leaf).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_creator_immutable_tree() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let payer = context.payer();
+
+    let mut tree = Tree::<MAX_DEPTH, MAX_BUF_SIZE>::with_creator(&payer, context.client());
+    tree.alloc(&payer).await.unwrap();
+    let mut tx = tree.create_tree_tx(&payer);
+    tx.data.creator_immutable = true;
+    tx.execute().await.unwrap();
+    let config = tree.read_tree_config().await.unwrap();
+    assert!(config.is_creator_immutable);
+
+    // The tree can be labeled once, but not relabeled.
+    tree.set_tree_metadata("first", "").await.unwrap();
+
+    let other = Keypair::new();
+    let results = vec![
+        tree.set_tree_metadata("second", "").await,
+        tree.set_tree_delegate(&other).await,
+        tree.propose_tree_creator(other.pubkey()).await,
+        tree.set_tree_hash_only(true).await,
+        tree.set_logging_mode(LoggingMode::None).await,
+        tree.set_tree_non_transferable(true).await,
+        tree.set_tree_public(true).await,
+        tree.add_tree_delegate(other.pubkey()).await,
+        tree.set_tree_paused(true).await,
+    ];
+    for result in results {
+        assert!(result
+            .unwrap_err()
+            .is_bubblegum_error(BubblegumError::TreeCreatorImmutable));
+    }
+    let after = tree.read_tree_config().await.unwrap();
+    assert_only_changed!(config, after, []);
+    assert_eq!(tree.read_tree_metadata().await.unwrap().name, "first");
+
+    // Minting is unaffected, but leaves can't be made soulbound on their own.
+    let leaf = LeafArgs::new(&payer, context.default_metadata_args("test", "tst"));
+    tree.mint_v1(&tree.tree_delegate, &leaf).await.unwrap();
+    let err = tree
+        .set_soulbound_override(&tree.tree_delegate, leaf.nonce, true)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::TreeCreatorImmutable));
+}
+
+#[tokio::test]
+async fn test_public_tree() {
+    let (mut context, mut tree, _) = context_tree_and_leaf().await.unwrap();
+
+    let minter = Keypair::new();
+    context
+        .fund_account(minter.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    let mut leaf = LeafArgs::new(&minter, context.default_metadata_args("public", "tst"));
+    leaf.nonce = 1;
+
+    let err = tree.mint_v1(&minter, &leaf).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::TreeAuthorityIncorrect));
+
+    // Only the creator can make the tree public.
+    let mut tx = tree.set_tree_public_tx(true);
+    tx.accounts.tree_creator = minter.pubkey();
+    tx.set_signers(&[&minter]).set_payer(minter.pubkey());
+    assert!(tx.execute().await.is_err());
+
+    let before = tree.read_tree_config().await.unwrap();
+    tree.set_tree_public(true).await.unwrap();
+    let after = tree.read_tree_config().await.unwrap();
+    assert!(after.is_public());
+    assert_only_changed!(before, after, [is_public]);
+
+    tree.mint_v1(&minter, &leaf).await.unwrap();
+    tree.transfer(&leaf, Keypair::new().pubkey()).await.unwrap();
+
+    // Public mints are not signed by the tree delegate, so it cannot be marked as a verified
+    // creator by them.
+    let tree_delegate = Keypair::new();
+    tree.set_tree_delegate(&tree_delegate).await.unwrap();
+    let mut leaf = LeafArgs::new(&minter, context.default_metadata_args("public", "tst"))
+        .with_updated_metadata(|metadata| {
+            metadata.creators[0].address = tree_delegate.pubkey();
+            metadata.creators[0].verified = true;
+        });
+    leaf.nonce = 2;
+    let err = tree.mint_v1(&minter, &leaf).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::CreatorDidNotVerify));
+
+    tree.set_tree_public(false).await.unwrap();
+    let err = tree.mint_v1(&minter, &leaf).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::TreeAuthorityIncorrect));
+}
+
+#[tokio::test]
+async fn test_mint_price() {
+    let (mut context, tree, _) = context_tree_and_leaf().await.unwrap();
+    tree.set_tree_public(true).await.unwrap();
+
+    let price = 1_000_000;
+    let treasury = Keypair::new().pubkey();
+    let before = tree.read_tree_config().await.unwrap();
+    tree.set_mint_price(price, treasury).await.unwrap();
+    let after = tree.read_tree_config().await.unwrap();
+    assert_eq!(after.fees(&tree.tree_pubkey()), Some(tree.mint_price()));
+    assert_only_changed!(before, after, [companion_accounts]);
+
+    let minter = Keypair::new();
+    context
+        .fund_account(minter.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    let mut leaf = LeafArgs::new(&minter, context.default_metadata_args("paid", "tst"));
+    leaf.nonce = 1;
+
+    // Public mints have to pass the accounts that pay the price, with the right treasury.
+    let err = tree.mint_v1(&minter, &leaf).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::MintPriceNotPaid));
+    let mut tx = tree.mint_v1_tx(&minter, &leaf);
+    let payer = minter.pubkey();
+    tx.set_additional_account_metas(&tree.mint_price_companions(payer, payer, payer));
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidTreasury));
+
+    tx.set_additional_account_metas(&tree.mint_price_companions(treasury, payer, payer));
+    tx.execute().await.unwrap();
+    let account = tree.read_account(treasury).await.unwrap();
+    assert_eq!(account.lamports, price);
+
+    // Mints signed by the tree authority are free.
+    let mut leaf = LeafArgs::new(
+        &tree.tree_creator,
+        context.default_metadata_args("free", "tst"),
+    );
+    leaf.nonce = 2;
+    tree.mint_v1(&tree.tree_creator, &leaf).await.unwrap();
+    assert_eq!(tree.read_account(treasury).await.unwrap().lamports, price);
+
+    // Mint instructions that cannot charge the price are closed to public minters.
+    let metadata = context.default_metadata_args("airdrop", "tst");
+    let err = tree
+        .mint_airdrop(&minter, &metadata, &[minter.pubkey()])
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::MintPriceNotPaid));
+
+    // Only the creator can set the price.
+    let mut tx = tree.set_mint_price_tx(0, treasury);
+    tx.accounts.tree_creator = minter.pubkey();
+    tx.set_signers(&[&minter]).set_payer(minter.pubkey());
+    assert!(tx.execute().await.is_err());
+
+    tree.set_mint_price(0, treasury).await.unwrap();
+    let config = tree.read_tree_config().await.unwrap();
+    assert_eq!(config.fees(&tree.tree_pubkey()), None);
+    let mut leaf = LeafArgs::new(&minter, context.default_metadata_args("paid", "tst"));
+    leaf.nonce = 3;
+    tree.mint_v1(&minter, &leaf).await.unwrap();
+    tree.mint_airdrop(&minter, &metadata, &[minter.pubkey()])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_paused_tree() {
+    let (mut context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let other = Keypair::new();
+    context
+        .fund_account(other.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    // Only the creator can pause the tree.
+    let mut tx = tree.set_tree_paused_tx(true);
+    tx.accounts.tree_creator = other.pubkey();
+    tx.set_signers(&[&other]).set_payer(other.pubkey());
+    assert!(tx.execute().await.is_err());
+
+    let before = tree.read_tree_config().await.unwrap();
+    tree.set_tree_paused(true).await.unwrap();
+    let after = tree.read_tree_config().await.unwrap();
+    assert!(after.is_paused);
+    assert_only_changed!(before, after, [is_paused]);
+
+    let mut next = LeafArgs::new(&other, context.default_metadata_args("next", "tst"));
+    next.nonce = 1;
+    let metadata = context.default_metadata_args("airdrop", "tst");
+    let payer = context.payer();
+    let collection = context.create_collection(&payer, false).await.unwrap();
+    let results = vec![
+        tree.mint_v1(&tree.tree_delegate, &next).await,
+        tree.mint_airdrop(&tree.tree_delegate, &metadata, &[other.pubkey()])
+            .await,
+        tree.mint_batch_v1(&tree.tree_delegate, &other, &[metadata.clone()])
+            .await,
+        tree.mint_to_collection_v1(
+            &tree.tree_delegate,
+            &next,
+            collection.mint,
+            collection.metadata,
+        )
+        .await,
+        tree.transfer(&leaf, other.pubkey()).await,
+        tree.transfer_timelocked(&leaf, other.pubkey(), 1_000).await,
+        tree.transfer_batch(&context.payer(), &[(&leaf, other.pubkey())])
+            .await,
+        tree.delegate(&leaf, other.pubkey()).await,
+        tree.delegate_with_expiry(&leaf, other.pubkey(), None).await,
+        tree.freeze(&leaf).await,
+        tree.burn(&leaf).await,
+        tree.burn_with_receipt(&leaf).await,
+        tree.redeem(&leaf).await,
+        tree.update_metadata(&leaf, &context.payer(), leaf.metadata.clone())
+            .await,
+    ];
+    for result in results {
+        assert!(result
+            .unwrap_err()
+            .is_bubblegum_error(BubblegumError::TreePaused));
+    }
+    assert_eq!(tree.read_tree_config().await.unwrap().num_minted, 1);
+
+    // Settings can still be changed while paused, and unpausing resumes activity.
+    tree.set_tree_public(true).await.unwrap();
+    tree.set_tree_paused(false).await.unwrap();
+    tree.mint_v1(&tree.tree_delegate, &next).await.unwrap();
+    tree.transfer(&leaf, other.pubkey()).await.unwrap();
+    let leaf = leaf.with_new_owner(&other);
+    let delegate = Keypair::new();
+    tree.delegate(&leaf, delegate.pubkey()).await.unwrap();
+    let leaf = leaf.with_new_delegate(&delegate);
+    tree.burn(&leaf).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_tree_delegates() {
+    let (mut context, tree, _) = context_tree_and_leaf().await.unwrap();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let outsider = Keypair::new();
+    for keypair in [&first, &second, &outsider] {
+        context
+            .fund_account(keypair.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    // Only the creator can register delegates.
+    let mut tx = tree.add_tree_delegate_tx(outsider.pubkey());
+    tx.accounts.tree_creator = outsider.pubkey();
+    tx.set_signers(&[&outsider]).set_payer(outsider.pubkey());
+    assert!(tx.execute().await.is_err());
+
+    let before = tree.read_tree_config().await.unwrap();
+    tree.add_tree_delegate(first.pubkey()).await.unwrap();
+    tree.add_tree_delegate(second.pubkey()).await.unwrap();
+    let after = tree.read_tree_config().await.unwrap();
+    assert_only_changed!(before, after, []);
+    let record = tree
+        .read_tree_delegate_record(first.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(record.merkle_tree, tree.tree_pubkey());
+    assert_eq!(record.delegate, first.pubkey());
+    assert!(tree.add_tree_delegate(first.pubkey()).await.is_err());
+
+    // Every registered delegate can mint, alongside the tree delegate.
+    let metadata = context.default_metadata_args("delegated", "tst");
+    for (nonce, delegate) in [&first, &second].into_iter().enumerate() {
+        let mut leaf = LeafArgs::new(delegate, metadata.clone());
+        leaf.nonce = nonce as u64 + 1;
+        tree.mint_v1(delegate, &leaf).await.unwrap();
+    }
+    tree.mint_airdrop(&second, &metadata, &[outsider.pubkey()])
+        .await
+        .unwrap();
+
+    let mut leaf = LeafArgs::new(&outsider, metadata.clone());
+    leaf.nonce = 4;
+    let err = tree.mint_v1(&outsider, &leaf).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::TreeAuthorityIncorrect));
+
+    // Registered delegates don't pay the mint price of public trees either.
+    tree.set_tree_public(true).await.unwrap();
+    tree.set_mint_price(1_000_000, Keypair::new().pubkey())
+        .await
+        .unwrap();
+    let mut leaf = LeafArgs::new(&first, metadata.clone());
+    leaf.nonce = 4;
+    tree.mint_v1(&first, &leaf).await.unwrap();
+    tree.set_tree_public(false).await.unwrap();
+
+    // Removed delegates can no longer mint, and the rent goes back to the creator.
+    tree.remove_tree_delegate(first.pubkey()).await.unwrap();
+    context
+        .assert_no_leaked_accounts(&[tree.tree_delegate_record(&first.pubkey())])
+        .await
+        .unwrap();
+    let mut leaf = LeafArgs::new(&first, metadata.clone());
+    leaf.nonce = 5;
+    let err = tree.mint_v1(&first, &leaf).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::TreeAuthorityIncorrect));
+    assert!(tree.remove_tree_delegate(first.pubkey()).await.is_err());
+
+    let mut leaf = LeafArgs::new(&second, metadata);
+    leaf.nonce = 5;
+    tree.mint_v1(&second, &leaf).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_mint_price_in_tokens() {
+    let (mut context, tree, _) = context_tree_and_leaf().await.unwrap();
+    tree.set_tree_public(true).await.unwrap();
+
+    let minter = Keypair::new();
+    context
+        .fund_account(minter.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    let treasury = Keypair::new().pubkey();
+    let token_mint = context.create_token_mint(100);
+    let minter_tokens = context.create_token_account(token_mint, minter.pubkey(), 100);
+    let treasury_tokens = context.create_token_account(token_mint, treasury, 0);
+    let other_mint = context.create_token_mint(0);
+    let other_tokens = context.create_token_account(other_mint, treasury, 0);
+
+    let mut tx = tree.set_mint_price_tx(0, treasury);
+    tx.data.token_amount = 30;
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidZeroAddress));
+
+    let mut tx = tree.set_mint_price_tx(0, treasury);
+    tx.data.token_mint = token_mint;
+    tx.data.token_amount = 30;
+    tx.execute().await.unwrap();
+    assert!(tree.read_tree_config().await.unwrap().has_mint_price());
+
+    let mut leaf = LeafArgs::new(&minter, context.default_metadata_args("paid", "tst"));
+    leaf.nonce = 1;
+
+    // The tokens must go to an account of the right mint, owned by the treasury.
+    for destination in [other_tokens, minter_tokens] {
+        let mut tx = tree.mint_v1_tx(&minter, &leaf);
+        tx.set_additional_account_metas(&tree.mint_price_companions(
+            treasury,
+            minter_tokens,
+            destination,
+        ));
+        let err = tx.execute().await.unwrap_err();
+        assert!(err.is_bubblegum_error(BubblegumError::InvalidTreasury));
+    }
+
+    let companions = tree.mint_price_companions(treasury, minter_tokens, treasury_tokens);
+    let mut tx = tree.mint_v1_tx(&minter, &leaf);
+    tx.set_additional_account_metas(&companions);
+    tx.execute().await.unwrap();
+    assert_eq!(context.token_balance(minter_tokens).await.unwrap(), 70);
+    assert_eq!(context.token_balance(treasury_tokens).await.unwrap(), 30);
+
+    // Lamport and token prices can be combined, and batches pay for each asset.
+    let mut tx = tree.set_mint_price_tx(1_000_000, treasury);
+    tx.data.token_mint = token_mint;
+    tx.data.token_amount = 30;
+    tx.execute().await.unwrap();
+
+    let metadata = context.default_metadata_args("paid", "tst");
+    let mut tx = tree.mint_batch_v1_tx(&minter, &minter, &[metadata.clone(), metadata]);
+    tx.set_additional_account_metas(&companions);
+    tx.execute().await.unwrap();
+    assert_eq!(context.token_balance(minter_tokens).await.unwrap(), 10);
+    assert_eq!(context.token_balance(treasury_tokens).await.unwrap(), 90);
+    assert_eq!(
+        tree.read_account(treasury).await.unwrap().lamports,
+        2_000_000
+    );
+
+    // The payer can't cover another mint.
+    let mut leaf = LeafArgs::new(&minter, context.default_metadata_args("paid", "tst"));
+    leaf.nonce = 4;
+    let mut tx = tree.mint_v1_tx(&minter, &leaf);
+    tx.set_additional_account_metas(&companions);
+    assert!(tx.execute().await.is_err());
+}
+
+#[tokio::test]
+async fn test_public_at_creation() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let payer = context.payer();
+
+    let mut tree = Tree::<MAX_DEPTH, MAX_BUF_SIZE>::with_creator(&payer, context.client());
+    tree.alloc(&payer).await.unwrap();
+    let mut tx = tree.create_tree_tx(&payer);
+    tx.data.is_public = true;
+    tx.execute().await.unwrap();
+    assert!(tree.read_tree_config().await.unwrap().is_public());
+
+    let minter = Keypair::new();
+    let leaf = LeafArgs::new(&minter, context.default_metadata_args("public", "tst"));
+    let mut tx = tree.mint_v1_tx(&minter, &leaf);
+    tx.set_payer(payer.pubkey()).set_signers(&[&payer, &minter]);
+    tx.execute().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_canopy_must_be_shallower_than_tree() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let payer = context.payer();
+
+    let mut tree = Tree::<5, 8>::with_creator(&payer, context.client());
+    tree.canopy_depth = 5;
+    tree.alloc(&payer).await.unwrap();
+    let err = tree.create(&payer).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::CanopyTooDeep));
+
+    let mut tree = Tree::<5, 8>::with_creator(&payer, context.client());
+    tree.canopy_depth = 4;
+    tree.alloc(&payer).await.unwrap();
+    tree.create(&payer).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_tree_program_version() {
+    let (mut context, tree, _leaf) = context_tree_and_leaf().await.unwrap();
+    assert_eq!(tree.read_tree_version().await.unwrap(), PROGRAM_VERSION);
+
+    // Trees created by earlier releases have a zero in place of the version, until the
+    // config gets written to.
+    let mut account = tree.read_account(tree.authority()).await.unwrap();
+    let mut config = tree.read_tree_config().await.unwrap();
+    config.program_version = 0;
+    let mut data = Vec::new();
+    config.try_serialize(&mut data).unwrap();
+    account.data[..data.len()].copy_from_slice(&data);
+    context.set_account(tree.authority(), account);
+    assert_eq!(tree.read_tree_version().await.unwrap(), 0);
+
+    let mut leaf = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("two", "tst"),
+    );
+    leaf.nonce = 1;
+    tree.mint_v1(&tree.tree_delegate, &leaf).await.unwrap();
+    assert_eq!(tree.read_tree_version().await.unwrap(), PROGRAM_VERSION);
+}
+
+#[tokio::test]
+async fn test_tree_capacity() {
+    let (mut context, tree, _leaf) = context_tree_and_leaf().await.unwrap();
+    let config = tree.read_tree_config().await.unwrap();
+    assert_eq!(config.remaining_capacity(), (1 << MAX_DEPTH) - 1);
+    assert!(!config.is_full());
+    assert!(config.contains_mint_capacity(config.remaining_capacity()));
+    assert!(!config.contains_mint_capacity(config.remaining_capacity() + 1));
+
+    // Filling up the tree for real takes too long, so the counter is bumped directly.
+    let mut account = tree.read_account(tree.authority()).await.unwrap();
+    let mut config = tree.read_tree_config().await.unwrap();
+    config.num_minted = config.total_mint_capacity;
+    let mut data = Vec::new();
+    config.try_serialize(&mut data).unwrap();
+    account.data[..data.len()].copy_from_slice(&data);
+    context.set_account(tree.authority(), account);
+
+    let config = tree.read_tree_config().await.unwrap();
+    assert_eq!(config.remaining_capacity(), 0);
+    assert!(config.is_full());
+
+    let metadata = context.default_metadata_args("full", "tst");
+    let err = tree
+        .mint_airdrop(&tree.tree_delegate, &metadata, &[Keypair::new().pubkey()])
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InsufficientMintCapacity));
+}
+
+#[tokio::test]
+async fn test_update_metadata() {
+    let (context, tree, _leaf) = context_tree_and_leaf().await.unwrap();
+    let payer = context.payer();
+    let mut metadata = context.default_metadata_args("before", "upd");
+    metadata.is_mutable = true;
+    let mut leaf = LeafArgs::new(&payer, metadata);
+    leaf.nonce = 1;
+    tree.mint_v1(&tree.tree_delegate, &leaf).await.unwrap();
+
+    let mut new_metadata = leaf.metadata.clone();
+    new_metadata.name = "after".to_owned();
+    new_metadata.uri = "www.solana.pos/after".to_owned();
+    new_metadata.primary_sale_happened = true;
+
+    let stranger = Keypair::new();
+    context
+        .fund_account(stranger.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    let err = tree
+        .update_metadata(&leaf, &stranger, new_metadata.clone())
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::UpdateAuthorityIncorrect));
+
+    let mut verified = new_metadata.clone();
+    verified.creators[0].verified = true;
+    let err = tree
+        .update_metadata(&leaf, &payer, verified)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::CreatorDidNotVerify));
+
+    tree.update_metadata(&leaf, &payer, new_metadata.clone())
+        .await
+        .unwrap();
+    // The full metadata is logged along with the new leaf, so clients can keep track of it.
+    let asset = &tree.list_assets(1..2)[0];
+    assert!(asset.metadata == new_metadata);
+    assert_eq!(
+        asset.leaf.data_hash(),
+        hash_metadata(&new_metadata).unwrap()
+    );
+    let previous = leaf.clone();
+    let leaf = leaf.with_updated_metadata(|metadata| *metadata = new_metadata);
+
+    // The old metadata no longer matches the leaf.
+    let mut again = leaf.metadata.clone();
+    again.symbol = "again".to_owned();
+    assert!(tree
+        .update_metadata(&previous, &payer, again)
+        .await
+        .is_err());
+
+    let mut unsold = leaf.metadata.clone();
+    unsold.primary_sale_happened = false;
+    let err = tree
+        .update_metadata(&leaf, &payer, unsold)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::PrimarySaleCanOnlyBeFlippedToTrue));
+
+    let mut frozen = leaf.metadata.clone();
+    frozen.is_mutable = false;
+    tree.update_metadata(&leaf, &payer, frozen.clone())
+        .await
+        .unwrap();
+    let leaf = leaf.with_updated_metadata(|metadata| *metadata = frozen);
+
+    let err = tree
+        .update_metadata(&leaf, &payer, leaf.metadata.clone())
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::MetadataImmutable));
+
+    // The updated leaf is still usable.
+    tree.burn(&leaf).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_metadata_update_proposal() {
+    let (mut context, mut tree, _leaf) = context_tree_and_leaf().await.unwrap();
+    let payer = context.payer();
+    let owner = Keypair::new();
+    let stranger = Keypair::new();
+    let delegate = Keypair::new();
+    for key in [owner.pubkey(), stranger.pubkey(), delegate.pubkey()] {
+        context
+            .fund_account(key, DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    let mut metadata = context.default_metadata_args("before", "upd");
+    metadata.is_mutable = true;
+    let mut leaf = LeafArgs::new(&owner, metadata);
+    leaf.nonce = 1;
+    tree.mint_v1(&tree.tree_delegate, &leaf).await.unwrap();
+
+    let mut new_metadata = leaf.metadata.clone();
+    new_metadata.name = "after".to_owned();
+    new_metadata.uri = "www.solana.pos/after".to_owned();
+
+    // The creator can't update the metadata of an asset it doesn't own without the owner.
+    let mut tx = tree
+        .update_metadata_tx(&leaf, &payer, new_metadata.clone())
+        .await
+        .unwrap();
+    tx.set_signers(&[&payer]);
+    let err = tx.execute_without_signer(owner.pubkey()).await.unwrap_err();
+    assert!(err.is_custom_error(ErrorCode::AccountNotSigner as u32));
+
+    let err = tree
+        .propose_metadata_update(&leaf, &stranger, new_metadata.clone())
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::UpdateAuthorityIncorrect));
+
+    tree.propose_metadata_update(&leaf, &payer, new_metadata.clone())
+        .await
+        .unwrap();
+
+    // Only the authority that made a proposal (and paid for it) can replace it.
+    tree.set_tree_delegate(&delegate).await.unwrap();
+    let err = tree
+        .propose_metadata_update(&leaf, &delegate, new_metadata.clone())
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::MetadataProposalPending));
+    let proposal = tree.read_metadata_proposal(leaf.nonce).await.unwrap();
+    assert_eq!(proposal.proposer, payer.pubkey());
+    tree.propose_metadata_update(&leaf, &payer, new_metadata.clone())
+        .await
+        .unwrap();
+
+    // The owner can only approve the metadata that was proposed.
+    let mut other = new_metadata.clone();
+    other.symbol = "other".to_owned();
+    let err = tree
+        .approve_metadata_update(&leaf, payer.pubkey(), other)
+        .await
+        .unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::MetadataProposalMismatch));
+
+    // Nobody else can approve on behalf of the owner.
+    let mut tx = tree
+        .approve_metadata_update_tx(&leaf, payer.pubkey(), new_metadata.clone())
+        .await
+        .unwrap();
+    tx.accounts.leaf_owner = stranger.pubkey();
+    tx.set_signers(&[&stranger]).set_payer(stranger.pubkey());
+    assert!(tx.execute().await.is_err());
+
+    tree.approve_metadata_update(&leaf, payer.pubkey(), new_metadata.clone())
+        .await
+        .unwrap();
+    context
+        .assert_no_leaked_accounts(&[tree.metadata_proposal(leaf.nonce)])
+        .await
+        .unwrap();
+    assert!(tree.list_assets(1..2)[0].metadata == new_metadata);
+    let leaf = leaf.with_updated_metadata(|metadata| *metadata = new_metadata);
+
+    // Proposals can be withdrawn by the proposer, until the owner approves them.
+    let mut unsold = leaf.metadata.clone();
+    unsold.primary_sale_happened = true;
+    tree.propose_metadata_update(&leaf, &payer, unsold.clone())
+        .await
+        .unwrap();
+    assert!(tree
+        .cancel_metadata_proposal(&leaf, &stranger)
+        .await
+        .is_err());
+    tree.cancel_metadata_proposal(&leaf, &payer).await.unwrap();
+    context
+        .assert_no_leaked_accounts(&[tree.metadata_proposal(leaf.nonce)])
+        .await
+        .unwrap();
+    assert!(tree
+        .approve_metadata_update(&leaf, payer.pubkey(), unsold)
+        .await
+        .is_err());
+
+    // The updated leaf is still usable.
+    tree.transfer(&leaf, Keypair::new().pubkey()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_feature_flags() {
+    let (mut context, mut tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let authority = Keypair::new();
+    context
+        .fund_account(authority.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    context.set_upgrade_authority(authority.pubkey());
+
+    let mask = FeatureFlags::instruction_mask(InstructionName::MintV1)
+        | FeatureFlags::instruction_mask(InstructionName::Transfer);
+
+    // Only the upgrade authority of the program can change the flags.
+    let err = tree
+        .set_feature_flags(&context.payer(), mask)
+        .await
+        .unwrap_err();
+    assert!(err.is_custom_error(ErrorCode::ConstraintRaw as u32));
+
+    tree.set_feature_flags(&authority, mask).await.unwrap();
+    let flags = tree.read_feature_flags().await.unwrap();
+    assert!(!flags.is_enabled(InstructionName::MintV1));
+    assert!(!flags.is_enabled(InstructionName::Transfer));
+    assert!(flags.is_enabled(InstructionName::Burn));
+
+    let mut second = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("second", "tst"),
+    );
+    second.nonce = 1;
+    let recipient = Keypair::new().pubkey();
+    let results = vec![
+        tree.mint_v1(&tree.tree_delegate, &second).await,
+        tree.transfer(&leaf, recipient).await,
+    ];
+    for result in results {
+        assert!(result
+            .unwrap_err()
+            .is_bubblegum_error(BubblegumError::InstructionDisabled));
+    }
+
+    // Bits that no instruction checks are rejected.
+    for mask in [
+        FeatureFlags::instruction_mask(InstructionName::Burn),
+        1 << 63,
+    ] {
+        let err = tree.set_feature_flags(&authority, mask).await.unwrap_err();
+        assert!(err.is_bubblegum_error(BubblegumError::InvalidFeatureFlags));
+    }
+
+    tree.set_feature_flags(&authority, 0).await.unwrap();
+    let flags = tree.read_feature_flags().await.unwrap();
+    assert_eq!(flags, FeatureFlags::default());
+    tree.mint_v1(&tree.tree_delegate, &second).await.unwrap();
+    tree.transfer(&leaf, recipient).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_feature_flags_are_required() {
+    let (mut context, mut tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let authority = Keypair::new();
+    context
+        .fund_account(authority.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+    context.set_upgrade_authority(authority.pubkey());
+
+    let payer = context.payer();
+    let mut second = LeafArgs::new(&payer, context.default_metadata_args("second", "tst"));
+    second.nonce = 1;
+    let recipient = Keypair::new().pubkey();
+
+    // Trees the flags were not applied to don't need them.
+    assert!(!tree.read_tree_config().await.unwrap().has_feature_flags());
+    tree.mint_v1(&tree.tree_delegate, &second).await.unwrap();
+
+    let mut third = LeafArgs::new(&payer, context.default_metadata_args("third", "tst"));
+    third.nonce = 2;
+    let mask = FeatureFlags::instruction_mask(InstructionName::MintV1);
+    tree.set_feature_flags(&authority, mask).await.unwrap();
+    assert!(tree.read_tree_config().await.unwrap().has_feature_flags());
+
+    // Once they were, instructions with a fixed account layout can't skip the flags by leaving
+    // them out.
+    let mut mint = tree.mint_v1_tx(&tree.tree_delegate, &third);
+    mint.set_additional_accounts(&[]);
+    let mut batch = tree.mint_batch_v1_tx(&tree.tree_delegate, &payer, &[third.metadata.clone()]);
+    batch.set_additional_accounts(&[]);
+    // Without the flags, the first proof node takes their place.
+    let mut transfer = tree.transfer_tx(&leaf, recipient).await.unwrap();
+    transfer.set_additional_accounts(&tree.proof(leaf.index()));
+
+    let results = vec![
+        mint.execute().await,
+        batch.execute().await,
+        transfer.execute().await,
+    ];
+    for result in results {
+        assert!(result
+            .unwrap_err()
+            .is_bubblegum_error(BubblegumError::FeatureFlagsMissing));
+    }
+
+    // Passing them along enforces them.
+    let err = tree.mint_v1(&tree.tree_delegate, &third).await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InstructionDisabled));
+    tree.transfer(&leaf, recipient).await.unwrap();
+
+    tree.set_feature_flags(&authority, 0).await.unwrap();
+    tree.mint_v1(&tree.tree_delegate, &third).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_disabled_transfer_covers_other_transfers() {
+    let (mut context, mut tree, leaf) = context_tree_and_leaf().await.unwrap();
+    let authority = Keypair::new();
+    let recipient = Keypair::new();
+    for key in [authority.pubkey(), recipient.pubkey()] {
+        context
+            .fund_account(key, DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+    context.set_upgrade_authority(authority.pubkey());
+
+    // Start a timelocked transfer while that's still possible, to try claiming it later.
+    let unlock_slot = context.current_slot().await.unwrap() + 1;
+    tree.transfer_timelocked(&leaf, recipient.pubkey(), unlock_slot)
+        .await
+        .unwrap();
+    context.warp_to_slot(unlock_slot + 1).unwrap();
+
+    let mask = FeatureFlags::instruction_mask(InstructionName::Transfer);
+    tree.set_feature_flags(&authority, mask).await.unwrap();
+    let flags = tree.read_feature_flags().await.unwrap();
+    for instruction in [
+        InstructionName::TransferBatch,
+        InstructionName::TransferTimelocked,
+        InstructionName::ClaimTimelockedTransfer,
+    ] {
+        assert!(!flags.is_enabled(instruction));
+    }
+
+    let payer = context.payer();
+    let mut second = LeafArgs::new(&payer, context.default_metadata_args("second", "tst"));
+    second.nonce = 1;
+    tree.mint_v1(&tree.tree_delegate, &second).await.unwrap();
+
+    let results = vec![
+        tree.transfer_batch(&payer, &[(&second, recipient.pubkey())])
+            .await,
+        tree.transfer_timelocked(&second, recipient.pubkey(), unlock_slot + 10)
+            .await,
+        tree.claim_timelocked_transfer(&leaf, &recipient).await,
+    ];
+    for result in results {
+        assert!(result
+            .unwrap_err()
+            .is_bubblegum_error(BubblegumError::InstructionDisabled));
+    }
+
+    tree.set_feature_flags(&authority, 0).await.unwrap();
+    tree.claim_timelocked_transfer(&leaf, &recipient)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_mint_with_third_party_delegate() {
+    let (mut context, tree, _leaf) = context_tree_and_leaf().await.unwrap();
+    let owner = Keypair::new();
+    let delegate = Keypair::new();
+    for key in [owner.pubkey(), delegate.pubkey()] {
+        context
+            .fund_account(key, DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    let mut leaf = LeafArgs::new(&owner, context.default_metadata_args("two", "tst"))
+        .with_new_delegate(&delegate);
+    leaf.nonce = 1;
+
+    // The owner has to sign to set a delegate other than themselves.
+    let mut tx = tree.mint_v1_tx(&tree.tree_delegate, &leaf);
+    tx.accounts.payer = delegate.pubkey();
+    tx.set_payer(delegate.pubkey())
+        .set_signers(&[&tree.tree_delegate, &delegate]);
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::LeafOwnerMustSignForDelegate));
+
+    // The default address is not a valid delegate.
+    let mut tx = tree.mint_v1_tx(&tree.tree_delegate, &leaf);
+    tx.accounts.leaf_delegate = solana_sdk::system_program::id();
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidLeafDelegate));
+
+    tree.mint_v1(&tree.tree_delegate, &leaf).await.unwrap();
+
+    // The delegate can transfer the asset right away, without the owner.
+    let mut tx = tree.transfer_tx(&leaf, delegate.pubkey()).await.unwrap();
+    tx.set_payer(delegate.pubkey()).set_signers(&[&delegate]);
+    tx.execute().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_account_validation() {
+    let (_context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    let new_delegate = Keypair::new().pubkey();
+    tree.set_tree_delegate_tx(new_delegate)
+        .assert_account_validation(&[new_delegate])
+        .await
+        .unwrap();
+
+    let new_owner = Keypair::new().pubkey();
+    tree.transfer_tx(&leaf, new_owner)
+        .await
+        .unwrap()
+        .assert_account_validation(&[new_owner])
+        .await
+        .unwrap();
+
+    tree.burn_tx(&leaf)
+        .await
+        .unwrap()
+        .assert_account_validation(&[])
+        .await
+        .unwrap();
+
+    // The leaf is untouched by all of the above.
+    tree.burn(&leaf).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_unknown_root_is_rejected() {
+    let (_context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    let mut tx = tree
+        .transfer_tx(&leaf, Keypair::new().pubkey())
+        .await
+        .unwrap();
+    let root = tx.data.root;
+
+    tx.data.root = [1; 32];
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_custom_error(u32::from(
+        AccountCompressionError::ConcurrentMerkleTreeError
+    )));
+
+    // Roots that are still in the change log are accepted.
+    tx.data.root = root;
+    tx.execute().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_proof_length_is_validated() {
+    let (context, tree, leaf) = context_tree_and_leaf().await.unwrap();
+
+    let mut second = LeafArgs::new(
+        &context.payer(),
+        context.default_metadata_args("second", "tst"),
+    );
+    second.nonce = 1;
+    tree.mint_v1(&tree.tree_delegate, &second).await.unwrap();
+
+    let new_owner = Keypair::new().pubkey();
+    let mut tx = tree.transfer_tx(&leaf, new_owner).await.unwrap();
+
+    // One node too many.
+    let mut remaining = tree.transfer_companions();
+    remaining.extend(tree.proof(leaf.index()));
+    remaining.push(Keypair::new().pubkey());
+    tx.set_additional_accounts(&remaining);
+    let err = tx.execute().await.unwrap_err();
+    assert!(err.is_bubblegum_error(BubblegumError::InvalidProofLength));
+
+    // One node too few, or none at all.
+    let proof = tree.proof(leaf.index());
+    for len in [proof.len() - 1, 0] {
+        let mut remaining = tree.transfer_companions();
+        remaining.extend(&proof[..len]);
+        tx.set_additional_accounts(&remaining);
+        let err = tx.execute().await.unwrap_err();
+        assert!(err.is_bubblegum_error(BubblegumError::InvalidProofLength));
+    }
+
+    let mut remaining = tree.transfer_companions();
+    remaining.extend(proof);
+    tx.set_additional_accounts(&remaining);
+    tx.execute().await.unwrap();
+    tree.burn(&second).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_assert_consistent_detects_divergence() {
+    let (_, tree, leaf) = context_tree_and_leaf().await.unwrap();
+    tree.assert_consistent().await.unwrap();
+
+    // Hide a transfer from the harness, so the mirror falls behind the on-chain tree.
+    let tx = tree
+        .transfer_tx(&leaf, Keypair::new().pubkey())
+        .await
+        .unwrap();
+    tree.process_tx(tx.instruction(), &leaf.owner.pubkey(), &[&leaf.owner])
+        .await
+        .unwrap();
+
+    match tree.assert_consistent().await {
+        Err(Error::InconsistentTreeState(diff)) => {
+            assert_eq!(diff.len(), 2);
+            assert!(diff[0].starts_with("root:"));
+            assert!(diff[1].starts_with("sequence_number:"));
+        }
+        _ => panic!("expected the tree state to be inconsistent"),
+    }
+}
+
+#[tokio::test]
+async fn test_list_assets_enumerates_all_mints() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+    let payer = context.payer();
+
+    const NUM_MINTS: u64 = 10;
+    const PAGE_SIZE: u64 = 3;
+
+    for nonce in 0..NUM_MINTS {
+        // Using a different name for each asset ensures the mint transactions are distinct.
+        let mut args = LeafArgs::new(
+            &payer,
+            context.default_metadata_args(format!("test{}", nonce), "tst"),
+        );
+        args.nonce = nonce;
+        tree.mint_v1(&tree.tree_delegate, &args).await.unwrap();
+    }
+
+    let num_minted = tree.read_tree_config().await.unwrap().num_minted;
+    assert_eq!(num_minted, NUM_MINTS);
+
+    let mut assets = Vec::new();
+    let mut start = 0;
+    while start < num_minted {
+        let page = tree.list_assets(start..start + PAGE_SIZE);
+        assert!(page.len() as u64 <= PAGE_SIZE);
+        assets.extend(page);
+        start += PAGE_SIZE;
+    }
+
+    assert_eq!(assets.len() as u64, NUM_MINTS);
+    for (nonce, asset) in assets.iter().enumerate() {
+        assert_eq!(asset.nonce, nonce as u64);
+        assert_eq!(asset.asset_id, tree.asset_id(asset.nonce));
+        assert_eq!(asset.metadata.name, format!("test{}", nonce));
+        assert_eq!(asset.state, AssetState::Live);
+    }
+
+    // Ranges past the number of minted assets are empty.
+    assert!(tree
+        .list_assets(NUM_MINTS..NUM_MINTS + PAGE_SIZE)
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_mirror_tracks_on_chain_root() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+    let payer = context.payer();
+
+    let mut leaves = Vec::new();
+    for nonce in 0..8 {
+        let mut args = LeafArgs::new(
+            &payer,
+            context.default_metadata_args(format!("test{}", nonce), "tst"),
+        );
+        args.nonce = nonce;
+        tree.mint_v1(&tree.tree_delegate, &args).await.unwrap();
+        assert_eq!(tree.mirror_root(), tree.decode_root().await.unwrap());
+        leaves.push(args);
+    }
+
+    // Operations on leaves other than the rightmost one require non-trivial proofs, which
+    // are provided by the mirror.
+    let new_owner = Keypair::new();
+    tree.transfer(&leaves[2], new_owner.pubkey()).await.unwrap();
+    assert_eq!(tree.mirror_root(), tree.decode_root().await.unwrap());
+
+    tree.burn(&leaves[5]).await.unwrap();
+    assert_eq!(tree.mirror_root(), tree.decode_root().await.unwrap());
+
+    tree.delegate(&leaves[0], new_owner.pubkey()).await.unwrap();
+    assert_eq!(tree.mirror_root(), tree.decode_root().await.unwrap());
 }
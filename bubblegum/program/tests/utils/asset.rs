@@ -0,0 +1,79 @@
+// A handle to a single asset of a `Tree`, which keeps track of the keys and hashes of the leaf
+// as operations are applied to it, so scenario tests don't have to thread (tree, leaf) pairs
+// and `with_new_*` updates through every step.
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use super::{LeafArgs, Result, Tree};
+
+pub struct AssetHandle<'a, const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> {
+    pub tree: &'a Tree<MAX_DEPTH, MAX_BUFFER_SIZE>,
+    leaf: LeafArgs,
+}
+
+impl<'a, const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize>
+    AssetHandle<'a, MAX_DEPTH, MAX_BUFFER_SIZE>
+{
+    // The leaf must already be in the tree (see `Tree::asset` and `Tree::mint_asset`).
+    pub fn new(tree: &'a Tree<MAX_DEPTH, MAX_BUFFER_SIZE>, leaf: LeafArgs) -> Self {
+        AssetHandle { tree, leaf }
+    }
+
+    pub fn nonce(&self) -> u64 {
+        self.leaf.nonce
+    }
+
+    // The current state of the leaf, i.e. for passing it to `Tree` methods directly.
+    pub fn leaf(&self) -> &LeafArgs {
+        &self.leaf
+    }
+
+    pub fn asset_id(&self) -> Pubkey {
+        self.tree.asset_id(self.leaf.nonce)
+    }
+
+    pub fn proof(&self) -> Vec<Pubkey> {
+        self.tree.proof(self.leaf.index())
+    }
+
+    pub async fn transfer_to(&mut self, new_owner: &Keypair) -> Result<()> {
+        self.tree.transfer(&self.leaf, new_owner.pubkey()).await?;
+        self.leaf = self.leaf.clone().with_new_owner(new_owner);
+        Ok(())
+    }
+
+    pub async fn delegate_to(&mut self, new_delegate: &Keypair) -> Result<()> {
+        self.tree
+            .delegate(&self.leaf, new_delegate.pubkey())
+            .await?;
+        self.leaf = self.leaf.clone().with_new_delegate(new_delegate);
+        Ok(())
+    }
+
+    pub async fn verify_creator(&mut self, creator: &Keypair) -> Result<()> {
+        self.tree.verify_creator(&self.leaf, creator).await?;
+        self.set_creator_verified(creator.pubkey(), true);
+        Ok(())
+    }
+
+    pub async fn unverify_creator(&mut self, creator: &Keypair) -> Result<()> {
+        self.tree.unverify_creator(&self.leaf, creator).await?;
+        self.set_creator_verified(creator.pubkey(), false);
+        Ok(())
+    }
+
+    // Consumes the handle, since there's nothing left to do with a burned asset.
+    pub async fn burn(self) -> Result<()> {
+        self.tree.burn(&self.leaf).await
+    }
+
+    fn set_creator_verified(&mut self, address: Pubkey, verified: bool) {
+        self.leaf = self.leaf.clone().with_updated_metadata(|metadata| {
+            for creator in metadata.creators.iter_mut() {
+                if creator.address == address {
+                    creator.verified = verified;
+                }
+            }
+        });
+    }
+}
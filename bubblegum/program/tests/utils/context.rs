@@ -1,16 +1,37 @@
 use std::fmt::Display;
 
-use mpl_bubblegum::state::metaplex_adapter::{Creator, MetadataArgs, TokenProgramVersion};
-use solana_program::pubkey::Pubkey;
+use anchor_lang::AccountDeserialize;
+use mpl_bubblegum::{
+    error::BubblegumError,
+    state::{
+        metaplex_adapter::{Creator, MetadataArgs, TokenProgramVersion},
+        metaplex_anchor::TokenMetadata,
+    },
+};
+use mpl_token_metadata::{
+    instruction,
+    state::{CollectionDetails, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH},
+};
+use solana_program::{program_option::COption, program_pack::Pack, pubkey::Pubkey, rent::Rent};
 use solana_program_test::{BanksClient, ProgramTestContext};
 use solana_sdk::{
-    signature::{Keypair, Signer},
+    account::{Account, AccountSharedData},
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    hash::Hash,
+    signature::{keypair_from_seed, Keypair, Signer},
     system_instruction,
     transaction::Transaction,
 };
 
 use super::{clone_keypair, program_test, Error, LeafArgs, Result, Tree};
 
+// The accounts of a collection NFT created via `BubblegumTestContext::create_collection`.
+pub struct CollectionNft {
+    pub mint: Pubkey,
+    pub metadata: Pubkey,
+    pub edition: Pubkey,
+}
+
 pub struct BubblegumTestContext {
     program_context: ProgramTestContext,
     pub default_creators: Vec<Keypair>,
@@ -20,25 +41,29 @@ pub const DEFAULT_LAMPORTS_FUND_AMOUNT: u64 = 1_000_000_000;
 
 impl BubblegumTestContext {
     pub async fn new() -> Result<Self> {
-        let program_context = program_test().start_with_context().await;
+        Self::with_programs(&[]).await
+    }
+
+    // Same as `new`, but also loads the given programs (see `program_test`).
+    pub async fn with_programs(programs: &[(&str, Pubkey)]) -> Result<Self> {
+        let program_context = program_test(programs).start_with_context().await;
 
         let mut ctx = BubblegumTestContext {
             program_context,
             default_creators: Vec::new(),
         };
 
-        let default_creators = vec![
-            Keypair::new(),
-            Keypair::new(),
-            Keypair::new(),
-            Keypair::new(),
-        ];
+        // Derived from fixed seeds, so every run of a test uses the same creators. Sorted by
+        // address, which is the order creators must have when minting.
+        let mut default_creators: Vec<Keypair> = (0..4u8)
+            .map(|index| keypair_from_seed(&[index; 32]).unwrap())
+            .collect();
+        default_creators.sort_by_key(|creator| creator.pubkey());
 
         for creator in default_creators.iter() {
             ctx.fund_account(creator.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
                 .await?;
         }
-
         ctx.default_creators = default_creators;
 
         Ok(ctx)
@@ -51,6 +76,7 @@ impl BubblegumTestContext {
     // TODO: implement this based on stuff from `mpl-testing-utils` after we can add it
     // as a dev-dependency without conflicts/issues.
     pub async fn fund_account(&mut self, address: Pubkey, lamports: u64) -> Result<()> {
+        let recent_blockhash = self.refresh_blockhash().await?;
         let payer = &self.program_context.payer;
 
         // Create a transaction to send some funds to the `new_owner` account, which is used
@@ -65,7 +91,7 @@ impl BubblegumTestContext {
             )],
             Some(&payer.pubkey()),
             &[payer],
-            self.program_context.last_blockhash,
+            recent_blockhash,
         );
 
         self.program_context
@@ -75,6 +101,236 @@ impl BubblegumTestContext {
             .map_err(Error::BanksClient)
     }
 
+    // Checks that the given accounts (i.e. vouchers and receipts that were supposed to be
+    // closed) returned all their lamports and were purged, and fails with the ones that weren't.
+    pub async fn assert_no_leaked_accounts(&self, addresses: &[Pubkey]) -> Result<()> {
+        let mut leaked = Vec::new();
+        for address in addresses {
+            let account = self
+                .client()
+                .get_account(*address)
+                .await
+                .map_err(Error::BanksClient)?;
+            if let Some(account) = account {
+                if account.lamports > 0 || account.data.iter().any(|byte| *byte != 0) {
+                    leaked.push(*address);
+                }
+            }
+        }
+
+        if leaked.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::LeakedAccounts(leaked))
+        }
+    }
+
+    // Overwrites an account directly in the bank, i.e. to recreate the state left behind by
+    // older releases of the program.
+    pub fn set_account(&mut self, address: Pubkey, account: Account) {
+        self.program_context
+            .set_account(&address, &AccountSharedData::from(account));
+    }
+
+    // Makes `authority` the upgrade authority of the program, which is otherwise not deployed
+    // through the upgradeable loader in the test bank.
+    pub fn set_upgrade_authority(&mut self, authority: Pubkey) {
+        let (program_data, _) = Pubkey::find_program_address(
+            &[mpl_bubblegum::id().as_ref()],
+            &bpf_loader_upgradeable::id(),
+        );
+        let data = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address: Some(authority),
+        })
+        .unwrap();
+
+        self.set_account(
+            program_data,
+            Account {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: bpf_loader_upgradeable::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Creates an SPL token mint directly in the bank, with `supply` tokens that are only ever
+    // handed out via `create_token_account`.
+    pub fn create_token_mint(&mut self, supply: u64) -> Pubkey {
+        let mint = spl_token::state::Mint {
+            mint_authority: COption::Some(self.program_context.payer.pubkey()),
+            supply,
+            decimals: 0,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        self.set_packed_token_account(mint)
+    }
+
+    // Creates a token account of `mint` owned by `owner` and holding `amount` tokens directly in
+    // the bank.
+    pub fn create_token_account(&mut self, mint: Pubkey, owner: Pubkey, amount: u64) -> Pubkey {
+        let account = spl_token::state::Account {
+            mint,
+            owner,
+            amount,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        };
+        self.set_packed_token_account(account)
+    }
+
+    // Creates a collection NFT through Token Metadata, with `authority` as its update authority.
+    // Sized collections start out empty, and their size is then kept up to date by Bubblegum.
+    pub async fn create_collection(
+        &mut self,
+        authority: &Keypair,
+        sized: bool,
+    ) -> Result<CollectionNft> {
+        let payer = self.payer();
+        let mint = self.create_token_mint(1);
+        self.create_token_account(mint, authority.pubkey(), 1);
+        let (metadata, _) = mpl_token_metadata::pda::find_metadata_account(&mint);
+        let (edition, _) = mpl_token_metadata::pda::find_master_edition_account(&mint);
+
+        let instructions = [
+            instruction::create_metadata_accounts_v3(
+                mpl_token_metadata::id(),
+                metadata,
+                mint,
+                payer.pubkey(),
+                payer.pubkey(),
+                authority.pubkey(),
+                "Collection".to_owned(),
+                "COL".to_owned(),
+                "https://example.com/collection.json".to_owned(),
+                None,
+                0,
+                true,
+                true,
+                None,
+                None,
+                sized.then(|| CollectionDetails::V1 { size: 0 }),
+            ),
+            instruction::create_master_edition_v3(
+                mpl_token_metadata::id(),
+                edition,
+                mint,
+                authority.pubkey(),
+                payer.pubkey(),
+                metadata,
+                payer.pubkey(),
+                Some(0),
+            ),
+        ];
+        let recent_blockhash = self.refresh_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&payer.pubkey()),
+            &[&payer, authority],
+            recent_blockhash,
+        );
+        self.program_context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .map_err(Error::BanksClient)?;
+
+        Ok(CollectionNft {
+            mint,
+            metadata,
+            edition,
+        })
+    }
+
+    // The size of a collection created via `create_collection`, or `None` if it is not sized.
+    pub async fn collection_size(&self, collection: &CollectionNft) -> Result<Option<u64>> {
+        let account = self
+            .client()
+            .get_account(collection.metadata)
+            .await
+            .map_err(Error::BanksClient)?
+            .ok_or(Error::AccountNotFound(collection.metadata))?;
+        let metadata =
+            TokenMetadata::try_deserialize(&mut account.data.as_slice()).map_err(Error::Anchor)?;
+        Ok(metadata
+            .collection_details
+            .as_ref()
+            .map(|CollectionDetails::V1 { size }| *size))
+    }
+
+    fn set_packed_token_account<T: Pack>(&mut self, state: T) -> Pubkey {
+        let address = Keypair::new().pubkey();
+        let mut data = vec![0; T::LEN];
+        T::pack(state, &mut data).unwrap();
+        self.set_account(
+            address,
+            Account {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: spl_token::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+        address
+    }
+
+    pub async fn token_balance(&self, address: Pubkey) -> Result<u64> {
+        let account = self
+            .client()
+            .get_account(address)
+            .await
+            .map_err(Error::BanksClient)?
+            .ok_or(Error::AccountNotFound(address))?;
+        Ok(spl_token::state::Account::unpack(&account.data)
+            .unwrap()
+            .amount)
+    }
+
+    pub async fn current_slot(&self) -> Result<u64> {
+        self.client()
+            .get_root_slot()
+            .await
+            .map_err(Error::BanksClient)
+    }
+
+    // Moves the bank forward, i.e. for testing instructions that depend on the current slot.
+    pub fn warp_to_slot(&mut self, slot: u64) -> Result<()> {
+        self.program_context
+            .warp_to_slot(slot)
+            .map_err(|_| Error::InvalidWarpSlot(slot))
+    }
+
+    // Updates the blockhash used by the context to the latest one of the bank, since the one
+    // from when the context was created expires once enough slots go by (i.e. after warping).
+    pub async fn refresh_blockhash(&mut self) -> Result<Hash> {
+        let blockhash = self
+            .client()
+            .get_latest_blockhash()
+            .await
+            .map_err(Error::BanksClient)?;
+        self.program_context.last_blockhash = blockhash;
+        Ok(blockhash)
+    }
+
+    // Moves the bank to the next slot and waits for a new blockhash, so that sending the
+    // same transaction again doesn't get rejected as a duplicate. Returns the new slot.
+    pub async fn advance_slot(&mut self) -> Result<u64> {
+        let slot = self.current_slot().await? + 1;
+        self.warp_to_slot(slot)?;
+        let blockhash = self
+            .client()
+            .get_new_latest_blockhash(&self.program_context.last_blockhash)
+            .await
+            .map_err(Error::Io)?;
+        self.program_context.last_blockhash = blockhash;
+        Ok(slot)
+    }
+
     pub fn payer(&self) -> Keypair {
         clone_keypair(&self.program_context.payer)
     }
@@ -121,6 +377,54 @@ impl BubblegumTestContext {
         }
     }
 
+    // Returns metadata with each of the length limited fields (and the basis points) set to
+    // exactly the maximum value, and to one over it, along with a description of each case and
+    // the error it is expected to fail with (if any).
+    pub fn boundary_metadata(&self) -> Vec<(String, MetadataArgs, Option<BubblegumError>)> {
+        let mut cases = Vec::new();
+        let base = self.default_metadata_args("boundary", "bnd");
+
+        let fields: [(&str, usize, fn(&mut MetadataArgs, String), BubblegumError); 3] = [
+            (
+                "name",
+                MAX_NAME_LENGTH,
+                |m, value| m.name = value,
+                BubblegumError::MetadataNameTooLong,
+            ),
+            (
+                "symbol",
+                MAX_SYMBOL_LENGTH,
+                |m, value| m.symbol = value,
+                BubblegumError::MetadataSymbolTooLong,
+            ),
+            (
+                "uri",
+                MAX_URI_LENGTH,
+                |m, value| m.uri = value,
+                BubblegumError::MetadataUriTooLong,
+            ),
+        ];
+
+        for (field, max, set, err) in fields {
+            for (len, expected) in [(max, None), (max + 1, Some(err))] {
+                let mut metadata = base.clone();
+                set(&mut metadata, "x".repeat(len));
+                cases.push((format!("{} of length {}", field, len), metadata, expected));
+            }
+        }
+
+        for (bps, expected) in [
+            (10000, None),
+            (10001, Some(BubblegumError::MetadataBasisPointsTooHigh)),
+        ] {
+            let mut metadata = base.clone();
+            metadata.seller_fee_basis_points = bps;
+            cases.push((format!("{} basis points", bps), metadata, expected));
+        }
+
+        cases
+    }
+
     pub async fn default_create_tree<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize>(
         &self,
     ) -> Result<Tree<MAX_DEPTH, MAX_BUFFER_SIZE>> {
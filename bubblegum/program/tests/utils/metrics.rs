@@ -0,0 +1,104 @@
+// Optional metrics about the transactions sent by the builders, which help with tracking the
+// cost of operations across program changes. Setting `HARNESS_METRICS` to the path of a JSON
+// file enables them, and the file then gets a summary for every test that sent transactions:
+//
+// "simple::test_burn_receipt": {
+//     "operations": { "BurnWithReceipt": 1, "MintV1": 1, ... },
+//     "transactions": 4,
+//     "compute_units": 123456,
+//     "lamports_spent": 1461600,
+//     "wall_time_ms": 42
+// }
+//
+// Only successful transactions are counted. The summaries of other tests already in the file
+// are kept, so the same file can be used for multiple test binaries.
+use serde_json::{json, Map, Value};
+use std::{collections::BTreeMap, env, fs, path::PathBuf, sync::Mutex, thread, time::Instant};
+
+pub const METRICS_ENV_VAR: &str = "HARNESS_METRICS";
+
+// What's recorded about a single transaction.
+pub struct TxMetrics {
+    // Names of the Bubblegum instructions executed by the transaction.
+    pub operations: Vec<String>,
+    pub compute_units: u64,
+    // How much the balance of the payer went down, including fees and rent.
+    pub lamports_spent: i64,
+}
+
+struct TestMetrics {
+    operations: BTreeMap<String, u64>,
+    transactions: u64,
+    compute_units: u64,
+    lamports_spent: i64,
+    // When the first transaction of the test was recorded.
+    started: Instant,
+}
+
+static METRICS: Mutex<BTreeMap<String, TestMetrics>> = Mutex::new(BTreeMap::new());
+
+pub fn metrics_path() -> Option<PathBuf> {
+    env::var_os(METRICS_ENV_VAR).map(PathBuf::from)
+}
+
+// Returns the number of compute units consumed by all invocations of `program_id`, based on
+// the logs of a transaction.
+pub fn compute_units(logs: &[String], program_id: &str) -> u64 {
+    let prefix = format!("Program {} consumed ", program_id);
+    logs.iter()
+        .filter_map(|log| log.strip_prefix(&prefix))
+        .filter_map(|rest| rest.split(' ').next())
+        .filter_map(|units| units.parse::<u64>().ok())
+        .sum()
+}
+
+// Adds the transaction to the summary of the current test, and writes out the file. Tests run
+// on threads named after them, which is how they are told apart.
+pub fn record(tx: TxMetrics) {
+    let path = match metrics_path() {
+        Some(path) => path,
+        None => return,
+    };
+    let test = format!(
+        "{}::{}",
+        env!("CARGO_CRATE_NAME"),
+        thread::current().name().unwrap_or("unknown")
+    );
+
+    let mut metrics = METRICS.lock().unwrap();
+    let entry = metrics.entry(test).or_insert_with(|| TestMetrics {
+        operations: BTreeMap::new(),
+        transactions: 0,
+        compute_units: 0,
+        lamports_spent: 0,
+        started: Instant::now(),
+    });
+    for operation in tx.operations {
+        *entry.operations.entry(operation).or_default() += 1;
+    }
+    entry.transactions += 1;
+    entry.compute_units += tx.compute_units;
+    entry.lamports_spent += tx.lamports_spent;
+
+    // Merges with what other test binaries wrote to the same file.
+    let mut summary: Map<String, Value> = fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default();
+    for (test, entry) in metrics.iter() {
+        summary.insert(
+            test.clone(),
+            json!({
+                "operations": entry.operations,
+                "transactions": entry.transactions,
+                "compute_units": entry.compute_units,
+                "lamports_spent": entry.lamports_spent,
+                "wall_time_ms": entry.started.elapsed().as_millis() as u64,
+            }),
+        );
+    }
+    let contents = serde_json::to_string_pretty(&summary).unwrap();
+    if let Err(err) = fs::write(&path, contents) {
+        println!("failed to write metrics to {}: {}", path.display(), err);
+    }
+}
@@ -1,44 +1,173 @@
+pub mod asset;
 pub mod context;
+pub mod metrics;
 
-use anchor_lang::{self, AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_lang::{self, AccountDeserialize, AnchorDeserialize, InstructionData, ToAccountMetas};
 use bytemuck::{try_from_bytes, PodCastError};
+use futures::{
+    channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
+    future::join_all,
+};
 use mpl_bubblegum::{
-    hash_creators, hash_metadata,
-    state::{metaplex_adapter::MetadataArgs, TreeConfig},
+    client::{
+        holder_distribution, list_assets, metas::normalize_account_metas, mirror::TreeMirror,
+        parse_logs, resolve_owner, AssetSummary, BubblegumOp, HolderDistribution, ResolvedOwner,
+    },
+    error::BubblegumError,
+    hash::hash_metadata_args,
+    hash_creators, hash_metadata, pda,
+    state::{
+        metaplex_adapter::MetadataArgs, AssetReceipt, BatchTransfer, BurnReceipt, CreatorTransfer,
+        DelegateExpiry, DenyList, FeatureFlags, HashedMetadataArgs, LoggingMode, MetadataProposal,
+        OnChainMetadata, TreeConfig, TreeDelegateRecord, TreeMetadata,
+        ON_CHAIN_METADATA_HEADER_SIZE,
+    },
+    utils::get_asset_id,
+    InstructionName,
 };
 use solana_program::{
-    instruction::Instruction, pubkey::Pubkey, rent::Rent, system_instruction, system_program,
+    instruction::Instruction, keccak, pubkey::Pubkey, rent::Rent, system_instruction,
+    system_program,
 };
 use solana_program_test::{BanksClient, BanksClientError, ProgramTest};
 use solana_sdk::{
     account::Account,
-    instruction::AccountMeta,
+    bpf_loader_upgradeable,
+    instruction::{AccountMeta, InstructionError},
     signature::{Keypair, Signer, SignerError},
     signer::signers::Signers,
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
-use spl_account_compression::state::ConcurrentMerkleTreeHeader;
+use spl_account_compression::{state::ConcurrentMerkleTreeHeader, Node};
 use spl_concurrent_merkle_tree::concurrent_merkle_tree::ConcurrentMerkleTree;
 use std::{
-    cell::{RefCell, RefMut},
     convert::TryFrom,
+    fmt::{Debug, Display},
     mem::size_of,
+    ops::Range,
     result,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
 };
 
+use asset::AssetHandle;
+
 #[derive(Debug)]
 pub enum Error {
+    // Invalid account substitutions that an instruction went through with (see
+    // `TxBuilder::assert_account_validation`).
+    AcceptedInvalidAccounts(Vec<String>),
     AccountNotFound(Pubkey),
     Anchor(anchor_lang::error::Error),
     BanksClient(BanksClientError),
     BytemuckPod(PodCastError),
+    // The on-chain state of a tree differs from what the harness expects; holds the list of
+    // mismatched fields.
+    InconsistentTreeState(Vec<String>),
+    InvalidWarpSlot(u64),
+    // A transaction to replay could not be decoded.
+    InvalidTransaction(String),
+    // Accounts that were expected to be closed, but still hold lamports or data.
+    LeakedAccounts(Vec<Pubkey>),
+    Io(std::io::Error),
     Signer(SignerError),
+    // A transaction sent by a builder failed (see `TransactionFailure`).
+    Transaction(Box<TransactionFailure>),
+}
+
+// Everything needed to figure out why a transaction failed without running it again.
+#[derive(Debug)]
+pub struct TransactionFailure {
+    pub error: TransactionError,
+    pub logs: Vec<String>,
+    // Debug output of the instructions in the transaction, in order.
+    pub instructions: Vec<String>,
+}
+
+impl TransactionFailure {
+    // The position of the instruction that failed, if the error is specific to one.
+    pub fn instruction_index(&self) -> Option<u8> {
+        match self.error {
+            TransactionError::InstructionError(index, _) => Some(index),
+            _ => None,
+        }
+    }
+}
+
+impl Display for TransactionFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.instruction_index() {
+            Some(index) => writeln!(f, "instruction {} failed: {}", index, self.error)?,
+            None => writeln!(f, "transaction failed: {}", self.error)?,
+        }
+        writeln!(f, "instructions:")?;
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            writeln!(f, "  {}: {}", index, instruction)?;
+        }
+        writeln!(f, "logs:")?;
+        for log in self.logs.iter() {
+            writeln!(f, "  {}", log)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::AcceptedInvalidAccounts(accounts) => {
+                write!(f, "invalid accounts accepted: {}", accounts.join(", "))
+            }
+            Error::AccountNotFound(address) => write!(f, "account {} not found", address),
+            Error::Anchor(err) => write!(f, "{}", err),
+            Error::BanksClient(err) => write!(f, "{}", err),
+            Error::BytemuckPod(err) => write!(f, "{:?}", err),
+            Error::InconsistentTreeState(fields) => {
+                write!(f, "inconsistent tree state: {}", fields.join(", "))
+            }
+            Error::InvalidWarpSlot(slot) => write!(f, "cannot warp to slot {}", slot),
+            Error::InvalidTransaction(reason) => write!(f, "invalid transaction: {}", reason),
+            Error::LeakedAccounts(addresses) => write!(f, "accounts not closed: {:?}", addresses),
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Signer(err) => write!(f, "{}", err),
+            Error::Transaction(failure) => write!(f, "{}", failure),
+        }
+    }
 }
 
 pub type Result<T> = result::Result<T, Error>;
 
-pub fn program_test() -> ProgramTest {
+impl Error {
+    // Whether the error is the result of a Bubblegum instruction failing with `expected`.
+    pub fn is_bubblegum_error(&self, expected: BubblegumError) -> bool {
+        self.is_custom_error(u32::from(expected))
+    }
+
+    // Whether the error is the result of an instruction failing with the custom error `expected`,
+    // i.e. for programs other than Bubblegum.
+    pub fn is_custom_error(&self, expected: u32) -> bool {
+        let error = match self {
+            Error::BanksClient(BanksClientError::TransactionError(error)) => error,
+            Error::Transaction(failure) => &failure.error,
+            _ => return false,
+        };
+        matches!(
+            error,
+            TransactionError::InstructionError(_, InstructionError::Custom(code))
+                if *code == expected
+        )
+    }
+}
+
+// Other programs (i.e. ones that call into Bubblegum) can be loaded alongside Bubblegum and the
+// programs it depends on by passing their names and ids as `programs`.
+pub fn program_test(programs: &[(&str, Pubkey)]) -> ProgramTest {
     let mut test = ProgramTest::new("mpl_bubblegum", mpl_bubblegum::id(), None);
+    for (name, id) in programs {
+        test.add_program(name, *id, None);
+    }
     test.add_program(
         "WRAPYChf58WFCnyjXKJHtrPgzKXgHp6MD9aVDqJBbGh",
         spl_noop::id(),
@@ -49,17 +178,154 @@ pub fn program_test() -> ProgramTest {
         spl_account_compression::id(),
         None,
     );
+    test.add_program(
+        "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+        mpl_token_metadata::id(),
+        None,
+    );
     test.set_compute_max_units(u64::MAX);
     test
 }
 
-fn instruction<T, U>(accounts: &T, data: &U) -> Instruction
+// Executes a transaction captured elsewhere (i.e. from mainnet, via an explorer), given in the
+// base64 encoded wire format, against a bank that only holds the programs from `program_test`
+// and `fixture_accounts`. Since the original blockhash is unknown to the bank, it gets
+// replaced with a recent one. This invalidates the signatures, but the bank doesn't verify
+// them, so the transaction can still be executed without the keys of the signers. Returns
+// the logs of the transaction.
+pub async fn replay_transaction(
+    fixture_accounts: &[(Pubkey, Account)],
+    raw_tx_base64: &str,
+) -> Result<Vec<String>> {
+    let bytes = base64::decode(raw_tx_base64.trim())
+        .map_err(|err| Error::InvalidTransaction(err.to_string()))?;
+    let mut tx: Transaction =
+        bincode::deserialize(&bytes).map_err(|err| Error::InvalidTransaction(err.to_string()))?;
+
+    let mut test = program_test(&[]);
+    for (address, account) in fixture_accounts {
+        test.add_account(*address, account.clone());
+    }
+    let (mut client, _payer, recent_blockhash) = test.start().await;
+    tx.message.recent_blockhash = recent_blockhash;
+
+    let instructions = tx
+        .message
+        .instructions
+        .iter()
+        .map(|ix| format!("{:?}", ix))
+        .collect();
+    let outcome = client
+        .process_transaction_with_metadata(tx)
+        .await
+        .map_err(Error::BanksClient)?;
+    let logs = outcome
+        .metadata
+        .map(|metadata| metadata.log_messages)
+        .unwrap_or_default();
+
+    match outcome.result {
+        Ok(()) => Ok(logs),
+        Err(error) => Err(Error::Transaction(Box::new(TransactionFailure {
+            error,
+            logs,
+            instructions,
+        }))),
+    }
+}
+
+// A top level field of a decoded account that differs between two snapshots of the account
+// (see `diff_accounts`). The values are rendered with `Debug`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FieldDiff {
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+impl Display for FieldDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} -> {}", self.field, self.before, self.after)
+    }
+}
+
+// Splits the pretty printed `Debug` output of a struct into its top level fields and their
+// values, which span multiple lines for nested types.
+fn debug_fields<T: Debug>(value: &T) -> Vec<(String, String)> {
+    let mut fields: Vec<(String, String)> = Vec::new();
+    // The first line holds the name of the struct, and the last one its closing brace.
+    for line in format!("{:#?}", value).lines().skip(1) {
+        let is_top_level = line.starts_with("    ") && !line.starts_with("     ");
+        match line.trim().split_once(": ") {
+            Some((field, value)) if is_top_level => {
+                fields.push((field.to_owned(), value.trim_end_matches(',').to_owned()))
+            }
+            _ if line == "}" => break,
+            _ => {
+                if let Some((_, value)) = fields.last_mut() {
+                    value.push('\n');
+                    value.push_str(line.trim_end_matches(','));
+                }
+            }
+        }
+    }
+    fields
+}
+
+// Returns the top level fields that differ between two values of the same struct.
+pub fn diff_values<T: Debug>(before: &T, after: &T) -> Vec<FieldDiff> {
+    debug_fields(before)
+        .into_iter()
+        .zip(debug_fields(after))
+        .filter(|(before, after)| before != after)
+        .map(|((field, before), (_, after))| FieldDiff {
+            field,
+            before,
+            after,
+        })
+        .collect()
+}
+
+// Decodes both snapshots of an account as `T`, and returns the fields that changed, which
+// makes for readable "nothing else changed" assertions.
+pub fn diff_accounts<T>(before: &Account, after: &Account) -> Result<Vec<FieldDiff>>
+where
+    T: AccountDeserialize + Debug,
+{
+    let before = T::try_deserialize(&mut before.data.as_slice()).map_err(Error::Anchor)?;
+    let after = T::try_deserialize(&mut after.data.as_slice()).map_err(Error::Anchor)?;
+    Ok(diff_values(&before, &after))
+}
+
+// Asserts that exactly the listed fields differ between two values of the same struct, i.e.
+// `assert_only_changed!(before, after, [tree_delegate])` for two decoded `TreeConfig`s.
+#[macro_export]
+macro_rules! assert_only_changed {
+    ($before:expr, $after:expr, [$($field:ident),* $(,)?]) => {{
+        let diff = $crate::utils::diff_values(&$before, &$after);
+        let mut changed: Vec<&str> = diff.iter().map(|d| d.field.as_str()).collect();
+        let mut expected: Vec<&str> = vec![$(stringify!($field)),*];
+        changed.sort_unstable();
+        expected.sort_unstable();
+        assert!(
+            changed == expected,
+            "expected only {:?} to change, but the diff is:\n{}",
+            expected,
+            diff.iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }};
+}
+
+fn instruction<T, U>(program_id: Pubkey, accounts: &T, data: &U) -> Instruction
 where
     T: ToAccountMetas,
     U: InstructionData,
 {
     Instruction {
-        program_id: mpl_bubblegum::id(),
+        program_id,
         accounts: accounts.to_account_metas(None),
         data: data.data(),
     }
@@ -95,19 +361,50 @@ pub struct TxBuilder<T, U> {
     // accounts as well if necessary.
     pub accounts: T,
     pub additional_accounts: Vec<AccountMeta>,
+    // The program that gets invoked, which is Bubblegum unless the builder was created for a
+    // program that calls into Bubblegum via CPI (see `Tree::external_leaf_tx`).
+    pub program_id: Pubkey,
     // Similar to the above, but for instruction data.
     pub data: U,
     // The currently configured payer for the tx.
     pub payer: Pubkey,
-    // Using `RefCell` to provide interior mutability and circumvent some
-    // annoyance with the borrow checker (i.e. provide helper methods that
-    // only need &self, vs &mut self); if we'll ever need to use this
-    // in a context with multiple threads, we can just replace the wrapper
-    // with a `Mutex`.
-    client: RefCell<BanksClient>,
+    // `BanksClient` is a cheap handle to the bank that can be cloned, so every request uses
+    // its own copy. This way helper methods only need &self, and multiple transactions
+    // can be in flight at the same time (i.e. when joining futures).
+    client: BanksClient,
     // Currently configured signers for the tx. Using only `Keypair`s as
     // signers for now; can make this more generic if needed.
     signers: Vec<Keypair>,
+    // Off-chain record of the tree, updated based on the logs of successful executions,
+    // which is shared with the `Tree` that created the builder.
+    replay: Arc<Mutex<Replay>>,
+    // Whether to check that the on-chain state of the tree matches the replay after every
+    // successful execution. Enabled by default.
+    check_consistency: bool,
+    // Whether to add a noop instruction with unique data to the tx, so that executing the
+    // same instruction more than once never results in identical transactions, which the
+    // bank would reject as duplicates. Disabled by default.
+    unique: bool,
+    // Instructions sent in the same transaction, after the one built by the builder, i.e. the
+    // payment for an asset that gets transferred. They succeed or fail together.
+    companion_instructions: Vec<Instruction>,
+}
+
+// Source of the data for the noop instructions added to unique transactions.
+static UNIQUE_TX_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+// A change to a single account of an instruction, which the program is expected to reject
+// (see `TxBuilder::assert_account_validation`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountMutation {
+    // Replaces the account with a random address.
+    RandomKey,
+    // Replaces the account with the Bubblegum PDA that has the original address as its only
+    // seed, i.e. the tree authority in place of the merkle tree.
+    WrongPda,
+    // Passes a writable account as read-only. The opposite is always allowed by the runtime,
+    // so it's not checked.
+    ReadOnly,
 }
 
 impl<T, U> TxBuilder<T, U>
@@ -115,33 +412,166 @@ where
     T: ToAccountMetas,
     U: InstructionData,
 {
-    fn client(&self) -> RefMut<BanksClient> {
-        self.client.borrow_mut()
+    fn client(&self) -> BanksClient {
+        self.client.clone()
     }
 
     pub async fn execute(&self) -> Result<()> {
+        self.process().await.map(|_| ())
+    }
+
+    // Executes the transaction and returns its log messages.
+    pub async fn execute_with_logs(&self) -> Result<Vec<String>> {
+        self.process().await
+    }
+
+    // Executes the transaction and decodes the return data set by the Bubblegum instruction.
+    pub async fn execute_with_return<R: AnchorDeserialize>(&self) -> Result<Option<R>> {
+        let ops = parse_logs(&self.process().await?);
+        match ops.last().and_then(|op| op.return_data.as_ref()) {
+            Some(data) => R::try_from_slice(data).map(Some).map_err(Error::Io),
+            None => Ok(None),
+        }
+    }
+
+    // Executes the instruction once for every mutation of every account (see
+    // `AccountMutation`), and fails with the ones that went through. Signers are left alone,
+    // since replacing them only yields transactions that can't be signed, and so are the
+    // accounts in `free`, which the instruction accepts any value for (i.e. a new owner).
+    // Mutations that unexpectedly succeed do change the state of the bank.
+    pub async fn assert_account_validation(&self, free: &[Pubkey]) -> Result<()> {
+        let ix = self.instruction();
+        let accounts = self.accounts.to_account_metas(None);
+        let mut accepted = Vec::new();
+
+        for (index, meta) in accounts.iter().enumerate() {
+            if meta.is_signer || free.contains(&meta.pubkey) {
+                continue;
+            }
+
+            for mutation in [
+                AccountMutation::RandomKey,
+                AccountMutation::WrongPda,
+                AccountMutation::ReadOnly,
+            ] {
+                let mut mutated = ix.clone();
+                let target = &mut mutated.accounts[index];
+                match mutation {
+                    AccountMutation::RandomKey => target.pubkey = Pubkey::new_unique(),
+                    AccountMutation::WrongPda => {
+                        target.pubkey = Pubkey::find_program_address(
+                            &[meta.pubkey.as_ref()],
+                            &mpl_bubblegum::id(),
+                        )
+                        .0
+                    }
+                    AccountMutation::ReadOnly if meta.is_writable => target.is_writable = false,
+                    AccountMutation::ReadOnly => continue,
+                }
+
+                if self.process_instruction(mutated).await.is_ok() {
+                    accepted.push(format!("{} ({:?})", meta.pubkey, mutation));
+                }
+            }
+        }
+
+        if accepted.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::AcceptedInvalidAccounts(accepted))
+        }
+    }
+
+    // Executes the transaction with `signer` marked as a regular account in the instruction,
+    // which is otherwise the only way for a transaction to go without a required signature.
+    // The signers of the builder must not include it.
+    pub async fn execute_without_signer(&self, signer: Pubkey) -> Result<()> {
+        let mut ix = self.instruction();
+        for meta in ix.accounts.iter_mut().filter(|meta| meta.pubkey == signer) {
+            meta.is_signer = false;
+        }
+        self.process_instruction(ix).await.map(|_| ())
+    }
+
+    // Executes the transaction and records the Bubblegum operations parsed from its logs,
+    // which are then returned.
+    async fn process(&self) -> Result<Vec<String>> {
+        self.process_instruction(self.instruction()).await
+    }
+
+    // Same as `process`, but for an instruction that was built from the builder and then
+    // modified.
+    async fn process_instruction(&self, ix: Instruction) -> Result<Vec<String>> {
         let recent_blockhash = self
             .client()
             .get_latest_blockhash()
             .await
             .map_err(Error::BanksClient)?;
 
-        let mut ix = instruction(&self.accounts, &self.data);
-
-        // Add the additional accounts metas (if any) as well.
-        ix.accounts.append(&mut self.additional_accounts.clone());
-
-        let mut tx = Transaction::new_with_payer(&[ix], Some(&self.payer));
+        let mut instructions = vec![ix];
+        instructions.extend(self.companion_instructions.iter().cloned());
+        if self.unique {
+            let counter = UNIQUE_TX_COUNTER.fetch_add(1, Ordering::Relaxed);
+            instructions.push(spl_noop::instruction(counter.to_le_bytes().to_vec()));
+        }
+        let mut tx = Transaction::new_with_payer(&instructions, Some(&self.payer));
+
+        let payer_balance = match metrics::metrics_path() {
+            Some(_) => Some(
+                self.client()
+                    .get_balance(self.payer)
+                    .await
+                    .map_err(Error::BanksClient)?,
+            ),
+            None => None,
+        };
 
         // Using `try_partial_sign` to avoid panics (and get an error when something is
         // wrong instead) no matter what signers are configured.
         tx.try_partial_sign(&self.signers.iter().collect::<Vec<_>>(), recent_blockhash)
             .map_err(Error::Signer)?;
 
-        self.client()
-            .process_transaction(tx)
+        let outcome = self
+            .client()
+            .process_transaction_with_metadata(tx)
             .await
-            .map_err(Error::BanksClient)
+            .map_err(Error::BanksClient)?;
+
+        let logs = outcome
+            .metadata
+            .map(|metadata| metadata.log_messages)
+            .unwrap_or_default();
+
+        if let Err(error) = outcome.result {
+            return Err(Error::Transaction(Box::new(TransactionFailure {
+                error,
+                logs,
+                instructions: instructions.iter().map(|ix| format!("{:?}", ix)).collect(),
+            })));
+        }
+        let ops = parse_logs(&logs);
+        if let Some(before) = payer_balance {
+            let after = self
+                .client()
+                .get_balance(self.payer)
+                .await
+                .map_err(Error::BanksClient)?;
+            metrics::record(metrics::TxMetrics {
+                operations: ops
+                    .iter()
+                    .map(|op| format!("{:?}", op.instruction))
+                    .collect(),
+                compute_units: metrics::compute_units(&logs, &self.program_id.to_string()),
+                lamports_spent: before as i64 - after as i64,
+            });
+        }
+        self.replay.lock().unwrap().record(ops);
+
+        if self.check_consistency {
+            check_consistency(&mut self.client(), &self.replay).await?;
+        }
+
+        Ok(logs)
     }
 
     // Returning `&mut Self` to allow method chaining.
@@ -150,6 +580,43 @@ where
         self
     }
 
+    // Returns the instruction that gets executed by the builder, i.e. for sending it as part
+    // of a different transaction. Duplicate metas are normalized (see `normalize_account_metas`),
+    // so the compiled message is the same regardless of where the duplicates come from.
+    pub fn instruction(&self) -> Instruction {
+        let mut ix = instruction(self.program_id, &self.accounts, &self.data);
+
+        // Add the additional accounts metas (if any) as well.
+        ix.accounts.append(&mut self.additional_accounts.clone());
+        normalize_account_metas(&mut ix.accounts);
+
+        ix
+    }
+
+    // Allows tests that deliberately leave the tree in an unexpected state to opt out of the
+    // consistency check.
+    pub fn set_check_consistency(&mut self, check: bool) -> &mut Self {
+        self.check_consistency = check;
+        self
+    }
+
+    // The signers of companion instructions usually have to be added as well (see
+    // `add_signer`).
+    pub fn add_companion_instruction(&mut self, ix: Instruction) -> &mut Self {
+        self.companion_instructions.push(ix);
+        self
+    }
+
+    pub fn add_signer(&mut self, signer: &Keypair) -> &mut Self {
+        self.signers.push(clone_keypair(signer));
+        self
+    }
+
+    pub fn set_unique(&mut self, unique: bool) -> &mut Self {
+        self.unique = unique;
+        self
+    }
+
     pub fn set_payer(&mut self, key: Pubkey) -> &mut Self {
         self.payer = key;
         self
@@ -180,19 +647,203 @@ pub type CreateBuilder =
 pub type MintV1Builder =
     TxBuilder<mpl_bubblegum::accounts::MintV1, mpl_bubblegum::instruction::MintV1>;
 
+pub type MintV1WithReceiptBuilder = TxBuilder<
+    mpl_bubblegum::accounts::MintV1WithReceipt,
+    mpl_bubblegum::instruction::MintV1WithReceipt,
+>;
+
+pub type CloseAssetReceiptBuilder = TxBuilder<
+    mpl_bubblegum::accounts::CloseAssetReceipt,
+    mpl_bubblegum::instruction::CloseAssetReceipt,
+>;
+
+pub type MintAirdropBuilder =
+    TxBuilder<mpl_bubblegum::accounts::MintAirdrop, mpl_bubblegum::instruction::MintAirdrop>;
+
+pub type TransferBatchBuilder =
+    TxBuilder<mpl_bubblegum::accounts::TransferBatch, mpl_bubblegum::instruction::TransferBatch>;
+
+pub type MintBatchV1Builder =
+    TxBuilder<mpl_bubblegum::accounts::MintV1, mpl_bubblegum::instruction::MintBatchV1>;
+
+pub type MintToCollectionV1Builder = TxBuilder<
+    mpl_bubblegum::accounts::MintToCollectionV1,
+    mpl_bubblegum::instruction::MintToCollectionV1,
+>;
+
+pub type MintV1HashedBuilder =
+    TxBuilder<mpl_bubblegum::accounts::MintV1, mpl_bubblegum::instruction::MintV1Hashed>;
+
+pub type SetTreeHashOnlyBuilder = TxBuilder<
+    mpl_bubblegum::accounts::SetTreeHashOnly,
+    mpl_bubblegum::instruction::SetTreeHashOnly,
+>;
+
+pub type SetLoggingModeBuilder =
+    TxBuilder<mpl_bubblegum::accounts::SetLoggingMode, mpl_bubblegum::instruction::SetLoggingMode>;
+
+pub type SetTreeNonTransferableBuilder = TxBuilder<
+    mpl_bubblegum::accounts::SetTreeNonTransferable,
+    mpl_bubblegum::instruction::SetTreeNonTransferable,
+>;
+
+pub type SetTreePublicBuilder =
+    TxBuilder<mpl_bubblegum::accounts::SetTreePublic, mpl_bubblegum::instruction::SetTreePublic>;
+
+pub type SetTreePausedBuilder =
+    TxBuilder<mpl_bubblegum::accounts::SetTreePaused, mpl_bubblegum::instruction::SetTreePaused>;
+
+pub type SetMintPriceBuilder =
+    TxBuilder<mpl_bubblegum::accounts::SetMintPrice, mpl_bubblegum::instruction::SetMintPrice>;
+
+pub type CreateDenyListBuilder =
+    TxBuilder<mpl_bubblegum::accounts::CreateDenyList, mpl_bubblegum::instruction::CreateDenyList>;
+
+pub type UpdateDenyListBuilder =
+    TxBuilder<mpl_bubblegum::accounts::UpdateDenyList, mpl_bubblegum::instruction::UpdateDenyList>;
+
+pub type SetTreeDenyListBuilder = TxBuilder<
+    mpl_bubblegum::accounts::SetTreeDenyList,
+    mpl_bubblegum::instruction::SetTreeDenyList,
+>;
+
+pub type AddTreeDelegateBuilder = TxBuilder<
+    mpl_bubblegum::accounts::AddTreeDelegate,
+    mpl_bubblegum::instruction::AddTreeDelegate,
+>;
+
+pub type RemoveTreeDelegateBuilder = TxBuilder<
+    mpl_bubblegum::accounts::RemoveTreeDelegate,
+    mpl_bubblegum::instruction::RemoveTreeDelegate,
+>;
+
+pub type SetSoulboundOverrideBuilder = TxBuilder<
+    mpl_bubblegum::accounts::SetSoulboundOverride,
+    mpl_bubblegum::instruction::SetSoulboundOverride,
+>;
+
+pub type WithdrawFromTreeAuthorityBuilder = TxBuilder<
+    mpl_bubblegum::accounts::WithdrawFromTreeAuthority,
+    mpl_bubblegum::instruction::WithdrawFromTreeAuthority,
+>;
+
 pub type BurnBuilder = TxBuilder<mpl_bubblegum::accounts::Burn, mpl_bubblegum::instruction::Burn>;
 
+pub type BurnWithReceiptBuilder = TxBuilder<
+    mpl_bubblegum::accounts::BurnWithReceipt,
+    mpl_bubblegum::instruction::BurnWithReceipt,
+>;
+
+pub type CloseBurnReceiptBuilder = TxBuilder<
+    mpl_bubblegum::accounts::CloseBurnReceipt,
+    mpl_bubblegum::instruction::CloseBurnReceipt,
+>;
+
+pub type CloseDelegateExpiryBuilder = TxBuilder<
+    mpl_bubblegum::accounts::CloseDelegateExpiry,
+    mpl_bubblegum::instruction::CloseDelegateExpiry,
+>;
+
+pub type RedeemBuilder =
+    TxBuilder<mpl_bubblegum::accounts::Redeem, mpl_bubblegum::instruction::Redeem>;
+
+pub type CancelRedeemBuilder =
+    TxBuilder<mpl_bubblegum::accounts::CancelRedeem, mpl_bubblegum::instruction::CancelRedeem>;
+
+pub type TransferTimelockedBuilder = TxBuilder<
+    mpl_bubblegum::accounts::TransferTimelocked,
+    mpl_bubblegum::instruction::TransferTimelocked,
+>;
+
+pub type ClaimTimelockedTransferBuilder = TxBuilder<
+    mpl_bubblegum::accounts::ClaimTimelockedTransfer,
+    mpl_bubblegum::instruction::ClaimTimelockedTransfer,
+>;
+
+pub type CancelTimelockedTransferBuilder = TxBuilder<
+    mpl_bubblegum::accounts::CancelTimelockedTransfer,
+    mpl_bubblegum::instruction::CancelTimelockedTransfer,
+>;
+
 pub type TransferBuilder =
     TxBuilder<mpl_bubblegum::accounts::Transfer, mpl_bubblegum::instruction::Transfer>;
 
 pub type DelegateBuilder =
     TxBuilder<mpl_bubblegum::accounts::Delegate, mpl_bubblegum::instruction::Delegate>;
 
+pub type DelegateWithExpiryBuilder = TxBuilder<
+    mpl_bubblegum::accounts::DelegateWithExpiry,
+    mpl_bubblegum::instruction::DelegateWithExpiry,
+>;
+
+pub type FreezeBuilder =
+    TxBuilder<mpl_bubblegum::accounts::Freeze, mpl_bubblegum::instruction::Freeze>;
+
+pub type ThawBuilder = TxBuilder<mpl_bubblegum::accounts::Thaw, mpl_bubblegum::instruction::Thaw>;
+
+pub type InitOnChainMetadataBuilder = TxBuilder<
+    mpl_bubblegum::accounts::InitOnChainMetadata,
+    mpl_bubblegum::instruction::InitOnChainMetadata,
+>;
+
+pub type WriteMetadataChunkBuilder = TxBuilder<
+    mpl_bubblegum::accounts::WriteMetadataChunk,
+    mpl_bubblegum::instruction::WriteMetadataChunk,
+>;
+
 pub type SetTreeDelegateBuilder = TxBuilder<
     mpl_bubblegum::accounts::SetTreeDelegate,
     mpl_bubblegum::instruction::SetTreeDelegate,
 >;
 
+pub type ProposeTreeCreatorBuilder = TxBuilder<
+    mpl_bubblegum::accounts::ProposeTreeCreator,
+    mpl_bubblegum::instruction::ProposeTreeCreator,
+>;
+
+pub type AcceptTreeCreatorBuilder = TxBuilder<
+    mpl_bubblegum::accounts::AcceptTreeCreator,
+    mpl_bubblegum::instruction::AcceptTreeCreator,
+>;
+
+pub type CancelTreeCreatorTransferBuilder = TxBuilder<
+    mpl_bubblegum::accounts::CancelTreeCreatorTransfer,
+    mpl_bubblegum::instruction::CancelTreeCreatorTransfer,
+>;
+
+pub type UpdateMetadataBuilder =
+    TxBuilder<mpl_bubblegum::accounts::UpdateMetadata, mpl_bubblegum::instruction::UpdateMetadata>;
+
+pub type ProposeMetadataUpdateBuilder = TxBuilder<
+    mpl_bubblegum::accounts::ProposeMetadataUpdate,
+    mpl_bubblegum::instruction::ProposeMetadataUpdate,
+>;
+
+pub type ApproveMetadataUpdateBuilder = TxBuilder<
+    mpl_bubblegum::accounts::ApproveMetadataUpdate,
+    mpl_bubblegum::instruction::ApproveMetadataUpdate,
+>;
+
+pub type CancelMetadataProposalBuilder = TxBuilder<
+    mpl_bubblegum::accounts::CancelMetadataProposal,
+    mpl_bubblegum::instruction::CancelMetadataProposal,
+>;
+
+pub type SetFeatureFlagsBuilder = TxBuilder<
+    mpl_bubblegum::accounts::SetFeatureFlags,
+    mpl_bubblegum::instruction::SetFeatureFlags,
+>;
+
+pub type SetTreeMetadataBuilder = TxBuilder<
+    mpl_bubblegum::accounts::SetTreeMetadata,
+    mpl_bubblegum::instruction::SetTreeMetadata,
+>;
+
+pub type VerifyMetadataHashBuilder = TxBuilder<
+    mpl_bubblegum::accounts::VerifyMetadataHash,
+    mpl_bubblegum::instruction::VerifyMetadataHash,
+>;
+
 pub type VerifyCreatorBuilder = TxBuilder<
     mpl_bubblegum::accounts::CreatorVerification,
     mpl_bubblegum::instruction::VerifyCreator,
@@ -203,12 +854,185 @@ pub type UnverifyCreatorBuilder = TxBuilder<
     mpl_bubblegum::instruction::UnverifyCreator,
 >;
 
+// The parts of the on-chain state of a tree (and its config) that can be predicted based on
+// the operations observed by the harness.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TreeState {
+    pub root: [u8; 32],
+    pub rightmost_index: u32,
+    pub sequence_number: u64,
+    pub num_minted: u64,
+}
+
+impl TreeState {
+    // Describes the fields which differ between `self` (the on-chain state) and `expected`.
+    fn diff(&self, expected: &TreeState) -> Vec<String> {
+        let mut diff = Vec::new();
+        let mut check = |field: &str, actual: String, expected: String| {
+            if actual != expected {
+                diff.push(format!(
+                    "{}: on-chain {} != mirror {}",
+                    field, actual, expected
+                ));
+            }
+        };
+
+        check(
+            "root",
+            Pubkey::new_from_array(self.root).to_string(),
+            Pubkey::new_from_array(expected.root).to_string(),
+        );
+        check(
+            "rightmost_index",
+            self.rightmost_index.to_string(),
+            expected.rightmost_index.to_string(),
+        );
+        check(
+            "sequence_number",
+            self.sequence_number.to_string(),
+            expected.sequence_number.to_string(),
+        );
+        check(
+            "num_minted",
+            self.num_minted.to_string(),
+            expected.num_minted.to_string(),
+        );
+
+        diff
+    }
+}
+
+// The `FeatureFlags` PDA, which is shared by every tree of the deployment.
+pub fn feature_flags() -> Pubkey {
+    pda::find_feature_flags()
+}
+
+// Decodes the tree related fields of `TreeState` from the data of a tree account.
+type TreeDecoder = fn(&[u8]) -> Result<TreeState>;
+
+fn decode_tree<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize>(
+    data: &[u8],
+) -> Result<TreeState> {
+    let (_header_bytes, rest) = data.split_at(size_of::<ConcurrentMerkleTreeHeader>());
+
+    let merkle_tree_size = size_of::<ConcurrentMerkleTree<MAX_DEPTH, MAX_BUFFER_SIZE>>();
+    let tree_bytes = &rest[..merkle_tree_size];
+
+    let tree = try_from_bytes::<ConcurrentMerkleTree<MAX_DEPTH, MAX_BUFFER_SIZE>>(tree_bytes)
+        .map_err(Error::BytemuckPod)?;
+
+    Ok(TreeState {
+        root: tree.change_logs[tree.active_index as usize].root,
+        rightmost_index: tree.rightmost_proof.index,
+        sequence_number: tree.sequence_number,
+        num_minted: 0,
+    })
+}
+
+// Everything the harness observed about a tree, based on the events of the transactions
+// executed via its builders.
+pub struct Replay {
+    pub ops: Vec<BubblegumOp>,
+    pub mirror: TreeMirror,
+    // Number of operations that changed a leaf of the tree, and how many of them were mints.
+    pub num_changes: u64,
+    pub num_mints: u64,
+    decoder: TreeDecoder,
+    // Receive every operation recorded after they subscribed (see `Tree::event_stream`).
+    subscribers: Vec<UnboundedSender<BubblegumOp>>,
+}
+
+impl Replay {
+    fn new(tree_id: Pubkey, max_depth: usize, decoder: TreeDecoder) -> Self {
+        Replay {
+            ops: Vec::new(),
+            mirror: TreeMirror::new(tree_id, max_depth),
+            num_changes: 0,
+            num_mints: 0,
+            decoder,
+            subscribers: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, ops: Vec<BubblegumOp>) {
+        for op in ops.iter() {
+            let changed = self.mirror.apply(op) as u64;
+            self.num_changes += changed;
+            if matches!(
+                op.instruction,
+                InstructionName::MintV1
+                    | InstructionName::MintV1Hashed
+                    | InstructionName::MintV1WithReceipt
+                    | InstructionName::MintToCollectionV1
+                    | InstructionName::MintBatchV1
+                    | InstructionName::MintAirdrop
+            ) {
+                self.num_mints += changed;
+            }
+        }
+        // Subscribers whose stream was dropped are forgotten.
+        self.subscribers.retain(|sender| {
+            ops.iter()
+                .all(|op| sender.unbounded_send(op.clone()).is_ok())
+        });
+        self.ops.extend(ops);
+    }
+
+    fn expected_state(&self) -> TreeState {
+        TreeState {
+            root: self.mirror.root(),
+            rightmost_index: u32::try_from(self.mirror.num_leaves()).unwrap(),
+            sequence_number: self.num_changes,
+            num_minted: self.num_mints,
+        }
+    }
+}
+
+// Compares the on-chain state of the tree tracked by `replay` with the expected one.
+async fn check_consistency(client: &mut BanksClient, replay: &Mutex<Replay>) -> Result<()> {
+    let (tree_id, decoder, expected) = {
+        let replay = replay.lock().unwrap();
+        (
+            replay.mirror.tree_id(),
+            replay.decoder,
+            replay.expected_state(),
+        )
+    };
+    let authority = Pubkey::find_program_address(&[tree_id.as_ref()], &mpl_bubblegum::id()).0;
+
+    let tree_account = client
+        .get_account(tree_id)
+        .await
+        .map_err(Error::BanksClient)?
+        .ok_or(Error::AccountNotFound(tree_id))?;
+    let mut state = decoder(&tree_account.data)?;
+
+    let config_account = client
+        .get_account(authority)
+        .await
+        .map_err(Error::BanksClient)?
+        .ok_or(Error::AccountNotFound(authority))?;
+    state.num_minted = TreeConfig::try_deserialize(&mut config_account.data.as_slice())
+        .map_err(Error::Anchor)?
+        .num_minted;
+
+    let diff = state.diff(&expected);
+    if diff.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InconsistentTreeState(diff))
+    }
+}
+
 pub struct LeafArgs {
     pub owner: Keypair,
     pub delegate: Keypair,
     pub metadata: MetadataArgs,
+    // The leaf index is derived from the nonce (see `index`).
     pub nonce: u64,
-    pub index: u32,
+    // Derived from `metadata`, and kept in sync by `with_updated_metadata`.
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
 }
 
 impl Clone for LeafArgs {
@@ -218,7 +1042,8 @@ impl Clone for LeafArgs {
             delegate: clone_keypair(&self.delegate),
             metadata: self.metadata.clone(),
             nonce: self.nonce,
-            index: self.index,
+            data_hash: self.data_hash,
+            creator_hash: self.creator_hash,
         }
     }
 }
@@ -226,26 +1051,65 @@ impl Clone for LeafArgs {
 impl LeafArgs {
     // Creates a new object with some default values.
     pub fn new(owner: &Keypair, metadata: MetadataArgs) -> Self {
+        let (data_hash, creator_hash) = compute_metadata_hashes(&metadata).unwrap();
         LeafArgs {
             owner: clone_keypair(owner),
             delegate: clone_keypair(owner),
             metadata,
             nonce: 0,
-            index: 0,
+            data_hash,
+            creator_hash,
         }
     }
+
+    // Leaves are appended in nonce order, so the index of a leaf always equals its nonce.
+    pub fn index(&self) -> u32 {
+        u32::try_from(self.nonce).unwrap()
+    }
+
+    // Reflects a transfer, which also resets the delegate to the new owner.
+    pub fn with_new_owner(mut self, owner: &Keypair) -> Self {
+        self.owner = clone_keypair(owner);
+        self.delegate = clone_keypair(owner);
+        self
+    }
+
+    pub fn with_new_delegate(mut self, delegate: &Keypair) -> Self {
+        self.delegate = clone_keypair(delegate);
+        self
+    }
+
+    // Applies `f` to the metadata and recomputes the hashes derived from it.
+    pub fn with_updated_metadata<F: FnOnce(&mut MetadataArgs)>(mut self, f: F) -> Self {
+        f(&mut self.metadata);
+        let (data_hash, creator_hash) = compute_metadata_hashes(&self.metadata).unwrap();
+        self.data_hash = data_hash;
+        self.creator_hash = creator_hash;
+        self
+    }
 }
 
 // A convenience object that records some of the parameters for compressed
 // trees and generates TX builders with the default configuration for each
 // operation.
 // TODO: finish implementing all operations.
+// Proofs must have a node for every level below the canopy, so trees get a canopy that leaves
+// at most this many levels out by default, which keeps transactions that also carry metadata
+// (such as `update_metadata`) within the size limit.
+pub const DEFAULT_PROOF_LENGTH: usize = 6;
+
 pub struct Tree<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> {
     pub tree_creator: Keypair,
     pub tree_delegate: Keypair,
     pub merkle_tree: Keypair,
     pub canopy_depth: u32,
-    client: RefCell<BanksClient>,
+    // The deny list set via `set_tree_deny_list`, which gets passed to transfers.
+    pub deny_list: Pubkey,
+    // Whether `set_feature_flags` applied the flags to the tree, which then have to be passed to
+    // the instructions with a fixed account layout.
+    pub has_feature_flags: bool,
+    client: BanksClient,
+    replay: Arc<Mutex<Replay>>,
 }
 
 impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_BUFFER_SIZE> {
@@ -257,12 +1121,22 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_B
     }
 
     pub fn with_creator(tree_creator: &Keypair, client: BanksClient) -> Self {
+        let merkle_tree = Keypair::new();
+        let replay = Replay::new(
+            merkle_tree.pubkey(),
+            MAX_DEPTH,
+            decode_tree::<MAX_DEPTH, MAX_BUFFER_SIZE>,
+        );
+
         Tree {
             tree_creator: clone_keypair(tree_creator),
             tree_delegate: clone_keypair(tree_creator),
-            merkle_tree: Keypair::new(),
-            canopy_depth: 0,
-            client: RefCell::new(client),
+            merkle_tree,
+            canopy_depth: MAX_DEPTH.saturating_sub(DEFAULT_PROOF_LENGTH) as u32,
+            deny_list: Pubkey::default(),
+            has_feature_flags: false,
+            client,
+            replay: Arc::new(Mutex::new(replay)),
         }
     }
 
@@ -279,44 +1153,170 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_B
     }
 
     pub fn authority(&self) -> Pubkey {
-        Pubkey::find_program_address(&[self.tree_pubkey().as_ref()], &mpl_bubblegum::id()).0
+        pda::find_tree_config(&self.tree_pubkey())
     }
 
-    pub fn mint_authority_request(&self, authority: &Pubkey) -> Pubkey {
-        Pubkey::find_program_address(
-            &[self.tree_pubkey().as_ref(), authority.as_ref()],
-            &mpl_bubblegum::id(),
-        )
-        .0
+    pub fn asset_id(&self, nonce: u64) -> Pubkey {
+        get_asset_id(&self.tree_pubkey(), nonce)
     }
 
-    pub fn merkle_tree_account_size(&self) -> usize {
-        size_of::<ConcurrentMerkleTreeHeader>()
-            + size_of::<ConcurrentMerkleTree<MAX_DEPTH, MAX_BUFFER_SIZE>>()
+    pub fn tree_metadata(&self) -> Pubkey {
+        pda::find_tree_metadata(&self.tree_pubkey())
     }
 
-    pub fn client(&self) -> RefMut<BanksClient> {
-        self.client.borrow_mut()
+    pub fn asset(&self, leaf: &LeafArgs) -> AssetHandle<'_, MAX_DEPTH, MAX_BUFFER_SIZE> {
+        AssetHandle::new(self, leaf.clone())
     }
 
-    // Helper method to execute a transaction with the specified arguments
-    // (i.e. single instruction) via the inner Banks client.
-    pub async fn process_tx<T: Signers>(
+    // Mints the leaf with the tree delegate, and returns a handle to the new asset.
+    pub async fn mint_asset(
         &self,
-        instruction: Instruction,
-        payer: &Pubkey,
-        signing_keypairs: &T,
-    ) -> Result<()> {
-        let recent_blockhash = self
-            .client()
-            .get_latest_blockhash()
-            .await
-            .map_err(Error::BanksClient)?;
+        leaf: &LeafArgs,
+    ) -> Result<AssetHandle<'_, MAX_DEPTH, MAX_BUFFER_SIZE>> {
+        self.mint_v1(&self.tree_delegate, leaf).await?;
+        Ok(self.asset(leaf))
+    }
 
-        self.client()
-            .process_transaction(Transaction::new_signed_with_payer(
-                &[instruction],
-                Some(payer),
+    pub fn tree_deny_list(&self) -> Pubkey {
+        pda::find_tree_deny_list(&self.tree_pubkey())
+    }
+
+    pub fn mint_price(&self) -> Pubkey {
+        pda::find_mint_price(&self.tree_pubkey())
+    }
+
+    // The companion accounts (see `utils::take_companion_accounts`) of mints that pay the mint
+    // price of the tree, i.e. mints signed by someone other than the tree authority.
+    pub fn mint_price_companions(
+        &self,
+        treasury: Pubkey,
+        payer_tokens: Pubkey,
+        treasury_tokens: Pubkey,
+    ) -> Vec<AccountMeta> {
+        let mut companions: Vec<_> = self
+            .feature_flags_companions()
+            .into_iter()
+            .map(|pubkey| AccountMeta::new_readonly(pubkey, false))
+            .collect();
+        companions.extend([
+            AccountMeta::new_readonly(self.mint_price(), false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(payer_tokens, false),
+            AccountMeta::new(treasury_tokens, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ]);
+        companions
+    }
+
+    // The `FeatureFlags` companion account, once `set_feature_flags` applied them to the tree.
+    pub fn feature_flags_companions(&self) -> Vec<Pubkey> {
+        if self.has_feature_flags {
+            vec![feature_flags()]
+        } else {
+            Vec::new()
+        }
+    }
+
+    // The companion accounts of `transfer`, ahead of the proof.
+    pub fn transfer_companions(&self) -> Vec<Pubkey> {
+        let mut companions = self.feature_flags_companions();
+        companions.extend(self.deny_list_companions());
+        companions
+    }
+
+    // The deny list companion accounts of `transfer` and `redeem`, if the tree has a deny list.
+    pub fn deny_list_companions(&self) -> Vec<Pubkey> {
+        if self.deny_list == Pubkey::default() {
+            Vec::new()
+        } else {
+            vec![self.tree_deny_list(), self.deny_list]
+        }
+    }
+
+    pub fn tree_delegate_record(&self, delegate: &Pubkey) -> Pubkey {
+        pda::find_tree_delegate_record(&self.tree_pubkey(), delegate)
+    }
+
+    pub fn creator_transfer(&self) -> Pubkey {
+        pda::find_creator_transfer(&self.tree_pubkey())
+    }
+
+    pub fn asset_receipt(&self, nonce: u64) -> Pubkey {
+        pda::find_asset_receipt(&self.tree_pubkey(), nonce)
+    }
+
+    pub fn burn_receipt(&self, nonce: u64) -> Pubkey {
+        pda::find_burn_receipt(&self.tree_pubkey(), nonce)
+    }
+
+    pub fn voucher(&self, nonce: u64) -> Pubkey {
+        pda::find_voucher(&self.tree_pubkey(), nonce)
+    }
+
+    pub fn pending_transfer(&self, nonce: u64) -> Pubkey {
+        pda::find_pending_transfer(&self.tree_pubkey(), nonce)
+    }
+
+    pub fn leaf_freeze(&self, nonce: u64) -> Pubkey {
+        pda::find_leaf_freeze(&self.tree_pubkey(), nonce)
+    }
+
+    pub fn soulbound_override(&self, nonce: u64) -> Pubkey {
+        pda::find_soulbound_override(&self.tree_pubkey(), nonce)
+    }
+
+    pub fn metadata_proposal(&self, nonce: u64) -> Pubkey {
+        pda::find_metadata_proposal(&self.tree_pubkey(), nonce)
+    }
+
+    pub fn delegate_expiry(&self, nonce: u64) -> Pubkey {
+        pda::find_delegate_expiry(&self.tree_pubkey(), nonce)
+    }
+
+    pub fn on_chain_metadata(&self, nonce: u64) -> Pubkey {
+        pda::find_on_chain_metadata(&self.tree_pubkey(), nonce)
+    }
+
+    pub fn mint_authority_request(&self, authority: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[self.tree_pubkey().as_ref(), authority.as_ref()],
+            &mpl_bubblegum::id(),
+        )
+        .0
+    }
+
+    // The account holds the canopy after the tree itself, which consists of the
+    // `2^(canopy_depth + 1) - 2` nodes below the root.
+    pub fn merkle_tree_account_size(&self) -> usize {
+        let canopy_size = ((1usize << (self.canopy_depth + 1)) - 2) * size_of::<Node>();
+        size_of::<ConcurrentMerkleTreeHeader>()
+            + size_of::<ConcurrentMerkleTree<MAX_DEPTH, MAX_BUFFER_SIZE>>()
+            + canopy_size
+    }
+
+    pub fn client(&self) -> BanksClient {
+        self.client.clone()
+    }
+
+    // Helper method to execute a transaction with the specified arguments
+    // (i.e. single instruction) via the inner Banks client.
+    pub async fn process_tx<T: Signers>(
+        &self,
+        instruction: Instruction,
+        payer: &Pubkey,
+        signing_keypairs: &T,
+    ) -> Result<()> {
+        let recent_blockhash = self
+            .client()
+            .get_latest_blockhash()
+            .await
+            .map_err(Error::BanksClient)?;
+
+        self.client()
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[instruction],
+                Some(payer),
                 signing_keypairs,
                 recent_blockhash,
             ))
@@ -363,13 +1363,69 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_B
         TxBuilder {
             accounts,
             additional_accounts: Vec::new(),
+            program_id: mpl_bubblegum::id(),
             data,
             payer,
             client: self.client.clone(),
             signers: def_signers,
+            replay: self.replay.clone(),
+            check_consistency: true,
+            unique: false,
+            companion_instructions: Vec::new(),
         }
     }
 
+    // Same as `tx_builder`, but also populates the additional accounts with the proof
+    // for the leaf at `index`, as computed by the off-chain mirror.
+    fn leaf_tx_builder<T, U>(
+        &self,
+        accounts: T,
+        data: U,
+        index: u32,
+        payer: Pubkey,
+        default_signers: &[&Keypair],
+    ) -> TxBuilder<T, U> {
+        let mut builder = self.tx_builder(accounts, data, payer, default_signers);
+        builder.set_additional_accounts(&self.proof(index));
+        builder
+    }
+
+    // Returns a builder for an instruction of another program that changes a leaf of this tree
+    // via CPI. The proof for the leaf at `index` is appended to the accounts, and the
+    // operations performed by Bubblegum are recorded just like for the builders above.
+    pub fn external_leaf_tx<T, U>(
+        &self,
+        program_id: Pubkey,
+        accounts: T,
+        data: U,
+        index: u32,
+        payer: Pubkey,
+        default_signers: &[&Keypair],
+    ) -> TxBuilder<T, U> {
+        let mut builder = self.leaf_tx_builder(accounts, data, index, payer, default_signers);
+        builder.program_id = program_id;
+        builder
+    }
+
+    // Same as `external_leaf_tx`, for instructions of another program that transfer the leaf via
+    // CPI, which have to pass along the companion accounts of `transfer` ahead of the proof.
+    pub fn external_transfer_tx<T, U>(
+        &self,
+        program_id: Pubkey,
+        accounts: T,
+        data: U,
+        index: u32,
+        payer: Pubkey,
+        default_signers: &[&Keypair],
+    ) -> TxBuilder<T, U> {
+        let mut builder =
+            self.external_leaf_tx(program_id, accounts, data, index, payer, default_signers);
+        let mut remaining = self.transfer_companions();
+        remaining.extend(self.proof(index));
+        builder.set_additional_accounts(&remaining);
+        builder
+    }
+
     // The `operation_tx` method instantiate a default builder object for a
     // transaction that can be used to execute that particular operation (tree
     // create in this case). The object can be modified (i.e. to use a
@@ -391,6 +1447,9 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_B
         let data = mpl_bubblegum::instruction::CreateTree {
             max_depth: u32::try_from(MAX_DEPTH).unwrap(),
             max_buffer_size: u32::try_from(MAX_BUFFER_SIZE).unwrap(),
+            logging_mode: LoggingMode::default(),
+            creator_immutable: false,
+            is_public: false,
         };
 
         self.tx_builder(accounts, data, payer.pubkey(), &[payer])
@@ -412,18 +1471,21 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_B
             leaf_owner: args.owner.pubkey(),
             leaf_delegate: args.delegate.pubkey(),
             merkle_tree: self.tree_pubkey(),
+            tree_delegate_record: self.tree_delegate_record(&tree_delegate.pubkey()),
         };
 
         let data = mpl_bubblegum::instruction::MintV1 {
             message: args.metadata.clone(),
         };
 
-        self.tx_builder(
+        let mut tx = self.tx_builder(
             accounts,
             data,
             args.owner.pubkey(),
             &[tree_delegate, &args.owner],
-        )
+        );
+        tx.set_additional_accounts(&self.feature_flags_companions());
+        tx
     }
 
     // This assumes the owner is the account paying for the tx. We can make things
@@ -432,216 +1494,1709 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_B
         self.mint_v1_tx(tree_delegate, args).execute().await
     }
 
-    pub async fn decode_root(&self) -> Result<[u8; 32]> {
-        let mut tree_account = self.read_account(self.tree_pubkey()).await?;
+    // Mints the given leaves with up to `parallelism` transactions in flight at a time, each
+    // paid for by the owner of the leaf. The nonces are assigned in the order the mints are
+    // processed, so they're read back from the logged leaves. The consistency of the tree is
+    // only checked at the end, since it changes while other transactions are in flight.
+    pub async fn mint_concurrent(&self, leaves: &mut [LeafArgs], parallelism: usize) -> Result<()> {
+        for batch in leaves.chunks_mut(parallelism.max(1)) {
+            let txs: Vec<_> = batch
+                .iter()
+                .map(|leaf| {
+                    let mut tx = self.mint_v1_tx(&self.tree_delegate, leaf);
+                    tx.set_check_consistency(false);
+                    tx
+                })
+                .collect();
+            let results = join_all(txs.iter().map(|tx| tx.execute_with_logs())).await;
+
+            for (leaf, logs) in batch.iter_mut().zip(results) {
+                let ops = parse_logs(&logs?);
+                leaf.nonce = ops
+                    .iter()
+                    .flat_map(|op| op.leaves())
+                    .map(|schema| schema.nonce())
+                    .next()
+                    .ok_or_else(|| {
+                        Error::InconsistentTreeState(vec!["missing minted leaf".to_owned()])
+                    })?;
+            }
+        }
+        self.assert_consistent().await
+    }
 
-        let merkle_tree_bytes = tree_account.data.as_mut_slice();
-        let (_header_bytes, rest) =
-            merkle_tree_bytes.split_at_mut(size_of::<ConcurrentMerkleTreeHeader>());
+    pub fn mint_v1_with_receipt_tx(
+        &self,
+        tree_delegate: &Keypair,
+        args: &LeafArgs,
+    ) -> MintV1WithReceiptBuilder {
+        let accounts = mpl_bubblegum::accounts::MintV1WithReceipt {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            payer: args.owner.pubkey(),
+            tree_delegate: tree_delegate.pubkey(),
+            asset_receipt: self.asset_receipt(args.nonce),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            system_program: system_program::id(),
+            tree_delegate_record: self.tree_delegate_record(&tree_delegate.pubkey()),
+            feature_flags: feature_flags(),
+        };
 
-        let merkle_tree_size = size_of::<ConcurrentMerkleTree<MAX_DEPTH, MAX_BUFFER_SIZE>>();
-        let tree_bytes = &mut rest[..merkle_tree_size];
+        let data = mpl_bubblegum::instruction::MintV1WithReceipt {
+            message: args.metadata.clone(),
+        };
 
-        let tree = try_from_bytes::<ConcurrentMerkleTree<MAX_DEPTH, MAX_BUFFER_SIZE>>(tree_bytes)
-            .map_err(Error::BytemuckPod)?;
-        let root = tree.change_logs[tree.active_index as usize].root;
+        self.tx_builder(
+            accounts,
+            data,
+            args.owner.pubkey(),
+            &[tree_delegate, &args.owner],
+        )
+    }
 
-        Ok(root)
+    pub async fn mint_v1_with_receipt(
+        &self,
+        tree_delegate: &Keypair,
+        args: &LeafArgs,
+    ) -> Result<()> {
+        self.mint_v1_with_receipt_tx(tree_delegate, args)
+            .execute()
+            .await
     }
 
-    // This is currently async due to calling `decode_root` (same goes for a bunch of others).
-    pub async fn burn_tx(&self, args: &LeafArgs) -> Result<BurnBuilder> {
+    pub async fn close_asset_receipt_tx(
+        &self,
+        args: &LeafArgs,
+        burned: bool,
+    ) -> Result<CloseAssetReceiptBuilder> {
         let root = self.decode_root().await?;
 
-        let (data_hash, creator_hash) = compute_metadata_hashes(&args.metadata)?;
-
-        let accounts = mpl_bubblegum::accounts::Burn {
-            tree_authority: self.authority(),
-            log_wrapper: spl_noop::id(),
-            compression_program: spl_account_compression::id(),
+        let accounts = mpl_bubblegum::accounts::CloseAssetReceipt {
             leaf_owner: args.owner.pubkey(),
             leaf_delegate: args.delegate.pubkey(),
             merkle_tree: self.tree_pubkey(),
+            asset_receipt: self.asset_receipt(args.nonce),
+            compression_program: spl_account_compression::id(),
+            burn_receipt: self.burn_receipt(args.nonce),
         };
 
-        let data = mpl_bubblegum::instruction::Burn {
+        let data = mpl_bubblegum::instruction::CloseAssetReceipt {
             root,
-            data_hash,
-            creator_hash,
-            nonce: args.nonce,
-            index: args.index,
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+            index: args.index(),
+            burned,
         };
 
-        Ok(self.tx_builder(accounts, data, args.owner.pubkey(), &[&args.owner]))
+        Ok(self.leaf_tx_builder(
+            accounts,
+            data,
+            args.index(),
+            args.owner.pubkey(),
+            &[&args.owner],
+        ))
     }
 
-    pub async fn burn(&self, args: &LeafArgs) -> Result<()> {
-        self.burn_tx(args).await?.execute().await
+    pub async fn close_asset_receipt(&self, args: &LeafArgs, burned: bool) -> Result<()> {
+        self.close_asset_receipt_tx(args, burned)
+            .await?
+            .execute()
+            .await
     }
 
-    pub async fn verify_creator_tx(
+    // Mints an asset with the given metadata to each of the owners, paid for by the tree
+    // delegate.
+    pub fn mint_airdrop_tx(
         &self,
-        args: &LeafArgs,
-        creator: &Keypair,
-    ) -> Result<VerifyCreatorBuilder> {
-        let root = self.decode_root().await?;
-        let (data_hash, creator_hash) = compute_metadata_hashes(&args.metadata)?;
-
-        let accounts = mpl_bubblegum::accounts::CreatorVerification {
+        tree_delegate: &Keypair,
+        metadata: &MetadataArgs,
+        owners: &[Pubkey],
+    ) -> MintAirdropBuilder {
+        let accounts = mpl_bubblegum::accounts::MintAirdrop {
             tree_authority: self.authority(),
-            leaf_owner: args.owner.pubkey(),
-            leaf_delegate: args.delegate.pubkey(),
-            payer: creator.pubkey(),
-            creator: creator.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            payer: tree_delegate.pubkey(),
+            tree_delegate: tree_delegate.pubkey(),
             log_wrapper: spl_noop::id(),
             compression_program: spl_account_compression::id(),
-            merkle_tree: self.tree_pubkey(),
+            tree_delegate_record: self.tree_delegate_record(&tree_delegate.pubkey()),
+            feature_flags: feature_flags(),
         };
 
-        let data = mpl_bubblegum::instruction::VerifyCreator {
-            root,
-            data_hash,
-            creator_hash,
-            nonce: args.nonce,
-            index: args.index,
-            message: args.metadata.clone(),
+        let data = mpl_bubblegum::instruction::MintAirdrop {
+            message: metadata.clone(),
+            owners: owners.to_vec(),
         };
 
-        Ok(self.tx_builder(accounts, data, creator.pubkey(), &[creator]))
+        self.tx_builder(accounts, data, tree_delegate.pubkey(), &[tree_delegate])
     }
 
-    pub async fn verify_creator(&self, args: &LeafArgs, creator: &Keypair) -> Result<()> {
-        self.verify_creator_tx(args, creator).await?.execute().await
+    pub async fn mint_airdrop(
+        &self,
+        tree_delegate: &Keypair,
+        metadata: &MetadataArgs,
+        owners: &[Pubkey],
+    ) -> Result<()> {
+        self.mint_airdrop_tx(tree_delegate, metadata, owners)
+            .execute()
+            .await
     }
 
-    pub async fn unverify_creator_tx(
+    // Mints an asset for each of the messages, all owned by `owner`, which is also the payer.
+    pub fn mint_batch_v1_tx(
         &self,
-        args: &LeafArgs,
-        creator: &Keypair,
-    ) -> Result<UnverifyCreatorBuilder> {
-        let root = self.decode_root().await?;
-        let (data_hash, creator_hash) = compute_metadata_hashes(&args.metadata)?;
-
-        let accounts = mpl_bubblegum::accounts::CreatorVerification {
+        tree_delegate: &Keypair,
+        owner: &Keypair,
+        messages: &[MetadataArgs],
+    ) -> MintBatchV1Builder {
+        let accounts = mpl_bubblegum::accounts::MintV1 {
             tree_authority: self.authority(),
-            leaf_owner: args.owner.pubkey(),
-            leaf_delegate: args.delegate.pubkey(),
-            payer: creator.pubkey(),
-            creator: creator.pubkey(),
+            tree_delegate: tree_delegate.pubkey(),
+            payer: owner.pubkey(),
             log_wrapper: spl_noop::id(),
             compression_program: spl_account_compression::id(),
+            leaf_owner: owner.pubkey(),
+            leaf_delegate: owner.pubkey(),
             merkle_tree: self.tree_pubkey(),
+            tree_delegate_record: self.tree_delegate_record(&tree_delegate.pubkey()),
         };
 
-        let data = mpl_bubblegum::instruction::UnverifyCreator {
-            root,
-            data_hash,
-            creator_hash,
-            nonce: args.nonce,
-            index: args.index,
-            message: args.metadata.clone(),
+        let data = mpl_bubblegum::instruction::MintBatchV1 {
+            messages: messages.to_vec(),
         };
 
-        Ok(self.tx_builder(accounts, data, creator.pubkey(), &[creator]))
+        let mut tx = self.tx_builder(accounts, data, owner.pubkey(), &[tree_delegate, owner]);
+        tx.set_additional_accounts(&self.feature_flags_companions());
+        tx
     }
 
-    pub async fn unverify_creator(&self, args: &LeafArgs, creator: &Keypair) -> Result<()> {
-        self.unverify_creator_tx(args, creator)
-            .await?
+    pub async fn mint_batch_v1(
+        &self,
+        tree_delegate: &Keypair,
+        owner: &Keypair,
+        messages: &[MetadataArgs],
+    ) -> Result<()> {
+        self.mint_batch_v1_tx(tree_delegate, owner, messages)
             .execute()
             .await
     }
 
-    pub async fn transfer_tx(
+    // The tree delegate is also the collection authority, and `collection_mint` and
+    // `collection_metadata` usually come from `BubblegumTestContext::create_collection`.
+    pub fn mint_to_collection_v1_tx(
         &self,
+        tree_delegate: &Keypair,
         args: &LeafArgs,
-        new_leaf_owner: Pubkey,
-    ) -> Result<TransferBuilder> {
-        let root = self.decode_root().await?;
-        let (data_hash, creator_hash) = compute_metadata_hashes(&args.metadata)?;
-
-        let accounts = mpl_bubblegum::accounts::Transfer {
+        collection_mint: Pubkey,
+        collection_metadata: Pubkey,
+    ) -> MintToCollectionV1Builder {
+        let accounts = mpl_bubblegum::accounts::MintToCollectionV1 {
             tree_authority: self.authority(),
             leaf_owner: args.owner.pubkey(),
             leaf_delegate: args.delegate.pubkey(),
-            new_leaf_owner,
+            merkle_tree: self.tree_pubkey(),
+            payer: args.owner.pubkey(),
+            tree_delegate: tree_delegate.pubkey(),
+            collection_authority: tree_delegate.pubkey(),
+            collection_authority_record_pda: mpl_bubblegum::id(),
+            collection_mint,
+            collection_metadata,
+            edition_account: mpl_token_metadata::pda::find_master_edition_account(&collection_mint)
+                .0,
+            bubblegum_signer: pda::find_collection_cpi_signer(),
             log_wrapper: spl_noop::id(),
             compression_program: spl_account_compression::id(),
-            merkle_tree: self.tree_pubkey(),
+            token_metadata_program: mpl_token_metadata::id(),
+            tree_delegate_record: self.tree_delegate_record(&tree_delegate.pubkey()),
+            feature_flags: feature_flags(),
         };
 
-        let data = mpl_bubblegum::instruction::Transfer {
-            root,
-            data_hash,
-            creator_hash,
-            nonce: args.nonce,
-            index: args.index,
+        let data = mpl_bubblegum::instruction::MintToCollectionV1 {
+            message: args.metadata.clone(),
         };
 
-        Ok(self.tx_builder(accounts, data, args.owner.pubkey(), &[&args.owner]))
+        self.tx_builder(
+            accounts,
+            data,
+            args.owner.pubkey(),
+            &[tree_delegate, &args.owner],
+        )
     }
 
-    pub async fn transfer(&self, args: &LeafArgs, new_owner: Pubkey) -> Result<()> {
-        self.transfer_tx(args, new_owner).await?.execute().await
+    pub async fn mint_to_collection_v1(
+        &self,
+        tree_delegate: &Keypair,
+        args: &LeafArgs,
+        collection_mint: Pubkey,
+        collection_metadata: Pubkey,
+    ) -> Result<()> {
+        self.mint_to_collection_v1_tx(tree_delegate, args, collection_mint, collection_metadata)
+            .execute()
+            .await
     }
 
-    pub async fn delegate_tx(
+    // Mints the asset described by `args`, but only sends the hash of its metadata along with
+    // the fields required in the clear.
+    pub fn mint_v1_hashed_tx(
         &self,
+        tree_delegate: &Keypair,
         args: &LeafArgs,
-        new_leaf_delegate: Pubkey,
-    ) -> Result<DelegateBuilder> {
-        let root = self.decode_root().await?;
-        let (data_hash, creator_hash) = compute_metadata_hashes(&args.metadata)?;
-
-        let accounts = mpl_bubblegum::accounts::Delegate {
+    ) -> Result<MintV1HashedBuilder> {
+        let accounts = mpl_bubblegum::accounts::MintV1 {
             tree_authority: self.authority(),
-            leaf_owner: args.owner.pubkey(),
-            previous_leaf_delegate: args.delegate.pubkey(),
-            new_leaf_delegate,
+            tree_delegate: tree_delegate.pubkey(),
+            payer: args.owner.pubkey(),
             log_wrapper: spl_noop::id(),
             compression_program: spl_account_compression::id(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
             merkle_tree: self.tree_pubkey(),
+            tree_delegate_record: self.tree_delegate_record(&tree_delegate.pubkey()),
         };
 
-        let data = mpl_bubblegum::instruction::Delegate {
-            root,
-            data_hash,
-            creator_hash,
-            nonce: args.nonce,
-            index: args.index,
+        let data = mpl_bubblegum::instruction::MintV1Hashed {
+            message: HashedMetadataArgs {
+                name: args.metadata.name.clone(),
+                symbol: args.metadata.symbol.clone(),
+                seller_fee_basis_points: args.metadata.seller_fee_basis_points,
+                creators: args.metadata.creators.clone(),
+                metadata_args_hash: hash_metadata_args(&args.metadata).map_err(Error::Anchor)?,
+            },
         };
 
-        Ok(self.tx_builder(accounts, data, args.owner.pubkey(), &[&args.owner]))
+        let mut tx = self.tx_builder(
+            accounts,
+            data,
+            args.owner.pubkey(),
+            &[tree_delegate, &args.owner],
+        );
+        tx.set_additional_accounts(&self.feature_flags_companions());
+        Ok(tx)
     }
 
-    // Does the prev delegate need to sign as well?
-    pub async fn delegate(&self, args: &LeafArgs, new_delegate: Pubkey) -> Result<()> {
-        self.delegate_tx(args, new_delegate).await?.execute().await
+    pub async fn mint_v1_hashed(&self, tree_delegate: &Keypair, args: &LeafArgs) -> Result<()> {
+        self.mint_v1_hashed_tx(tree_delegate, args)?.execute().await
+    }
+
+    pub async fn decode_root(&self) -> Result<[u8; 32]> {
+        let tree_account = self.read_account(self.tree_pubkey()).await?;
+        decode_tree::<MAX_DEPTH, MAX_BUFFER_SIZE>(&tree_account.data).map(|state| state.root)
+    }
+
+    // Checks that the on-chain root, rightmost index, sequence number, and mint count of the
+    // tree match the ones tracked by the harness. This already happens after every successful
+    // execution of a builder, unless explicitly disabled.
+    pub async fn assert_consistent(&self) -> Result<()> {
+        check_consistency(&mut self.client(), &self.replay).await
+    }
+
+    // This is currently async due to calling `decode_root` (same goes for a bunch of others).
+    pub async fn burn_tx(&self, args: &LeafArgs) -> Result<BurnBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::Burn {
+            tree_authority: self.authority(),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            voucher: self.voucher(args.nonce),
+            delegate_expiry: self.delegate_expiry(args.nonce),
+            leaf_freeze: self.leaf_freeze(args.nonce),
+        };
+
+        let data = mpl_bubblegum::instruction::Burn {
+            root,
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+            nonce: args.nonce,
+            index: args.index(),
+        };
+
+        Ok(self.leaf_tx_builder(
+            accounts,
+            data,
+            args.index(),
+            args.owner.pubkey(),
+            &[&args.owner],
+        ))
+    }
+
+    pub async fn burn(&self, args: &LeafArgs) -> Result<()> {
+        self.burn_tx(args).await?.execute().await
+    }
+
+    // The leaf owner also pays for the receipt by default.
+    pub async fn burn_with_receipt_tx(&self, args: &LeafArgs) -> Result<BurnWithReceiptBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::BurnWithReceipt {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            voucher: self.voucher(args.nonce),
+            delegate_expiry: self.delegate_expiry(args.nonce),
+            payer: args.owner.pubkey(),
+            burn_receipt: self.burn_receipt(args.nonce),
+            system_program: system_program::id(),
+            leaf_freeze: self.leaf_freeze(args.nonce),
+        };
+
+        let data = mpl_bubblegum::instruction::BurnWithReceipt {
+            root,
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+            nonce: args.nonce,
+            index: args.index(),
+        };
+
+        Ok(self.leaf_tx_builder(
+            accounts,
+            data,
+            args.index(),
+            args.owner.pubkey(),
+            &[&args.owner],
+        ))
+    }
+
+    pub async fn burn_with_receipt(&self, args: &LeafArgs) -> Result<()> {
+        self.burn_with_receipt_tx(args).await?.execute().await
+    }
+
+    pub fn close_burn_receipt_tx(&self, owner: &Keypair, nonce: u64) -> CloseBurnReceiptBuilder {
+        let accounts = mpl_bubblegum::accounts::CloseBurnReceipt {
+            owner: owner.pubkey(),
+            burn_receipt: self.burn_receipt(nonce),
+        };
+
+        let data = mpl_bubblegum::instruction::CloseBurnReceipt {};
+
+        self.tx_builder(accounts, data, owner.pubkey(), &[owner])
+    }
+
+    pub async fn close_burn_receipt(&self, owner: &Keypair, nonce: u64) -> Result<()> {
+        self.close_burn_receipt_tx(owner, nonce).execute().await
+    }
+
+    pub fn close_delegate_expiry_tx(
+        &self,
+        owner: &Keypair,
+        nonce: u64,
+    ) -> CloseDelegateExpiryBuilder {
+        let accounts = mpl_bubblegum::accounts::CloseDelegateExpiry {
+            owner: owner.pubkey(),
+            delegate_expiry: self.delegate_expiry(nonce),
+        };
+
+        let data = mpl_bubblegum::instruction::CloseDelegateExpiry {};
+
+        self.tx_builder(accounts, data, owner.pubkey(), &[owner])
+    }
+
+    pub async fn close_delegate_expiry(&self, owner: &Keypair, nonce: u64) -> Result<()> {
+        self.close_delegate_expiry_tx(owner, nonce).execute().await
+    }
+
+    pub async fn redeem_tx(&self, args: &LeafArgs) -> Result<RedeemBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::Redeem {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            voucher: self.voucher(args.nonce),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            soulbound_override: self.soulbound_override(args.nonce),
+            system_program: system_program::id(),
+            leaf_freeze: self.leaf_freeze(args.nonce),
+        };
+
+        let data = mpl_bubblegum::instruction::Redeem {
+            root,
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+            nonce: args.nonce,
+            index: args.index(),
+        };
+
+        let mut tx = self.tx_builder(accounts, data, args.owner.pubkey(), &[&args.owner]);
+        let mut remaining = self.deny_list_companions();
+        remaining.extend(self.proof(args.index()));
+        tx.set_additional_accounts(&remaining);
+        Ok(tx)
+    }
+
+    pub async fn redeem(&self, args: &LeafArgs) -> Result<()> {
+        self.redeem_tx(args).await?.execute().await
+    }
+
+    pub async fn cancel_redeem_tx(&self, args: &LeafArgs) -> Result<CancelRedeemBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::CancelRedeem {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            voucher: self.voucher(args.nonce),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::CancelRedeem { root };
+
+        Ok(self.leaf_tx_builder(
+            accounts,
+            data,
+            args.index(),
+            args.owner.pubkey(),
+            &[&args.owner],
+        ))
+    }
+
+    pub async fn cancel_redeem(&self, args: &LeafArgs) -> Result<()> {
+        self.cancel_redeem_tx(args).await?.execute().await
+    }
+
+    pub async fn verify_creator_tx(
+        &self,
+        args: &LeafArgs,
+        creator: &Keypair,
+    ) -> Result<VerifyCreatorBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::CreatorVerification {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            payer: creator.pubkey(),
+            creator: creator.pubkey(),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            merkle_tree: self.tree_pubkey(),
+        };
+
+        let data = mpl_bubblegum::instruction::VerifyCreator {
+            root,
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+            nonce: args.nonce,
+            index: args.index(),
+            message: args.metadata.clone(),
+        };
+
+        Ok(self.leaf_tx_builder(accounts, data, args.index(), creator.pubkey(), &[creator]))
+    }
+
+    pub async fn verify_creator(&self, args: &LeafArgs, creator: &Keypair) -> Result<()> {
+        self.verify_creator_tx(args, creator).await?.execute().await
+    }
+
+    pub async fn unverify_creator_tx(
+        &self,
+        args: &LeafArgs,
+        creator: &Keypair,
+    ) -> Result<UnverifyCreatorBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::CreatorVerification {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            payer: creator.pubkey(),
+            creator: creator.pubkey(),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            merkle_tree: self.tree_pubkey(),
+        };
+
+        let data = mpl_bubblegum::instruction::UnverifyCreator {
+            root,
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+            nonce: args.nonce,
+            index: args.index(),
+            message: args.metadata.clone(),
+        };
+
+        Ok(self.leaf_tx_builder(accounts, data, args.index(), creator.pubkey(), &[creator]))
+    }
+
+    pub async fn unverify_creator(&self, args: &LeafArgs, creator: &Keypair) -> Result<()> {
+        self.unverify_creator_tx(args, creator)
+            .await?
+            .execute()
+            .await
+    }
+
+    // `authority` must be the tree creator or delegate, and the update is signed by the owner
+    // of the leaf as well.
+    pub async fn update_metadata_tx(
+        &self,
+        args: &LeafArgs,
+        authority: &Keypair,
+        new_metadata: MetadataArgs,
+    ) -> Result<UpdateMetadataBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::UpdateMetadata {
+            tree_authority: self.authority(),
+            authority: authority.pubkey(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::UpdateMetadata {
+            root,
+            nonce: args.nonce,
+            index: args.index(),
+            current_metadata: args.metadata.clone(),
+            new_metadata,
+        };
+
+        Ok(self.leaf_tx_builder(
+            accounts,
+            data,
+            args.index(),
+            authority.pubkey(),
+            &[authority, &args.owner],
+        ))
+    }
+
+    pub async fn update_metadata(
+        &self,
+        args: &LeafArgs,
+        authority: &Keypair,
+        new_metadata: MetadataArgs,
+    ) -> Result<()> {
+        self.update_metadata_tx(args, authority, new_metadata)
+            .await?
+            .execute()
+            .await
+    }
+
+    pub fn propose_metadata_update_tx(
+        &self,
+        args: &LeafArgs,
+        authority: &Keypair,
+        new_metadata: MetadataArgs,
+    ) -> ProposeMetadataUpdateBuilder {
+        let accounts = mpl_bubblegum::accounts::ProposeMetadataUpdate {
+            tree_authority: self.authority(),
+            authority: authority.pubkey(),
+            metadata_proposal: self.metadata_proposal(args.nonce),
+            merkle_tree: self.tree_pubkey(),
+            system_program: system_program::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::ProposeMetadataUpdate {
+            nonce: args.nonce,
+            new_metadata,
+        };
+
+        self.tx_builder(accounts, data, authority.pubkey(), &[authority])
+    }
+
+    pub async fn propose_metadata_update(
+        &self,
+        args: &LeafArgs,
+        authority: &Keypair,
+        new_metadata: MetadataArgs,
+    ) -> Result<()> {
+        self.propose_metadata_update_tx(args, authority, new_metadata)
+            .execute()
+            .await
+    }
+
+    // Approved by the owner of the leaf, who also pays for the transaction.
+    pub async fn approve_metadata_update_tx(
+        &self,
+        args: &LeafArgs,
+        proposer: Pubkey,
+        new_metadata: MetadataArgs,
+    ) -> Result<ApproveMetadataUpdateBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::ApproveMetadataUpdate {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            metadata_proposal: self.metadata_proposal(args.nonce),
+            proposer,
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::ApproveMetadataUpdate {
+            root,
+            nonce: args.nonce,
+            index: args.index(),
+            current_metadata: args.metadata.clone(),
+            new_metadata,
+        };
+
+        Ok(self.leaf_tx_builder(
+            accounts,
+            data,
+            args.index(),
+            args.owner.pubkey(),
+            &[&args.owner],
+        ))
+    }
+
+    pub async fn approve_metadata_update(
+        &self,
+        args: &LeafArgs,
+        proposer: Pubkey,
+        new_metadata: MetadataArgs,
+    ) -> Result<()> {
+        self.approve_metadata_update_tx(args, proposer, new_metadata)
+            .await?
+            .execute()
+            .await
+    }
+
+    pub fn cancel_metadata_proposal_tx(
+        &self,
+        args: &LeafArgs,
+        proposer: &Keypair,
+    ) -> CancelMetadataProposalBuilder {
+        let accounts = mpl_bubblegum::accounts::CancelMetadataProposal {
+            metadata_proposal: self.metadata_proposal(args.nonce),
+            proposer: proposer.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+        };
+
+        let data = mpl_bubblegum::instruction::CancelMetadataProposal { nonce: args.nonce };
+
+        self.tx_builder(accounts, data, proposer.pubkey(), &[proposer])
+    }
+
+    pub async fn cancel_metadata_proposal(
+        &self,
+        args: &LeafArgs,
+        proposer: &Keypair,
+    ) -> Result<()> {
+        self.cancel_metadata_proposal_tx(args, proposer)
+            .execute()
+            .await
+    }
+
+    pub async fn transfer_tx(
+        &self,
+        args: &LeafArgs,
+        new_leaf_owner: Pubkey,
+    ) -> Result<TransferBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::Transfer {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            new_leaf_owner,
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            merkle_tree: self.tree_pubkey(),
+            voucher: self.voucher(args.nonce),
+            soulbound_override: self.soulbound_override(args.nonce),
+            delegate_expiry: self.delegate_expiry(args.nonce),
+            leaf_freeze: self.leaf_freeze(args.nonce),
+        };
+
+        let data = mpl_bubblegum::instruction::Transfer {
+            root,
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+            nonce: args.nonce,
+            index: args.index(),
+        };
+
+        let mut tx = self.tx_builder(accounts, data, args.owner.pubkey(), &[&args.owner]);
+        let mut remaining = self.transfer_companions();
+        remaining.extend(self.proof(args.index()));
+        tx.set_additional_accounts(&remaining);
+        Ok(tx)
+    }
+
+    pub async fn transfer(&self, args: &LeafArgs, new_owner: Pubkey) -> Result<()> {
+        self.transfer_tx(args, new_owner).await?.execute().await
+    }
+
+    // Transfers each of the leaves, which must all be owned by `owner`, to the paired owner.
+    pub async fn transfer_batch_tx(
+        &self,
+        owner: &Keypair,
+        transfers: &[(&LeafArgs, Pubkey)],
+    ) -> Result<TransferBatchBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::TransferBatch {
+            tree_authority: self.authority(),
+            leaf_owner: owner.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            tree_deny_list: self.tree_deny_list(),
+            deny_list: self.deny_list,
+            feature_flags: feature_flags(),
+        };
+
+        let mut batch = Vec::new();
+        let mut additional_accounts = Vec::new();
+        for (args, new_leaf_owner) in transfers {
+            let proof = self.proof(args.index());
+            batch.push(BatchTransfer {
+                root,
+                data_hash: args.data_hash,
+                creator_hash: args.creator_hash,
+                nonce: args.nonce,
+                index: args.index(),
+                leaf_delegate: args.delegate.pubkey(),
+                new_leaf_owner: *new_leaf_owner,
+                proof_length: u8::try_from(proof.len()).unwrap(),
+            });
+            additional_accounts.push(self.soulbound_override(args.nonce));
+            additional_accounts.push(self.leaf_freeze(args.nonce));
+            additional_accounts.extend(proof);
+        }
+
+        let data = mpl_bubblegum::instruction::TransferBatch { transfers: batch };
+
+        let mut builder = self.tx_builder(accounts, data, owner.pubkey(), &[owner]);
+        builder.set_additional_accounts(&additional_accounts);
+        Ok(builder)
+    }
+
+    pub async fn transfer_batch(
+        &self,
+        owner: &Keypair,
+        transfers: &[(&LeafArgs, Pubkey)],
+    ) -> Result<()> {
+        self.transfer_batch_tx(owner, transfers)
+            .await?
+            .execute()
+            .await
+    }
+
+    pub async fn transfer_timelocked_tx(
+        &self,
+        args: &LeafArgs,
+        new_leaf_owner: Pubkey,
+        unlock_slot: u64,
+    ) -> Result<TransferTimelockedBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::TransferTimelocked {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            new_leaf_owner,
+            merkle_tree: self.tree_pubkey(),
+            pending_transfer: self.pending_transfer(args.nonce),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            soulbound_override: self.soulbound_override(args.nonce),
+            system_program: system_program::id(),
+            leaf_freeze: self.leaf_freeze(args.nonce),
+            tree_deny_list: self.tree_deny_list(),
+            deny_list: self.deny_list,
+            feature_flags: feature_flags(),
+        };
+
+        let data = mpl_bubblegum::instruction::TransferTimelocked {
+            root,
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+            nonce: args.nonce,
+            index: args.index(),
+            unlock_slot,
+        };
+
+        Ok(self.leaf_tx_builder(
+            accounts,
+            data,
+            args.index(),
+            args.owner.pubkey(),
+            &[&args.owner],
+        ))
+    }
+
+    pub async fn transfer_timelocked(
+        &self,
+        args: &LeafArgs,
+        new_leaf_owner: Pubkey,
+        unlock_slot: u64,
+    ) -> Result<()> {
+        self.transfer_timelocked_tx(args, new_leaf_owner, unlock_slot)
+            .await?
+            .execute()
+            .await
+    }
+
+    pub async fn claim_timelocked_transfer_tx(
+        &self,
+        args: &LeafArgs,
+        new_leaf_owner: &Keypair,
+    ) -> Result<ClaimTimelockedTransferBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::ClaimTimelockedTransfer {
+            tree_authority: self.authority(),
+            new_leaf_owner: new_leaf_owner.pubkey(),
+            leaf_owner: args.owner.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            pending_transfer: self.pending_transfer(args.nonce),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            tree_deny_list: self.tree_deny_list(),
+            deny_list: self.deny_list,
+            feature_flags: feature_flags(),
+        };
+
+        let data = mpl_bubblegum::instruction::ClaimTimelockedTransfer { root };
+
+        Ok(self.leaf_tx_builder(
+            accounts,
+            data,
+            args.index(),
+            new_leaf_owner.pubkey(),
+            &[new_leaf_owner],
+        ))
+    }
+
+    pub async fn claim_timelocked_transfer(
+        &self,
+        args: &LeafArgs,
+        new_leaf_owner: &Keypair,
+    ) -> Result<()> {
+        self.claim_timelocked_transfer_tx(args, new_leaf_owner)
+            .await?
+            .execute()
+            .await
+    }
+
+    pub async fn cancel_timelocked_transfer_tx(
+        &self,
+        args: &LeafArgs,
+    ) -> Result<CancelTimelockedTransferBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::CancelTimelockedTransfer {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            pending_transfer: self.pending_transfer(args.nonce),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::CancelTimelockedTransfer { root };
+
+        Ok(self.leaf_tx_builder(
+            accounts,
+            data,
+            args.index(),
+            args.owner.pubkey(),
+            &[&args.owner],
+        ))
+    }
+
+    pub async fn cancel_timelocked_transfer(&self, args: &LeafArgs) -> Result<()> {
+        self.cancel_timelocked_transfer_tx(args)
+            .await?
+            .execute()
+            .await
+    }
+
+    pub async fn delegate_tx(
+        &self,
+        args: &LeafArgs,
+        new_leaf_delegate: Pubkey,
+    ) -> Result<DelegateBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::Delegate {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            previous_leaf_delegate: args.delegate.pubkey(),
+            new_leaf_delegate,
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            merkle_tree: self.tree_pubkey(),
+            voucher: self.voucher(args.nonce),
+            leaf_freeze: self.leaf_freeze(args.nonce),
+        };
+
+        let data = mpl_bubblegum::instruction::Delegate {
+            root,
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+            nonce: args.nonce,
+            index: args.index(),
+        };
+
+        Ok(self.leaf_tx_builder(
+            accounts,
+            data,
+            args.index(),
+            args.owner.pubkey(),
+            &[&args.owner],
+        ))
+    }
+
+    // Does the prev delegate need to sign as well?
+    pub async fn delegate(&self, args: &LeafArgs, new_delegate: Pubkey) -> Result<()> {
+        self.delegate_tx(args, new_delegate).await?.execute().await
+    }
+
+    pub async fn delegate_with_expiry_tx(
+        &self,
+        args: &LeafArgs,
+        new_leaf_delegate: Pubkey,
+        expiry_slot: Option<u64>,
+    ) -> Result<DelegateWithExpiryBuilder> {
+        let root = self.decode_root().await?;
+        let previous_expiry_owner = match self.read_delegate_expiry(args.nonce).await {
+            Ok(record) => record.owner,
+            Err(_) => args.owner.pubkey(),
+        };
+
+        let accounts = mpl_bubblegum::accounts::DelegateWithExpiry {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            previous_leaf_delegate: args.delegate.pubkey(),
+            new_leaf_delegate,
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            merkle_tree: self.tree_pubkey(),
+            voucher: self.voucher(args.nonce),
+            delegate_expiry: self.delegate_expiry(args.nonce),
+            system_program: system_program::id(),
+            leaf_freeze: self.leaf_freeze(args.nonce),
+            previous_expiry_owner,
+        };
+
+        let data = mpl_bubblegum::instruction::DelegateWithExpiry {
+            root,
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+            nonce: args.nonce,
+            index: args.index(),
+            expiry_slot,
+        };
+
+        Ok(self.leaf_tx_builder(
+            accounts,
+            data,
+            args.index(),
+            args.owner.pubkey(),
+            &[&args.owner],
+        ))
+    }
+
+    pub async fn delegate_with_expiry(
+        &self,
+        args: &LeafArgs,
+        new_delegate: Pubkey,
+        expiry_slot: Option<u64>,
+    ) -> Result<()> {
+        self.delegate_with_expiry_tx(args, new_delegate, expiry_slot)
+            .await?
+            .execute()
+            .await
+    }
+
+    // Freezes the leaf, signed and paid for by its delegate.
+    pub async fn freeze_tx(&self, args: &LeafArgs) -> Result<FreezeBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::Freeze {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            compression_program: spl_account_compression::id(),
+            voucher: self.voucher(args.nonce),
+            delegate_expiry: self.delegate_expiry(args.nonce),
+            leaf_freeze: self.leaf_freeze(args.nonce),
+            system_program: system_program::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::Freeze {
+            root,
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+            nonce: args.nonce,
+            index: args.index(),
+        };
+
+        Ok(self.leaf_tx_builder(
+            accounts,
+            data,
+            args.index(),
+            args.delegate.pubkey(),
+            &[&args.delegate],
+        ))
+    }
+
+    pub async fn freeze(&self, args: &LeafArgs) -> Result<()> {
+        self.freeze_tx(args).await?.execute().await
+    }
+
+    pub fn thaw_tx(&self, args: &LeafArgs) -> ThawBuilder {
+        let accounts = mpl_bubblegum::accounts::Thaw {
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            leaf_freeze: self.leaf_freeze(args.nonce),
+            delegate_expiry: self.delegate_expiry(args.nonce),
+        };
+
+        self.tx_builder(
+            accounts,
+            mpl_bubblegum::instruction::Thaw {},
+            args.delegate.pubkey(),
+            &[&args.delegate],
+        )
+    }
+
+    pub async fn thaw(&self, args: &LeafArgs) -> Result<()> {
+        self.thaw_tx(args).execute().await
     }
 
     pub fn set_tree_delegate_tx(&self, new_tree_delegate: Pubkey) -> SetTreeDelegateBuilder {
         let accounts = mpl_bubblegum::accounts::SetTreeDelegate {
             tree_creator: self.creator_pubkey(),
-            new_tree_delegate,
+            new_tree_delegate,
+            merkle_tree: self.tree_pubkey(),
+            tree_authority: self.authority(),
+        };
+
+        let data = mpl_bubblegum::instruction::SetTreeDelegate;
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn set_tree_delegate(&mut self, new_delegate: &Keypair) -> Result<()> {
+        self.set_tree_delegate_tx(new_delegate.pubkey())
+            .execute()
+            .await?;
+        self.tree_delegate = clone_keypair(new_delegate);
+        Ok(())
+    }
+
+    pub fn propose_tree_creator_tx(&self, new_tree_creator: Pubkey) -> ProposeTreeCreatorBuilder {
+        let accounts = mpl_bubblegum::accounts::ProposeTreeCreator {
+            tree_authority: self.authority(),
+            creator_transfer: self.creator_transfer(),
+            tree_creator: self.creator_pubkey(),
+            new_tree_creator,
+            merkle_tree: self.tree_pubkey(),
+            system_program: system_program::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::ProposeTreeCreator {
+            reset_tree_delegate: false,
+        };
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn propose_tree_creator(&self, new_tree_creator: Pubkey) -> Result<()> {
+        self.propose_tree_creator_tx(new_tree_creator)
+            .execute()
+            .await
+    }
+
+    pub fn accept_tree_creator_tx(&self, new_tree_creator: &Keypair) -> AcceptTreeCreatorBuilder {
+        let accounts = mpl_bubblegum::accounts::AcceptTreeCreator {
+            tree_authority: self.authority(),
+            creator_transfer: self.creator_transfer(),
+            tree_creator: self.creator_pubkey(),
+            new_tree_creator: new_tree_creator.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+        };
+
+        let data = mpl_bubblegum::instruction::AcceptTreeCreator;
+
+        self.tx_builder(
+            accounts,
+            data,
+            new_tree_creator.pubkey(),
+            &[new_tree_creator],
+        )
+    }
+
+    pub async fn accept_tree_creator(&mut self, new_tree_creator: &Keypair) -> Result<()> {
+        self.accept_tree_creator_tx(new_tree_creator)
+            .execute()
+            .await?;
+        self.tree_creator = clone_keypair(new_tree_creator);
+        Ok(())
+    }
+
+    pub fn cancel_tree_creator_transfer_tx(&self) -> CancelTreeCreatorTransferBuilder {
+        let accounts = mpl_bubblegum::accounts::CancelTreeCreatorTransfer {
+            tree_authority: self.authority(),
+            creator_transfer: self.creator_transfer(),
+            tree_creator: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
+        };
+
+        let data = mpl_bubblegum::instruction::CancelTreeCreatorTransfer;
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn cancel_tree_creator_transfer(&self) -> Result<()> {
+        self.cancel_tree_creator_transfer_tx().execute().await
+    }
+
+    // The feature flags are shared by every tree of the deployment; `authority` must be the
+    // upgrade authority of the program (see `BubblegumTestContext::set_upgrade_authority`).
+    // The flags are also applied to this tree, which then requires them as a companion account.
+    pub fn set_feature_flags_tx(
+        &self,
+        authority: &Keypair,
+        disabled_instructions: u64,
+    ) -> SetFeatureFlagsBuilder {
+        let accounts = mpl_bubblegum::accounts::SetFeatureFlags {
+            feature_flags: feature_flags(),
+            authority: authority.pubkey(),
+            program_data: Pubkey::find_program_address(
+                &[mpl_bubblegum::id().as_ref()],
+                &bpf_loader_upgradeable::id(),
+            )
+            .0,
+            system_program: system_program::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::SetFeatureFlags {
+            disabled_instructions,
+        };
+
+        let mut builder = self.tx_builder(accounts, data, authority.pubkey(), &[authority]);
+        builder.set_additional_account_metas(&[AccountMeta::new(self.authority(), false)]);
+        builder
+    }
+
+    pub async fn set_feature_flags(
+        &mut self,
+        authority: &Keypair,
+        disabled_instructions: u64,
+    ) -> Result<()> {
+        self.set_feature_flags_tx(authority, disabled_instructions)
+            .execute()
+            .await?;
+        self.has_feature_flags = true;
+        Ok(())
+    }
+
+    // Sends lamports to the tree authority with a plain system transfer.
+    pub async fn fund_tree_authority(&self, payer: &Keypair, lamports: u64) -> Result<()> {
+        let ix = system_instruction::transfer(&payer.pubkey(), &self.authority(), lamports);
+        self.process_tx(ix, &payer.pubkey(), &[payer]).await
+    }
+
+    pub fn withdraw_from_tree_authority_tx(
+        &self,
+        destination: Pubkey,
+        amount: u64,
+    ) -> WithdrawFromTreeAuthorityBuilder {
+        let accounts = mpl_bubblegum::accounts::WithdrawFromTreeAuthority {
+            tree_authority: self.authority(),
+            tree_creator: self.creator_pubkey(),
+            destination,
+            merkle_tree: self.tree_pubkey(),
+        };
+
+        let data = mpl_bubblegum::instruction::WithdrawFromTreeAuthority { amount };
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn withdraw_from_tree_authority(
+        &self,
+        destination: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        self.withdraw_from_tree_authority_tx(destination, amount)
+            .execute()
+            .await
+    }
+
+    // Returns the lamports held by the tree authority above the rent exemption minimum.
+    pub async fn tree_authority_surplus(&self) -> Result<u64> {
+        let account = self.read_account(self.authority()).await?;
+        let min_balance = self.rent().await?.minimum_balance(account.data.len());
+        Ok(account.lamports.saturating_sub(min_balance))
+    }
+
+    pub async fn balance(&self, key: Pubkey) -> Result<u64> {
+        self.client()
+            .get_balance(key)
+            .await
+            .map_err(Error::BanksClient)
+    }
+
+    pub fn set_tree_hash_only_tx(&self, is_hash_only: bool) -> SetTreeHashOnlyBuilder {
+        let accounts = mpl_bubblegum::accounts::SetTreeHashOnly {
+            tree_authority: self.authority(),
+            tree_creator: self.creator_pubkey(),
             merkle_tree: self.tree_pubkey(),
+        };
+
+        let data = mpl_bubblegum::instruction::SetTreeHashOnly { is_hash_only };
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn set_tree_hash_only(&self, is_hash_only: bool) -> Result<()> {
+        self.set_tree_hash_only_tx(is_hash_only).execute().await
+    }
+
+    pub fn set_logging_mode_tx(&self, logging_mode: LoggingMode) -> SetLoggingModeBuilder {
+        let accounts = mpl_bubblegum::accounts::SetLoggingMode {
             tree_authority: self.authority(),
+            tree_creator: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
         };
 
-        let data = mpl_bubblegum::instruction::SetTreeDelegate;
+        let data = mpl_bubblegum::instruction::SetLoggingMode { logging_mode };
 
         self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
     }
 
-    pub async fn set_tree_delegate(&mut self, new_delegate: &Keypair) -> Result<()> {
-        self.set_tree_delegate_tx(new_delegate.pubkey())
+    pub async fn set_logging_mode(&self, logging_mode: LoggingMode) -> Result<()> {
+        self.set_logging_mode_tx(logging_mode).execute().await
+    }
+
+    pub fn set_tree_non_transferable_tx(
+        &self,
+        is_non_transferable: bool,
+    ) -> SetTreeNonTransferableBuilder {
+        let accounts = mpl_bubblegum::accounts::SetTreeNonTransferable {
+            tree_authority: self.authority(),
+            tree_creator: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
+        };
+
+        let data = mpl_bubblegum::instruction::SetTreeNonTransferable {
+            is_non_transferable,
+        };
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn set_tree_non_transferable(&self, is_non_transferable: bool) -> Result<()> {
+        self.set_tree_non_transferable_tx(is_non_transferable)
             .execute()
-            .await?;
-        self.tree_delegate = clone_keypair(new_delegate);
+            .await
+    }
+
+    pub fn set_tree_public_tx(&self, is_public: bool) -> SetTreePublicBuilder {
+        let accounts = mpl_bubblegum::accounts::SetTreePublic {
+            tree_authority: self.authority(),
+            tree_creator: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
+        };
+
+        let data = mpl_bubblegum::instruction::SetTreePublic { is_public };
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn set_tree_public(&self, is_public: bool) -> Result<()> {
+        self.set_tree_public_tx(is_public).execute().await
+    }
+
+    pub fn set_tree_paused_tx(&self, is_paused: bool) -> SetTreePausedBuilder {
+        let accounts = mpl_bubblegum::accounts::SetTreePaused {
+            tree_authority: self.authority(),
+            tree_creator: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
+        };
+
+        let data = mpl_bubblegum::instruction::SetTreePaused { is_paused };
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn set_tree_paused(&self, is_paused: bool) -> Result<()> {
+        self.set_tree_paused_tx(is_paused).execute().await
+    }
+
+    pub fn set_mint_price_tx(&self, lamports: u64, treasury: Pubkey) -> SetMintPriceBuilder {
+        let accounts = mpl_bubblegum::accounts::SetMintPrice {
+            tree_authority: self.authority(),
+            tree_creator: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            mint_price: self.mint_price(),
+            system_program: system_program::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::SetMintPrice {
+            lamports,
+            treasury,
+            token_mint: Pubkey::default(),
+            token_amount: 0,
+        };
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn set_mint_price(&self, lamports: u64, treasury: Pubkey) -> Result<()> {
+        self.set_mint_price_tx(lamports, treasury).execute().await
+    }
+
+    // Deny lists aren't tied to a tree, but the tree provides the client for the transaction.
+    pub fn create_deny_list_tx(&self, authority: &Keypair) -> CreateDenyListBuilder {
+        let accounts = mpl_bubblegum::accounts::CreateDenyList {
+            deny_list: pda::find_deny_list(&authority.pubkey()),
+            authority: authority.pubkey(),
+            system_program: system_program::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::CreateDenyList;
+
+        self.tx_builder(accounts, data, authority.pubkey(), &[authority])
+    }
+
+    pub async fn create_deny_list(&self, authority: &Keypair) -> Result<()> {
+        self.create_deny_list_tx(authority).execute().await
+    }
+
+    pub fn update_deny_list_tx(
+        &self,
+        authority: &Keypair,
+        add: &[Pubkey],
+        remove: &[Pubkey],
+    ) -> UpdateDenyListBuilder {
+        let accounts = mpl_bubblegum::accounts::UpdateDenyList {
+            deny_list: pda::find_deny_list(&authority.pubkey()),
+            authority: authority.pubkey(),
+        };
+
+        let data = mpl_bubblegum::instruction::UpdateDenyList {
+            add: add.to_vec(),
+            remove: remove.to_vec(),
+        };
+
+        self.tx_builder(accounts, data, authority.pubkey(), &[authority])
+    }
+
+    pub async fn update_deny_list(
+        &self,
+        authority: &Keypair,
+        add: &[Pubkey],
+        remove: &[Pubkey],
+    ) -> Result<()> {
+        self.update_deny_list_tx(authority, add, remove)
+            .execute()
+            .await
+    }
+
+    pub fn set_tree_deny_list_tx(&self, deny_list: Pubkey) -> SetTreeDenyListBuilder {
+        let accounts = mpl_bubblegum::accounts::SetTreeDenyList {
+            tree_authority: self.authority(),
+            tree_creator: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            tree_deny_list: self.tree_deny_list(),
+            system_program: system_program::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::SetTreeDenyList { deny_list };
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn set_tree_deny_list(&mut self, deny_list: Pubkey) -> Result<()> {
+        self.set_tree_deny_list_tx(deny_list).execute().await?;
+        self.deny_list = deny_list;
         Ok(())
     }
 
+    pub async fn read_deny_list(&self, authority: Pubkey) -> Result<DenyList> {
+        self.read_account_data(pda::find_deny_list(&authority))
+            .await
+    }
+
+    pub fn add_tree_delegate_tx(&self, delegate: Pubkey) -> AddTreeDelegateBuilder {
+        let accounts = mpl_bubblegum::accounts::AddTreeDelegate {
+            tree_authority: self.authority(),
+            tree_creator: self.creator_pubkey(),
+            delegate,
+            merkle_tree: self.tree_pubkey(),
+            tree_delegate_record: self.tree_delegate_record(&delegate),
+            system_program: system_program::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::AddTreeDelegate;
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn add_tree_delegate(&self, delegate: Pubkey) -> Result<()> {
+        self.add_tree_delegate_tx(delegate).execute().await
+    }
+
+    pub fn remove_tree_delegate_tx(&self, delegate: Pubkey) -> RemoveTreeDelegateBuilder {
+        let accounts = mpl_bubblegum::accounts::RemoveTreeDelegate {
+            tree_authority: self.authority(),
+            tree_creator: self.creator_pubkey(),
+            delegate,
+            merkle_tree: self.tree_pubkey(),
+            tree_delegate_record: self.tree_delegate_record(&delegate),
+        };
+
+        let data = mpl_bubblegum::instruction::RemoveTreeDelegate;
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn remove_tree_delegate(&self, delegate: Pubkey) -> Result<()> {
+        self.remove_tree_delegate_tx(delegate).execute().await
+    }
+
+    pub async fn read_tree_delegate_record(&self, delegate: Pubkey) -> Result<TreeDelegateRecord> {
+        self.read_account_data(self.tree_delegate_record(&delegate))
+            .await
+    }
+
+    pub fn set_soulbound_override_tx(
+        &self,
+        tree_delegate: &Keypair,
+        nonce: u64,
+        is_non_transferable: bool,
+    ) -> SetSoulboundOverrideBuilder {
+        let accounts = mpl_bubblegum::accounts::SetSoulboundOverride {
+            tree_authority: self.authority(),
+            tree_delegate: tree_delegate.pubkey(),
+            payer: tree_delegate.pubkey(),
+            soulbound_override: self.soulbound_override(nonce),
+            merkle_tree: self.tree_pubkey(),
+            system_program: system_program::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::SetSoulboundOverride {
+            nonce,
+            is_non_transferable,
+        };
+
+        self.tx_builder(accounts, data, tree_delegate.pubkey(), &[tree_delegate])
+    }
+
+    pub async fn set_soulbound_override(
+        &self,
+        tree_delegate: &Keypair,
+        nonce: u64,
+        is_non_transferable: bool,
+    ) -> Result<()> {
+        self.set_soulbound_override_tx(tree_delegate, nonce, is_non_transferable)
+            .execute()
+            .await
+    }
+
+    pub fn set_tree_metadata_tx<T, U>(&self, name: T, uri: U) -> SetTreeMetadataBuilder
+    where
+        T: Display,
+        U: Display,
+    {
+        let accounts = mpl_bubblegum::accounts::SetTreeMetadata {
+            tree_authority: self.authority(),
+            tree_metadata: self.tree_metadata(),
+            tree_creator: self.creator_pubkey(),
+            payer: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            system_program: system_program::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::SetTreeMetadata {
+            name: name.to_string(),
+            uri: uri.to_string(),
+        };
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn set_tree_metadata<T, U>(&self, name: T, uri: U) -> Result<()>
+    where
+        T: Display,
+        U: Display,
+    {
+        self.set_tree_metadata_tx(name, uri).execute().await
+    }
+
+    // Uses the hashes stored in `args`, which can be tweaked via the builder.
+    pub fn verify_metadata_hash_tx(&self, args: &LeafArgs) -> VerifyMetadataHashBuilder {
+        let accounts = mpl_bubblegum::accounts::VerifyMetadataHash {};
+
+        let data = mpl_bubblegum::instruction::VerifyMetadataHash {
+            metadata: args.metadata.clone(),
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+        };
+
+        self.tx_builder(accounts, data, self.creator_pubkey(), &[&self.tree_creator])
+    }
+
+    pub async fn verify_metadata_hash(&self, args: &LeafArgs) -> Result<bool> {
+        self.verify_metadata_hash_tx(args)
+            .execute_with_return()
+            .await?
+            .ok_or_else(|| Error::Io(std::io::ErrorKind::UnexpectedEof.into()))
+    }
+
+    // Allocates the on-chain metadata account for the leaf, sized and hashed for `json`.
+    pub async fn init_on_chain_metadata_tx(
+        &self,
+        creator: &Keypair,
+        args: &LeafArgs,
+        json: &[u8],
+    ) -> Result<InitOnChainMetadataBuilder> {
+        let root = self.decode_root().await?;
+
+        let accounts = mpl_bubblegum::accounts::InitOnChainMetadata {
+            creator: creator.pubkey(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            on_chain_metadata: self.on_chain_metadata(args.nonce),
+            compression_program: spl_account_compression::id(),
+            system_program: system_program::id(),
+        };
+
+        let data = mpl_bubblegum::instruction::InitOnChainMetadata {
+            root,
+            nonce: args.nonce,
+            index: args.index(),
+            metadata: args.metadata.clone(),
+            asset_id: get_asset_id(&self.tree_pubkey(), args.nonce),
+            json_hash: keccak::hash(json).to_bytes(),
+            json_size: u32::try_from(json.len()).unwrap(),
+        };
+
+        Ok(self.leaf_tx_builder(accounts, data, args.index(), creator.pubkey(), &[creator]))
+    }
+
+    pub async fn init_on_chain_metadata(
+        &self,
+        creator: &Keypair,
+        args: &LeafArgs,
+        json: &[u8],
+    ) -> Result<()> {
+        self.init_on_chain_metadata_tx(creator, args, json)
+            .await?
+            .execute()
+            .await
+    }
+
+    pub fn write_metadata_chunk_tx(
+        &self,
+        authority: &Keypair,
+        nonce: u64,
+        offset: u32,
+        chunk: &[u8],
+    ) -> WriteMetadataChunkBuilder {
+        let accounts = mpl_bubblegum::accounts::WriteMetadataChunk {
+            authority: authority.pubkey(),
+            on_chain_metadata: self.on_chain_metadata(nonce),
+        };
+
+        let data = mpl_bubblegum::instruction::WriteMetadataChunk {
+            offset,
+            chunk: chunk.to_vec(),
+        };
+
+        self.tx_builder(accounts, data, authority.pubkey(), &[authority])
+    }
+
+    pub async fn write_metadata_chunk(
+        &self,
+        authority: &Keypair,
+        nonce: u64,
+        offset: u32,
+        chunk: &[u8],
+    ) -> Result<()> {
+        self.write_metadata_chunk_tx(authority, nonce, offset, chunk)
+            .execute()
+            .await
+    }
+
+    // Returns the on-chain metadata header for the leaf, together with the JSON bytes
+    // written so far.
+    pub async fn read_on_chain_metadata(&self, nonce: u64) -> Result<(OnChainMetadata, Vec<u8>)> {
+        let account = self.read_account(self.on_chain_metadata(nonce)).await?;
+        let header = OnChainMetadata::try_deserialize(&mut account.data.as_slice())
+            .map_err(Error::Anchor)?;
+        let start = ON_CHAIN_METADATA_HEADER_SIZE;
+        let json = account.data[start..start + header.bytes_written as usize].to_vec();
+        Ok((header, json))
+    }
+
+    // Returns the Bubblegum operations observed so far for transactions executed via
+    // builders associated with this tree.
+    pub fn replay_ops(&self) -> Vec<BubblegumOp> {
+        self.replay.lock().unwrap().ops.clone()
+    }
+
+    // Yields the Bubblegum operations observed for this tree from now on, as the transactions
+    // executing them complete. Operations observed before subscribing are available via
+    // `replay_ops`. The stream ends once the tree (and every builder created by it) is dropped.
+    pub fn event_stream(&self) -> UnboundedReceiver<BubblegumOp> {
+        let (sender, receiver) = unbounded();
+        self.replay.lock().unwrap().subscribers.push(sender);
+        receiver
+    }
+
+    // Pages through the assets minted into this tree, based on the events captured
+    // for transactions executed via the harness.
+    pub fn list_assets(&self, range: Range<u64>) -> Vec<AssetSummary> {
+        list_assets(&self.tree_pubkey(), &self.replay.lock().unwrap().ops, range)
+    }
+
+    pub fn holder_distribution(&self) -> HolderDistribution {
+        holder_distribution(&self.tree_pubkey(), &self.replay.lock().unwrap().ops)
+    }
+
+    pub fn resolve_owner(&self, nonce: u64) -> Option<ResolvedOwner> {
+        resolve_owner(&self.tree_pubkey(), &self.replay.lock().unwrap().ops, nonce)
+    }
+
+    pub fn mirror_leaf(&self, index: u32) -> [u8; 32] {
+        self.replay.lock().unwrap().mirror.leaf(index)
+    }
+
+    pub fn mirror_root(&self) -> [u8; 32] {
+        self.replay.lock().unwrap().mirror.root()
+    }
+
+    // Returns the proof for the leaf at `index` as a list of pubkeys that can be directly
+    // passed as additional accounts. The nodes stored in the canopy are left out, since they
+    // get filled in by the compression program.
+    pub fn proof(&self, index: u32) -> Vec<Pubkey> {
+        let proof = self.replay.lock().unwrap().mirror.proof(index);
+        proof
+            .into_iter()
+            .take(MAX_DEPTH - self.canopy_depth as usize)
+            .map(Pubkey::new_from_array)
+            .collect()
+    }
+
     // The following methods provide convenience when reading data from accounts.
-    async fn read_account(&self, key: Pubkey) -> Result<Account> {
+    pub async fn read_account(&self, key: Pubkey) -> Result<Account> {
         self.client()
             .get_account(key)
             .await
@@ -663,4 +3218,36 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_B
     pub async fn read_tree_config(&self) -> Result<TreeConfig> {
         self.read_account_data(self.authority()).await
     }
+
+    pub async fn read_tree_version(&self) -> Result<u8> {
+        Ok(self.read_tree_config().await?.program_version)
+    }
+
+    pub async fn read_tree_metadata(&self) -> Result<TreeMetadata> {
+        self.read_account_data(self.tree_metadata()).await
+    }
+
+    pub async fn read_asset_receipt(&self, nonce: u64) -> Result<AssetReceipt> {
+        self.read_account_data(self.asset_receipt(nonce)).await
+    }
+
+    pub async fn read_burn_receipt(&self, nonce: u64) -> Result<BurnReceipt> {
+        self.read_account_data(self.burn_receipt(nonce)).await
+    }
+
+    pub async fn read_delegate_expiry(&self, nonce: u64) -> Result<DelegateExpiry> {
+        self.read_account_data(self.delegate_expiry(nonce)).await
+    }
+
+    pub async fn read_metadata_proposal(&self, nonce: u64) -> Result<MetadataProposal> {
+        self.read_account_data(self.metadata_proposal(nonce)).await
+    }
+
+    pub async fn read_creator_transfer(&self) -> Result<CreatorTransfer> {
+        self.read_account_data(self.creator_transfer()).await
+    }
+
+    pub async fn read_feature_flags(&self) -> Result<FeatureFlags> {
+        self.read_account_data(feature_flags()).await
+    }
 }